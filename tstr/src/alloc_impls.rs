@@ -0,0 +1,142 @@
+//! Conversions from `TStr` to the owned string types of the `alloc` crate.
+
+use crate::{
+    fmt_impls::{write_tstr, WriteRepr},
+    TStr,
+};
+
+use alloc::{borrow::Cow, string::String};
+
+impl<T> TStr<T>
+where
+    T: WriteRepr,
+{
+    /// Allocates a `String` with the string of this `TStr`.
+    ///
+    /// This requires the `"alloc"` feature,
+    /// and works with every representation of type-level strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::ts;
+    ///
+    /// assert_eq!(ts!(foo).to_string(), "foo");
+    /// assert_eq!(ts!("hello world").to_string(), "hello world");
+    ///
+    /// ```
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+    // `TStr` doesn't implement `Display`, so this doesn't shadow `ToString::to_string`
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(self) -> String {
+        let mut out = String::new();
+        let _ = write_tstr::<T, core::convert::Infallible>(&mut |string| {
+            out.push_str(string);
+            Ok(())
+        });
+        out
+    }
+}
+
+/// Allocates a `String` with the string of the `TStr`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::ts;
+///
+/// let string: String = ts!(bar).into();
+/// assert_eq!(string, "bar");
+///
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+impl<T> From<TStr<T>> for String
+where
+    T: WriteRepr,
+{
+    #[inline]
+    fn from(tstr: TStr<T>) -> Self {
+        tstr.to_string()
+    }
+}
+
+/// Converts the `TStr` into a `Cow::Owned` with its string.
+///
+/// With the `"const_generics"` feature, this conversion borrows the string instead.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::ts;
+///
+/// use std::borrow::Cow;
+///
+/// let name: Cow<'static, str> = ts!(name).into();
+/// assert_eq!(name, "name");
+///
+/// ```
+#[cfg(not(feature = "const_generics"))]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+impl<T> From<TStr<T>> for Cow<'static, str>
+where
+    T: WriteRepr,
+{
+    #[inline]
+    fn from(tstr: TStr<T>) -> Self {
+        Cow::Owned(tstr.to_string())
+    }
+}
+
+/// Converts the `TStr` into a `Cow::Borrowed` of its `&'static str`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::ts;
+///
+/// use std::borrow::Cow;
+///
+/// let name: Cow<'static, str> = ts!(name).into();
+/// match name {
+///     Cow::Borrowed(string) => assert_eq!(string, "name"),
+///     Cow::Owned(_) => unreachable!(),
+/// }
+///
+/// ```
+#[cfg(feature = "const_generics")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "alloc")))]
+impl<T> From<TStr<T>> for Cow<'static, str>
+where
+    TStr<T>: crate::StrValue,
+{
+    #[inline]
+    fn from(_: TStr<T>) -> Self {
+        Cow::Borrowed(<TStr<T> as crate::StrValue>::STR)
+    }
+}
+
+/// Allocates a `String` with the string of the `ErasedTStr`.
+#[cfg(feature = "const_generics")]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "alloc", feature = "const_generics")))
+)]
+impl From<crate::ErasedTStr> for String {
+    #[inline]
+    fn from(tstr: crate::ErasedTStr) -> Self {
+        String::from(tstr.as_str())
+    }
+}
+
+/// Borrows the `&'static str` of the `ErasedTStr`.
+#[cfg(feature = "const_generics")]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "alloc", feature = "const_generics")))
+)]
+impl From<crate::ErasedTStr> for Cow<'static, str> {
+    #[inline]
+    fn from(tstr: crate::ErasedTStr) -> Self {
+        Cow::Borrowed(tstr.as_str())
+    }
+}
@@ -0,0 +1,70 @@
+//! Implementations of [`Index`] and [`IndexMut`] for arrays,
+//! with numeric [`TStr`]s that are checked to be in bounds at compile-time.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tstr::ts;
+//!
+//! let mut array = [3, 5, 8, 13];
+//!
+//! assert_eq!(array[ts!(0)], 3);
+//! assert_eq!(array[ts!(3)], 13);
+//!
+//! array[ts!(1)] += 100;
+//! assert_eq!(array, [3, 105, 8, 13]);
+//! ```
+//!
+//! Indexing out of bounds is a compile-time error:
+//!
+//! ```compile_fail
+//! use tstr::ts;
+//!
+//! let array = [3, 5, 8, 13];
+//!
+//! let _ = array[ts!(4)];
+//! ```
+//!
+//! [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+//! [`IndexMut`]: https://doc.rust-lang.org/core/ops/trait.IndexMut.html
+//! [`TStr`]: ../struct.TStr.html
+
+use core::ops::{Index, IndexMut};
+
+use crate::{TStr, ToUint};
+
+struct AssertInBounds<K, const LEN: usize>(K);
+
+impl<K, const LEN: usize> AssertInBounds<K, LEN>
+where
+    K: ToUint,
+{
+    const ASSERT: () = {
+        #[allow(clippy::no_effect)]
+        ["Expected the index to be less than the length of the array"][(K::USIZE >= LEN) as usize];
+    };
+}
+
+impl<T, S, const LEN: usize> Index<TStr<S>> for [T; LEN]
+where
+    TStr<S>: ToUint,
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, _: TStr<S>) -> &T {
+        let () = AssertInBounds::<TStr<S>, LEN>::ASSERT;
+        &self[<TStr<S>>::USIZE]
+    }
+}
+
+impl<T, S, const LEN: usize> IndexMut<TStr<S>> for [T; LEN]
+where
+    TStr<S>: ToUint,
+{
+    #[inline(always)]
+    fn index_mut(&mut self, _: TStr<S>) -> &mut T {
+        let () = AssertInBounds::<TStr<S>, LEN>::ASSERT;
+        &mut self[<TStr<S>>::USIZE]
+    }
+}
@@ -1,7 +1,11 @@
 //! Types for asserting properties of type-level strings.
 
+#[cfg(any(feature = "cmp_traits", feature = "const_generics"))]
 use core::marker::PhantomData;
 
+#[cfg(feature = "const_generics")]
+use crate::{utils, StrValue};
+
 /// For asserting the (in)equality of two type-level strings.
 ///
 /// # Warning
@@ -26,6 +30,7 @@ where
 {
     /// Asserts that the `A` and `B` type-level strings compare equal.
     pub const EQUAL: EqualityProof<A, B> = {
+        #[allow(clippy::no_effect)]
         ["Expected the type parameters to be equal"][A::NE as usize];
         EqualityProof(PhantomData)
     };
@@ -84,11 +89,121 @@ where
     /// ```
     ///
     pub const NOT_EQUAL: InequalityProof<A, B> = {
+        #[allow(clippy::no_effect)]
         ["Expected the type parameters to not be equal"][A::EQ as usize];
         InequalityProof(PhantomData)
     };
 }
 
+#[cfg(feature = "const_generics")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+impl<A, B> Assert<A, B>
+where
+    A: StrValue,
+    B: StrValue,
+{
+    /// Asserts that the `A` and `B` type-level strings are equal,
+    /// with an error message that includes both strings.
+    ///
+    /// This is only available with the `"const_generics"` feature,
+    /// since it requires getting the `&'static str` value of both type-level strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::{Assert, TS};
+    ///
+    /// let _ = Assert::<TS!(name), TS!(name)>::STR_EQUAL;
+    ///
+    /// ```
+    ///
+    /// Comparing different strings fails to compile:
+    ///
+    /// ```compile_fail
+    /// use tstr::{Assert, TS};
+    ///
+    /// const _: () = {
+    ///     Assert::<TS!(nmae), TS!(name)>::STR_EQUAL;
+    /// };
+    /// ```
+    ///
+    /// Truncated error:
+    /// ```text
+    /// error[E0080]: evaluation panicked: expected `name`, found `nmae`
+    /// ```
+    ///
+    pub const STR_EQUAL: EqualityProof<A, B> = {
+        if !utils::str_eq(A::STR, B::STR) {
+            const_panic(&["expected `", B::STR, "`, found `", A::STR, "`"]);
+        }
+        EqualityProof(PhantomData)
+    };
+
+    /// Asserts that the `A` and `B` type-level strings are not equal,
+    /// with an error message that includes the string.
+    ///
+    /// This is only available with the `"const_generics"` feature,
+    /// since it requires getting the `&'static str` value of both type-level strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::{Assert, TS};
+    ///
+    /// let _ = Assert::<TS!(foo), TS!(bar)>::STR_NOT_EQUAL;
+    ///
+    /// ```
+    ///
+    /// Comparing equal strings fails to compile:
+    ///
+    /// ```compile_fail
+    /// use tstr::{Assert, TS};
+    ///
+    /// const _: () = {
+    ///     Assert::<TS!(foo), TS!(foo)>::STR_NOT_EQUAL;
+    /// };
+    /// ```
+    ///
+    /// Truncated error:
+    /// ```text
+    /// error[E0080]: evaluation panicked: expected a string other than `foo`
+    /// ```
+    ///
+    pub const STR_NOT_EQUAL: InequalityProof<A, B> = {
+        if utils::str_eq(A::STR, B::STR) {
+            const_panic(&["expected a string other than `", B::STR, "`"]);
+        }
+        InequalityProof(PhantomData)
+    };
+}
+
+/// Panics with the concatenation of `parts` as the message.
+///
+/// Messages longer than `MAX_MESSAGE_LEN` bytes are replaced with a generic message.
+#[cfg(feature = "const_generics")]
+pub(crate) const fn const_panic(parts: &[&str]) -> ! {
+    const MAX_MESSAGE_LEN: usize = 1024;
+
+    let mut len = 0;
+    let mut i = 0;
+    while i != parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+
+    if len > MAX_MESSAGE_LEN {
+        panic!("the type-level strings didn't compare as expected");
+    }
+
+    let (buffer, len) = utils::concat_into::<MAX_MESSAGE_LEN>(parts);
+    // SAFETY: `buffer[..len]` is the concatenation of `&str`s, which is valid UTF-8
+    let message = unsafe {
+        core::str::from_utf8_unchecked(core::slice::from_raw_parts(buffer.as_ptr(), len))
+    };
+    panic!("{}", message)
+}
+
+#[cfg(any(feature = "cmp_traits", feature = "const_generics"))]
 macro_rules! declare_assert_res {
     (
         $(#[$meta:meta])*
@@ -112,7 +227,7 @@ macro_rules! declare_assert_res {
     };
 }
 
-#[cfg(feature = "cmp_traits")]
+#[cfg(any(feature = "cmp_traits", feature = "const_generics"))]
 declare_assert_res! {
     /// Value-level proof that the `L` and `R` type-level strings compared equal.
     ///
@@ -122,7 +237,7 @@ declare_assert_res! {
     struct EqualityProof<L,R>;
 }
 
-#[cfg(feature = "cmp_traits")]
+#[cfg(any(feature = "cmp_traits", feature = "const_generics"))]
 declare_assert_res! {
     /// Value-level proof that the `L` and `R` type-level strings compared not equal.
     ///
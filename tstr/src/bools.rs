@@ -0,0 +1,216 @@
+//! Type-level booleans, and operators for combining them.
+//!
+//! These are designed to compose the type-level outputs of the comparison traits,
+//! like [`TStrEq::Output`].
+//!
+//! # Example
+//!
+//! This example demonstrates a type-level check of whether a string is one of two keys.
+//!
+#![cfg_attr(
+    all(feature = "cmp_traits", not(feature = "min_const_generics")),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(feature = "cmp_traits", not(feature = "min_const_generics"))),
+    doc = "```ignore"
+)]
+//! use tstr::bools::{Boolean, If, Or};
+//! use tstr::{TStrEq, TS};
+//!
+//! type IsKey<K> = <<K as TStrEq<TS!(id)>>::Output as Or<<K as TStrEq<TS!(name)>>::Output>>::Output;
+//!
+//! assert!(<IsKey<TS!(id)>>::VALUE);
+//! assert!(<IsKey<TS!(name)>>::VALUE);
+//! assert!(!<IsKey<TS!(age)>>::VALUE);
+//!
+//! let _: If<IsKey<TS!(id)>, u8, ()> = 3u8;
+//! let _: If<IsKey<TS!(age)>, u8, ()> = ();
+//!
+//! ```
+//!
+//! [`TStrEq::Output`]: ../trait.TStrEq.html#associatedtype.Output
+
+/// Type-level `true`.
+///
+/// This is the [`TStrEq::Output`] of type-level strings that are equal.
+///
+/// [`TStrEq::Output`]: ../trait.TStrEq.html#associatedtype.Output
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct True;
+
+/// Type-level `false`.
+///
+/// This is the [`TStrEq::Output`] of type-level strings that are not equal.
+///
+/// [`TStrEq::Output`]: ../trait.TStrEq.html#associatedtype.Output
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct False;
+
+/// A type-level boolean, either [`True`] or [`False`].
+///
+/// # Example
+///
+/// This example demonstrates a conditional impl,
+/// which is selected by whether two type-level strings are equal.
+///
+#[cfg_attr(
+    all(feature = "cmp_traits", not(feature = "min_const_generics")),
+    doc = "```rust"
+)]
+#[cfg_attr(
+    not(all(feature = "cmp_traits", not(feature = "min_const_generics"))),
+    doc = "```ignore"
+)]
+/// use tstr::{Boolean, False, TStrEq, True, TS};
+///
+/// assert_eq!(describe::<TS!(foo), TS!(foo)>(), "same");
+/// assert_eq!(describe::<TS!(foo), TS!(bar)>(), "different");
+///
+/// fn describe<A, B>() -> &'static str
+/// where
+///     A: TStrEq<B>,
+///     A::Output: Describe,
+/// {
+///     assert_eq!(<A::Output as Boolean>::VALUE, A::EQ);
+///     <A::Output as Describe>::DESCRIPTION
+/// }
+///
+/// trait Describe {
+///     const DESCRIPTION: &'static str;
+/// }
+///
+/// impl Describe for True {
+///     const DESCRIPTION: &'static str = "same";
+/// }
+///
+/// impl Describe for False {
+///     const DESCRIPTION: &'static str = "different";
+/// }
+///
+/// ```
+///
+/// [`True`]: ./struct.True.html
+/// [`False`]: ./struct.False.html
+pub trait Boolean: Copy + Default + 'static {
+    /// The `bool` value of this type.
+    const VALUE: bool;
+}
+
+impl Boolean for True {
+    const VALUE: bool = true;
+}
+
+impl Boolean for False {
+    const VALUE: bool = false;
+}
+
+/// Type-level `&&`
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::bools::{And, False, True};
+///
+/// let _: <True as And<True>>::Output = True;
+/// let _: <True as And<False>>::Output = False;
+/// let _: <False as And<True>>::Output = False;
+/// let _: <False as And<False>>::Output = False;
+///
+/// ```
+pub trait And<Rhs> {
+    /// The result of the operation.
+    type Output;
+}
+
+impl<Rhs> And<Rhs> for True {
+    type Output = Rhs;
+}
+
+impl<Rhs> And<Rhs> for False {
+    type Output = False;
+}
+
+/// Type-level `||`
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::bools::{False, Or, True};
+///
+/// let _: <True as Or<True>>::Output = True;
+/// let _: <True as Or<False>>::Output = True;
+/// let _: <False as Or<True>>::Output = True;
+/// let _: <False as Or<False>>::Output = False;
+///
+/// ```
+pub trait Or<Rhs> {
+    /// The result of the operation.
+    type Output;
+}
+
+impl<Rhs> Or<Rhs> for True {
+    type Output = True;
+}
+
+impl<Rhs> Or<Rhs> for False {
+    type Output = Rhs;
+}
+
+/// Type-level `!`
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::bools::{False, Not, True};
+///
+/// let _: <True as Not>::Output = False;
+/// let _: <False as Not>::Output = True;
+///
+/// ```
+pub trait Not {
+    /// The result of the operation.
+    type Output;
+}
+
+impl Not for True {
+    type Output = False;
+}
+
+impl Not for False {
+    type Output = True;
+}
+
+/// Type-level `if`, evaluates to `Then` for `True`, and `Else` for `False`.
+///
+/// The [`If`] type alias is more convenient to use than this trait.
+///
+/// [`If`]: ./type.If.html
+pub trait IfElse<Then, Else> {
+    /// The selected type.
+    type Output;
+}
+
+impl<Then, Else> IfElse<Then, Else> for True {
+    type Output = Then;
+}
+
+impl<Then, Else> IfElse<Then, Else> for False {
+    type Output = Else;
+}
+
+/// Type-level `if`, evaluates to `Then` if `Cond` is [`True`], and `Else` if it's [`False`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::bools::{False, If, True};
+///
+/// let _: If<True, u8, &str> = 3u8;
+/// let _: If<False, u8, &str> = "hello";
+///
+/// ```
+///
+/// [`True`]: ./struct.True.html
+/// [`False`]: ./struct.False.html
+pub type If<Cond, Then, Else> = <Cond as IfElse<Then, Else>>::Output;
@@ -0,0 +1,124 @@
+//! Type-level operators which convert the case of [`TStr`]s.
+//!
+//! This allows generic code to get the `UpperCamelCase` and `snake_case`
+//! versions of a key from one another (eg: a `FooBar` type from its `foo_bar` field),
+//! without writing both strings wherever they're used.
+//!
+//! This module is not available with the `"min_const_generics"` feature on its own,
+//! since converting the case requires knowing whether each character is uppercase
+//! at the type level, which can't be done with `char` const parameters.
+//! With the `"nightly_generic_const_exprs"` feature, the operators compute the string with
+//! const fns instead,
+//! and it's a compile-time error for the built string to be longer than 1024 bytes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tstr::case::{CamelCase, SnakeCase, ToCamelCase};
+//! use tstr::{ts, MakeTStr, TS};
+//!
+//! let _: TS!(FooBar) = <CamelCase<TS!(foo_bar)>>::NEW;
+//! let _: TS!(foo_bar) = <SnakeCase<TS!(FooBar)>>::NEW;
+//!
+//! let _: (TS!(user_id), TS!(UserId)) = with_variant(ts!(user_id));
+//!
+//! // Pairs the name of a field with the name of its variant in a field enum
+//! fn with_variant<F>(field: F) -> (F, CamelCase<F>)
+//! where
+//!     F: ToCamelCase,
+//!     CamelCase<F>: MakeTStr,
+//! {
+//!     (field, MakeTStr::MAKE)
+//! }
+//!
+//! ```
+//!
+//! [`TStr`]: ../struct.TStr.html
+
+use crate::TStr;
+
+#[cfg(not(feature = "min_const_generics"))]
+mod impl_no_const_generics;
+
+#[cfg(feature = "nightly_generic_const_exprs")]
+mod impl_nightly_const_generics;
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// Type-level operator which converts a [`TStr`] in `UpperCamelCase` to `snake_case`.
+///
+/// This inserts a `_` before every ASCII uppercase letter other than the first character,
+/// and converts all ASCII uppercase letters to lowercase,
+/// the same way that serde's `#[serde(rename_all = "snake_case")]` renames enum variants.
+///
+/// This trait is sealed, it's only implemented for [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::case::{SnakeCase, ToSnakeCase};
+/// use tstr::TS;
+///
+/// let _: TS!(waiting_for_input) = <SnakeCase<TS!(WaitingForInput)>>::NEW;
+/// let _: TS!(id) = <TS!(Id) as ToSnakeCase>::Output::NEW;
+/// let _: TS!(user_id) = <SnakeCase<TS!(user_id)>>::NEW;
+/// let _: TS!(h_t_t_p) = <SnakeCase<TS!(HTTP)>>::NEW;
+/// let _: TS!("") = <SnakeCase<TS!("")>>::NEW;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+pub trait ToSnakeCase: Sealed {
+    /// The `snake_case` string.
+    type Output;
+}
+
+/// The `snake_case` version of the `S` [`TStr`].
+///
+/// For examples, look at the docs for [`ToSnakeCase`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`ToSnakeCase`]: ./trait.ToSnakeCase.html
+pub type SnakeCase<S> = <S as ToSnakeCase>::Output;
+
+/// Type-level operator which converts a [`TStr`] in `snake_case` to `UpperCamelCase`.
+///
+/// This removes every `_`, and converts the first character and the characters after
+/// a `_` to ASCII uppercase,
+/// the same way that serde's `#[serde(rename_all = "PascalCase")]` renames struct fields.
+///
+/// This trait is sealed, it's only implemented for [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::case::{CamelCase, ToCamelCase};
+/// use tstr::TS;
+///
+/// let _: TS!(WaitingForInput) = <CamelCase<TS!(waiting_for_input)>>::NEW;
+/// let _: TS!(Id) = <TS!(id) as ToCamelCase>::Output::NEW;
+/// let _: TS!(FooBar) = <CamelCase<TS!(FooBar)>>::NEW;
+/// let _: TS!(Http2) = <CamelCase<TS!(__http_2)>>::NEW;
+/// let _: TS!("") = <CamelCase<TS!("")>>::NEW;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+pub trait ToCamelCase: Sealed {
+    /// The `UpperCamelCase` string.
+    type Output;
+}
+
+/// The `UpperCamelCase` version of the `S` [`TStr`].
+///
+/// For examples, look at the docs for [`ToCamelCase`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`ToCamelCase`]: ./trait.ToCamelCase.html
+pub type CamelCase<S> = <S as ToCamelCase>::Output;
+
+impl<T> Sealed for TStr<T> {}
@@ -0,0 +1,88 @@
+use crate::{
+    for_nightly_const_generics::{assert_fits, buffer_str, Buffer, MAX_LEN},
+    TStr, ___,
+};
+
+use super::{ToCamelCase, ToSnakeCase};
+
+pub struct Snake<const S: &'static str>;
+
+impl<const S: &'static str> Snake<S> {
+    const BUFFER: &'static Buffer = &snake_into(S);
+    pub const STR: &'static str = buffer_str(Self::BUFFER);
+}
+
+pub struct Camel<const S: &'static str>;
+
+impl<const S: &'static str> Camel<S> {
+    const BUFFER: &'static Buffer = &camel_into(S);
+    pub const STR: &'static str = buffer_str(Self::BUFFER);
+}
+
+const fn snake_into(string: &str) -> Buffer {
+    let bytes = string.as_bytes();
+    let mut buffer = [0u8; MAX_LEN];
+    let mut len = 0;
+
+    let mut i = 0;
+    while i != bytes.len() {
+        let byte = bytes[i];
+        if byte.is_ascii_uppercase() {
+            if i != 0 {
+                assert_fits(len + 1);
+                buffer[len] = b'_';
+                len += 1;
+            }
+            assert_fits(len + 1);
+            buffer[len] = byte.to_ascii_lowercase();
+        } else {
+            assert_fits(len + 1);
+            buffer[len] = byte;
+        }
+        len += 1;
+        i += 1;
+    }
+
+    (buffer, len)
+}
+
+const fn camel_into(string: &str) -> Buffer {
+    let bytes = string.as_bytes();
+    let mut buffer = [0u8; MAX_LEN];
+    let mut len = 0;
+
+    let mut capitalize = true;
+    let mut i = 0;
+    while i != bytes.len() {
+        let byte = bytes[i];
+        if byte == b'_' {
+            capitalize = true;
+        } else {
+            assert_fits(len + 1);
+            buffer[len] = if capitalize {
+                byte.to_ascii_uppercase()
+            } else {
+                byte
+            };
+            len += 1;
+            capitalize = false;
+        }
+        i += 1;
+    }
+
+    (buffer, len)
+}
+
+impl<const S: &'static str> ToSnakeCase for TStr<___<S>>
+where
+    ___<{ Snake::<S>::STR }>: Sized,
+{
+    type Output = TStr<___<{ Snake::<S>::STR }>>;
+}
+
+impl<const S: &'static str> ToCamelCase for TStr<___<S>>
+where
+    ___<{ Camel::<S>::STR }>: Sized,
+{
+    type Output = TStr<___<{ Camel::<S>::STR }>>;
+}
@@ -0,0 +1,246 @@
+use crate::{
+    bools::{False, True},
+    tlist::{FromCharList, TList, TNil, ToCharList},
+    TStr,
+};
+
+use super::{ToCamelCase, ToSnakeCase};
+
+/// The case information of the type of a byte.
+pub trait CaseByte {
+    /// The ASCII lowercase version of the byte.
+    type Lower;
+    /// The ASCII uppercase version of the byte.
+    type Upper;
+    /// Whether the byte is an ASCII uppercase letter.
+    type IsUpper;
+    /// Whether the byte is `_`.
+    type IsUnderscore;
+}
+
+/// Converts the `Self` list of characters to `snake_case`,
+/// where `Started` is whether there were characters before the list.
+pub trait SnakeChars<Started> {
+    type Output;
+}
+
+/// Implementation of `SnakeChars` for `TList<H, Tail>`,
+/// where `Self` is whether `H` is uppercase.
+pub trait SnakeCharIf<H, Tail, Started> {
+    type Output;
+}
+
+/// Prepends `_` to `List` if `Self` is `True`.
+pub trait UnderscoreIf<List> {
+    type Output;
+}
+
+/// Converts the `Self` list of characters to `UpperCamelCase`,
+/// where `Capitalize` is whether the first character is capitalized.
+pub trait CamelChars<Capitalize> {
+    type Output;
+}
+
+/// Implementation of `CamelChars` for `TList<H, Tail>`,
+/// where `Self` is whether `H` is `_`.
+pub trait CamelCharIf<H, Tail, Capitalize> {
+    type Output;
+}
+
+/// Converts `H` to uppercase if `Self` is `True`.
+pub trait UpperIf<H> {
+    type Output;
+}
+
+impl<T> ToSnakeCase for TStr<T>
+where
+    Self: ToCharList,
+    <Self as ToCharList>::Output: SnakeChars<False>,
+    <<Self as ToCharList>::Output as SnakeChars<False>>::Output: FromCharList,
+{
+    type Output =
+        <<<Self as ToCharList>::Output as SnakeChars<False>>::Output as FromCharList>::Output;
+}
+
+impl<T> ToCamelCase for TStr<T>
+where
+    Self: ToCharList,
+    <Self as ToCharList>::Output: CamelChars<True>,
+    <<Self as ToCharList>::Output as CamelChars<True>>::Output: FromCharList,
+{
+    type Output =
+        <<<Self as ToCharList>::Output as CamelChars<True>>::Output as FromCharList>::Output;
+}
+
+impl<Started> SnakeChars<Started> for TNil {
+    type Output = TNil;
+}
+
+impl<H, Tail, Started> SnakeChars<Started> for TList<H, Tail>
+where
+    H: CaseByte,
+    H::IsUpper: SnakeCharIf<H, Tail, Started>,
+{
+    type Output = <H::IsUpper as SnakeCharIf<H, Tail, Started>>::Output;
+}
+
+impl<H, Tail, Started> SnakeCharIf<H, Tail, Started> for False
+where
+    Tail: SnakeChars<True>,
+{
+    type Output = TList<H, Tail::Output>;
+}
+
+impl<H, Tail, Started> SnakeCharIf<H, Tail, Started> for True
+where
+    H: CaseByte,
+    Tail: SnakeChars<True>,
+    Started: UnderscoreIf<TList<H::Lower, Tail::Output>>,
+{
+    type Output = <Started as UnderscoreIf<TList<H::Lower, Tail::Output>>>::Output;
+}
+
+impl<List> UnderscoreIf<List> for False {
+    type Output = List;
+}
+
+impl<List> UnderscoreIf<List> for True {
+    type Output = TList<crate::____, List>;
+}
+
+impl<Capitalize> CamelChars<Capitalize> for TNil {
+    type Output = TNil;
+}
+
+impl<H, Tail, Capitalize> CamelChars<Capitalize> for TList<H, Tail>
+where
+    H: CaseByte,
+    H::IsUnderscore: CamelCharIf<H, Tail, Capitalize>,
+{
+    type Output = <H::IsUnderscore as CamelCharIf<H, Tail, Capitalize>>::Output;
+}
+
+impl<H, Tail, Capitalize> CamelCharIf<H, Tail, Capitalize> for True
+where
+    Tail: CamelChars<True>,
+{
+    type Output = Tail::Output;
+}
+
+impl<H, Tail, Capitalize> CamelCharIf<H, Tail, Capitalize> for False
+where
+    Capitalize: UpperIf<H>,
+    Tail: CamelChars<False>,
+{
+    type Output = TList<<Capitalize as UpperIf<H>>::Output, Tail::Output>;
+}
+
+impl<H> UpperIf<H> for False {
+    type Output = H;
+}
+
+impl<H> UpperIf<H> for True
+where
+    H: CaseByte,
+{
+    type Output = H::Upper;
+}
+
+macro_rules! letter_impls {
+    ($($upper:ident $lower:ident,)*) => {
+        $(
+            impl CaseByte for crate::$upper {
+                type Lower = crate::$lower;
+                type Upper = crate::$upper;
+                type IsUpper = True;
+                type IsUnderscore = False;
+            }
+
+            impl CaseByte for crate::$lower {
+                type Lower = crate::$lower;
+                type Upper = crate::$upper;
+                type IsUpper = False;
+                type IsUnderscore = False;
+            }
+        )*
+    };
+}
+
+letter_impls! {
+    __A __a,
+    __B __b,
+    __C __c,
+    __D __d,
+    __E __e,
+    __F __f,
+    __G __g,
+    __H __h,
+    __I __i,
+    __J __j,
+    __K __k,
+    __L __l,
+    __M __m,
+    __N __n,
+    __O __o,
+    __P __p,
+    __Q __q,
+    __R __r,
+    __S __s,
+    __T __t,
+    __U __u,
+    __V __v,
+    __W __w,
+    __X __x,
+    __Y __y,
+    __Z __z,
+}
+
+impl CaseByte for crate::____ {
+    type Lower = Self;
+    type Upper = Self;
+    type IsUpper = False;
+    type IsUnderscore = True;
+}
+
+// The bytes that aren't ASCII letters or `_`
+macro_rules! uncased_impls {
+    ($($byte:ident)*) => {
+        $(
+            impl CaseByte for crate::$byte {
+                type Lower = Self;
+                type Upper = Self;
+                type IsUpper = False;
+                type IsUnderscore = False;
+            }
+        )*
+    };
+}
+
+uncased_impls! {
+    __0x00 __0x01 __0x02 __0x03 __0x04 __0x05 __0x06 __0x07
+    __0x08 __0x09 __0x0A __0x0B __0x0C __0x0D __0x0E __0x0F
+    __0x10 __0x11 __0x12 __0x13 __0x14 __0x15 __0x16 __0x17
+    __0x18 __0x19 __0x1A __0x1B __0x1C __0x1D __0x1E __0x1F
+    __0x20 __0x21 __0x22 __0x23 __0x24 __0x25 __0x26 __0x27
+    __0x28 __0x29 __0x2A __0x2B __0x2C __0x2D __0x2E __0x2F
+    __0x30 __0x31 __0x32 __0x33 __0x34 __0x35 __0x36 __0x37
+    __0x38 __0x39 __0x3A __0x3B __0x3C __0x3D __0x3E __0x3F
+    __0x40 __0x5B __0x5C __0x5D __0x5E __0x60 __0x7B __0x7C
+    __0x7D __0x7E __0x7F __0x80 __0x81 __0x82 __0x83 __0x84
+    __0x85 __0x86 __0x87 __0x88 __0x89 __0x8A __0x8B __0x8C
+    __0x8D __0x8E __0x8F __0x90 __0x91 __0x92 __0x93 __0x94
+    __0x95 __0x96 __0x97 __0x98 __0x99 __0x9A __0x9B __0x9C
+    __0x9D __0x9E __0x9F __0xA0 __0xA1 __0xA2 __0xA3 __0xA4
+    __0xA5 __0xA6 __0xA7 __0xA8 __0xA9 __0xAA __0xAB __0xAC
+    __0xAD __0xAE __0xAF __0xB0 __0xB1 __0xB2 __0xB3 __0xB4
+    __0xB5 __0xB6 __0xB7 __0xB8 __0xB9 __0xBA __0xBB __0xBC
+    __0xBD __0xBE __0xBF __0xC0 __0xC1 __0xC2 __0xC3 __0xC4
+    __0xC5 __0xC6 __0xC7 __0xC8 __0xC9 __0xCA __0xCB __0xCC
+    __0xCD __0xCE __0xCF __0xD0 __0xD1 __0xD2 __0xD3 __0xD4
+    __0xD5 __0xD6 __0xD7 __0xD8 __0xD9 __0xDA __0xDB __0xDC
+    __0xDD __0xDE __0xDF __0xE0 __0xE1 __0xE2 __0xE3 __0xE4
+    __0xE5 __0xE6 __0xE7 __0xE8 __0xE9 __0xEA __0xEB __0xEC
+    __0xED __0xEE __0xEF __0xF0 __0xF1 __0xF2 __0xF3 __0xF4
+    __0xF5 __0xF6 __0xF7 __0xF8 __0xF9 __0xFA __0xFB __0xFC
+    __0xFD __0xFE __0xFF
+}
@@ -0,0 +1,63 @@
+#[cfg(feature = "proc_macros")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "proc_macros")))]
+pub use tstr_proc_macros::Columns;
+
+/// The column names of a struct, as [`TStr`]s,
+/// for query builders that use the names of the fields of a struct as the names of columns.
+///
+/// # Deriving
+///
+/// This trait can be derived for structs with named fields,
+/// with the `#[derive(tstr::Columns)]` derive macro
+/// (which requires the default `"proc_macros"` feature).
+///
+/// The column names are the names of the fields,
+/// which can be converted to a case style with the
+/// `#[columns(case = "snake_case")]` or `#[columns(case = "SCREAMING_SNAKE_CASE")]` attribute
+/// on the struct.
+/// With the `"serde_rename"` feature, the case style is applied to the serde names of the fields.
+///
+/// The derive generates code that refers to this crate as `::tstr`,
+/// which can be changed with the `#[tstr(crate = path::to::tstr)]` attribute.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::{Columns, TS};
+///
+/// #[derive(Columns)]
+/// #[columns(case = "SCREAMING_SNAKE_CASE")]
+/// struct Employee {
+///     id: u64,
+///     first_name: String,
+///     hire_date: String,
+/// }
+///
+/// assert_eq!(Employee::COLUMN_NAMES, ["ID", "FIRST_NAME", "HIRE_DATE"]);
+///
+/// let _: TList<TS!(ID), TList<TS!(FIRST_NAME), TList<TS!(HIRE_DATE), TNil>>> =
+///     Employee::COLUMNS;
+///
+/// assert_eq!(select::<Employee>(), "SELECT ID, FIRST_NAME, HIRE_DATE");
+///
+/// fn select<T: Columns>() -> String {
+///     format!("SELECT {}", T::COLUMN_NAMES.join(", "))
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+pub trait Columns {
+    /// A [`TList`] of the [`TStr`]s of the column names, in declaration order.
+    ///
+    /// [`TList`]: ./tlist/struct.TList.html
+    /// [`TStr`]: ./struct.TStr.html
+    type List;
+
+    /// The `List` value.
+    const COLUMNS: Self::List;
+
+    /// The column names, in declaration order.
+    const COLUMN_NAMES: &'static [&'static str];
+}
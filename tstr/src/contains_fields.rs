@@ -0,0 +1,139 @@
+use core::ops::{Index, IndexMut};
+
+/// For types that have every field in the `K0` to `K7` list of field names.
+///
+/// This is blanket implemented for every type that implements
+/// [`Index`] for all of the field names,
+/// allowing functions to require many fields with a single bound.
+///
+/// The field names are usually [`TStr`]s,
+/// and up to 8 names can be passed.
+/// The unused parameters default to `K0`,
+/// so that `ContainsFields<A, B>` only requires the `A` and `B` fields.
+///
+/// # Example
+///
+/// This uses types from the `for_examples` module,
+/// which can be seen in the docs with the "for_examples" feature.
+///
+#[cfg_attr(feature = "for_examples", doc = "```rust")]
+#[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
+/// use std::ops::Index;
+///
+/// use tstr::for_examples::{Bar, Foo};
+/// use tstr::{ContainsFields, TS, ts};
+///
+/// assert_eq!(bar_and_baz(&Foo::new(3, 5, "hello")), (&3, &5));
+/// assert_eq!(bar_and_baz(&Bar::new(8, false, None)), (&8, &false));
+///
+/// assert_eq!(sum_bar(&Foo::new(3, 5, "hello"), &Bar::new(8, false, None)), 11);
+///
+/// fn bar_and_baz<T>(this: &T) -> (
+///     &<T as Index<TS!(bar)>>::Output,
+///     &<T as Index<TS!(baz)>>::Output,
+/// )
+/// where
+///     T: ContainsFields<TS!(bar), TS!(baz)>,
+/// {
+///     (&this[ts!(bar)], &this[ts!(baz)])
+/// }
+///
+/// // The type of a field can be constrained with an `Index` bound
+/// fn sum_bar<T, U>(left: &T, right: &U) -> u32
+/// where
+///     T: ContainsFields<TS!(bar), TS!(baz)> + Index<TS!(bar), Output = u32>,
+///     U: ContainsFields<TS!(bar), TS!(baz)> + Index<TS!(bar), Output = u32>,
+/// {
+///     left[ts!(bar)] + right[ts!(bar)]
+/// }
+///
+/// ```
+///
+/// [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+/// [`TStr`]: ./struct.TStr.html
+pub trait ContainsFields<K0, K1 = K0, K2 = K0, K3 = K0, K4 = K0, K5 = K0, K6 = K0, K7 = K0>:
+    Index<K0> + Index<K1> + Index<K2> + Index<K3> + Index<K4> + Index<K5> + Index<K6> + Index<K7>
+{
+}
+
+impl<This, K0, K1, K2, K3, K4, K5, K6, K7> ContainsFields<K0, K1, K2, K3, K4, K5, K6, K7> for This where
+    This: ?Sized
+        + Index<K0>
+        + Index<K1>
+        + Index<K2>
+        + Index<K3>
+        + Index<K4>
+        + Index<K5>
+        + Index<K6>
+        + Index<K7>
+{
+}
+
+/// For types that have every field in the `K0` to `K7` list of field names,
+/// mutably accessible.
+///
+/// This is blanket implemented for every type that implements
+/// [`IndexMut`] for all of the field names.
+///
+/// For more details, you can look at [`ContainsFields`].
+///
+/// # Example
+///
+/// This uses types from the `for_examples` module,
+/// which can be seen in the docs with the "for_examples" feature.
+///
+#[cfg_attr(feature = "for_examples", doc = "```rust")]
+#[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
+/// use std::ops::Index;
+///
+/// use tstr::for_examples::{Bar, Foo};
+/// use tstr::{ContainsFieldsMut, TS, ts};
+///
+/// let mut foo = Foo::new(3, 5, "hello");
+/// assert_eq!(*increment_bar(&mut foo), 5);
+/// assert_eq!(foo[ts!(bar)], 4);
+///
+/// let mut bar = Bar::new(8, false, None);
+/// assert_eq!(*increment_bar(&mut bar), false);
+/// assert_eq!(bar[ts!(bar)], 9);
+///
+/// // Increments the `bar` field, returning a reference to the `baz` field.
+/// fn increment_bar<T>(this: &mut T) -> &<T as Index<TS!(baz)>>::Output
+/// where
+///     T: ContainsFieldsMut<TS!(bar), TS!(baz)> + Index<TS!(bar), Output = u32>,
+/// {
+///     this[ts!(bar)] += 1;
+///     &this[ts!(baz)]
+/// }
+///
+/// ```
+///
+/// [`IndexMut`]: https://doc.rust-lang.org/core/ops/trait.IndexMut.html
+/// [`ContainsFields`]: ./trait.ContainsFields.html
+pub trait ContainsFieldsMut<K0, K1 = K0, K2 = K0, K3 = K0, K4 = K0, K5 = K0, K6 = K0, K7 = K0>:
+    ContainsFields<K0, K1, K2, K3, K4, K5, K6, K7>
+    + IndexMut<K0>
+    + IndexMut<K1>
+    + IndexMut<K2>
+    + IndexMut<K3>
+    + IndexMut<K4>
+    + IndexMut<K5>
+    + IndexMut<K6>
+    + IndexMut<K7>
+{
+}
+
+impl<This, K0, K1, K2, K3, K4, K5, K6, K7> ContainsFieldsMut<K0, K1, K2, K3, K4, K5, K6, K7>
+    for This
+where
+    This: ?Sized
+        + IndexMut<K0>
+        + IndexMut<K1>
+        + IndexMut<K2>
+        + IndexMut<K3>
+        + IndexMut<K4>
+        + IndexMut<K5>
+        + IndexMut<K6>
+        + IndexMut<K7>,
+{
+}
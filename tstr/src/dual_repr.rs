@@ -0,0 +1,151 @@
+//! Conversions between the default representation of type-level strings,
+//! and the `&'static str` const parameter representation.
+
+use crate::{
+    tlist::{TList, TNil},
+    TStr,
+};
+
+mod sealed {
+    /// Prepends the bytes of `Self` (a tupled representation of a `TStr`) to `Rest`.
+    #[doc(hidden)]
+    pub trait PushBytes<Rest> {
+        type Output;
+    }
+
+    /// Prepends the bytes of every element of the `Self` list to `Rest`.
+    #[doc(hidden)]
+    pub trait PushElemsBytes<Rest> {
+        type Output;
+    }
+}
+use sealed::{PushBytes, PushElemsBytes};
+
+#[doc(hidden)]
+pub trait ByteValue {
+    const VALUE: u8;
+}
+
+impl<B, Rest> PushBytes<Rest> for B
+where
+    B: ByteValue,
+{
+    type Output = TList<B, Rest>;
+}
+
+impl<Rest> PushBytes<Rest> for () {
+    type Output = Rest;
+}
+
+macro_rules! tuple_impl {
+    ($($ty:ident)*) => {
+        impl<$($ty,)* Rest> PushBytes<Rest> for ($($ty,)*)
+        where
+            tuple_impl!(@list $($ty)*): PushElemsBytes<Rest>,
+        {
+            type Output = <tuple_impl!(@list $($ty)*) as PushElemsBytes<Rest>>::Output;
+        }
+    };
+    (@list) => { TNil };
+    (@list $first:ident $($rest:ident)*) => { TList<$first, tuple_impl!(@list $($rest)*)> };
+}
+
+tuple_impl! {A}
+tuple_impl! {A B}
+tuple_impl! {A B C}
+tuple_impl! {A B C D}
+tuple_impl! {A B C D E}
+tuple_impl! {A B C D E F}
+tuple_impl! {A B C D E F G}
+tuple_impl! {A B C D E F G H}
+
+impl<Rest> PushElemsBytes<Rest> for TNil {
+    type Output = Rest;
+}
+
+impl<H, T, Rest> PushElemsBytes<Rest> for TList<H, T>
+where
+    T: PushElemsBytes<Rest>,
+    H: PushBytes<T::Output>,
+{
+    type Output = H::Output;
+}
+
+macro_rules! declare_conversions {
+    () => {
+        /// Whether `Self`, a list of bytes, is a suffix of `S`.
+        #[doc(hidden)]
+        pub trait IsSuffixOf<const S: &'static str> {
+            const LEN: usize;
+            const VALUE: bool;
+        }
+
+        impl<const S: &'static str> IsSuffixOf<S> for TNil {
+            const LEN: usize = 0;
+            const VALUE: bool = true;
+        }
+
+        impl<H, T, const S: &'static str> IsSuffixOf<S> for TList<H, T>
+        where
+            H: ByteValue,
+            T: IsSuffixOf<S>,
+        {
+            const LEN: usize = 1 + T::LEN;
+            const VALUE: bool =
+                T::VALUE && Self::LEN <= S.len() && S.as_bytes()[S.len() - Self::LEN] == H::VALUE;
+        }
+
+        struct AssertSameStr<T, const S: &'static str>(T);
+
+        impl<T, const S: &'static str> AssertSameStr<T, S>
+        where
+            T: PushBytes<TNil>,
+            T::Output: IsSuffixOf<S>,
+        {
+            const ASSERT: () = {
+                let bytes = <T::Output as IsSuffixOf<S>>::LEN;
+                if !<T::Output as IsSuffixOf<S>>::VALUE || bytes != S.len() {
+                    crate::asserts::const_panic(&[
+                        "expected both representations to be of the string `",
+                        S,
+                        "`",
+                    ]);
+                }
+            };
+        }
+
+        /// Converts from the default representation to the `&'static str` representation.
+        ///
+        /// Converting between representations of different strings
+        /// causes a compile-time error.
+        impl<T, const S: &'static str> From<TStr<T>> for TStr<crate::___<S>>
+        where
+            T: PushBytes<TNil>,
+            T::Output: IsSuffixOf<S>,
+        {
+            #[inline(always)]
+            fn from(_: TStr<T>) -> Self {
+                let () = AssertSameStr::<T, S>::ASSERT;
+                TStr::NEW
+            }
+        }
+
+        /// Converts from the `&'static str` representation to the default representation.
+        ///
+        /// Converting between representations of different strings
+        /// causes a compile-time error.
+        impl<T, const S: &'static str> From<TStr<crate::___<S>>> for TStr<T>
+        where
+            T: PushBytes<TNil>,
+            T::Output: IsSuffixOf<S>,
+        {
+            #[inline(always)]
+            fn from(_: TStr<crate::___<S>>) -> Self {
+                let () = AssertSameStr::<T, S>::ASSERT;
+                TStr::NEW
+            }
+        }
+    };
+}
+
+declare_conversions! {}
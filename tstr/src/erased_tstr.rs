@@ -0,0 +1,202 @@
+use crate::{StrValue, TStr};
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+};
+
+/// A runtime handle for a [`TStr`], with its string and a precomputed hash of it.
+///
+/// This erases the type-level string into a value,
+/// so that `TStr`s of different strings can be stored in the same collection,
+/// or returned from trait objects.
+///
+/// Comparing `ErasedTStr`s for equality compares their hashes before their strings,
+/// and hashing one only writes its precomputed hash.
+/// `ErasedTStr`s are ordered by their strings.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ErasedTStr, ts};
+///
+/// use std::collections::{BTreeSet, HashSet};
+///
+/// let names = vec![
+///     ErasedTStr::from(ts!(foo)),
+///     ErasedTStr::from(ts!(bar)),
+///     ErasedTStr::from(ts!(foo)),
+/// ];
+///
+/// assert_eq!(names[0], names[2]);
+/// assert_ne!(names[0], names[1]);
+/// assert_eq!(names[1].as_str(), "bar");
+///
+/// let set = names.iter().copied().collect::<BTreeSet<ErasedTStr>>();
+/// assert_eq!(set.iter().map(|x| x.as_str()).collect::<Vec<_>>(), ["bar", "foo"]);
+///
+/// let set = names.iter().copied().collect::<HashSet<ErasedTStr>>();
+/// assert_eq!(set.len(), 2);
+///
+/// ```
+///
+/// # Trait object example
+///
+/// ```rust
+/// use tstr::{ErasedTStr, TS, ts};
+///
+/// trait Named {
+///     fn name(&self) -> ErasedTStr;
+/// }
+///
+/// struct Named_<N>(N);
+///
+/// impl<N> Named for Named_<tstr::TStr<N>>
+/// where
+///     tstr::TStr<N>: tstr::StrValue,
+/// {
+///     fn name(&self) -> ErasedTStr {
+///         self.0.into()
+///     }
+/// }
+///
+/// let list: Vec<Box<dyn Named>> = vec![
+///     Box::new(Named_(ts!(hello))),
+///     Box::new(Named_(ts!(world))),
+/// ];
+///
+/// let names = list.iter().map(|x| x.name().as_str()).collect::<Vec<_>>();
+/// assert_eq!(names, ["hello", "world"]);
+///
+/// const WORLD: ErasedTStr = ErasedTStr::new(<TS!(world)>::STR);
+/// assert_eq!(list[1].name(), WORLD);
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+#[derive(Copy, Clone)]
+pub struct ErasedTStr {
+    string: &'static str,
+    hash: u64,
+}
+
+impl ErasedTStr {
+    /// Constructs an `ErasedTStr` from a string, hashing it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::{ErasedTStr, ts};
+    ///
+    /// const FOO: ErasedTStr = ErasedTStr::new("foo");
+    ///
+    /// assert_eq!(FOO, ErasedTStr::from(ts!(foo)));
+    /// assert_eq!(FOO.as_str(), "foo");
+    ///
+    /// ```
+    pub const fn new(string: &'static str) -> Self {
+        Self {
+            string,
+            hash: fnv1a_hash(string.as_bytes()),
+        }
+    }
+
+    /// Gets the string of this `ErasedTStr`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::{ErasedTStr, ts};
+    ///
+    /// assert_eq!(ErasedTStr::from(ts!(bar)).as_str(), "bar");
+    ///
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        self.string
+    }
+
+    /// Gets the precomputed hash of the string.
+    ///
+    /// This hash is the same for equal strings, within one version of this crate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::{ErasedTStr, ts};
+    ///
+    /// let foo = ErasedTStr::from(ts!(foo));
+    ///
+    /// assert_eq!(foo.precomputed_hash(), ErasedTStr::new("foo").precomputed_hash());
+    /// assert_ne!(foo.precomputed_hash(), ErasedTStr::new("bar").precomputed_hash());
+    ///
+    /// ```
+    pub const fn precomputed_hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+// The 64-bit FNV-1a hash
+const fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+impl<T> From<TStr<T>> for ErasedTStr
+where
+    TStr<T>: StrValue,
+{
+    #[inline]
+    fn from(_: TStr<T>) -> Self {
+        Self::new(<TStr<T> as StrValue>::STR)
+    }
+}
+
+impl PartialEq for ErasedTStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.string == other.string
+    }
+}
+
+impl Eq for ErasedTStr {}
+
+impl PartialOrd for ErasedTStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ErasedTStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.string.cmp(other.string)
+    }
+}
+
+impl Hash for ErasedTStr {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl Debug for ErasedTStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ErasedTStr").field(&self.string).finish()
+    }
+}
+
+impl Display for ErasedTStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.string, f)
+    }
+}
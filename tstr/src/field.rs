@@ -0,0 +1,135 @@
+use core::{
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut, Index, IndexMut},
+};
+
+use crate::MakeTStr;
+
+/// A value labelled with a type-level string.
+///
+/// `N` is the [`TStr`] that labels the value, and `T` is the type of the value.
+///
+/// `Field` dereferences to the value,
+/// and implements [`Index`]/[`IndexMut`] with its name,
+/// which means that it can be used as a field with [`NestedIndex`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{Field, TS, ts};
+///
+/// let mut age: Field<TS!(age), u32> = Field::new(ts!(age), 21);
+///
+/// assert_eq!(*age, 21);
+/// assert_eq!(age[ts!(age)], 21);
+///
+/// *age += 1;
+/// assert_eq!(age.into_inner(), 22);
+///
+/// // Constructing a `Field` without passing its name
+/// let name = Field::<TS!(name), _>::from_value("Bob");
+///
+/// assert_eq!(name.name(), ts!(name));
+/// assert_eq!(*name, "Bob");
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`NestedIndex`]: ./trait.NestedIndex.html
+/// [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+/// [`IndexMut`]: https://doc.rust-lang.org/core/ops/trait.IndexMut.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Field<N, T> {
+    name: N,
+    value: T,
+}
+
+impl<N, T> Field<N, T> {
+    /// Constructs a `Field` from its name and value.
+    #[inline(always)]
+    pub const fn new(name: N, value: T) -> Self {
+        Self { name, value }
+    }
+
+    /// Constructs a `Field` from its value, getting the name with [`MakeTStr`].
+    ///
+    /// [`MakeTStr`]: ./trait.MakeTStr.html
+    #[inline(always)]
+    pub fn from_value(value: T) -> Self
+    where
+        N: MakeTStr,
+    {
+        Self {
+            name: N::MAKE,
+            value,
+        }
+    }
+
+    /// Gets the name of this field.
+    #[inline(always)]
+    pub fn name(&self) -> N
+    where
+        N: Copy,
+    {
+        self.name
+    }
+
+    /// Gets a reference to the value of this field.
+    #[inline(always)]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Gets a mutable reference to the value of this field.
+    #[inline(always)]
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Unwraps this into its value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<N, T> Deref for Field<N, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<N, T> DerefMut for Field<N, T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<N, T> Index<N> for Field<N, T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, _: N) -> &T {
+        &self.value
+    }
+}
+
+impl<N, T> IndexMut<N> for Field<N, T> {
+    #[inline(always)]
+    fn index_mut(&mut self, _: N) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<N, T> Hash for Field<N, T>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(feature = "proc_macros")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "proc_macros")))]
+pub use tstr_proc_macros::Fields;
+
+/// Reflection information about the named fields of a struct.
+///
+/// # Deriving
+///
+/// This trait can be derived for structs with named fields,
+/// with the `#[derive(tstr::Fields)]` derive macro
+/// (which requires the default `"proc_macros"` feature).
+///
+/// The derive generates code that refers to this crate as `::tstr`,
+/// which can be changed with the `#[tstr(crate = path::to::tstr)]` attribute.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::{Fields, TS};
+///
+/// #[derive(Fields)]
+/// struct Person<'a> {
+///     name: &'a str,
+///     age: u32,
+/// }
+///
+/// assert_eq!(Person::FIELD_NAMES, ["name", "age"]);
+///
+/// let _: <Person<'static> as Fields>::List =
+///     TList::<(TS!(name), &'static str), TList<(TS!(age), u32), TNil>>::NEW;
+///
+/// assert_eq!(field_count::<Person<'_>>(), 2);
+///
+/// fn field_count<T: Fields>() -> usize {
+///     T::FIELD_NAMES.len()
+/// }
+///
+/// ```
+///
+/// With the `"cmp_traits"` feature, the `List` can be used as the list of a
+/// [`TMap`](./maps/struct.TMap.html), to get the type of a field by name.
+///
+#[cfg_attr(
+    all(feature = "cmp_traits", not(feature = "min_const_generics")),
+    doc = "```rust"
+)]
+#[cfg_attr(
+    not(all(feature = "cmp_traits", not(feature = "min_const_generics"))),
+    doc = "```ignore"
+)]
+/// use tstr::maps::{Get, TMap};
+/// use tstr::{Fields, TS};
+///
+/// #[derive(Fields)]
+/// struct Point {
+///     x: u32,
+///     y: i64,
+/// }
+///
+/// type FieldType<T, K> = <TMap<<T as Fields>::List> as Get<K>>::Output;
+///
+/// let _: FieldType<Point, TS!(x)> = 3u32;
+/// let _: FieldType<Point, TS!(y)> = -5i64;
+///
+/// ```
+pub trait Fields {
+    /// A [`TList`] of `(name, type)` pairs, one for each field, in declaration order,
+    /// where `name` is the [`TStr`] of the field's name.
+    ///
+    /// [`TList`]: ./tlist/struct.TList.html
+    /// [`TStr`]: ./struct.TStr.html
+    type List;
+
+    /// The names of the fields, in declaration order.
+    const FIELD_NAMES: &'static [&'static str];
+}
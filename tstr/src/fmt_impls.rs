@@ -0,0 +1,209 @@
+//! Impls of the formatting traits of the `defmt` and `ufmt` crates for `TStr`,
+//! which print the string.
+//!
+//! This also has the `WriteRepr` trait that `alloc_impls` uses to build `String`s.
+
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+use crate::TStr;
+
+mod sealed {
+    use super::StrWriter;
+
+    /// Writes the bytes of `Self` (a representation of a `TStr`) to `out`.
+    #[doc(hidden)]
+    pub trait WriteRepr {
+        fn write_repr<Err_>(out: &mut StrWriter<'_, Err_>) -> Result<(), Err_>;
+    }
+}
+
+#[doc(hidden)]
+pub use sealed::WriteRepr;
+
+/// Buffers the bytes of a string,
+/// passing them to `write` in chunks that are valid UTF-8.
+///
+/// This allows writing the string of representations that
+/// split non-ASCII characters into bytes.
+#[doc(hidden)]
+pub struct StrWriter<'a, E> {
+    buffer: [u8; 64],
+    len: usize,
+    write: &'a mut dyn FnMut(&str) -> Result<(), E>,
+}
+
+impl<'a, E> StrWriter<'a, E> {
+    fn new(write: &'a mut dyn FnMut(&str) -> Result<(), E>) -> Self {
+        Self {
+            buffer: [0; 64],
+            len: 0,
+            write,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), E> {
+        for &byte in bytes {
+            if self.len == self.buffer.len() {
+                self.flush()?;
+            }
+            self.buffer[self.len] = byte;
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    // Writes the buffered bytes up to the last complete character,
+    // keeping the bytes of the incomplete character at the end.
+    fn flush(&mut self) -> Result<(), E> {
+        let valid_len = match core::str::from_utf8(&self.buffer[..self.len]) {
+            Ok(_) => self.len,
+            Err(e) => e.valid_up_to(),
+        };
+
+        if let Ok(string) = core::str::from_utf8(&self.buffer[..valid_len]) {
+            if !string.is_empty() {
+                (self.write)(string)?;
+            }
+        }
+
+        self.buffer.copy_within(valid_len..self.len, 0);
+        self.len -= valid_len;
+        Ok(())
+    }
+}
+
+/// Passes the string of `TStr<T>` to `write`, in one or more chunks.
+pub(crate) fn write_tstr<T, E>(write: &mut dyn FnMut(&str) -> Result<(), E>) -> Result<(), E>
+where
+    T: WriteRepr,
+{
+    let mut out = StrWriter::new(write);
+    T::write_repr(&mut out)?;
+    out.flush()
+}
+
+impl WriteRepr for () {
+    #[inline]
+    fn write_repr<Err_>(_: &mut StrWriter<'_, Err_>) -> Result<(), Err_> {
+        Ok(())
+    }
+}
+
+macro_rules! tuple_impl {
+    ($($ty:ident)*) => {
+        impl<$($ty,)*> WriteRepr for ($($ty,)*)
+        where
+            $($ty: WriteRepr,)*
+        {
+            #[inline]
+            fn write_repr<Err_>(out: &mut StrWriter<'_, Err_>) -> Result<(), Err_> {
+                $( $ty::write_repr(out)?; )*
+                Ok(())
+            }
+        }
+    };
+}
+
+tuple_impl! {A}
+tuple_impl! {A B}
+tuple_impl! {A B C}
+tuple_impl! {A B C D}
+tuple_impl! {A B C D E}
+tuple_impl! {A B C D E F}
+tuple_impl! {A B C D E F G}
+tuple_impl! {A B C D E F G H}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+macro_rules! min_const_impls {
+    ($( ($chars_struct:ident, [$($chars:ident),*]) ,)*) => {
+        $(
+            impl<$(const $chars: char,)*> WriteRepr for crate::$chars_struct<$($chars,)*> {
+                #[inline]
+                fn write_repr<Err_>(out: &mut StrWriter<'_, Err_>) -> Result<(), Err_> {
+                    $( out.write_bytes($chars.encode_utf8(&mut [0; 4]).as_bytes())?; )*
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+min_const_impls! {
+    (__a, [A]),
+    (__b, [A, B]),
+    (__c, [A, B, C]),
+    (__d, [A, B, C, D]),
+    (__e, [A, B, C, D, E]),
+    (__f, [A, B, C, D, E, F]),
+    (__g, [A, B, C, D, E, F, G]),
+    (__, [A, B, C, D, E, F, G, H]),
+}
+
+#[cfg(feature = "const_generics")]
+macro_rules! const_impls {
+    () => {
+        impl<const S: &'static str> WriteRepr for crate::___<S> {
+            #[inline]
+            fn write_repr<Err_>(out: &mut StrWriter<'_, Err_>) -> Result<(), Err_> {
+                out.write_bytes(S.as_bytes())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "const_generics")]
+const_impls! {}
+
+/// Prints the string of the `TStr`.
+#[cfg(feature = "defmt")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "defmt")))]
+impl<T> defmt::Format for TStr<T>
+where
+    T: WriteRepr,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let _ = write_tstr::<T, core::convert::Infallible>(&mut |string| {
+            defmt::write!(f, "{=str}", string);
+            Ok(())
+        });
+    }
+}
+
+/// Prints the string of the `TStr`.
+#[cfg(feature = "ufmt")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "ufmt")))]
+impl<T> ufmt::uDisplay for TStr<T>
+where
+    T: WriteRepr,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        write_tstr::<T, W::Error>(&mut |string| f.write_str(string))
+    }
+}
+
+/// Prints the string of the `TStr` as a quoted and escaped string literal,
+/// like the `Debug` impl of `str`.
+#[cfg(feature = "ufmt")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "ufmt")))]
+impl<T> ufmt::uDebug for TStr<T>
+where
+    T: WriteRepr,
+{
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.write_char('"')?;
+        write_tstr::<T, W::Error>(&mut |string| {
+            for c in string.chars().flat_map(char::escape_debug) {
+                f.write_char(c)?;
+            }
+            Ok(())
+        })?;
+        f.write_char('"')
+    }
+}
@@ -1,5 +1,7 @@
 //! Types used by documentation examples.
 
+#![allow(clippy::disallowed_names)]
+
 use core::ops::{Index, IndexMut};
 
 #[cfg(feature = "cmp_traits")]
@@ -115,3 +117,291 @@ impl_index_indexmut! {
         (boom: Option<char>)
     }
 }
+
+/// For examples, contains an [`Inner`] in the `inner` field.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::for_examples::{Inner, Outer};
+/// use tstr::ts;
+///
+/// let mut outer = Outer::new(Inner::new(3, false), "hello");
+///
+/// assert_eq!(outer[ts!(inner)][ts!(leaf)], 3);
+/// assert!(!outer[ts!(inner)][ts!(flag)]);
+/// assert_eq!(outer[ts!(name)], "hello");
+///
+/// outer[ts!(inner)][ts!(leaf)] = 5;
+/// assert_eq!(outer[ts!(inner)][ts!(leaf)], 5);
+///
+/// ```
+///
+/// [`Inner`]: ./struct.Inner.html
+#[derive(Debug)]
+pub struct Outer {
+    inner: Inner,
+    name: &'static str,
+}
+
+impl Outer {
+    /// A simple contructor
+    pub fn new(inner: Inner, name: &'static str) -> Self {
+        Self { inner, name }
+    }
+}
+
+impl_index_indexmut! {
+    impl[] Outer {
+        (inner: Inner)
+        (name: &'static str)
+    }
+}
+
+/// For examples, is the type of the `inner` field in [`Outer`].
+///
+/// [`Outer`]: ./struct.Outer.html
+#[derive(Debug)]
+pub struct Inner {
+    leaf: u32,
+    flag: bool,
+}
+
+impl Inner {
+    /// A simple contructor
+    pub fn new(leaf: u32, flag: bool) -> Self {
+        Self { leaf, flag }
+    }
+}
+
+impl_index_indexmut! {
+    impl[] Inner {
+        (leaf: u32)
+        (flag: bool)
+    }
+}
+
+macro_rules! impl_index_indexmut_unnamed {
+    (
+        impl $impl_params:tt $self:ty {
+            $( ($index:tt : $type:ty) )*
+        }
+    ) => (
+        $(
+            impl_index_indexmut_unnamed!{
+                @rep
+                impl $impl_params $self { $index : $type }
+            }
+        )*
+
+        impl_index_indexmut!{
+            @inner
+            impl $impl_params $self
+        }
+    );
+    (@rep impl[$($impl:tt)*] $self:ty { $index:tt : $type:ty } ) => {
+        impl<$($impl)*> Index<TS!($index)> for $self {
+            type Output = $type;
+
+            fn index(&self, _: TS!($index)) -> &$type {
+                &self.$index
+            }
+        }
+
+        impl<$($impl)*> IndexMut<TS!($index)> for $self {
+            fn index_mut(&mut self, _: TS!($index)) -> &mut $type {
+                &mut self.$index
+            }
+        }
+    };
+}
+
+/// For examples, a pair that's indexed by the `TS!(0)` and `TS!(1)` type-level strings.
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::Index;
+///
+/// use tstr::for_examples::Pair;
+/// use tstr::{ts, ToUint};
+///
+/// let mut pair = Pair::new(3, "hello");
+///
+/// assert_eq!(pair[ts!(0)], 3);
+/// assert_eq!(pair[ts!(1)], "hello");
+///
+/// pair[ts!(0)] += 5;
+/// assert_eq!(pair[ts!(0)], 8);
+///
+/// assert_eq!(get_with_index(&pair, ts!(0)), (&8, 0));
+/// assert_eq!(get_with_index(&pair, ts!(1)), (&"hello", 1));
+///
+/// // Gets the element at the `K` index, along with `K` as an integer
+/// fn get_with_index<T, K>(this: &T, index: K) -> (&T::Output, usize)
+/// where
+///     T: Index<K>,
+///     K: ToUint,
+/// {
+///     (&this[index], K::USIZE)
+/// }
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Pair<T, U>(T, U);
+
+impl<T, U> Pair<T, U> {
+    /// A simple contructor
+    pub fn new(t: T, u: U) -> Self {
+        Self(t, u)
+    }
+
+    /// Unwraps this into a tuple.
+    pub fn into_tuple(self) -> (T, U) {
+        (self.0, self.1)
+    }
+}
+
+impl_index_indexmut_unnamed! {
+    impl[T, U] Pair<T, U> {
+        (0: T)
+        (1: U)
+    }
+}
+
+/// For examples, a triple that's indexed by the `TS!(0)`, `TS!(1)`,
+/// and `TS!(2)` type-level strings.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::for_examples::Triple;
+/// use tstr::{ts, ToUint, TS};
+///
+/// let triple = Triple::new('a', 5u8, "c");
+///
+/// assert_eq!(triple[ts!(0)], 'a');
+/// assert_eq!(triple[ts!(1)], 5);
+/// assert_eq!(triple[ts!(2)], "c");
+///
+/// // The indices can be converted to integers
+/// assert_eq!(<TS!(2)>::USIZE, 2);
+/// assert_eq!(ts!(1).to_u8(), triple[ts!(1)] - 4);
+///
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Triple<T, U, V>(T, U, V);
+
+impl<T, U, V> Triple<T, U, V> {
+    /// A simple contructor
+    pub fn new(t: T, u: U, v: V) -> Self {
+        Self(t, u, v)
+    }
+
+    /// Unwraps this into a tuple.
+    pub fn into_tuple(self) -> (T, U, V) {
+        (self.0, self.1, self.2)
+    }
+}
+
+impl_index_indexmut_unnamed! {
+    impl[T, U, V] Triple<T, U, V> {
+        (0: T)
+        (1: U)
+        (2: V)
+    }
+}
+
+/// Accesses the `V` variant of an enum, for examples.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::for_examples::{Shape, VariantAccess};
+/// use tstr::ts;
+///
+/// let mut shape = Shape::new_variant(ts!(Circle), 3);
+///
+/// assert_eq!(shape.variant(ts!(Circle)), Some(&3));
+/// assert_eq!(shape.variant(ts!(Label)), None);
+///
+/// if let Some(radius) = shape.variant_mut(ts!(Circle)) {
+///     *radius *= 2;
+/// }
+/// assert_eq!(shape.variant(ts!(Circle)), Some(&6));
+///
+/// let label = Shape::new_variant(ts!(Label), "hello");
+/// assert_eq!(label.variant(ts!(Label)), Some(&"hello"));
+/// assert_eq!(label.variant(ts!(Circle)), None);
+///
+/// ```
+pub trait VariantAccess<V> {
+    /// The type of the value in the variant.
+    type Output;
+
+    /// Constructs the `V` variant.
+    fn new_variant(variant: V, value: Self::Output) -> Self;
+
+    /// Gets a reference to the value in the variant if `self` is the `V` variant.
+    fn variant(&self, variant: V) -> Option<&Self::Output>;
+
+    /// Gets a mutable reference to the value in the variant if `self` is the `V` variant.
+    fn variant_mut(&mut self, variant: V) -> Option<&mut Self::Output>;
+}
+
+macro_rules! impl_variant_access {
+    (
+        impl $self:ident {
+            $( ($variant:ident : $type:ty) )*
+        }
+    ) => (
+        $(
+            const _: () = {
+                type $variant = TS!($variant);
+
+                impl VariantAccess<$variant> for $self {
+                    type Output = $type;
+
+                    fn new_variant(_: $variant, value: $type) -> Self {
+                        $self::$variant(value)
+                    }
+
+                    fn variant(&self, _: $variant) -> Option<&$type> {
+                        match self {
+                            $self::$variant(x) => Some(x),
+                            #[allow(unreachable_patterns)]
+                            _ => None,
+                        }
+                    }
+
+                    fn variant_mut(&mut self, _: $variant) -> Option<&mut $type> {
+                        match self {
+                            $self::$variant(x) => Some(x),
+                            #[allow(unreachable_patterns)]
+                            _ => None,
+                        }
+                    }
+                }
+            };
+        )*
+    );
+}
+
+/// For examples
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// A circle with a radius
+    Circle(u32),
+    /// A rectangle with a width and height
+    Rectangle((u32, u32)),
+    /// Text
+    Label(&'static str),
+}
+
+impl_variant_access! {
+    impl Shape {
+        (Circle: u32)
+        (Rectangle: (u32, u32))
+        (Label: &'static str)
+    }
+}
@@ -0,0 +1,41 @@
+//! Helpers for the type-level operators which compute their outputted string
+//! with const fns over the `&'static str` const parameter, using `generic_const_exprs`.
+//!
+//! The strings are built in fixed-size buffers, which are borrowed from associated constants
+//! of the generic types that compute them, so that the strings are `'static`.
+
+/// The maximum length in bytes of the strings that the type-level operators build.
+pub const MAX_LEN: usize = 1024;
+
+/// A string built in a buffer, and the length of the string.
+pub type Buffer = ([u8; MAX_LEN], usize);
+
+/// Panics with an explanation if a string of `len` bytes doesn't fit in a [`Buffer`],
+/// instead of failing with an index out of bounds error.
+pub const fn assert_fits(len: usize) {
+    if len > MAX_LEN {
+        panic!("the string built by the type-level operator is longer than 1024 bytes")
+    }
+}
+
+/// The string in `buffer`.
+pub const fn buffer_str(buffer: &'static Buffer) -> &'static str {
+    let (bytes, _) = buffer.0.split_at(buffer.1);
+    str_from_utf8(bytes)
+}
+
+/// The bytes of `string` from `start` up to `end`,
+/// panicking if either is out of bounds or not at a `char` boundary.
+#[cfg(feature = "typenum")]
+pub const fn str_slice(string: &'static str, start: usize, end: usize) -> &'static str {
+    let (bytes, _) = string.as_bytes().split_at(end);
+    let (_, bytes) = bytes.split_at(start);
+    str_from_utf8(bytes)
+}
+
+const fn str_from_utf8(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(string) => string,
+        Err(_) => panic!("the string can't be split inside of a character"),
+    }
+}
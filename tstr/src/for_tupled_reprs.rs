@@ -1,9 +1,10 @@
 pub(crate) mod classify;
 pub(crate) mod integers;
 
-pub struct True;
+#[cfg(not(feature = "min_const_generics"))]
+pub(crate) mod type_cmp;
 
-pub struct False;
+pub(crate) use crate::bools::{False, True};
 
 pub struct Less;
 
@@ -3,6 +3,10 @@ use super::{Equal, False, Greater, Less, True};
 /// A 2 digit, base 5 number
 pub struct Number2_5<D1, D0>(D1, D0);
 
+/// A 4 digit, base 5 number
+#[cfg(not(feature = "min_const_generics"))]
+pub struct Number4_5<D3, D2, D1, D0>(D3, D2, D1, D0);
+
 // Digit for 0 in base 5
 pub struct D0;
 
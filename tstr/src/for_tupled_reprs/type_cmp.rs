@@ -0,0 +1,529 @@
+//! Type-level comparison of the (default) tupled representation of type-level strings.
+//!
+//! The strings are compared structurally,
+//! each byte is compared by its base 5 digits.
+
+use super::{
+    classify::{Classify, HasSameKindNumber},
+    integers::{CmpCarry, Number4_5, D0, D1, D2, D3, D4},
+    Equal, False, Greater, Less, True,
+};
+
+use crate::{
+    tlist::{TList, TNil},
+    TStr,
+};
+
+/// Gets the `Number4_5` that encodes the value of a byte.
+pub trait ByteNumber {
+    type Number;
+}
+
+/// Type-level comparison of two bytes, outputting one of `Less`, `Equal`, or `Greater`.
+pub trait CmpByte<Rhs> {
+    type Ord;
+}
+
+impl<L, R, L3, L2, L1, L0, R3, R2, R1, R0> CmpByte<R> for L
+where
+    L: ByteNumber<Number = Number4_5<L3, L2, L1, L0>>,
+    R: ByteNumber<Number = Number4_5<R3, R2, R1, R0>>,
+    L3: CmpCarry<Equal, R3>,
+    L2: CmpCarry<L3::Ord, R2>,
+    L1: CmpCarry<L2::Ord, R1>,
+    L0: CmpCarry<L1::Ord, R0>,
+{
+    type Ord = L0::Ord;
+}
+
+/// Converts an ordering type into whether it's `Equal`.
+pub trait IsEqual {
+    type Output;
+}
+
+impl IsEqual for Less {
+    type Output = False;
+}
+
+impl IsEqual for Equal {
+    type Output = True;
+}
+
+impl IsEqual for Greater {
+    type Output = False;
+}
+
+/// Type-level equality of two `TStr`s, outputting either `True` or `False`.
+pub trait TStrEqType<Rhs> {
+    type Output;
+}
+
+impl<T, U> TStrEqType<TStr<U>> for TStr<T>
+where
+    T: ReprEq<U>,
+{
+    type Output = T::Output;
+}
+
+/// Type-level lexicographic comparison of two `TStr`s,
+/// outputting one of `Less`, `Equal`, or `Greater`.
+///
+/// This compares the lists of leaf tuples (the tuples of up to 8 bytes) of both strings,
+/// which line up because every leaf tuple except for the last one has 8 bytes.
+/// Comparing leaf tuples instead of individual bytes keeps the recursion depth low.
+pub trait TStrCmpType<Rhs> {
+    type Output;
+}
+
+impl<T, U, TL, UL> TStrCmpType<TStr<U>> for TStr<T>
+where
+    T: ReprLeaves<TNil, Output = TL>,
+    U: ReprLeaves<TNil, Output = UL>,
+    TL: LeafListCmp<UL>,
+{
+    type Output = TL::Output;
+}
+
+/// Prepends the leaf tuples of a representation of a type-level string
+/// to the `Rest` `TList`.
+pub trait ReprLeaves<Rest> {
+    type Output;
+}
+
+impl<Rest> ReprLeaves<Rest> for () {
+    type Output = Rest;
+}
+
+/// Implementation detail of `ReprLeaves`,
+/// `IsLeaf` is whether the first element of the tuple is a byte.
+pub trait ReprLeavesInner<Rest, IsLeaf> {
+    type Output;
+}
+
+/// Prepends the bytes of a leaf tuple to the `Rest` `TList`.
+pub trait ReprBytes<Rest> {
+    type Output;
+}
+
+impl<Rest> ReprBytes<Rest> for () {
+    type Output = Rest;
+}
+
+/// Lexicographic comparison of two `TList`s of leaf tuples,
+/// outputting one of `Less`, `Equal`, or `Greater`.
+pub trait LeafListCmp<Rhs> {
+    type Output;
+}
+
+impl LeafListCmp<TNil> for TNil {
+    type Output = Equal;
+}
+
+impl<H, T> LeafListCmp<TList<H, T>> for TNil {
+    type Output = Less;
+}
+
+impl<H, T> LeafListCmp<TNil> for TList<H, T> {
+    type Output = Greater;
+}
+
+impl<LH, LT, RH, RT, LB, RB> LeafListCmp<TList<RH, RT>> for TList<LH, LT>
+where
+    LH: ReprBytes<TNil, Output = LB>,
+    RH: ReprBytes<TNil, Output = RB>,
+    LB: ByteListCmp<RB>,
+    LB::Output: ThenCmpLeaves<LT, RT>,
+{
+    type Output = <LB::Output as ThenCmpLeaves<LT, RT>>::Output;
+}
+
+/// Compares the `L` and `R` lists of leaf tuples if `Self` is `Equal`,
+/// otherwise outputs `Self`.
+pub trait ThenCmpLeaves<L, R> {
+    type Output;
+}
+
+impl<L, R> ThenCmpLeaves<L, R> for Less {
+    type Output = Less;
+}
+
+impl<L, R> ThenCmpLeaves<L, R> for Equal
+where
+    L: LeafListCmp<R>,
+{
+    type Output = L::Output;
+}
+
+impl<L, R> ThenCmpLeaves<L, R> for Greater {
+    type Output = Greater;
+}
+
+/// Lexicographic comparison of two `TList`s of bytes,
+/// outputting one of `Less`, `Equal`, or `Greater`.
+pub trait ByteListCmp<Rhs> {
+    type Output;
+}
+
+impl ByteListCmp<TNil> for TNil {
+    type Output = Equal;
+}
+
+impl<H, T> ByteListCmp<TList<H, T>> for TNil {
+    type Output = Less;
+}
+
+impl<H, T> ByteListCmp<TNil> for TList<H, T> {
+    type Output = Greater;
+}
+
+impl<LH, LT, RH, RT> ByteListCmp<TList<RH, RT>> for TList<LH, LT>
+where
+    LH: CmpByte<RH>,
+    LH::Ord: ThenCmp<LT, RT>,
+{
+    type Output = <LH::Ord as ThenCmp<LT, RT>>::Output;
+}
+
+/// Compares the `L` and `R` lists if `Self` is `Equal`, otherwise outputs `Self`.
+pub trait ThenCmp<L, R> {
+    type Output;
+}
+
+impl<L, R> ThenCmp<L, R> for Less {
+    type Output = Less;
+}
+
+impl<L, R> ThenCmp<L, R> for Equal
+where
+    L: ByteListCmp<R>,
+{
+    type Output = L::Output;
+}
+
+impl<L, R> ThenCmp<L, R> for Greater {
+    type Output = Greater;
+}
+
+/// Type-level equality of two representations of type-level strings,
+/// outputting either `True` or `False`.
+///
+/// Equal strings have the same structure, so this compares both structurally,
+/// comparing all the elements of a pair of tuples in a single impl.
+/// The comparison stops at the first pair of elements that isn't equal,
+/// which keeps the amount of trait obligations low.
+pub trait ReprEq<Rhs> {
+    type Output;
+}
+
+impl<L, R, DI> ReprEq<R> for L
+where
+    L: HasSameKindNumber<R, DoesIt = DI>,
+    L: ReprEqInner<R, DI>,
+{
+    type Output = <L as ReprEqInner<R, DI>>::Output;
+}
+
+/// Equality of representations, where `SameKind` is whether both have the same `KindNumber`.
+pub trait ReprEqInner<Rhs, SameKind> {
+    type Output;
+}
+
+impl<L, R> ReprEqInner<R, False> for L {
+    type Output = False;
+}
+
+impl ReprEqInner<(), True> for () {
+    type Output = True;
+}
+
+/// Equality of two tuples of the same length,
+/// where `IsByteL` and `IsByteR` are whether their first elements are bytes.
+pub trait TupleEq<Rhs, IsByteL, IsByteR> {
+    type Output;
+}
+
+impl<L, R> TupleEq<R, True, False> for L {
+    type Output = False;
+}
+
+impl<L, R> TupleEq<R, False, True> for L {
+    type Output = False;
+}
+
+/// For comparing bytes for equality, while taking into account
+/// whether the previous bytes were equal.
+pub trait ByteEqCarry<PrevEq, R> {
+    /// Whether Self and all the previous bytes are equal. Either True or False.
+    type Eq;
+}
+
+impl<L, R> ByteEqCarry<False, R> for L {
+    type Eq = False;
+}
+
+impl<L, R> ByteEqCarry<True, R> for L
+where
+    L: CmpByte<R>,
+    L::Ord: IsEqual,
+{
+    type Eq = <L::Ord as IsEqual>::Output;
+}
+
+/// For comparing representations of strings for equality, while taking into account
+/// whether the previous elements were equal.
+pub trait ReprEqCarry<PrevEq, R> {
+    /// Whether Self and all the previous elements are equal. Either True or False.
+    type Eq;
+}
+
+impl<L, R> ReprEqCarry<False, R> for L {
+    type Eq = False;
+}
+
+impl<L, R> ReprEqCarry<True, R> for L
+where
+    L: ReprEq<R>,
+{
+    type Eq = L::Output;
+}
+
+// `$fl` and `$fr` are the first elements of the tuples,
+// `$prev` is whether the elements before each one are equal,
+// and `$eq` is whether the elements up to (and including) each one are equal.
+macro_rules! tuple_eq_impl {
+    (
+        $fl:ident $fr:ident,
+        [$($l:ident)*],
+        [$($r:ident)*],
+        [$($prev:ident)*],
+        [$($eq:ident)*],
+        $last:ident
+    ) => (
+        impl<$($l,)* $($r,)*> ReprEqInner<($($r,)*), True> for ($($l,)*)
+        where
+            $fl: Classify,
+            $fr: Classify,
+            Self: TupleEq<($($r,)*), $fl::IsByte, $fr::IsByte>,
+        {
+            type Output = <Self as TupleEq<($($r,)*), $fl::IsByte, $fr::IsByte>>::Output;
+        }
+
+        impl<$($l,)* $($r,)* $($eq,)*> TupleEq<($($r,)*), True, True> for ($($l,)*)
+        where
+            $($l: ByteEqCarry<$prev, $r, Eq = $eq>,)*
+        {
+            type Output = $last;
+        }
+
+        impl<$($l,)* $($r,)* $($eq,)*> TupleEq<($($r,)*), False, False> for ($($l,)*)
+        where
+            $($l: ReprEqCarry<$prev, $r, Eq = $eq>,)*
+        {
+            type Output = $last;
+        }
+    );
+}
+
+tuple_eq_impl! { L0 R0, [L0], [R0], [True], [E0], E0 }
+tuple_eq_impl! { L0 R0, [L0 L1], [R0 R1], [True E0], [E0 E1], E1 }
+tuple_eq_impl! { L0 R0, [L0 L1 L2], [R0 R1 R2], [True E0 E1], [E0 E1 E2], E2 }
+tuple_eq_impl! {
+    L0 R0,
+    [L0 L1 L2 L3],
+    [R0 R1 R2 R3],
+    [True E0 E1 E2],
+    [E0 E1 E2 E3],
+    E3
+}
+tuple_eq_impl! {
+    L0 R0,
+    [L0 L1 L2 L3 L4],
+    [R0 R1 R2 R3 R4],
+    [True E0 E1 E2 E3],
+    [E0 E1 E2 E3 E4],
+    E4
+}
+tuple_eq_impl! {
+    L0 R0,
+    [L0 L1 L2 L3 L4 L5],
+    [R0 R1 R2 R3 R4 R5],
+    [True E0 E1 E2 E3 E4],
+    [E0 E1 E2 E3 E4 E5],
+    E5
+}
+tuple_eq_impl! {
+    L0 R0,
+    [L0 L1 L2 L3 L4 L5 L6],
+    [R0 R1 R2 R3 R4 R5 R6],
+    [True E0 E1 E2 E3 E4 E5],
+    [E0 E1 E2 E3 E4 E5 E6],
+    E6
+}
+tuple_eq_impl! {
+    L0 R0,
+    [L0 L1 L2 L3 L4 L5 L6 L7],
+    [R0 R1 R2 R3 R4 R5 R6 R7],
+    [True E0 E1 E2 E3 E4 E5 E6],
+    [E0 E1 E2 E3 E4 E5 E6 E7],
+    E7
+}
+
+macro_rules! repr_bytes_tuple_impl {
+    ($fty:ident $($ty:ident)*) => (
+        impl<$fty, $($ty,)* Rest> ReprBytes<Rest> for ($fty, $($ty,)*)
+        where
+            ($($ty,)*): ReprBytes<Rest>,
+            $fty: ReprBytes<<($($ty,)*) as ReprBytes<Rest>>::Output>,
+        {
+            type Output = <$fty as ReprBytes<<($($ty,)*) as ReprBytes<Rest>>::Output>>::Output;
+        }
+
+        impl<$fty, $($ty,)* Rest> ReprLeaves<Rest> for ($fty, $($ty,)*)
+        where
+            $fty: Classify,
+            Self: ReprLeavesInner<Rest, $fty::IsByte>,
+        {
+            type Output = <Self as ReprLeavesInner<Rest, $fty::IsByte>>::Output;
+        }
+
+        impl<$fty, $($ty,)* Rest> ReprLeavesInner<Rest, True> for ($fty, $($ty,)*) {
+            type Output = TList<Self, Rest>;
+        }
+
+        impl<$fty, $($ty,)* Rest> ReprLeavesInner<Rest, False> for ($fty, $($ty,)*)
+        where
+            ($($ty,)*): ReprLeaves<Rest>,
+            $fty: ReprLeaves<<($($ty,)*) as ReprLeaves<Rest>>::Output>,
+        {
+            type Output = <$fty as ReprLeaves<<($($ty,)*) as ReprLeaves<Rest>>::Output>>::Output;
+        }
+    )
+}
+
+repr_bytes_tuple_impl! { L0 }
+repr_bytes_tuple_impl! { L0 L1 }
+repr_bytes_tuple_impl! { L0 L1 L2 }
+repr_bytes_tuple_impl! { L0 L1 L2 L3 }
+repr_bytes_tuple_impl! { L0 L1 L2 L3 L4 }
+repr_bytes_tuple_impl! { L0 L1 L2 L3 L4 L5 }
+repr_bytes_tuple_impl! { L0 L1 L2 L3 L4 L5 L6 }
+repr_bytes_tuple_impl! { L0 L1 L2 L3 L4 L5 L6 L7 }
+
+macro_rules! impl_bytes {
+    ($($byte:ident = [$d3:ident $d2:ident $d1:ident $d0:ident],)*) => (
+        $(
+            impl ByteNumber for crate::$byte {
+                type Number = Number4_5<$d3, $d2, $d1, $d0>;
+            }
+
+            impl<Rest> ReprBytes<Rest> for crate::$byte {
+                type Output = TList<Self, Rest>;
+            }
+        )*
+    )
+}
+
+/*
+fn main() {
+    let list = (0..=255u32)
+        .map(|b| {
+            let digits = [b / 125, (b / 25) % 5, (b / 5) % 5, b % 5];
+            let digits = digits.iter().map(|d| format!("D{}", d)).collect::<Vec<_>>();
+            format!("__0x{:02X} = [{}],", b, digits.join(" "))
+        })
+        .collect::<Vec<_>>();
+
+    for chunk in list.chunks(3) {
+        println!("    {}", chunk.join(" "));
+    }
+}
+*/
+
+impl_bytes! {
+    __0x00 = [D0 D0 D0 D0], __0x01 = [D0 D0 D0 D1], __0x02 = [D0 D0 D0 D2],
+    __0x03 = [D0 D0 D0 D3], __0x04 = [D0 D0 D0 D4], __0x05 = [D0 D0 D1 D0],
+    __0x06 = [D0 D0 D1 D1], __0x07 = [D0 D0 D1 D2], __0x08 = [D0 D0 D1 D3],
+    __0x09 = [D0 D0 D1 D4], __0x0A = [D0 D0 D2 D0], __0x0B = [D0 D0 D2 D1],
+    __0x0C = [D0 D0 D2 D2], __0x0D = [D0 D0 D2 D3], __0x0E = [D0 D0 D2 D4],
+    __0x0F = [D0 D0 D3 D0], __0x10 = [D0 D0 D3 D1], __0x11 = [D0 D0 D3 D2],
+    __0x12 = [D0 D0 D3 D3], __0x13 = [D0 D0 D3 D4], __0x14 = [D0 D0 D4 D0],
+    __0x15 = [D0 D0 D4 D1], __0x16 = [D0 D0 D4 D2], __0x17 = [D0 D0 D4 D3],
+    __0x18 = [D0 D0 D4 D4], __0x19 = [D0 D1 D0 D0], __0x1A = [D0 D1 D0 D1],
+    __0x1B = [D0 D1 D0 D2], __0x1C = [D0 D1 D0 D3], __0x1D = [D0 D1 D0 D4],
+    __0x1E = [D0 D1 D1 D0], __0x1F = [D0 D1 D1 D1], __0x20 = [D0 D1 D1 D2],
+    __0x21 = [D0 D1 D1 D3], __0x22 = [D0 D1 D1 D4], __0x23 = [D0 D1 D2 D0],
+    __0x24 = [D0 D1 D2 D1], __0x25 = [D0 D1 D2 D2], __0x26 = [D0 D1 D2 D3],
+    __0x27 = [D0 D1 D2 D4], __0x28 = [D0 D1 D3 D0], __0x29 = [D0 D1 D3 D1],
+    __0x2A = [D0 D1 D3 D2], __0x2B = [D0 D1 D3 D3], __0x2C = [D0 D1 D3 D4],
+    __0x2D = [D0 D1 D4 D0], __0x2E = [D0 D1 D4 D1], __0x2F = [D0 D1 D4 D2],
+    __0x30 = [D0 D1 D4 D3], __0x31 = [D0 D1 D4 D4], __0x32 = [D0 D2 D0 D0],
+    __0x33 = [D0 D2 D0 D1], __0x34 = [D0 D2 D0 D2], __0x35 = [D0 D2 D0 D3],
+    __0x36 = [D0 D2 D0 D4], __0x37 = [D0 D2 D1 D0], __0x38 = [D0 D2 D1 D1],
+    __0x39 = [D0 D2 D1 D2], __0x3A = [D0 D2 D1 D3], __0x3B = [D0 D2 D1 D4],
+    __0x3C = [D0 D2 D2 D0], __0x3D = [D0 D2 D2 D1], __0x3E = [D0 D2 D2 D2],
+    __0x3F = [D0 D2 D2 D3], __0x40 = [D0 D2 D2 D4], __0x41 = [D0 D2 D3 D0],
+    __0x42 = [D0 D2 D3 D1], __0x43 = [D0 D2 D3 D2], __0x44 = [D0 D2 D3 D3],
+    __0x45 = [D0 D2 D3 D4], __0x46 = [D0 D2 D4 D0], __0x47 = [D0 D2 D4 D1],
+    __0x48 = [D0 D2 D4 D2], __0x49 = [D0 D2 D4 D3], __0x4A = [D0 D2 D4 D4],
+    __0x4B = [D0 D3 D0 D0], __0x4C = [D0 D3 D0 D1], __0x4D = [D0 D3 D0 D2],
+    __0x4E = [D0 D3 D0 D3], __0x4F = [D0 D3 D0 D4], __0x50 = [D0 D3 D1 D0],
+    __0x51 = [D0 D3 D1 D1], __0x52 = [D0 D3 D1 D2], __0x53 = [D0 D3 D1 D3],
+    __0x54 = [D0 D3 D1 D4], __0x55 = [D0 D3 D2 D0], __0x56 = [D0 D3 D2 D1],
+    __0x57 = [D0 D3 D2 D2], __0x58 = [D0 D3 D2 D3], __0x59 = [D0 D3 D2 D4],
+    __0x5A = [D0 D3 D3 D0], __0x5B = [D0 D3 D3 D1], __0x5C = [D0 D3 D3 D2],
+    __0x5D = [D0 D3 D3 D3], __0x5E = [D0 D3 D3 D4], __0x5F = [D0 D3 D4 D0],
+    __0x60 = [D0 D3 D4 D1], __0x61 = [D0 D3 D4 D2], __0x62 = [D0 D3 D4 D3],
+    __0x63 = [D0 D3 D4 D4], __0x64 = [D0 D4 D0 D0], __0x65 = [D0 D4 D0 D1],
+    __0x66 = [D0 D4 D0 D2], __0x67 = [D0 D4 D0 D3], __0x68 = [D0 D4 D0 D4],
+    __0x69 = [D0 D4 D1 D0], __0x6A = [D0 D4 D1 D1], __0x6B = [D0 D4 D1 D2],
+    __0x6C = [D0 D4 D1 D3], __0x6D = [D0 D4 D1 D4], __0x6E = [D0 D4 D2 D0],
+    __0x6F = [D0 D4 D2 D1], __0x70 = [D0 D4 D2 D2], __0x71 = [D0 D4 D2 D3],
+    __0x72 = [D0 D4 D2 D4], __0x73 = [D0 D4 D3 D0], __0x74 = [D0 D4 D3 D1],
+    __0x75 = [D0 D4 D3 D2], __0x76 = [D0 D4 D3 D3], __0x77 = [D0 D4 D3 D4],
+    __0x78 = [D0 D4 D4 D0], __0x79 = [D0 D4 D4 D1], __0x7A = [D0 D4 D4 D2],
+    __0x7B = [D0 D4 D4 D3], __0x7C = [D0 D4 D4 D4], __0x7D = [D1 D0 D0 D0],
+    __0x7E = [D1 D0 D0 D1], __0x7F = [D1 D0 D0 D2], __0x80 = [D1 D0 D0 D3],
+    __0x81 = [D1 D0 D0 D4], __0x82 = [D1 D0 D1 D0], __0x83 = [D1 D0 D1 D1],
+    __0x84 = [D1 D0 D1 D2], __0x85 = [D1 D0 D1 D3], __0x86 = [D1 D0 D1 D4],
+    __0x87 = [D1 D0 D2 D0], __0x88 = [D1 D0 D2 D1], __0x89 = [D1 D0 D2 D2],
+    __0x8A = [D1 D0 D2 D3], __0x8B = [D1 D0 D2 D4], __0x8C = [D1 D0 D3 D0],
+    __0x8D = [D1 D0 D3 D1], __0x8E = [D1 D0 D3 D2], __0x8F = [D1 D0 D3 D3],
+    __0x90 = [D1 D0 D3 D4], __0x91 = [D1 D0 D4 D0], __0x92 = [D1 D0 D4 D1],
+    __0x93 = [D1 D0 D4 D2], __0x94 = [D1 D0 D4 D3], __0x95 = [D1 D0 D4 D4],
+    __0x96 = [D1 D1 D0 D0], __0x97 = [D1 D1 D0 D1], __0x98 = [D1 D1 D0 D2],
+    __0x99 = [D1 D1 D0 D3], __0x9A = [D1 D1 D0 D4], __0x9B = [D1 D1 D1 D0],
+    __0x9C = [D1 D1 D1 D1], __0x9D = [D1 D1 D1 D2], __0x9E = [D1 D1 D1 D3],
+    __0x9F = [D1 D1 D1 D4], __0xA0 = [D1 D1 D2 D0], __0xA1 = [D1 D1 D2 D1],
+    __0xA2 = [D1 D1 D2 D2], __0xA3 = [D1 D1 D2 D3], __0xA4 = [D1 D1 D2 D4],
+    __0xA5 = [D1 D1 D3 D0], __0xA6 = [D1 D1 D3 D1], __0xA7 = [D1 D1 D3 D2],
+    __0xA8 = [D1 D1 D3 D3], __0xA9 = [D1 D1 D3 D4], __0xAA = [D1 D1 D4 D0],
+    __0xAB = [D1 D1 D4 D1], __0xAC = [D1 D1 D4 D2], __0xAD = [D1 D1 D4 D3],
+    __0xAE = [D1 D1 D4 D4], __0xAF = [D1 D2 D0 D0], __0xB0 = [D1 D2 D0 D1],
+    __0xB1 = [D1 D2 D0 D2], __0xB2 = [D1 D2 D0 D3], __0xB3 = [D1 D2 D0 D4],
+    __0xB4 = [D1 D2 D1 D0], __0xB5 = [D1 D2 D1 D1], __0xB6 = [D1 D2 D1 D2],
+    __0xB7 = [D1 D2 D1 D3], __0xB8 = [D1 D2 D1 D4], __0xB9 = [D1 D2 D2 D0],
+    __0xBA = [D1 D2 D2 D1], __0xBB = [D1 D2 D2 D2], __0xBC = [D1 D2 D2 D3],
+    __0xBD = [D1 D2 D2 D4], __0xBE = [D1 D2 D3 D0], __0xBF = [D1 D2 D3 D1],
+    __0xC0 = [D1 D2 D3 D2], __0xC1 = [D1 D2 D3 D3], __0xC2 = [D1 D2 D3 D4],
+    __0xC3 = [D1 D2 D4 D0], __0xC4 = [D1 D2 D4 D1], __0xC5 = [D1 D2 D4 D2],
+    __0xC6 = [D1 D2 D4 D3], __0xC7 = [D1 D2 D4 D4], __0xC8 = [D1 D3 D0 D0],
+    __0xC9 = [D1 D3 D0 D1], __0xCA = [D1 D3 D0 D2], __0xCB = [D1 D3 D0 D3],
+    __0xCC = [D1 D3 D0 D4], __0xCD = [D1 D3 D1 D0], __0xCE = [D1 D3 D1 D1],
+    __0xCF = [D1 D3 D1 D2], __0xD0 = [D1 D3 D1 D3], __0xD1 = [D1 D3 D1 D4],
+    __0xD2 = [D1 D3 D2 D0], __0xD3 = [D1 D3 D2 D1], __0xD4 = [D1 D3 D2 D2],
+    __0xD5 = [D1 D3 D2 D3], __0xD6 = [D1 D3 D2 D4], __0xD7 = [D1 D3 D3 D0],
+    __0xD8 = [D1 D3 D3 D1], __0xD9 = [D1 D3 D3 D2], __0xDA = [D1 D3 D3 D3],
+    __0xDB = [D1 D3 D3 D4], __0xDC = [D1 D3 D4 D0], __0xDD = [D1 D3 D4 D1],
+    __0xDE = [D1 D3 D4 D2], __0xDF = [D1 D3 D4 D3], __0xE0 = [D1 D3 D4 D4],
+    __0xE1 = [D1 D4 D0 D0], __0xE2 = [D1 D4 D0 D1], __0xE3 = [D1 D4 D0 D2],
+    __0xE4 = [D1 D4 D0 D3], __0xE5 = [D1 D4 D0 D4], __0xE6 = [D1 D4 D1 D0],
+    __0xE7 = [D1 D4 D1 D1], __0xE8 = [D1 D4 D1 D2], __0xE9 = [D1 D4 D1 D3],
+    __0xEA = [D1 D4 D1 D4], __0xEB = [D1 D4 D2 D0], __0xEC = [D1 D4 D2 D1],
+    __0xED = [D1 D4 D2 D2], __0xEE = [D1 D4 D2 D3], __0xEF = [D1 D4 D2 D4],
+    __0xF0 = [D1 D4 D3 D0], __0xF1 = [D1 D4 D3 D1], __0xF2 = [D1 D4 D3 D2],
+    __0xF3 = [D1 D4 D3 D3], __0xF4 = [D1 D4 D3 D4], __0xF5 = [D1 D4 D4 D0],
+    __0xF6 = [D1 D4 D4 D1], __0xF7 = [D1 D4 D4 D2], __0xF8 = [D1 D4 D4 D3],
+    __0xF9 = [D1 D4 D4 D4], __0xFA = [D2 D0 D0 D0], __0xFB = [D2 D0 D0 D1],
+    __0xFC = [D2 D0 D0 D2], __0xFD = [D2 D0 D0 D3], __0xFE = [D2 D0 D0 D4],
+    __0xFF = [D2 D0 D1 D0],
+}
@@ -0,0 +1,109 @@
+//! Conversion from `usize` constants to the [`TStr`] of their decimal representation.
+//!
+//! [`TStr`]: ../struct.TStr.html
+
+use crate::{TStr, ToUint, TS};
+
+/// A type-level `usize`, which can be converted to a [`TStr`] with [`FromUint`].
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`FromUint`]: ./trait.FromUint.html
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Uint<const N: usize>;
+
+/// Converts a type-level integer into the [`TStr`] of its decimal representation,
+/// the inverse of [`ToUint`].
+///
+/// This is only implemented for [`Uint`]s from `Uint<0>` to `Uint<255>`,
+/// because producing the string of an arbitrary `N` requires the
+/// unstable `generic_const_exprs` feature.
+///
+/// The [`TStrOfUint`] type alias is the more convenient way to use this trait.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{FromUint, ToUint, TStrOfUint, Uint, ts};
+///
+/// let _: TStrOfUint<0> = ts!(0);
+/// let _: TStrOfUint<10> = ts!(10);
+/// let _: <Uint<255> as FromUint>::TStr = ts!(255);
+///
+/// // Getting the `TStr` of a position that's stored in a constant
+/// const SECOND: usize = 1;
+///
+/// let _: TStrOfUint<SECOND> = ts!(1);
+/// assert_eq!(<TStrOfUint<SECOND>>::USIZE, 1);
+/// assert_eq!(<Uint<13> as FromUint>::NEW, ts!(13));
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`ToUint`]: ./trait.ToUint.html
+/// [`Uint`]: ./struct.Uint.html
+/// [`TStrOfUint`]: ./type.TStrOfUint.html
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+pub trait FromUint {
+    /// The `TStr` of the decimal representation of the integer.
+    type TStr: ToUint + Copy;
+
+    /// Constructs the `TStr` of the integer.
+    const NEW: Self::TStr;
+}
+
+/// The [`TStr`] of the decimal representation of `N`.
+///
+/// Only `N`s from 0 to 255 are supported, as explained in the docs for [`FromUint`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TStrOfUint, TS};
+///
+/// let _: TStrOfUint<3> = tstr::ts!(3);
+/// let _: TStrOfUint<100> = <TS!(100)>::NEW;
+///
+/// ```
+///
+/// Numbers past 255 aren't supported:
+///
+/// ```compile_fail
+/// let _: tstr::TStrOfUint<256>;
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`FromUint`]: ./trait.FromUint.html
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+pub type TStrOfUint<const N: usize> = <Uint<N> as FromUint>::TStr;
+
+macro_rules! from_uint_impls {
+    ($($n:literal)*) => {
+        $(
+            impl FromUint for Uint<$n> {
+                type TStr = TS!($n);
+
+                const NEW: Self::TStr = TStr::NEW;
+            }
+        )*
+    };
+}
+
+from_uint_impls! {
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+    16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+    48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+    64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+    80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+    96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+    112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+    128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143
+    144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159
+    160 161 162 163 164 165 166 167 168 169 170 171 172 173 174 175
+    176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191
+    192 193 194 195 196 197 198 199 200 201 202 203 204 205 206 207
+    208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223
+    224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239
+    240 241 242 243 244 245 246 247 248 249 250 251 252 253 254 255
+}
@@ -0,0 +1,260 @@
+//! Conversions from the labelled generic representation of the [`frunk`] crate
+//! to [`Field`]s keyed by [`TStr`]s.
+//!
+//! This module requires the `"frunk"` feature.
+//!
+//! The labels of the fields that `#[derive(LabelledGeneric)]` generates
+//! are tuples of the character types in `frunk::labelled::chars`,
+//! which [`FrunkLabel`] maps to the [`TStr`] of the same string.
+//! `frunk` labels can also be [`TStr`]s, which are mapped to themselves.
+//!
+//! # Example
+//!
+//! This example requires the `"cmp_traits"` feature,
+//! and no `"min_const_generics"` feature, to use [`Record`]s.
+//!
+#![cfg_attr(
+    all(feature = "cmp_traits", not(feature = "min_const_generics")),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(feature = "cmp_traits", not(feature = "min_const_generics"))),
+    doc = "```ignore"
+)]
+//! use frunk::hlist;
+//! use frunk::labelled::chars::{a, e, g, m, n};
+//! use frunk::labelled::field_with_name;
+//!
+//! use tstr::records::Record;
+//! use tstr::{ts, Field, TS};
+//!
+//! // What `frunk::into_labelled_generic` returns for
+//! // a `struct Person { name: &'static str, age: u32 }`
+//! let person = hlist![
+//!     field_with_name::<(n, a, m, e), _>("name", "Bob"),
+//!     field_with_name::<(a, g, e), _>("age", 21u32),
+//! ];
+//!
+//! let record: Record<(Field<TS!(name), &str>, (Field<TS!(age), u32>, ()))> = person.into();
+//!
+//! assert_eq!(record[ts!(name)], "Bob");
+//! assert_eq!(record[ts!(age)], 21);
+//!
+//! ```
+//!
+//! [`frunk`]: https://docs.rs/frunk/
+//! [`Field`]: ../struct.Field.html
+//! [`TStr`]: ../struct.TStr.html
+//! [`FrunkLabel`]: ./trait.FrunkLabel.html
+//! [`Record`]: ../records/struct.Record.html
+
+use crate::{Field, MakeTStr, TStr};
+
+use frunk::labelled::Field as FrunkField;
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait Sealed {}
+
+    /// A character type from `frunk::labelled::chars`,
+    /// with the type of the byte of the character in the default representation of `TStr`s.
+    #[doc(hidden)]
+    pub trait FrunkChar {
+        type Byte;
+    }
+}
+use sealed::Sealed;
+
+#[doc(hidden)]
+pub use sealed::FrunkChar;
+
+/// A label of a `frunk` field, which is mapped to the [`TStr`] of the same string.
+///
+/// This trait is sealed, it's implemented for:
+///
+/// - [`TStr`]s: the `TStr` is the label itself.
+///
+/// - Tuples of up to 64 of the character types from `frunk::labelled::chars`
+/// (the labels that the `frunk` derives generate),
+/// for the ASCII characters that can be in identifiers.
+/// This is only implemented without the `"min_const_generics"` feature,
+/// since these labels are mapped to the default representation of type-level strings.
+///
+/// # Example
+///
+#[cfg_attr(not(feature = "min_const_generics"), doc = "```rust")]
+#[cfg_attr(feature = "min_const_generics", doc = "```ignore")]
+/// use frunk::labelled::chars::{a, e, m, n, _0, _1, __};
+///
+/// use tstr::frunk_fields::{FrunkLabel, LabelTStr};
+/// use tstr::{ts, TS};
+///
+/// let _: LabelTStr<(n, a, m, e)> = ts!(name);
+/// let _: LabelTStr<(__, _1, _0)> = ts!(_10);
+/// let _: LabelTStr<TS!(foo)> = ts!(foo);
+///
+/// let _: <(a, __, a) as FrunkLabel>::TStr = ts!(a_a);
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+pub trait FrunkLabel: Sealed {
+    /// The `TStr` of the label.
+    type TStr: MakeTStr;
+}
+
+/// The [`TStr`] of the `L` label of a `frunk` field.
+///
+/// For examples, look at the docs for [`FrunkLabel`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`FrunkLabel`]: ./trait.FrunkLabel.html
+pub type LabelTStr<L> = <L as FrunkLabel>::TStr;
+
+impl<T> Sealed for TStr<T> {}
+
+impl<T> FrunkLabel for TStr<T> {
+    type TStr = Self;
+}
+
+/// Converts a `frunk` field into a `Field` with the same value,
+/// whose name is the [`TStr`] of the label of the `frunk` field.
+///
+/// [`TStr`]: ../struct.TStr.html
+impl<L, V> From<FrunkField<L, V>> for Field<LabelTStr<L>, V>
+where
+    L: FrunkLabel,
+{
+    #[inline]
+    fn from(field: FrunkField<L, V>) -> Self {
+        Field::from_value(field.value)
+    }
+}
+
+/// Converts a `Field` into a `frunk` field that uses the [`TStr`] as the label,
+/// and the string of the `TStr` as the name.
+///
+/// This is only available with the `"const_generics"` feature,
+/// since it's the only one that allows getting the string of a `TStr`.
+///
+/// [`TStr`]: ../struct.TStr.html
+#[cfg(feature = "const_generics")]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "frunk", feature = "const_generics")))
+)]
+impl<T, V> From<Field<TStr<T>, V>> for FrunkField<TStr<T>, V>
+where
+    TStr<T>: crate::StrValue,
+{
+    #[inline]
+    fn from(field: Field<TStr<T>, V>) -> Self {
+        frunk::labelled::field_with_name(<TStr<T> as crate::StrValue>::STR, field.into_inner())
+    }
+}
+
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+mod record_impls {
+    use super::{FrunkField, FrunkLabel, LabelTStr};
+
+    use crate::{records::Record, Field};
+
+    use frunk::hlist::{HCons, HNil};
+
+    /// Converts the labelled generic representation of a struct without fields
+    /// into an empty `Record`.
+    impl From<HNil> for Record<()> {
+        #[inline]
+        fn from(_: HNil) -> Self {
+            Record::new(())
+        }
+    }
+
+    /// Converts the labelled generic representation of a struct into a `Record`,
+    /// with the fields in the same order.
+    impl<L, V, T, R> From<HCons<FrunkField<L, V>, T>> for Record<(Field<LabelTStr<L>, V>, R)>
+    where
+        L: FrunkLabel,
+        Record<R>: From<T>,
+    {
+        #[inline]
+        fn from(list: HCons<FrunkField<L, V>, T>) -> Self {
+            let tail = Record::<R>::from(list.tail).into_fields();
+            Record::new((Field::from(list.head), tail))
+        }
+    }
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+macro_rules! char_impls {
+    ($($char:ident = $byte:ident),* $(,)?) => {
+        $(
+            impl FrunkChar for frunk::labelled::chars::$char {
+                type Byte = crate::$byte;
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+char_impls! {
+    a = __0x61, b = __0x62, c = __0x63, d = __0x64, e = __0x65, f = __0x66,
+    g = __0x67, h = __0x68, i = __0x69, j = __0x6A, k = __0x6B, l = __0x6C,
+    m = __0x6D, n = __0x6E, o = __0x6F, p = __0x70, q = __0x71, r = __0x72,
+    s = __0x73, t = __0x74, u = __0x75, v = __0x76, w = __0x77, x = __0x78,
+    y = __0x79, z = __0x7A, A = __0x41, B = __0x42, C = __0x43, D = __0x44,
+    E = __0x45, F = __0x46, G = __0x47, H = __0x48, I = __0x49, J = __0x4A,
+    K = __0x4B, L = __0x4C, M = __0x4D, N = __0x4E, O = __0x4F, P = __0x50,
+    Q = __0x51, R = __0x52, S = __0x53, T = __0x54, U = __0x55, V = __0x56,
+    W = __0x57, X = __0x58, Y = __0x59, Z = __0x5A, _0 = __0x30, _1 = __0x31,
+    _2 = __0x32, _3 = __0x33, _4 = __0x34, _5 = __0x35, _6 = __0x36, _7 = __0x37,
+    _8 = __0x38, _9 = __0x39, __ = __0x5F,
+}
+
+// Implements `FrunkLabel` for tuples of every length up to 64,
+// splitting the characters into chunks of 8 for labels that are longer than 8 characters.
+#[cfg(not(feature = "min_const_generics"))]
+macro_rules! label_impls {
+    (@lengths [$($prev:ident)*]) => {};
+    (@lengths [$($prev:ident)*] $next:ident $($rest:ident)*) => {
+        label_impls! {@chunks [] $($prev)* $next}
+        label_impls! {@lengths [$($prev)* $next] $($rest)*}
+    };
+
+    (
+        @chunks [$($chunks:tt)*]
+        $c0:ident $c1:ident $c2:ident $c3:ident $c4:ident $c5:ident $c6:ident $c7:ident
+        $($rest:ident)+
+    ) => {
+        label_impls! {@chunks [$($chunks)* ($c0 $c1 $c2 $c3 $c4 $c5 $c6 $c7)] $($rest)*}
+    };
+    (@chunks [] $($chars:ident)*) => {
+        impl<$($chars,)*> Sealed for ($($chars,)*) {}
+
+        impl<$($chars,)*> FrunkLabel for ($($chars,)*)
+        where
+            $($chars: FrunkChar,)*
+        {
+            type TStr = TStr<($($chars::Byte,)*)>;
+        }
+    };
+    (@chunks [$(($($chunk:ident)*))*] $($chars:ident)*) => {
+        impl<$($($chunk,)*)* $($chars,)*> Sealed for ($($($chunk,)*)* $($chars,)*) {}
+
+        impl<$($($chunk,)*)* $($chars,)*> FrunkLabel for ($($($chunk,)*)* $($chars,)*)
+        where
+            $($($chunk: FrunkChar,)*)*
+            $($chars: FrunkChar,)*
+        {
+            type TStr = TStr<($(($($chunk::Byte,)*),)* ($($chars::Byte,)*),)>;
+        }
+    };
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+label_impls! {
+    @lengths []
+    C0 C1 C2 C3 C4 C5 C6 C7 C8 C9 C10 C11 C12 C13 C14 C15 C16 C17 C18 C19 C20 C21 C22 C23 C24
+    C25 C26 C27 C28 C29 C30 C31 C32 C33 C34 C35 C36 C37 C38 C39 C40 C41 C42 C43 C44 C45 C46 C47
+    C48 C49 C50 C51 C52 C53 C54 C55 C56 C57 C58 C59 C60 C61 C62 C63
+}
@@ -0,0 +1,82 @@
+//! Conversions from `TStr` to `GenericArray`s of the bytes of the string.
+
+use crate::{
+    fmt_impls::{write_tstr, WriteRepr},
+    typenum_uints::{ByteLen, ByteLenOf},
+    TStr,
+};
+
+use generic_array::{ArrayLength, GenericArray};
+
+/// A `GenericArray` with exactly enough capacity for the bytes of the `S` [`TStr`].
+///
+/// This requires the `"generic_array"` feature,
+/// and is not available with the `"min_const_generics"` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts, ByteArrayOf, TS};
+///
+/// fn buffer() -> ByteArrayOf<TS!(key)> {
+///     ts!(key).to_generic_array()
+/// }
+///
+/// assert_eq!(buffer().as_slice(), b"key");
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "generic_array", not(feature = "min_const_generics"))))
+)]
+pub type ByteArrayOf<S> = GenericArray<u8, ByteLenOf<S>>;
+
+impl<T> TStr<T>
+where
+    T: WriteRepr,
+    Self: ByteLen,
+    ByteLenOf<Self>: ArrayLength,
+{
+    /// Gets the bytes of this `TStr` as a `GenericArray` of exactly the length of the string.
+    ///
+    /// This requires the `"generic_array"` feature,
+    /// and is not available with the `"min_const_generics"` feature.
+    ///
+    /// This allows storing the string in a fixed-capacity buffer without allocating,
+    /// eg: when serializing keys on `no_std` targets.
+    ///
+    /// With the `"const_generics"` feature, the `ToArray` trait
+    /// can be used to get the bytes as a `[u8; N]` array instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::ts;
+    ///
+    /// use generic_array::GenericArray;
+    /// use typenum::U5;
+    ///
+    /// let array: GenericArray<u8, U5> = ts!(hello).to_generic_array();
+    /// assert_eq!(array.as_slice(), b"hello");
+    ///
+    /// assert_eq!(ts!("ñu").to_generic_array().as_slice(), "ñu".as_bytes());
+    /// assert_eq!(ts!("").to_generic_array().as_slice(), b"");
+    ///
+    /// ```
+    #[cfg_attr(
+        feature = "docsrs",
+        doc(cfg(all(feature = "generic_array", not(feature = "min_const_generics"))))
+    )]
+    pub fn to_generic_array(self) -> ByteArrayOf<Self> {
+        let mut out = GenericArray::<u8, ByteLenOf<Self>>::default();
+        let mut len = 0;
+        let _ = write_tstr::<T, core::convert::Infallible>(&mut |string| {
+            out[len..len + string.len()].copy_from_slice(string.as_bytes());
+            len += string.len();
+            Ok(())
+        });
+        out
+    }
+}
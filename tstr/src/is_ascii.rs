@@ -0,0 +1,170 @@
+/// Queries whether a [`TStr`] only contains ASCII characters.
+///
+/// This is implemented for all [`TStr`]s.
+///
+/// For requiring the string to be ASCII, you can use the [`TStrAsciiOnly`] trait.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{IsAscii, TS};
+///
+/// assert!(<TS!(content_type)>::VALUE);
+/// assert!(<TS!("Content-Type")>::VALUE);
+/// assert!(<TS!("")>::VALUE);
+///
+/// assert!(!<TS!("Größe")>::VALUE);
+/// assert!(!<TS!("人")>::VALUE);
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TStrAsciiOnly`]: ./trait.TStrAsciiOnly.html
+pub trait IsAscii {
+    /// Whether the string only contains ASCII characters.
+    const VALUE: bool;
+}
+
+/// For asserting that a [`TStr`] only contains ASCII characters.
+///
+/// This is blanket implemented for all types that implement [`IsAscii`],
+/// and the assertion happens when the [`ASSERT_ASCII`] constant is used.
+///
+/// # Warning
+///
+/// Like the assertions in [`Assert`],
+/// the assertion might not be evaluated in functions that
+/// aren't reachable by public functions.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TStrAsciiOnly, ts};
+///
+/// let mut headers = Headers(Vec::new());
+/// headers.insert(ts!("Content-Type"), "text/plain");
+/// headers.insert(ts!("Content-Length"), "10");
+///
+/// struct Headers(Vec<&'static str>);
+///
+/// impl Headers {
+///     // HTTP header names are required to be ASCII
+///     fn insert<K>(&mut self, _key: K, value: &'static str)
+///     where
+///         K: TStrAsciiOnly,
+///     {
+///         let () = K::ASSERT_ASCII;
+///         self.0.push(value);
+///     }
+/// }
+/// ```
+///
+/// Using a non-ASCII string causes a compile-time error:
+///
+/// ```compile_fail
+/// use tstr::{TStrAsciiOnly, TS};
+///
+/// const _: () = <TS!("Größe")>::ASSERT_ASCII;
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`IsAscii`]: ./trait.IsAscii.html
+/// [`Assert`]: ./struct.Assert.html
+/// [`ASSERT_ASCII`]: #associatedconstant.ASSERT_ASCII
+pub trait TStrAsciiOnly: IsAscii {
+    /// Asserts that `Self` only contains ASCII characters,
+    /// causing a compile-time error if it doesn't.
+    const ASSERT_ASCII: ();
+}
+
+impl<T> TStrAsciiOnly for T
+where
+    T: ?Sized + IsAscii,
+{
+    const ASSERT_ASCII: () = {
+        #[allow(clippy::no_effect)]
+        ["Expected the string to only contain ASCII characters"][!T::VALUE as usize];
+    };
+}
+
+impl<T> IsAscii for crate::TStr<T>
+where
+    T: IsAscii,
+{
+    const VALUE: bool = T::VALUE;
+}
+
+#[cfg(any(not(feature = "const_generics"), feature = "dual_repr"))]
+macro_rules! tuple_impl {
+    ($($ty:ident)*) => (
+        #[doc(hidden)]
+        impl<$($ty,)*> IsAscii for ($($ty,)*)
+        where
+            $($ty: IsAscii,)*
+        {
+            const VALUE: bool = true $( & $ty::VALUE )*;
+        }
+    )
+}
+
+#[cfg(any(not(feature = "const_generics"), feature = "dual_repr"))]
+mod tuple_impls {
+    use super::IsAscii;
+
+    tuple_impl! {}
+    tuple_impl! {A }
+    tuple_impl! {A B}
+    tuple_impl! {A B C}
+    tuple_impl! {A B C D}
+    tuple_impl! {A B C D E}
+    tuple_impl! {A B C D E F}
+    tuple_impl! {A B C D E F G}
+    tuple_impl! {A B C D E F G H}
+}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+macro_rules! impl_for_min_const {
+    ($( ($chars_structs:ident, [$($chars:ident),*]) ,)*) => {
+        $(
+            impl<$(const $chars: char,)*> IsAscii for crate::$chars_structs<$($chars,)*> {
+                const VALUE: bool = true $( & $chars.is_ascii() )*;
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+impl_for_min_const! {
+    (__a, [A]),
+    (__b, [A,B]),
+    (__c, [A,B,C]),
+    (__d, [A,B,C,D]),
+    (__e, [A,B,C,D,E]),
+    (__f, [A,B,C,D,E,F]),
+    (__g, [A,B,C,D,E,F,G]),
+    (__ , [A,B,C,D,E,F,G,H]),
+}
+
+#[cfg(feature = "const_generics")]
+macro_rules! impl_for_const {
+    () => {
+        const fn str_is_ascii(s: &str) -> bool {
+            let s = s.as_bytes();
+            let mut i = 0;
+            while i != s.len() {
+                if s[i] >= 128 {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+
+        impl<const S: &'static str> IsAscii for crate::___<S> {
+            const VALUE: bool = str_is_ascii(S);
+        }
+    };
+}
+
+#[cfg(feature = "const_generics")]
+impl_for_const! {}
@@ -0,0 +1,139 @@
+use core::mem;
+
+use crate::{MakeTStr, NestedIndex, NestedIndexMut};
+
+/// A zero-sized accessor for the value at a path of type-level strings,
+/// built on the [`NestedIndex`] and [`NestedIndexMut`] traits.
+///
+/// `P` is a path that [`NestedIndex`] accepts:
+/// a [`TStr`] for a single field, or a tuple of paths (like the `TS!(foo.bar)` type)
+/// for a nested field.
+///
+/// Lenses can be combined with [`compose`](#method.compose),
+/// which returns a lens for the path of `self` followed by the path of the argument.
+///
+/// # Example
+///
+/// This uses types from the `for_examples` module,
+/// which can be seen in the docs with the "for_examples" feature.
+///
+#[cfg_attr(feature = "for_examples", doc = "```rust")]
+#[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
+/// use tstr::for_examples::{Inner, Outer};
+/// use tstr::{Lens, TS, ts};
+///
+/// let mut outer = Outer::new(Inner::new(3, false), "hello");
+///
+/// const INNER: Lens<TS!(inner)> = Lens::NEW;
+/// const LEAF: Lens<TS!(leaf)> = Lens::NEW;
+///
+/// let inner_leaf = INNER.compose(LEAF);
+///
+/// assert_eq!(*inner_leaf.get(&outer), 3);
+/// assert_eq!(*Lens::new(ts!(name)).get(&outer), "hello");
+///
+/// *inner_leaf.get_mut(&mut outer) += 2;
+/// assert_eq!(inner_leaf.set(&mut outer, 8), 5);
+///
+/// // Equivalent to `inner_leaf`
+/// let same_leaf = Lens::new(ts!(inner.leaf));
+/// assert_eq!(*same_leaf.get(&outer), 8);
+///
+/// ```
+///
+/// [`NestedIndex`]: ./trait.NestedIndex.html
+/// [`NestedIndexMut`]: ./trait.NestedIndexMut.html
+/// [`TStr`]: ./struct.TStr.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lens<P> {
+    path: P,
+}
+
+impl<P> Lens<P>
+where
+    P: MakeTStr,
+{
+    /// Constructs a `Lens`, getting the path with [`MakeTStr`].
+    ///
+    /// [`MakeTStr`]: ./trait.MakeTStr.html
+    pub const NEW: Self = Self { path: P::MAKE };
+}
+
+impl<P> Lens<P> {
+    /// Constructs a `Lens` from its path.
+    #[inline(always)]
+    pub const fn new(path: P) -> Self {
+        Self { path }
+    }
+
+    /// Unwraps this into its path.
+    #[inline(always)]
+    pub fn into_path(self) -> P {
+        self.path
+    }
+
+    /// Gets a reference to the value at the path of this `Lens`.
+    #[inline(always)]
+    pub fn get<T>(self, this: &T) -> &T::Output
+    where
+        T: ?Sized + NestedIndex<P>,
+    {
+        this.nested_index(self.path)
+    }
+
+    /// Gets a mutable reference to the value at the path of this `Lens`.
+    #[inline(always)]
+    pub fn get_mut<T>(self, this: &mut T) -> &mut T::Output
+    where
+        T: ?Sized + NestedIndexMut<P>,
+    {
+        this.nested_index_mut(self.path)
+    }
+
+    /// Replaces the value at the path of this `Lens`, returning the previous value.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "for_examples", doc = "```rust")]
+    #[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
+    /// use tstr::for_examples::{Inner, Outer};
+    /// use tstr::{Lens, ts};
+    ///
+    /// let mut outer = Outer::new(Inner::new(3, false), "hello");
+    /// let flag = Lens::new(ts!(inner.flag));
+    ///
+    /// assert_eq!(flag.set(&mut outer, true), false);
+    /// assert_eq!(*flag.get(&outer), true);
+    ///
+    /// ```
+    #[inline(always)]
+    pub fn set<T>(self, this: &mut T, value: T::Output) -> T::Output
+    where
+        T: ?Sized + NestedIndexMut<P>,
+        T::Output: Sized,
+    {
+        mem::replace(this.nested_index_mut(self.path), value)
+    }
+
+    /// Combines this `Lens` with `other`,
+    /// returning a `Lens` that accesses the value at the path of `other`,
+    /// inside the value at the path of `self`.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "for_examples", doc = "```rust")]
+    #[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
+    /// use tstr::for_examples::{Inner, Outer};
+    /// use tstr::{Lens, TS, ts};
+    ///
+    /// let outer = Outer::new(Inner::new(3, false), "hello");
+    ///
+    /// let lens: Lens<(TS!(inner), TS!(leaf))> = Lens::new(ts!(inner)).compose(Lens::new(ts!(leaf)));
+    /// assert_eq!(*lens.get(&outer), 3);
+    ///
+    /// ```
+    #[inline(always)]
+    pub fn compose<Q>(self, other: Lens<Q>) -> Lens<(P, Q)> {
+        Lens::new((self.path, other.path))
+    }
+}
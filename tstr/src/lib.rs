@@ -63,9 +63,7 @@
 //!
 //! use other_person::OtherPerson;
 //! mod other_person {
-//!     use std::ops::Index;
-//!
-//!     use tstr::TS;
+//!     use tstr::impl_index;
 //!     
 //!     pub struct OtherPerson {
 //!         name: &'static str,
@@ -78,19 +76,11 @@
 //!         }
 //!     }
 //!     
-//!     impl Index<TS!(name)> for OtherPerson {
-//!         type Output = str;
-//!         
-//!         fn index(&self, _: TS!(name)) -> &str {
-//!             self.name
-//!         }
-//!     }
-//!    
-//!     impl Index<TS!(surname)> for OtherPerson {
-//!         type Output = str;
-//!         
-//!         fn index(&self, _: TS!(surname)) -> &str {
-//!             self.surname
+//!     // The `impl_index` macro generates `Index` impls like the ones for `Person`
+//!     impl_index! {
+//!         OtherPerson {
+//!             name: str => self.name,
+//!             surname: str => self.surname,
 //!         }
 //!     }
 //! }
@@ -100,36 +90,181 @@
 //! # Macro expansion
 //!
 //! This library reserves the right to change how it represent type-level strings internally
-//! in every single release, and cargo feature combination.
+//! in every single release, and cargo feature combination
+//! (except with the `"stable_repr"` feature, described below).
 //!
 //! This only affects you if you expand the code generated by macros from this crate,
 //! and then use that expanded code instead of going through the macros.
 //!
+//! Proc macros that generate type-level strings can use the [`tstr_codegen`] crate,
+//! which generates invocations of the macros of this crate.
+//!
+//! ### Stable representation
+//!
+//! With the `"stable_repr"` feature,
+//! the representation of type-level strings is guaranteed to be the one described here,
+//! and to only change in semver-incompatible releases.
+//! This feature can't be combined with the features that change the representation.
+//!
+//! A type-level string is a `TStr<T>`, where `T` is computed from
+//! the UTF-8 bytes of the string like this:
+//!
+//! - The empty string is `()`.
+//!
+//! - Strings of 1 to 8 bytes are a tuple of the type of each byte,
+//! where the byte `0xNN` (in uppercase hexadecimal) is the `tstr::__0xNN` unit struct.
+//!
+//! - Longer strings are split into chunks of `P` bytes (the last chunk can be shorter),
+//! where `P` is the largest power of 8 that's smaller than the length of the string.
+//! Then the string is a tuple of the representation of each chunk
+//! (as described in this list, without the `TStr`).
+//!
+//! This makes the representation a tree where every tuple has at most 8 elements,
+//! nested as many levels as the logarithm (base 8) of the length of the string, rounded up,
+//! so the traits that recurse over the representation do so for a few levels,
+//! even for long strings (a 4096 byte string is nested 4 levels deep).
+//!
+//! The `tstr::__a`, `tstr::__0`, `tstr::____` (etc) type aliases of ascii letters,
+//! digits, and `_` are the same types as their `tstr::__0xNN` struct.
+//!
+//! Examples:
+//! ```text
+//! TS!("")           == TStr<()>
+//! TS!("ab")         == TStr<(__0x61, __0x62)>
+//! TS!("abcdefghi")  == TStr<((__0x61, ..., __0x68), (__0x69,))>
+//! ```
+//!
 //! # Cargo features
 //!
+//! - `"proc_macros"` (enabled by default):
+//! Enables the [`TS`], [`ts`], and [`alias`] macros, which depend on proc macros.
+//! Without this feature, the [`TS_chars`] and [`ts_chars`] macros can still be used,
+//! which are implemented without proc macros.
+//! The features that change the representation of type-level strings,
+//! and the features that change how literals are parsed, also enable this feature.
+//! The proc macros only use the compiler's `proc_macro` API,
+//! so this feature adds no dependencies other than `tstr_proc_macros`
+//! (the `"use_syn"` and `"use_litrs"` features add the dependencies that they're named after).
+//!
 //! - `"rust_1_46"`:
 //! Enables const functions in [`tstr::utils`] for comparing `&str` and `&[u8]`.
 //!
-//! - `"cmp_traits"`: Enables the traits for comparing type-level strings.
+//! - `"rust_1_88"`:
+//! Enables the [`TS_file`], [`ts_file`], [`TS_line`], and [`ts_line`] macros,
+//! which get the location that they're invoked at.
+//! This also enables the `"proc_macros"` and `"rust_1_46"` features.
+//! Requires Rust 1.88.0.
+//!
+//! - `"cmp_traits"`: Enables the traits for comparing type-level strings,
+//! the type-level collections in [`tstr::sets`] and [`tstr::maps`],
+//! and the anonymous records in [`tstr::records`]
+//! (only without the `"min_const_generics"` feature).
 //!
 //! - `"use_syn"`:
 //! Changes how literals passed to the macros of this crate are parsed to use the `syn` crate.
 //! Use this if there is some literal that could not be
 //! parsed but is a valid str/integer literal.
 //!
+//! - `"use_litrs"`:
+//! Changes how literals passed to the macros of this crate are parsed to use the `litrs` crate,
+//! which is a lighter dependency than `syn`.
+//! This has no effect if the `"use_syn"` feature is also enabled.
+//! Requires Rust 1.56.0.
+//!
 //! - `"min_const_generics"`:
 //! changes the representation of type-level strings to use many `char` const parameter,
+//! with each char of the string (including non-ASCII ones) being one const parameter,
 //! making for better compiler errors for non-alphanumeric-ascii strings.
+//! This also enables the [`ToUintRadix`] trait, for parsing strings as numbers in any base.
 //! Requires Rust 1.51.0.
 //!
 //! - `"const_generics"`:
 //! changes the representation of type-level strings to use a `&'static str` const parameter,
 //! making for better compiler errors, and a few more features.
+//! With this representation, the comparison and conversion traits
+//! (eg: [`TStrEq`], [`TStrOrd`], [`ToUint`]) are implemented with `const fn`s
+//! over the string, instead of recursing over the characters with traits.
 //! Requires `&'static str` to be stably usable as const parameters.
 //!
 //! - `"nightly_const_generics"`: Equivalent to the `"const_generics"` feature,
 //! but enables the nightly compiler features to use `&'static str` const parameters.
 //!
+//! - `"nightly_generic_const_exprs"`: Enables the `"nightly_const_generics"` feature,
+//! and the incomplete `generic_const_exprs` nightly compiler feature.
+//! With the `"cmp_traits"` feature, this implements [`TStrMinMax`]
+//! by comparing the strings in a const expression,
+//! and implements the type-level operators in the [`ops`] and [`case`] modules
+//! by building their string in a const expression.
+//! The strings that those operators build can be at most 1024 bytes long,
+//! it's a compile-time error for them to be longer.
+//!
+//! - `"dual_repr"`:
+//! Enables the [`TS_tupled`] and [`ts_tupled`] macros,
+//! which produce the default representation of type-level strings regardless of the enabled features.
+//! With the `"const_generics"` feature, this also enables [`From`] conversions between
+//! the default representation and the `&'static str` representation of the same string,
+//! so that crates that depend on different representations can interoperate.
+//!
+//! - `"array_indexing"`:
+//! Implements [`Index`] and [`IndexMut`] for arrays,
+//! with numeric type-level strings that are checked to be in bounds at compile-time
+//! (`[3, 5, 8][ts!(2)] == 8`).
+//! Requires Rust 1.51.0.
+//!
+//! - `"tuple_indexing"`:
+//! Implements [`Index`] and [`IndexMut`] for tuples of up to 12 elements,
+//! with the type-level string of the position of each element
+//! (`(3, 'c')[ts!(1)] == 'c'`).
+//! This also enables the `"proc_macros"` feature.
+//! Requires Rust 1.41.0.
+//!
+//! - `"stable_repr"`:
+//! Guarantees the representation of type-level strings described in the
+//! [stable representation](#stable-representation) section.
+//! This is incompatible with the `"min_const_generics"`, `"const_generics"`,
+//! and `"nightly_const_generics"` features.
+//!
+//! - `"reprs"`: Enables the [`tstr::reprs`] module,
+//! with the building-block types of the representation of type-level strings
+//! that the enabled features select, for writing impls over that representation.
+//! The contents of that module are exempt from semver guarantees.
+//!
+//! - `"serde"`: Enables the [`tstr::serde_fields`] module,
+//! with helpers for manually implementing `serde::Deserialize` for structs,
+//! which take a [`TList`] of the [`TStr`]s of the names of the fields.
+//!
+//! - `"serde_rename"`: Makes the [`Fields`], [`IntoRecord`], [`VisitFields`], [`Columns`],
+//! [`TStrKeys`], and [`VariantAccess`] derives use the names from
+//! `#[serde(rename = "...")]` and `#[serde(rename_all = "...")]` attributes,
+//! so that the [`TStr`] keys are the same as the serialized names
+//! (the `serialize` name is used if it's different from the `deserialize` name).
+//! This also enables the `"proc_macros"` feature.
+//!
+//! - `"defmt"`: Implements the `defmt::Format` trait for [`TStr`], printing the string.
+//!
+//! - `"ufmt"`: Implements the `ufmt::uDisplay` and `ufmt::uDebug` traits for [`TStr`],
+//! printing the string (quoted and escaped with `uDebug`).
+//!
+//! - `"frunk"`: Enables the [`tstr::frunk_fields`] module,
+//! with conversions from the labelled generic representation of the `frunk` crate
+//! to [`Field`]s keyed by [`TStr`]s.
+//!
+//! - `"typenum"`: Enables the [`tstr::typenum_uints`] module,
+//! with type-level conversions between [`TStr`]s of decimal integers
+//! and the unsigned integers of the `typenum` crate,
+//! and the [`SplitAt`] and [`Slice`] type operators in the `tstr::ops` module.
+//! These are not available with the `"const_generics"` feature.
+//!
+//! - `"generic_array"`: Enables the `TStr::to_generic_array` method and the [`ByteArrayOf`] alias,
+//! for getting the bytes of a [`TStr`] as a `generic_array::GenericArray` of
+//! exactly the length of the string, using the [`ByteLen`] type operator.
+//! This also enables the `"typenum"` feature,
+//! and is not available with the `"min_const_generics"` feature.
+//! This feature requires Rust 1.65.0, the minimum version supported by `generic-array` 1.0.
+//!
+//! - `"alloc"`: Enables conversions from [`TStr`] to the owned string types of the `alloc` crate:
+//! the `TStr::to_string` method, and [`From`] impls for `String` and `Cow<'static, str>`.
+//!
 //! - `"for_examples"`: Enables the `for_examples` module,
 //! with a few types used in documentation examples.
 //!
@@ -137,18 +272,72 @@
 //!
 //! This crate is unconditionally `#![no_std]`, and can be used anywhere that Rust can be.
 //!
+//! The `"alloc"` feature enables the items that require the `alloc` crate.
+//!
 //! # Minimum Supported Rust Version
 //!
 //! This crate supports Rust versions back to Rust 1.40.0.
 //!
 //! [`Index`]: https://doc.rust-lang.org/std/ops/trait.Index.html
+//! [`IndexMut`]: https://doc.rust-lang.org/std/ops/trait.IndexMut.html
 //! [`tstr::utils`]: ./utils/index.html
+//! [`tstr::sets`]: ./sets/index.html
+//! [`TS`]: ./macro.TS.html
+//! [`ts`]: ./macro.ts.html
+//! [`alias`]: ./macro.alias.html
+//! [`TS_chars`]: ./macro.TS_chars.html
+//! [`ts_chars`]: ./macro.ts_chars.html
+//! [`TS_tupled`]: ./macro.TS_tupled.html
+//! [`ts_tupled`]: ./macro.ts_tupled.html
+//! [`From`]: https://doc.rust-lang.org/core/convert/trait.From.html
+//! [`TS_file`]: ./macro.TS_file.html
+//! [`ts_file`]: ./macro.ts_file.html
+//! [`TS_line`]: ./macro.TS_line.html
+//! [`ts_line`]: ./macro.ts_line.html
+//! [`tstr::maps`]: ./maps/index.html
+//! [`tstr::records`]: ./records/index.html
+//! [`tstr::reprs`]: ./reprs/index.html
+//! [`tstr::serde_fields`]: ./serde_fields/index.html
+//! [`tstr::frunk_fields`]: ./frunk_fields/index.html
+//! [`tstr::typenum_uints`]: ./typenum_uints/index.html
+//! [`ByteArrayOf`]: ./type.ByteArrayOf.html
+//! [`ByteLen`]: ./typenum_uints/trait.ByteLen.html
+//! [`SplitAt`]: ./ops/trait.SplitAt.html
+//! [`Slice`]: ./ops/trait.Slice.html
+//! [`TList`]: ./tlist/struct.TList.html
+//! [`TStr`]: ./struct.TStr.html
+//! [`Field`]: ./struct.Field.html
+//! [`TStrEq`]: ./trait.TStrEq.html
+//! [`Columns`]: ./trait.Columns.html
+//! [`Fields`]: ./trait.Fields.html
+//! [`IntoRecord`]: ./records/trait.IntoRecord.html
+//! [`VisitFields`]: ./trait.VisitFields.html
+//! [`TStrKeys`]: ./trait.TStrKeys.html
+//! [`VariantAccess`]: ./trait.VariantAccess.html
+//! [`ToUintRadix`]: ./trait.ToUintRadix.html
+//! [`TStrMinMax`]: ./trait.TStrMinMax.html
+//! [`case`]: ./case/index.html
+//! [`ops`]: ./ops/index.html
+//! [`TStrOrd`]: ./trait.TStrOrd.html
+//! [`ToUint`]: ./trait.ToUint.html
+//! [`tstr_codegen`]: https://docs.rs/tstr_codegen/
 #![no_std]
-#![cfg_attr(feature = "nightly_const_generics", feature(adt_const_params))]
+#![cfg_attr(
+    feature = "nightly_const_generics",
+    feature(adt_const_params, unsized_const_params)
+)]
+#![cfg_attr(feature = "nightly_generic_const_exprs", feature(generic_const_exprs))]
 #![cfg_attr(feature = "docsrs", feature(doc_cfg, doc_auto_cfg))]
 #![allow(non_camel_case_types)]
+#![allow(clippy::doc_lazy_continuation)]
 #![cfg_attr(feature = "nightly_const_generics", allow(incomplete_features))]
 
+#[cfg(all(feature = "stable_repr", feature = "min_const_generics"))]
+compile_error! {
+    "The \"stable_repr\" feature can't be combined with features \
+     that change the representation of type-level strings."
+}
+
 #[cfg(feature = "for_examples")]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "for_examples")))]
 pub mod for_examples;
@@ -157,25 +346,187 @@ pub mod for_examples;
 #[cfg(feature = "cmp_traits")]
 mod for_tupled_reprs;
 
+#[cfg(feature = "nightly_generic_const_exprs")]
+mod for_nightly_const_generics;
+
+#[cfg(feature = "array_indexing")]
+mod array_indexing;
+
 pub mod asserts;
 
+pub mod bools;
+
+#[cfg(any(
+    not(feature = "min_const_generics"),
+    feature = "nightly_generic_const_exprs"
+))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(any(
+        not(feature = "min_const_generics"),
+        feature = "nightly_generic_const_exprs"
+    )))
+)]
+pub mod case;
+
+#[cfg(any(
+    not(feature = "const_generics"),
+    feature = "nightly_generic_const_exprs"
+))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(any(
+        not(feature = "const_generics"),
+        feature = "nightly_generic_const_exprs"
+    )))
+)]
+pub mod ops;
+
+#[cfg(feature = "reprs")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "reprs")))]
+pub mod reprs;
+
+mod columns;
+mod contains_fields;
+
+#[cfg(feature = "const_generics")]
+mod erased_tstr;
+
+#[cfg(all(feature = "dual_repr", feature = "const_generics"))]
+mod dual_repr;
+
+mod field;
+mod fields;
+
+#[cfg(feature = "const_generics")]
+mod from_uint;
+
+#[cfg(any(
+    feature = "defmt",
+    feature = "ufmt",
+    feature = "alloc",
+    all(feature = "generic_array", not(feature = "min_const_generics"))
+))]
+mod fmt_impls;
+
+#[cfg(feature = "alloc")]
+mod alloc_impls;
+
+#[cfg(all(feature = "generic_array", not(feature = "min_const_generics")))]
+mod generic_array_impls;
+
+mod is_ascii;
+mod lens;
 mod macros;
 mod make_tstr;
+mod naming_conventions;
+mod nested_index;
 mod to_uint;
+mod try_index;
+mod tstr_keys;
 mod tstr_type;
+mod variant_access;
+mod visit_fields;
+
+#[cfg(feature = "tuple_indexing")]
+mod tuple_indexing;
 
 #[cfg(feature = "cmp_traits")]
 mod tstr_cmp;
 
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "cmp_traits", not(feature = "min_const_generics"))))
+)]
+pub mod maps;
+
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "cmp_traits", not(feature = "min_const_generics"))))
+)]
+pub mod records;
+
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "cmp_traits", not(feature = "min_const_generics"))))
+)]
+pub mod sets;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "serde")))]
+pub mod serde_fields;
+
+#[cfg(feature = "frunk")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "frunk")))]
+pub mod frunk_fields;
+
+pub mod testing;
+
+pub mod tlist;
+
+#[cfg(all(feature = "typenum", not(feature = "const_generics")))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "typenum", not(feature = "const_generics"))))
+)]
+pub mod typenum_uints;
+
 pub mod utils;
 
 #[doc(hidden)]
 extern crate self as tstr;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[doc(hidden)]
-pub use tstr_proc_macros::__ts_impl;
+#[cfg(feature = "proc_macros")]
+pub use tstr_proc_macros::{__alias_impl, __ts_impl, __ts_value_impl};
 
-pub use crate::{asserts::Assert, make_tstr::MakeTStr, to_uint::ToUint, tstr_type::TStr};
+#[doc(hidden)]
+#[cfg(feature = "proc_macros")]
+pub use tstr_proc_macros::{__ts_dedup_impl, __ts_sort_impl};
+
+#[doc(hidden)]
+#[cfg(feature = "proc_macros")]
+pub use tstr_proc_macros::__include_tstr_lines_impl;
+
+#[cfg(feature = "proc_macros")]
+tstr_proc_macros::__check_repr_impl!();
+
+#[doc(hidden)]
+pub use crate::macros::include_macros::__IncludedFile;
+
+#[doc(hidden)]
+#[cfg(feature = "dual_repr")]
+pub use tstr_proc_macros::__ts_tupled_impl;
+
+pub use crate::{
+    asserts::Assert,
+    bools::{Boolean, False, True},
+    columns::Columns,
+    contains_fields::{ContainsFields, ContainsFieldsMut},
+    field::Field,
+    fields::Fields,
+    is_ascii::{IsAscii, TStrAsciiOnly},
+    lens::Lens,
+    make_tstr::MakeTStr,
+    naming_conventions::{AssertNamingConvention, NamingConvention},
+    nested_index::{NestedIndex, NestedIndexMut},
+    to_uint::ToUint,
+    try_index::{TryIndex, TryIndexMut},
+    tstr_keys::{FromTStr, TStrKeys, ToTStr, VariantVisitor},
+    tstr_type::TStr,
+    variant_access::{IntoVariant, VariantAccess},
+    visit_fields::{FieldVisitor, VisitFields},
+};
+
+#[cfg(feature = "rust_1_88")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_88")))]
+pub use crate::macros::location_macros::{ts_file, ts_line, TS_file, TS_line};
 
 #[cfg(feature = "cmp_traits")]
 pub use tstr_cmp::TStrEq;
@@ -183,8 +534,31 @@ pub use tstr_cmp::TStrEq;
 #[cfg(all(feature = "cmp_traits", feature = "const_generics"))]
 pub use tstr_cmp::TStrOrd;
 
+#[cfg(all(feature = "cmp_traits", feature = "nightly_generic_const_exprs"))]
+pub use tstr_cmp::{TStrMax, TStrMin, TStrMinMax};
+
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+pub use crate::{
+    maps::TMap,
+    records::{IntoRecord, Record},
+    sets::TStrSet,
+    tstr_cmp::{TStrMax, TStrMin, TStrMinMax},
+};
+
+#[cfg(feature = "min_const_generics")]
+pub use crate::to_uint::ToUintRadix;
+
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
 #[cfg(feature = "const_generics")]
-pub use crate::tstr_type::StrValue;
+pub use crate::tstr_type::{StrValue, ToArray};
+
+#[cfg(feature = "const_generics")]
+pub use crate::erased_tstr::ErasedTStr;
+
+#[cfg(all(feature = "generic_array", not(feature = "min_const_generics")))]
+pub use crate::generic_array_impls::ByteArrayOf;
+
+#[cfg(feature = "const_generics")]
+pub use crate::from_uint::{FromUint, TStrOfUint, Uint};
 
 include! {"./p.rs"}
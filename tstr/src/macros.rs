@@ -1,6 +1,48 @@
 #[macro_use]
 mod cmp_macros;
 
+#[macro_use]
+mod collection_macros;
+
+#[macro_use]
+mod chars_macros;
+
+#[macro_use]
+mod concat_macros;
+
+#[macro_use]
+mod declare_fields_macros;
+
+#[macro_use]
+mod dedup_macros;
+
+#[macro_use]
+mod dual_repr_macros;
+
+#[macro_use]
+mod format_macros;
+
+#[macro_use]
+mod impl_index_macros;
+
+#[macro_use]
+pub(crate) mod include_macros;
+
+#[macro_use]
+pub(crate) mod location_macros;
+
+#[macro_use]
+mod naming_macros;
+
+#[macro_use]
+mod proj_macros;
+
+#[macro_use]
+mod sort_macros;
+
+#[macro_use]
+mod testing_macros;
+
 /// The type of a type-level string, always a [`TStr`].
 ///
 /// # Arguments
@@ -11,16 +53,53 @@ mod cmp_macros;
 ///
 /// - Integers (eg: `TS!(0)`, `TS!(100)`):
 /// converting the integer to decimal, then stringifying it.
+/// `_` separators are removed and prefixed integers are converted,
+/// eg: `TS!(1_000_000)` and `TS!(0xF_4240)` are both `TS!("1000000")`.
+/// Type suffixes are ignored, eg: `TS!(42usize)` is `TS!(42)`.
+///
+/// - Float literals (eg: `TS!(1.5)`, `TS!(2.5e3)`): stringifying the literal,
+/// without `_` separators or the type suffix, eg: `TS!(1_0.5f32)` is `TS!("10.5")`.
+/// Floats after a `.` are parsed as path segments instead, eg: `TS!(foo.0.1)`.
+///
+/// - Negative numbers (eg: `TS!(-5)`, `TS!(-0x10)`, `TS!(-1.5)`):
+/// a `-` followed by the string of the integer or float literal,
+/// eg: `TS!(-0x10)` is `TS!("-16")`.
 ///
 /// - Single identifiers (eg: `TS!(foo)`, `TS!(bar)`): stringifying the identifier.
+/// This includes keywords, and the `true` and `false` literals (eg: `TS!(true)` is `TS!("true")`).
+/// Raw identifiers are stringified without the `r#` prefix (eg: `TS!(r#type)` is `TS!("type")`),
+/// which can be kept by using `stringify!(...)` (eg: `TS!(stringify!(r#type))` is `TS!("r#type")`).
+///
+/// - Type paths, which start with an identifier followed by `::` or `<`, or with a `::`
+/// (eg: `TS!(Vec<u8>)`, `TS!(std::string::String)`):
+/// stringifying the type with the same spacing regardless of how it's written,
+/// eg: `TS!(HashMap<K,V>)` and `TS!(HashMap < K , V >)` are both `TS!("HashMap<K, V>")`.
 ///
 /// - A comma separated list of the other valid arguments to this macro
 /// (eg: `TS!(foo, "bar", 0)`), this evaluates to a tuple of `TStr`s.
 ///
+/// - A dotted path of the other valid arguments (eg: `TS!(foo.bar.0)`),
+/// this evaluates to a tuple of the `TStr` of each segment,
+/// which can be used with [`NestedIndex`] to access nested fields.
+///
 /// - `concat!(...)`-like syntax: concatenates its arguments,
-/// accepting the same arguments as this macro.
+/// accepting the same arguments as this macro, as well as char literals
+/// (eg: `TS!(concat!('a', -1, 2.5))` is `TS!("a-12.5")`).
+/// The [`TS_concat`] macro is a shorthand for this syntax.
+///
+/// - `format!(...)`-like syntax: substitutes the `{}` and `{N}` placeholders of
+/// a string literal with the other arguments (eg: `TS!(format!("{}_{}", foo, 0))` is `TS!(foo_0)`),
+/// for more details look at the [`TS_format`] macro.
 ///
 /// - `stringify!(...)`-like syntax: stringifies its arguments.
+/// This also works with `macro_rules!` fragments,
+/// eg: `TS!(stringify!($ty))` with a `$ty:ty` argument of `Vec<u8>` is `TS!("Vec<u8>")`.
+/// How tokens are spaced in the string follows the compiler's formatting of them.
+///
+/// - `@const` followed by a `&'static str` constant (eg: `TS!(@const my_crate::KEY)`),
+/// which must be the only argument of this macro.
+/// This requires the `"const_generics"` feature,
+/// and the constant can't depend on generic parameters.
 ///
 /// # Examples
 ///
@@ -100,16 +179,100 @@ mod cmp_macros;
 ///
 /// type Tup = TS!(foo, 1, "bar"); // equivalent to `(TS!(foo), TS!(1), TS!(bar))`
 ///
+/// type Type = TS!(Vec<Option<u8>>); // equivalent to `TS!("Vec<Option<u8>>")`
+///
+/// type Path = TS!(foo.1.bar); // equivalent to `(TS!(foo), TS!(1), TS!(bar))`
+///
 /// // Equivalent to TS!("foo4bar200")
 /// type Conc = TS!(concat!(foo, 0b100, "bar", 200));
 ///
 /// ```
 ///
+/// ### Using in `macro_rules!` macros
+///
+/// This macro refers to the items of this crate through `$crate`,
+/// so it can be invoked from other `macro_rules!` macros through a re-export of this crate,
+/// even if the crate that invokes it doesn't depend on `tstr` directly,
+/// or renamed the dependency.
+///
+/// To forward arguments to this macro, capture them as `$($args:tt)*`,
+/// which preserves the syntax that is specific to this macro, like `@const` arguments.
+///
+/// The derives and the macros that are implemented as proc macros
+/// ([`TS_file`], [`TS_line`], and their value equivalents)
+/// refer to this crate as `::tstr` by default,
+/// which can be changed with the `#[tstr(crate = path::to::tstr)]` attribute for derives,
+/// and a `crate = path::to::tstr` argument for the other macros.
+///
+/// ```rust
+/// // This is in the root module of a library that re-exports tstr
+/// #[doc(hidden)]
+/// pub mod __tstr {
+///     pub use tstr::{ts, TS};
+/// }
+///
+/// #[macro_export]
+/// macro_rules! field_name {
+///     ($($args:tt)*) => {
+///         $crate::__tstr::TS!($($args)*)
+///     };
+/// }
+///
+/// #[macro_export]
+/// macro_rules! field_value {
+///     ($($args:tt)*) => {
+///         $crate::__tstr::ts!($($args)*)
+///     };
+/// }
+///
+/// // Crates that use `field_name` and `field_value` don't need to depend on tstr.
+/// fn main() {
+///     let _: field_name!(foo) = field_value!("foo");
+///     let _: field_name!(foo, bar) = field_value!(foo, bar);
+///
+///     macro_rules! nested_field {
+///         ($a:ident . $b:ident) => {
+///             field_value!($a.$b)
+///         };
+///     }
+///     let _: field_name!(foo.bar) = nested_field!(foo.bar);
+/// }
+///
+/// ```
+///
+/// ### Constants
+///
+/// This example demonstrates how keys defined as constants can be used as type-level strings.
+///
+#[cfg_attr(feature = "const_generics", doc = "```rust")]
+#[cfg_attr(not(feature = "const_generics"), doc = "```ignore")]
+/// use tstr::{StrValue, TS};
+///
+/// mod keys {
+///     pub const NAME: &str = "name";
+/// }
+///
+/// type Name = TS!(@const keys::NAME);
+///
+/// let _: TS!(name) = Name::NEW;
+/// assert_eq!(Name::STR, keys::NAME);
+///
+/// ```
+///
 /// [`TStr`]: ./struct.TStr.html
+/// [`NestedIndex`]: ./trait.NestedIndex.html
+/// [`TS_file`]: ./macro.TS_file.html
+/// [`TS_line`]: ./macro.TS_line.html
+/// [`TS_format`]: ./macro.TS_format.html
+/// [`TS_concat`]: ./macro.TS_concat.html
 #[macro_export]
+#[cfg(feature = "proc_macros")]
 macro_rules! TS {
-    ($($expr:expr),* $(,)* ) => {
-        $crate::__ts_impl!(($crate) $($expr)*)
+    (@const $($const:tt)+) => {
+        $crate::__priv_ts_const!($($const)+)
+    };
+    ($($args:tt)*) => {
+        $crate::__ts_impl!(($crate) $($args)*)
     };
 }
 
@@ -119,6 +282,10 @@ macro_rules! TS {
 ///
 /// You can use anything that the [`tstr::TS`] macro accepts
 ///
+/// For how to use this macro in other `macro_rules!` macros,
+/// you can look at [this section](./macro.TS.html#using-in-macro_rules-macros)
+/// of the `TS` docs.
+///
 /// # Examples
 ///
 /// ### Indexing
@@ -126,7 +293,8 @@ macro_rules! TS {
 /// This uses types from the `for_examples` module,
 /// which can be seen in the docs with the "for_examples" feature.
 ///
-/// ```rust
+#[cfg_attr(feature = "for_examples", doc = "```rust")]
+#[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
 /// use tstr::ts;
 /// use tstr::for_examples::{Foo, Bar};
 ///
@@ -158,23 +326,216 @@ macro_rules! TS {
 ///
 /// let tup = ts!(foo, 1, "bar"); // equivalent to `(ts!(foo), ts!(1), ts!(bar))`
 ///
+/// let ty = ts!(std::string::String); // equivalent to `ts!("std::string::String")`
+///
+/// let path = ts!(foo.1.bar); // equivalent to `(ts!(foo), ts!(1), ts!(bar))`
+///
 /// // Equivalent to ts!("foo4bar200")
 /// let conc = ts!(concat!(foo, 0b100, "bar", 200));
 /// # const _: tstr::TS!("foo4bar200") = ts!(concat!(foo, 0b100, "bar", 200));
+///
+/// // Equivalent to ts!("v-1.5")
+/// let conc_std = ts!(concat!('v', -1.5));
+/// # const _: tstr::TS!("v-1.5") = ts!(concat!('v', -1.5));
 /// ```
 ///
 ///
 /// [`TStr`]: ./struct.TStr.html
 /// [`tstr::TS`]: ./macro.TS.html#arguments
 #[macro_export]
+#[cfg(feature = "proc_macros")]
 macro_rules! ts {
-    ($($expr:expr),* $(,)* ) => {{
+    (@const $($const:tt)+) => {
+        <$crate::__priv_ts_const!($($const)+) as $crate::MakeTStr>::MAKE
+    };
+    ($($args:tt)*) => {{
+        // The type is declared by items in a module,
+        // so that the type of each distinct string is only written once.
+        mod __tstr_value {
+            $crate::__ts_value_impl!{($crate) $($args)*}
+        }
         let __look_at_the_notes__ =
-            <$crate::__ts_impl!(($crate) $($expr)*) as $crate::MakeTStr>::MAKE;
+            <__tstr_value::__TStrValue as $crate::MakeTStr>::MAKE;
         __look_at_the_notes__
     }};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "const_generics")]
+macro_rules! __priv_ts_const {
+    ($($const:tt)+) => {
+        $crate::TStr<$crate::___<{ $($const)+ }>>
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "const_generics"))]
+macro_rules! __priv_ts_const {
+    ($($const:tt)+) => {
+        compile_error! {"`@const` arguments require the \"const_generics\" feature"}
+    };
+}
+
+/// Errors if `tstr_proc_macros` outputs a different representation of type-level strings,
+/// this is invoked once, when compiling tstr, instead of in every macro expansion.
+///
+/// ```compile_fail
+/// tstr::__priv_check_repr!(const_generics);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "min_const_generics"))]
+macro_rules! __priv_check_repr {
+    (tupled) => {};
+    ($repr:ident) => {
+        $crate::__priv_repr_mismatch! {$repr, tupled}
+    };
+}
+
+/// Errors if `tstr_proc_macros` outputs a different representation of type-level strings,
+/// this is invoked once, when compiling tstr, instead of in every macro expansion.
+///
+/// ```compile_fail
+/// tstr::__priv_check_repr!(tupled);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+macro_rules! __priv_check_repr {
+    (min_const_generics) => {};
+    ($repr:ident) => {
+        $crate::__priv_repr_mismatch! {$repr, min_const_generics}
+    };
+}
+
+/// Errors if `tstr_proc_macros` outputs a different representation of type-level strings,
+/// this is invoked once, when compiling tstr, instead of in every macro expansion.
+///
+/// ```compile_fail
+/// tstr::__priv_check_repr!(tupled);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "const_generics")]
+macro_rules! __priv_check_repr {
+    (const_generics) => {};
+    ($repr:ident) => {
+        $crate::__priv_repr_mismatch! {$repr, const_generics}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_repr_mismatch {
+    ($proc_macros_repr:ident, $tstr_repr:ident) => {
+        compile_error! {concat!(
+            "tstr_proc_macros outputs the `",
+            stringify!($proc_macros_repr),
+            "` representation of type-level strings, ",
+            "but tstr was compiled with the `",
+            stringify!($tstr_repr),
+            "` representation.\n",
+            "This happens when a crate enables the features of tstr_proc_macros directly, ",
+            "enable the same features of tstr instead.",
+        )}
+    };
+}
+
+/// The type of the [`TStr`] of the name of a type, the last segment of a type path.
+///
+/// # Arguments
+///
+/// This takes a path to a type, optionally with generic arguments,
+/// like `Foo`, `std::vec::Vec<u8>`, or `::core::option::Option::<T>`.
+///
+/// The generic arguments are ignored,
+/// `TS_type_name!(Vec<u8>)` is the same type as `TS!(Vec)`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TS, TS_type_name};
+///
+/// struct MyStruct;
+///
+/// let _: TS_type_name!(MyStruct) = <TS!(MyStruct)>::NEW;
+/// let _: TS_type_name!(std::string::String) = <TS!(String)>::NEW;
+/// let _: TS_type_name!(::std::vec::Vec<Vec<u8>>) = <TS!(Vec)>::NEW;
+/// let _: TS_type_name!(Option::<u8>) = <TS!(Option)>::NEW;
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! TS_type_name {
+    ($($path:tt)*) => {
+        $crate::__priv_type_name!{TS [] $($path)*}
+    };
+}
+
+/// The [`TStr`] value of the name of a type, the last segment of a type path.
+///
+/// # Arguments
+///
+/// This takes the same arguments as [`TS_type_name`].
+///
+/// # Example
+///
+/// This example demonstrates a registry of handlers, keyed by the name of the handled type.
+///
+/// ```rust
+/// use tstr::{TS, ts, ts_type_name};
+///
+/// struct Registry;
+///
+/// impl Registry {
+///     fn handler(&self, _: TS!(Request)) -> &'static str {
+///         "request handler"
+///     }
+/// }
+///
+/// mod messages {
+///     pub struct Request;
+/// }
+///
+/// assert_eq!(Registry.handler(ts_type_name!(messages::Request)), "request handler");
+/// assert_eq!(ts_type_name!(messages::Request), ts!(Request));
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS_type_name`]: ./macro.TS_type_name.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! ts_type_name {
+    ($($path:tt)*) => {
+        $crate::__priv_type_name!{ts [] $($path)*}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_type_name {
+    ($macro:ident [$last:ident]) => {
+        $crate::$macro!($last)
+    };
+    ($macro:ident [$last:ident] < $($rem:tt)*) => {
+        $crate::$macro!($last)
+    };
+    ($macro:ident [$($last:ident)?] :: $($rem:tt)*) => {
+        $crate::__priv_type_name!{$macro [$($last)?] $($rem)*}
+    };
+    ($macro:ident [$($last:ident)?] $segment:ident $($rem:tt)*) => {
+        $crate::__priv_type_name!{$macro [$segment] $($rem)*}
+    };
+    ($macro:ident [$($last:ident)?] $($rem:tt)*) => {
+        compile_error!{concat!("Expected a path to a type, found: ", stringify!($($rem)*))}
+    };
+}
+
 /// Declares `const` and `type` aliases for type-level strings.
 ///
 /// # String Arguments
@@ -204,7 +565,8 @@ macro_rules! ts {
 /// This uses types from the `for_examples` module,
 /// which can be seen in the docs with the "for_examples" feature.
 ///
-/// ```rust
+#[cfg_attr(feature = "for_examples", doc = "```rust")]
+#[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
 /// use std::ops::Index;
 ///
 /// use tstr::for_examples::{Foo, Bar};
@@ -245,6 +607,7 @@ macro_rules! ts {
 /// ```
 ///
 #[macro_export]
+#[cfg(feature = "proc_macros")]
 macro_rules! alias {
     (
         $(
@@ -308,10 +671,16 @@ macro_rules! __priv_alias {
         [$($expr:expr),*]
         $autodoc:expr
     )=>{
-        $(#[$attr])*
-        #[allow(broken_intra_doc_links)]
-        #[doc = $autodoc]
-        $vis type $name = $crate::TS!($($expr),*);
+        $crate::__alias_impl!{
+            [
+                $(#[$attr])*
+                #[allow(broken_intra_doc_links)]
+                #[doc = $autodoc]
+            ]
+            ($vis)
+            $name
+            ($crate) $($expr),*
+        }
 
         $(#[$attr])*
         #[allow(non_upper_case_globals, broken_intra_doc_links)]
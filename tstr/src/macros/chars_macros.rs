@@ -0,0 +1,353 @@
+/// The type of a type-level string, implemented without proc macros.
+///
+/// Unlike the [`TS`] macro, this is implemented with a `macro_rules!` macro,
+/// so it can be used without the `"proc_macros"` feature.
+///
+/// # Arguments
+///
+/// This takes the characters of the string as separate tokens,
+/// only accepting ascii letters, digits, and `_`.
+/// Each string can be up to 64 characters long.
+///
+/// A comma separated list of strings evaluates to a tuple of [`TStr`]s,
+/// like the [`TS`] macro does.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::TS_chars;
+///
+/// type Foo = TS_chars!(f o o);
+///
+/// type BarBaz = TS_chars!(b a r _ b a z);
+///
+/// type Number = TS_chars!(1 0 0);
+///
+/// type Tuple = TS_chars!(f o o, b a r);
+///
+/// # #[cfg(feature = "proc_macros")]
+/// # const _: (Foo, BarBaz, Number, Tuple)
+/// #     = (tstr::ts!(foo), tstr::ts!(bar_baz), tstr::ts!(100), tstr::ts!(foo, bar));
+/// ```
+///
+/// [`TS`]: ./macro.TS.html
+/// [`TStr`]: ./struct.TStr.html
+#[macro_export]
+macro_rules! TS_chars {
+    ($($args:tt)*) => {
+        $crate::__priv_ts_chars!{@args [] [] $($args)*}
+    };
+}
+
+/// A type-level string value, implemented without proc macros.
+///
+/// This takes the same arguments as the [`TS_chars`] macro.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TS_chars, ts_chars};
+///
+/// let _: TS_chars!(f o o) = ts_chars!(f o o);
+///
+/// let _: (TS_chars!(f o o), TS_chars!(b a r)) = ts_chars!(f o o, b a r);
+///
+/// ```
+///
+/// [`TS_chars`]: ./macro.TS_chars.html
+#[macro_export]
+macro_rules! ts_chars {
+    ($($args:tt)*) => {
+        <$crate::TS_chars!($($args)*) as $crate::MakeTStr>::MAKE
+    };
+}
+
+// Every character is handled by its own `@args` rule,
+// so that each character takes only one level of macro recursion.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_ts_chars {
+    (@args [$($done:tt)*] [$($curr:tt)*] , $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args [$($done)* [$($curr)*]] [] $($rem)*}
+    };
+    (@args [$($done:tt)*] []) => {
+        $crate::__priv_ts_chars!{@output $($done)*}
+    };
+    (@args [$($done:tt)*] [$($curr:tt)+]) => {
+        $crate::__priv_ts_chars!{@output $($done)* [$($curr)*]}
+    };
+    (@args $done:tt [$($curr:tt)*] a $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__a 'a' "a"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] b $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__b 'b' "b"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] c $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__c 'c' "c"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] d $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__d 'd' "d"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] e $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__e 'e' "e"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] f $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__f 'f' "f"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] g $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__g 'g' "g"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] h $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__h 'h' "h"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] i $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__i 'i' "i"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] j $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__j 'j' "j"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] k $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__k 'k' "k"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] l $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__l 'l' "l"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] m $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__m 'm' "m"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] n $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__n 'n' "n"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] o $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__o 'o' "o"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] p $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__p 'p' "p"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] q $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__q 'q' "q"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] r $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__r 'r' "r"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] s $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__s 's' "s"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] t $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__t 't' "t"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] u $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__u 'u' "u"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] v $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__v 'v' "v"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] w $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__w 'w' "w"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] x $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__x 'x' "x"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] y $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__y 'y' "y"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] z $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__z 'z' "z"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] A $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__A 'A' "A"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] B $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__B 'B' "B"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] C $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__C 'C' "C"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] D $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__D 'D' "D"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] E $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__E 'E' "E"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] F $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__F 'F' "F"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] G $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__G 'G' "G"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] H $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__H 'H' "H"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] I $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__I 'I' "I"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] J $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__J 'J' "J"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] K $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__K 'K' "K"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] L $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__L 'L' "L"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] M $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__M 'M' "M"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] N $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__N 'N' "N"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] O $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__O 'O' "O"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] P $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__P 'P' "P"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] Q $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__Q 'Q' "Q"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] R $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__R 'R' "R"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] S $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__S 'S' "S"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] T $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__T 'T' "T"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] U $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__U 'U' "U"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] V $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__V 'V' "V"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] W $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__W 'W' "W"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] X $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__X 'X' "X"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] Y $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__Y 'Y' "Y"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] Z $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__Z 'Z' "Z"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 0 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__0 '0' "0"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 1 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__1 '1' "1"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 2 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__2 '2' "2"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 3 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__3 '3' "3"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 4 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__4 '4' "4"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 5 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__5 '5' "5"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 6 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__6 '6' "6"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 7 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__7 '7' "7"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 8 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__8 '8' "8"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] 9 $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [__9 '9' "9"]] $($rem)*}
+    };
+    (@args $done:tt [$($curr:tt)*] _ $($rem:tt)*) => {
+        $crate::__priv_ts_chars!{@args $done [$($curr)* [____ '_' "_"]] $($rem)*}
+    };
+    (@args $done:tt $curr:tt $other:tt $($rem:tt)*) => {
+        compile_error!{concat!(
+            "Expected an ascii letter, digit, or `_`, found: `",
+            stringify!($other),
+            "`",
+        )}
+    };
+    (@output $chars:tt) => {
+        $crate::TStr<$crate::__priv_ts_chars_param!{$chars}>
+    };
+    (@output $($chars:tt)*) => {
+        ($($crate::TStr<$crate::__priv_ts_chars_param!{$chars}>,)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "const_generics")]
+macro_rules! __priv_ts_chars_param {
+    ([$([$byte:ident $char:literal $str:literal])*]) => {
+        $crate::___<{ concat!($($str,)*) }>
+    };
+}
+
+// Splits the string into the same nested tuples as the proc macro,
+// only handling up to 2 levels of nesting.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "const_generics"))]
+macro_rules! __priv_ts_chars_param {
+    ([$c0:tt $c1:tt $c2:tt $c3:tt $c4:tt $c5:tt $c6:tt $c7:tt $c8:tt $($rem:tt)*]) => {
+        $crate::__priv_ts_chars_param!{
+            @chunks [] [$c0 $c1 $c2 $c3 $c4 $c5 $c6 $c7 $c8 $($rem)*]
+        }
+    };
+    ([$($chars:tt)*]) => {
+        $crate::__priv_ts_chars_leaf!{$($chars)*}
+    };
+    (@chunks [$k0:tt $k1:tt $k2:tt $k3:tt $k4:tt $k5:tt $k6:tt $k7:tt $($chunks:tt)*] $rem:tt) => {
+        compile_error!{"`TS_chars` only supports strings of up to 64 characters"}
+    };
+    (
+        @chunks [$($chunks:tt)*]
+        [$c0:tt $c1:tt $c2:tt $c3:tt $c4:tt $c5:tt $c6:tt $c7:tt $($rem:tt)+]
+    ) => {
+        $crate::__priv_ts_chars_param!{
+            @chunks [$($chunks)* [$c0 $c1 $c2 $c3 $c4 $c5 $c6 $c7]] [$($rem)*]
+        }
+    };
+    (@chunks [$([$($chunk:tt)*])*] [$($rem:tt)*]) => {
+        (
+            $($crate::__priv_ts_chars_leaf!{$($chunk)*},)*
+            $crate::__priv_ts_chars_leaf!{$($rem)*},
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "min_const_generics"))]
+macro_rules! __priv_ts_chars_leaf {
+    ($([$byte:ident $char:literal $str:literal])*) => {
+        ($($crate::$byte,)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+macro_rules! __priv_ts_chars_leaf {
+    ($([$byte:ident $char:literal $str:literal])*) => {
+        $crate::__priv_ts_chars_leaf!{@chars $($char)*}
+    };
+    (@chars $a:tt) => { $crate::__a<$a> };
+    (@chars $a:tt $b:tt) => { $crate::__b<$a, $b> };
+    (@chars $a:tt $b:tt $c:tt) => { $crate::__c<$a, $b, $c> };
+    (@chars $a:tt $b:tt $c:tt $d:tt) => { $crate::__d<$a, $b, $c, $d> };
+    (@chars $a:tt $b:tt $c:tt $d:tt $e:tt) => { $crate::__e<$a, $b, $c, $d, $e> };
+    (@chars $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt) => { $crate::__f<$a, $b, $c, $d, $e, $f> };
+    (@chars $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt) => {
+        $crate::__g<$a, $b, $c, $d, $e, $f, $g>
+    };
+    (@chars $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt $h:tt) => {
+        $crate::__<$a, $b, $c, $d, $e, $f, $g, $h>
+    };
+}
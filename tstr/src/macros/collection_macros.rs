@@ -0,0 +1,211 @@
+/// The type of a [`TStrSet`], with the type-level strings passed as arguments.
+///
+/// # Arguments
+///
+/// This takes a comma separated list of anything that the [`tstr::TS`] macro accepts,
+/// the set contains the type-level string of each argument.
+///
+/// Repeated arguments are only included once in the set.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::{TStrSet, TS};
+///
+/// let _: TStrSet!() = TStrSet::<TNil>::NEW;
+///
+/// let _: TStrSet!(foo, bar) = TStrSet::<TList<TS!(foo), TList<TS!(bar), TNil>>>::NEW;
+///
+/// let set: TStrSet!(foo, 0, bar, foo) = TStrSet::NEW;
+/// let _: TStrSet!(foo, 0, "bar") = set;
+///
+/// ```
+///
+/// [`TStrSet`]: ./sets/struct.TStrSet.html
+/// [`tstr::TS`]: ./macro.TS.html#arguments
+#[macro_export]
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+macro_rules! TStrSet {
+    ($($expr:expr),* $(,)* ) => {
+        $crate::__priv_tstr_set!{
+            ($crate::sets::TStrSet<$crate::tlist::TNil>)
+            $($expr,)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_tstr_set {
+    (($set:ty)) => {
+        $set
+    };
+    (($set:ty) $expr:expr, $($rem:tt)*) => {
+        $crate::__priv_tstr_set!{
+            (<$set as $crate::sets::Insert<$crate::TS!($expr)>>::Output)
+            $($rem)*
+        }
+    };
+}
+
+/// The type of a [`TMap`], with the `key => value` entries passed as arguments.
+///
+/// # Arguments
+///
+/// This takes a comma separated list of `key => value` entries,
+/// where `key` is anything that the [`tstr::TS`] macro accepts,
+/// and `value` is a type.
+///
+/// If a key is repeated, the map has the last value for that key,
+/// at the position where the key first appeared.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::{TMap, TS};
+///
+/// let _: TMap!() = TMap::<TNil>::NEW;
+///
+/// let _: TMap!(foo => u8, 0 => bool) = TMap::<TList<(TS!(foo), u8), TList<(TS!(0), bool), TNil>>>::NEW;
+///
+/// let map: TMap!(foo => u8, bar => u16, foo => u32) = TMap::NEW;
+/// let _: TMap!(foo => u32, "bar" => u16) = map;
+///
+/// ```
+///
+/// [`TMap`]: ./maps/struct.TMap.html
+/// [`tstr::TS`]: ./macro.TS.html#arguments
+#[macro_export]
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+macro_rules! TMap {
+    ($($key:expr => $value:ty),* $(,)* ) => {
+        $crate::__priv_tmap!{
+            ($crate::maps::TMap<$crate::tlist::TNil>)
+            $($key => $value,)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_tmap {
+    (($map:ty)) => {
+        $map
+    };
+    (($map:ty) $key:expr => $value:ty, $($rem:tt)*) => {
+        $crate::__priv_tmap!{
+            (<$map as $crate::maps::Insert<$crate::TS!($key), $value>>::Output)
+            $($rem)*
+        }
+    };
+}
+
+/// Constructs a [`Record`], with the `key: value` fields passed as arguments.
+///
+/// # Arguments
+///
+/// This takes a comma separated list of `key: value` fields,
+/// where `key` is a single token that the [`tstr::ts`] macro accepts
+/// (an identifier, or a string or integer literal),
+/// and `value` is an expression.
+///
+/// If a key is repeated, only the first field with that key can be accessed.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::records::Record;
+/// use tstr::{record, ts, Field};
+///
+/// let rec = record!{name: "Bob", age: 21, 0: 'c', "hello world": [3, 5]};
+///
+/// assert_eq!(rec[ts!(name)], "Bob");
+/// assert_eq!(rec[ts!(age)], 21);
+/// assert_eq!(rec[ts!(0)], 'c');
+/// assert_eq!(rec[ts!("hello world")], [3, 5]);
+///
+/// assert_eq!(record!{}, Record::new(()));
+///
+/// assert_eq!(
+///     record!{foo: 10u8, bar: false},
+///     Record::new((Field::new(ts!(foo), 10u8), (Field::new(ts!(bar), false), ()))),
+/// );
+///
+/// ```
+///
+/// [`Record`]: ./records/struct.Record.html
+/// [`tstr::ts`]: ./macro.ts.html#arguments
+#[macro_export]
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+macro_rules! record {
+    ($($key:tt : $value:expr),* $(,)* ) => {
+        $crate::records::Record::new(
+            $crate::__priv_record_fields!{ $($key : $value,)* }
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_record_fields {
+    () => {
+        ()
+    };
+    ($key:tt : $value:expr, $($rem:tt)*) => {
+        (
+            $crate::Field::new($crate::ts!($key), $value),
+            $crate::__priv_record_fields!{ $($rem)* },
+        )
+    };
+}
+
+/// The type of a [`Record`], with the `key: value` fields passed as arguments.
+///
+/// # Arguments
+///
+/// This takes a comma separated list of `key: value` fields,
+/// where `key` is a single token that the [`tstr::TS`] macro accepts
+/// (an identifier, or a string or integer literal),
+/// and `value` is a type.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::records::Record;
+/// use tstr::{record, Field, TS};
+///
+/// let _: tstr::Record!{} = Record::new(());
+///
+/// let rec: tstr::Record!{name: &str, 0: char} = record!{name: "Bob", 0: 'c'};
+///
+/// let _: Record<(Field<TS!(name), &str>, (Field<TS!(0), char>, ()))> = rec;
+///
+/// ```
+///
+/// [`Record`]: ./records/struct.Record.html
+/// [`tstr::TS`]: ./macro.TS.html#arguments
+#[macro_export]
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+macro_rules! Record {
+    ($($key:tt : $value:ty),* $(,)* ) => {
+        $crate::records::Record<
+            $crate::__priv_record_field_types!{ $($key : $value,)* }
+        >
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_record_field_types {
+    () => {
+        ()
+    };
+    ($key:tt : $value:ty, $($rem:tt)*) => {
+        (
+            $crate::Field<$crate::TS!($key), $value>,
+            $crate::__priv_record_field_types!{ $($rem)* },
+        )
+    };
+}
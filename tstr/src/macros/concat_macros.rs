@@ -0,0 +1,95 @@
+/// The type of a [`TStr`] composed by joining identifiers and literals.
+///
+/// This is equivalent to `TS!(concat!(...))`,
+/// and can be used as a stable replacement for `concat_idents!`
+/// when generating the keys of accessors in `macro_rules!` macros.
+///
+/// # Arguments
+///
+/// This takes a comma separated list of anything that
+/// [`concat!(...)`](./macro.TS.html#arguments) accepts in the [`TS`] macro,
+/// joining the strings of all of them.
+///
+/// Unlike the [`TS`] macro, where a comma separated list of arguments
+/// evaluates to a tuple of `TStr`s, this always evaluates to a single `TStr`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts_concat, TS, TS_concat};
+///
+/// type Getter = TS_concat!(get_, name);
+///
+/// let _: TS!(get_name) = Getter::NEW;
+///
+/// let _: TS!(user_id2) = ts_concat!(user, _, id, 2);
+/// let _: TS!("set-x") = ts_concat!(set, '-', "x");
+///
+/// ```
+///
+/// ### Using in `macro_rules!` macros
+///
+/// ```rust
+/// use tstr::{TS, TS_concat};
+///
+/// macro_rules! accessors {
+///     ($ty:ident, $field:ident: $field_ty:ty) => {
+///         impl Field<TS_concat!(get_, $field)> for $ty {
+///             type Type = $field_ty;
+///         }
+///         impl Field<TS_concat!(set_, $field)> for $ty {
+///             type Type = fn($field_ty);
+///         }
+///     };
+/// }
+///
+/// trait Field<K> {
+///     type Type;
+/// }
+///
+/// struct Person;
+///
+/// accessors! {Person, age: u32}
+///
+/// let _: <Person as Field<TS!(get_age)>>::Type = 3u32;
+/// let _: <Person as Field<TS!(set_age)>>::Type = |_: u32| ();
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS`]: ./macro.TS.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! TS_concat {
+    ($($args:tt)*) => {
+        $crate::TS!(concat!($($args)*))
+    };
+}
+
+/// A [`TStr`] value composed by joining identifiers and literals.
+///
+/// This is equivalent to `ts!(concat!(...))`,
+/// and takes the same arguments as the [`TS_concat`] macro.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts, ts_concat, TS};
+///
+/// let _: TS!(max_len) = ts_concat!(max_, len);
+/// let _: TS!(field_0) = ts_concat!(field_, 0);
+///
+/// // `ts!(concat!(...))` is equivalent, and can be combined with other arguments
+/// let _: (TS!(len), TS!(min_len)) = ts!(len, concat!(min_, len));
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS_concat`]: ./macro.TS_concat.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! ts_concat {
+    ($($args:tt)*) => {
+        $crate::ts!(concat!($($args)*))
+    };
+}
@@ -0,0 +1,121 @@
+/// Declares a struct, along with [`Index`] and [`IndexMut`] impls for each field,
+/// indexed by the type-level string of the name of the field.
+///
+/// This is a `macro_rules!` alternative to writing those impls by hand.
+///
+/// # Syntax
+///
+/// This takes a struct definition with named fields,
+/// which can have attributes (including doc comments) on the struct and fields,
+/// visibilities, and type parameters without bounds.
+///
+/// It generates these items:
+///
+/// - The struct, with the same attributes, visibility, and fields.
+///
+/// - An `Index<TS!(field_name)>` impl for each field, with the field's type as the `Output`.
+///
+/// - An `IndexMut<TS!(field_name)>` impl for each field.
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::Index;
+///
+/// use tstr::{declare_fields, ts, TS};
+///
+/// declare_fields! {
+///     /// A person, with a name and a surname.
+///     #[derive(Debug, Clone, PartialEq)]
+///     pub struct Person {
+///         pub name: String,
+///         surname: String,
+///     }
+/// }
+///
+/// declare_fields! {
+///     struct Pair<A, B> {
+///         name: A,
+///         surname: B,
+///     }
+/// }
+///
+/// let mut person = Person { name: "Bob".into(), surname: "Marley".into() };
+/// person[ts!(name)].push_str("by");
+///
+/// assert_eq!(person[ts!(name)], "Bobby");
+/// assert_eq!(person[ts!(surname)], "Marley");
+///
+/// let pair = Pair { name: "Bob", surname: 3 };
+/// assert_eq!(pair[ts!(name)], "Bob");
+/// assert_eq!(pair[ts!(surname)], 3);
+///
+/// takes_person(&person);
+///
+/// fn takes_person<P>(person: &P)
+/// where
+///     P: Index<TS!(name), Output = String> + Index<TS!(surname), Output = String>,
+/// {
+///     assert_eq!(person[ts!(name)], "Bobby");
+///     assert_eq!(person[ts!(surname)], "Marley");
+/// }
+///
+/// ```
+///
+/// [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+/// [`IndexMut`]: https://doc.rust-lang.org/core/ops/trait.IndexMut.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! declare_fields {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident $(< $($param:ident),* $(,)? >)? {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident : $field_ty:ty
+            ),*
+            $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name $(< $($param),* >)? {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $field_ty,
+            )*
+        }
+
+        $crate::__priv_declare_fields! {
+            @fields [$($($param),*)?] $name { $($field: $field_ty,)* }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_declare_fields {
+    (@fields $params:tt $name:ident { $($field:ident: $field_ty:ty,)* }) => {
+        $(
+            $crate::__priv_declare_fields! {
+                @field $params $name $field: $field_ty
+            }
+        )*
+    };
+    (@field [$($param:ident),*] $name:ident $field:ident: $field_ty:ty) => {
+        impl<$($param),*> ::core::ops::Index<$crate::TS!($field)> for $name<$($param),*> {
+            type Output = $field_ty;
+
+            #[inline(always)]
+            fn index(&self, _: $crate::TS!($field)) -> &$field_ty {
+                &self.$field
+            }
+        }
+
+        impl<$($param),*> ::core::ops::IndexMut<$crate::TS!($field)> for $name<$($param),*> {
+            #[inline(always)]
+            fn index_mut(&mut self, _: $crate::TS!($field)) -> &mut $field_ty {
+                &mut self.$field
+            }
+        }
+    };
+}
@@ -0,0 +1,72 @@
+/// The type of a type-level string, or a tuple of them,
+/// with repeated type-level strings removed.
+///
+/// # Arguments
+///
+/// This takes the same arguments as the [`TS`] macro, except for `@const` arguments.
+///
+/// Arguments are repeated when they have the same string,
+/// regardless of how they're written (eg: `foo` and `"foo"`, `100` and `0x64`).
+/// The first occurrence of each argument is kept, in the order that they were passed.
+///
+/// Like with the [`TS`] macro, a single argument evaluates to a [`TStr`],
+/// and multiple arguments evaluate to a tuple of [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TS, TS_dedup};
+///
+/// let _: TS_dedup!(foo, bar, foo) = <TS!(foo, bar)>::default();
+///
+/// // keys merged from several sources
+/// macro_rules! all_keys {
+///     ($($key:tt),*) => {
+///         TS_dedup!(id, name, $($key),*)
+///     }
+/// }
+///
+/// let _: all_keys!(name, age, "id") = <TS!(id, name, age)>::default();
+///
+/// // equivalent representations of a string are duplicates
+/// let _: TS_dedup!(100, 0x64, "100", a.b, a.b) = <TS!(100, a.b)>::default();
+///
+/// let _: TS_dedup!(foo, foo) = <TS!(foo)>::default();
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS`]: ./macro.TS.html#arguments
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! TS_dedup {
+    ($($args:tt)*) => {
+        $crate::__ts_dedup_impl!(($crate) $($args)*)
+    };
+}
+
+/// A type-level string value, or a tuple of them,
+/// with repeated type-level strings removed.
+///
+/// This takes the same arguments as the [`TS_dedup`] macro,
+/// which has more details.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts, ts_dedup};
+///
+/// assert_eq!(ts_dedup!(foo, bar, foo, baz, bar), ts!(foo, bar, baz));
+///
+/// ```
+///
+/// [`TS_dedup`]: ./macro.TS_dedup.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! ts_dedup {
+    ($($args:tt)*) => {{
+        let __look_at_the_notes__ =
+            <$crate::__ts_dedup_impl!(($crate) $($args)*) as $crate::MakeTStr>::MAKE;
+        __look_at_the_notes__
+    }};
+}
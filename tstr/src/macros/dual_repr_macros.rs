@@ -0,0 +1,82 @@
+/// The type of a [`TStr`] in the default representation, regardless of the enabled features.
+///
+/// This takes the same arguments as the [`TS`] macro,
+/// and is only available with the `"dual_repr"` feature.
+///
+/// With the `"const_generics"` feature,
+/// the types produced by this macro can be converted to and from
+/// the types produced by [`TS`] with the [`From`] trait.
+///
+/// # Example
+///
+#[cfg_attr(feature = "const_generics", doc = "```rust")]
+#[cfg_attr(not(feature = "const_generics"), doc = "```ignore")]
+/// use tstr::{TS, TS_tupled, ts, ts_tupled};
+///
+/// // The two representations are different types
+/// let tupled: TS_tupled!(foo) = ts_tupled!(foo);
+/// let consted: TS!(foo) = ts!(foo);
+///
+/// // which can be converted between each other
+/// assert_eq!(<TS!(foo)>::from(tupled), consted);
+/// assert_eq!(<TS_tupled!(foo)>::from(consted), tupled);
+///
+/// ```
+///
+/// Converting between the representations of different strings
+/// causes a compile-time error:
+///
+#[cfg_attr(feature = "const_generics", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "const_generics"), doc = "```ignore")]
+/// use tstr::{TS, ts_tupled};
+///
+/// let _: TS!(bar) = ts_tupled!(foo).into();
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS`]: ./macro.TS.html
+/// [`From`]: https://doc.rust-lang.org/core/convert/trait.From.html
+#[macro_export]
+#[cfg(feature = "dual_repr")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "dual_repr")))]
+macro_rules! TS_tupled {
+    ($($args:tt)*) => {
+        $crate::__ts_tupled_impl!(($crate) $($args)*)
+    };
+}
+
+/// A [`TStr`] value in the default representation, regardless of the enabled features.
+///
+/// This takes the same arguments as the [`ts`] macro,
+/// and is only available with the `"dual_repr"` feature.
+///
+/// For more details, you can look at [`TS_tupled`].
+///
+/// # Example
+///
+#[cfg_attr(feature = "const_generics", doc = "```rust")]
+#[cfg_attr(not(feature = "const_generics"), doc = "```ignore")]
+/// use tstr::{TS, ts_tupled};
+///
+/// fn takes_name(name: TS!(name)) -> TS!(name) {
+///     name
+/// }
+///
+/// assert_eq!(takes_name(ts_tupled!(name).into()), tstr::ts!(name));
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`ts`]: ./macro.ts.html
+/// [`TS_tupled`]: ./macro.TS_tupled.html
+#[macro_export]
+#[cfg(feature = "dual_repr")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "dual_repr")))]
+macro_rules! ts_tupled {
+    ($($args:tt)*) => {{
+        let __look_at_the_notes__ =
+            <$crate::__ts_tupled_impl!(($crate) $($args)*) as $crate::MakeTStr>::MAKE;
+        __look_at_the_notes__
+    }};
+}
@@ -0,0 +1,114 @@
+/// The type of a [`TStr`] composed by substituting placeholders in a string literal.
+///
+/// This is equivalent to `TS!(format!(...))`.
+///
+/// # Arguments
+///
+/// The first argument is a string literal, whose placeholders are substituted
+/// by the strings of the other arguments:
+///
+/// - `{}`: the next argument, starting from the first one.
+///
+/// - `{N}`: the `N`th argument, starting from zero
+/// (eg: `{1}` is the second argument).
+///
+/// `{{` and `}}` are escaped braces.
+/// Format specifiers (eg: `{:?}`) and named placeholders (eg: `{name}`) aren't supported.
+///
+/// The other arguments can be anything that [`concat!(...)`](./macro.TS.html#arguments)
+/// accepts in the [`TS`] macro, as well as `TS!(...)` and `ts!(...)` with a single argument,
+/// which are replaced by their string.
+///
+/// It's a compile-time error for an argument to not be used.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts_format, TS, TS_format};
+///
+/// type Getter = TS_format!("get_{}", name);
+///
+/// let _: TS!(get_name) = Getter::NEW;
+///
+/// let _: TS!("user_id.0") = ts_format!("{}_{}.{}", user, id, 0);
+/// let _: TS!("b-a-b") = ts_format!("{1}-{0}-{1}", 'a', TS!(b));
+/// let _: TS!("{version}: 1.5") = ts_format!("{{version}}: {}", 1.5);
+///
+/// ```
+///
+/// ### Using in `macro_rules!` macros
+///
+/// ```rust
+/// use tstr::{ts, TS, TS_format};
+///
+/// macro_rules! accessors {
+///     ($ty:ident, $field:ident: $field_ty:ty) => {
+///         impl Field<TS_format!("get_{}", $field)> for $ty {
+///             type Type = $field_ty;
+///         }
+///         impl Field<TS_format!("set_{}", $field)> for $ty {
+///             type Type = fn($field_ty);
+///         }
+///     };
+/// }
+///
+/// trait Field<K> {
+///     type Type;
+/// }
+///
+/// struct Person;
+///
+/// accessors! {Person, age: u32}
+///
+/// let _: <Person as Field<TS!(get_age)>>::Type = 3u32;
+/// let _: <Person as Field<TS!(set_age)>>::Type = |_: u32| ();
+///
+/// ```
+///
+/// ### Unused argument
+///
+/// ```compile_fail
+/// use tstr::TS_format;
+///
+/// type Getter = TS_format!("get_{}", name, age);
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS`]: ./macro.TS.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! TS_format {
+    ($($args:tt)*) => {
+        $crate::TS!(format!($($args)*))
+    };
+}
+
+/// A [`TStr`] value composed by substituting placeholders in a string literal.
+///
+/// This is equivalent to `ts!(format!(...))`,
+/// and takes the same arguments as the [`TS_format`] macro.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts, ts_format, TS};
+///
+/// let versioned = ts_format!("{}_v{}", "schema", 2);
+/// let _: TS!(schema_v2) = versioned;
+///
+/// let _: (TS!(min_len), TS!(max_len)) = (ts_format!("min_{}", len), ts_format!("max_{}", len));
+///
+/// // `ts!(format!(...))` is equivalent, and can be combined with other arguments
+/// let _: (TS!(id), TS!(user_id)) = ts!(id, format!("user_{}", id));
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS_format`]: ./macro.TS_format.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! ts_format {
+    ($($args:tt)*) => {
+        $crate::ts!(format!($($args)*))
+    };
+}
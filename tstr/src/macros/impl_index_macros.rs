@@ -0,0 +1,148 @@
+/// Implements [`Index`] for a type, with the type-level string of each field name,
+/// using an expression to get each field.
+///
+/// This is for types that can't be declared with [`declare_fields`],
+/// like types with private representations, or fields that are computed from other fields.
+///
+/// # Syntax
+///
+/// ```text
+/// impl_index! {
+///     impl[<generic parameters>] <type> {
+///         <field name>: <output type> => <expression>,
+///         ...
+///     }
+/// }
+/// ```
+///
+/// The `impl[...]` is optional, only required for declaring generic parameters.
+///
+/// The expression returns a reference to the field, and can use `self`.
+/// Field names can be identifiers or integers.
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::Index;
+///
+/// use tstr::{impl_index, ts, TS};
+///
+/// struct Person {
+///     name: String,
+///     surname: String,
+/// }
+///
+/// impl_index! {
+///     Person {
+///         name: str => &self.name,
+///         surname: str => &self.surname,
+///     }
+/// }
+///
+/// struct Wrapper<T>(Vec<T>);
+///
+/// impl_index! {
+///     impl[T: Copy] Wrapper<T> {
+///         first: T => &self.0[0],
+///         0: Vec<T> => &self.0,
+///     }
+/// }
+///
+/// let person = Person { name: "Bob".into(), surname: "Marley".into() };
+///
+/// assert_eq!(&person[ts!(name)], "Bob");
+/// assert_eq!(&person[ts!(surname)], "Marley");
+/// takes_person(&person);
+///
+/// let wrapper = Wrapper(vec![3, 5, 8]);
+/// assert_eq!(wrapper[ts!(first)], 3);
+/// assert_eq!(wrapper[ts!(0)], [3, 5, 8]);
+///
+/// fn takes_person<P>(pers: &P)
+/// where
+///     P: Index<TS!(name), Output = str> + Index<TS!(surname), Output = str>
+/// {
+///     assert_eq!(&pers[ts!(name)], "Bob");
+///     assert_eq!(&pers[ts!(surname)], "Marley");
+/// }
+/// ```
+///
+/// [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+/// [`declare_fields`]: ./macro.declare_fields.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! impl_index {
+    (impl[$($generics:tt)*] $self:ty { $($fields:tt)* }) => {
+        $crate::__priv_impl_index! {@fields [$($generics)*] [$self] $($fields)*}
+    };
+    ($self:ty { $($fields:tt)* }) => {
+        $crate::__priv_impl_index! {@fields [] [$self] $($fields)*}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_impl_index {
+    (@fields $generics:tt $self:tt) => {};
+    (@fields $generics:tt $self:tt $field:tt : $out:ty => $($rest:tt)*) => {
+        $crate::__priv_impl_index! {@expr $generics $self $field [$out] [] $($rest)*}
+    };
+
+    // Collects the tokens of the expression, up to the next `,`
+    (@expr $generics:tt $self:tt $field:tt $out:tt $expr:tt) => {
+        $crate::__priv_impl_index! {@find_self $generics $self $field $out $expr $expr}
+    };
+    (@expr $generics:tt $self:tt $field:tt $out:tt $expr:tt , $($rest:tt)*) => {
+        $crate::__priv_impl_index! {@find_self $generics $self $field $out $expr $expr}
+        $crate::__priv_impl_index! {@fields $generics $self $($rest)*}
+    };
+    (@expr $generics:tt $self:tt $field:tt $out:tt [$($expr:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__priv_impl_index! {@expr $generics $self $field $out [$($expr)* $token] $($rest)*}
+    };
+
+    // Finds the `self` token in the expression,
+    // so that the `self` parameter is declared with the same hygiene as the expression.
+    (@find_self $generics:tt $self:tt $field:tt $out:tt [] $expr:tt) => {
+        $crate::__priv_impl_index! {@impl $generics $self $field $out self $expr}
+    };
+    (@find_self $generics:tt $self:tt $field:tt $out:tt [($($inner:tt)*) $($rest:tt)*] $expr:tt) => {
+        $crate::__priv_impl_index! {
+            @find_self $generics $self $field $out [$($inner)* $($rest)*] $expr
+        }
+    };
+    (@find_self $generics:tt $self:tt $field:tt $out:tt [[$($inner:tt)*] $($rest:tt)*] $expr:tt) => {
+        $crate::__priv_impl_index! {
+            @find_self $generics $self $field $out [$($inner)* $($rest)*] $expr
+        }
+    };
+    (@find_self $generics:tt $self:tt $field:tt $out:tt [{$($inner:tt)*} $($rest:tt)*] $expr:tt) => {
+        $crate::__priv_impl_index! {
+            @find_self $generics $self $field $out [$($inner)* $($rest)*] $expr
+        }
+    };
+    (@find_self $generics:tt $self:tt $field:tt $out:tt [$token:tt $($rest:tt)*] $expr:tt) => {
+        $crate::__priv_impl_index! {
+            @check_self $generics $self $field $out $token $token [$($rest)*] $expr
+        }
+    };
+    (@check_self $generics:tt $self:tt $field:tt $out:tt self $self_param:tt $rest:tt $expr:tt) => {
+        $crate::__priv_impl_index! {@impl $generics $self $field $out $self_param $expr}
+    };
+    (@check_self $generics:tt $self:tt $field:tt $out:tt $other:tt $_token:tt $rest:tt $expr:tt) => {
+        $crate::__priv_impl_index! {@find_self $generics $self $field $out $rest $expr}
+    };
+
+    (
+        @impl [$($generics:tt)*] [$self:ty] $field:tt [$out:ty]
+        $self_param:tt [$($expr:tt)*]
+    ) => {
+        impl<$($generics)*> ::core::ops::Index<$crate::TS!($field)> for $self {
+            type Output = $out;
+
+            #[inline(always)]
+            fn index(&$self_param, _: $crate::TS!($field)) -> &$out {
+                $($expr)*
+            }
+        }
+    };
+}
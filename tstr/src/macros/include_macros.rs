@@ -0,0 +1,68 @@
+/// The type of the type-level strings of the lines of a file, read at compile-time.
+///
+/// This is the type-level equivalent of [`core::include_str`],
+/// for keeping large sets of keys in a data file.
+///
+/// # Arguments
+///
+/// This takes a string literal with the path of the file,
+/// relative to the directory of the `Cargo.toml` of the crate that invokes this macro
+/// (unlike [`core::include_str`], which is relative to the file that it's invoked in).
+///
+/// Each line of the file is trimmed of leading and trailing whitespace,
+/// and the lines that are then empty are skipped.
+///
+/// Like with the [`TS`] macro, a file with a single line evaluates to a [`TStr`],
+/// and a file with multiple lines evaluates to a tuple of [`TStr`]s.
+///
+/// The crate is recompiled when the file changes.
+///
+/// # Example
+///
+/// With a `tests/data/permissions.txt` file that contains:
+///
+/// ```text
+/// read
+/// write
+///
+///   delete  
+/// admin
+/// ```
+///
+/// ```rust
+/// use tstr::{include_tstr_lines, TS};
+///
+/// type Permissions = include_tstr_lines!("tests/data/permissions.txt");
+///
+/// let _: Permissions = <TS!(read, write, delete, admin)>::default();
+///
+/// ```
+///
+/// Reading a file that doesn't exist is a compile-time error:
+///
+/// ```compile_fail
+/// type Keys = tstr::include_tstr_lines!("this/file/does/not/exist.txt");
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS`]: ./macro.TS.html
+/// [`core::include_str`]: https://doc.rust-lang.org/core/macro.include_str.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! include_tstr_lines {
+    ($path:literal $(,)?) => {
+        $crate::__include_tstr_lines_impl!(($crate) $path)
+    };
+}
+
+/// Passes through the `T` type of the `[T; 0]` array,
+/// whose length expression includes the file read by `include_tstr_lines`,
+/// so that the compiler tracks it.
+#[doc(hidden)]
+pub trait __IncludedFile {
+    type Type;
+}
+
+impl<T> __IncludedFile for [T; 0] {
+    type Type = T;
+}
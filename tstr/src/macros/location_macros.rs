@@ -0,0 +1,187 @@
+/// The type of the [`TStr`] of the path of the file that this macro is invoked in.
+///
+/// This is the `TStr` equivalent of [`core::file`],
+/// and is only available with the `"rust_1_88"` feature.
+///
+/// Unlike [`core::file`], invoking this inside of a `macro_rules!` macro
+/// gets the file where that macro is defined, not the file that it's invoked in.
+///
+/// The expanded code refers to this crate as `::tstr`,
+/// which can be changed by passing a `crate = path::to::tstr` argument
+/// (for more details, you can look at the
+/// [macro_rules section](./macro.TS.html#using-in-macro_rules-macros) of the `TS` docs).
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TS_file, ts_file};
+///
+/// let _: TS_file!() = ts_file!();
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`core::file`]: https://doc.rust-lang.org/core/macro.file.html
+#[cfg(feature = "rust_1_88")]
+pub use tstr_proc_macros::TS_file;
+
+/// The [`TStr`] of the path of the file that this macro is invoked in.
+///
+/// This is the `TStr` equivalent of [`core::file`],
+/// and is only available with the `"rust_1_88"` feature.
+///
+/// For more details, you can look at [`TS_file`].
+///
+/// # Example
+///
+/// This example demonstrates a registry of log categories keyed by the source file.
+///
+/// ```rust
+/// use tstr::{TS_file, ts_file};
+///
+/// trait Category<K> {
+///     fn category(&self, key: K) -> &'static str;
+/// }
+///
+/// struct Logger;
+///
+/// impl Category<TS_file!()> for Logger {
+///     fn category(&self, _: TS_file!()) -> &'static str {
+///         "main"
+///     }
+/// }
+///
+/// assert_eq!(Logger.category(ts_file!()), "main");
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS_file`]: ./macro.TS_file.html
+/// [`core::file`]: https://doc.rust-lang.org/core/macro.file.html
+#[cfg(feature = "rust_1_88")]
+pub use tstr_proc_macros::ts_file;
+
+/// The type of the [`TStr`] of the line that this macro is invoked at,
+/// as a decimal number.
+///
+/// This is the `TStr` equivalent of [`core::line`],
+/// and is only available with the `"rust_1_88"` feature.
+///
+/// Unlike [`core::line`], invoking this inside of a `macro_rules!` macro
+/// gets the line in the definition of that macro, not the line that it's invoked at.
+///
+/// The expanded code refers to this crate as `::tstr`,
+/// which can be changed by passing a `crate = path::to::tstr` argument
+/// (for more details, you can look at the
+/// [macro_rules section](./macro.TS.html#using-in-macro_rules-macros) of the `TS` docs).
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ToUint, TS_line};
+///
+/// assert_eq!(<TS_line!()>::U32, line!());
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`core::line`]: https://doc.rust-lang.org/core/macro.line.html
+#[cfg(feature = "rust_1_88")]
+pub use tstr_proc_macros::TS_line;
+
+/// The [`TStr`] of the line that this macro is invoked at, as a decimal number.
+///
+/// This is the `TStr` equivalent of [`core::line`],
+/// and is only available with the `"rust_1_88"` feature.
+///
+/// For more details, you can look at [`TS_line`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts_line, ToUint};
+///
+/// let line = ts_line!();
+/// assert_eq!(line.to_u32(), line!() - 1);
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS_line`]: ./macro.TS_line.html
+/// [`core::line`]: https://doc.rust-lang.org/core/macro.line.html
+#[cfg(feature = "rust_1_88")]
+pub use tstr_proc_macros::ts_line;
+
+/// The type of the [`TStr`] of the path of the module that this macro is invoked in.
+///
+/// This is the `TStr` equivalent of [`core::module_path`],
+/// and is only available with the `"const_generics"` feature,
+/// since it passes the string to a `&'static str` const parameter.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{StrValue, TS_module_path};
+///
+/// mod foo {
+///     pub type Path = tstr::TS_module_path!();
+/// }
+///
+/// assert_eq!(<TS_module_path!()>::STR, module_path!());
+/// assert!(<foo::Path>::STR.ends_with("::foo"));
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`core::module_path`]: https://doc.rust-lang.org/core/macro.module_path.html
+#[macro_export]
+#[cfg(feature = "const_generics")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+macro_rules! TS_module_path {
+    () => {
+        $crate::TStr<$crate::___<{ ::core::module_path!() }>>
+    };
+}
+
+/// The [`TStr`] of the path of the module that this macro is invoked in.
+///
+/// This is the `TStr` equivalent of [`core::module_path`],
+/// and is only available with the `"const_generics"` feature.
+///
+/// # Example
+///
+/// This example demonstrates a registry of metrics, keyed by the module that they're in.
+///
+/// ```rust
+/// use tstr::{StrValue, TS_module_path, ts_module_path};
+///
+/// mod requests {
+///     pub struct Metrics;
+///
+///     impl Metrics {
+///         pub fn count(&self, _: tstr::TS_module_path!()) -> u32 {
+///             10
+///         }
+///     }
+///
+///     pub fn key() -> tstr::TS_module_path!() {
+///         tstr::ts_module_path!()
+///     }
+/// }
+///
+/// assert_eq!(requests::Metrics.count(requests::key()), 10);
+///
+/// assert_eq!(ts_module_path!().to_str(), module_path!());
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`core::module_path`]: https://doc.rust-lang.org/core/macro.module_path.html
+#[macro_export]
+#[cfg(feature = "const_generics")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+macro_rules! ts_module_path {
+    () => {
+        <$crate::TS_module_path!() as $crate::MakeTStr>::MAKE
+    };
+}
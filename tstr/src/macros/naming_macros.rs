@@ -0,0 +1,120 @@
+/// Asserts at compile-time that [`TStr`]s are in `snake_case`.
+///
+/// `assert_snake_case!(TS!(user_id), TS!(name))` fails to compile if any of the
+/// type-level strings isn't in `snake_case`,
+/// as defined by [`NamingConvention::IS_SNAKE_CASE`].
+///
+/// The types must not have generic parameters,
+/// for asserting generic type-level strings you can use [`AssertNamingConvention`].
+/// This can be used both in item position and in statement position.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{assert_snake_case, TS};
+///
+/// type UserId = TS!(user_id);
+///
+/// assert_snake_case!(UserId, TS!(created_at), TS!(_id));
+///
+/// ```
+///
+/// Asserting a string that isn't in `snake_case`:
+///
+/// ```compile_fail
+/// use tstr::{assert_snake_case, TS};
+///
+/// assert_snake_case!(TS!(userId));
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`NamingConvention::IS_SNAKE_CASE`]:
+/// ./trait.NamingConvention.html#associatedconstant.IS_SNAKE_CASE
+/// [`AssertNamingConvention`]: ./trait.AssertNamingConvention.html
+#[macro_export]
+macro_rules! assert_snake_case {
+    ($($type:ty),* $(,)?) => {
+        $(
+            const _: () = <$type as $crate::AssertNamingConvention>::ASSERT_SNAKE_CASE;
+        )*
+    };
+}
+
+/// Asserts at compile-time that [`TStr`]s are in `kebab-case`.
+///
+/// `assert_kebab_case!(TS!("user-id"))` fails to compile if any of the
+/// type-level strings isn't in `kebab-case`,
+/// as defined by [`NamingConvention::IS_KEBAB_CASE`].
+///
+/// This takes the same arguments as [`assert_snake_case`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{assert_kebab_case, TS};
+///
+/// assert_kebab_case!(TS!("content-type"), TS!("x-request-id"), TS!(accept));
+///
+/// ```
+///
+/// Asserting a string that isn't in `kebab-case`:
+///
+/// ```compile_fail
+/// use tstr::{assert_kebab_case, TS};
+///
+/// assert_kebab_case!(TS!("Content-Type"));
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`NamingConvention::IS_KEBAB_CASE`]:
+/// ./trait.NamingConvention.html#associatedconstant.IS_KEBAB_CASE
+/// [`assert_snake_case`]: ./macro.assert_snake_case.html
+#[macro_export]
+macro_rules! assert_kebab_case {
+    ($($type:ty),* $(,)?) => {
+        $(
+            const _: () = <$type as $crate::AssertNamingConvention>::ASSERT_KEBAB_CASE;
+        )*
+    };
+}
+
+/// Asserts at compile-time that [`TStr`]s are identifiers.
+///
+/// `assert_is_identifier!(TS!(userId))` fails to compile if any of the
+/// type-level strings isn't an identifier,
+/// as defined by [`NamingConvention::IS_IDENTIFIER`].
+///
+/// This takes the same arguments as [`assert_snake_case`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{assert_is_identifier, TS};
+///
+/// assert_is_identifier!(TS!(userId), TS!(USER_ID), TS!(_0));
+///
+/// fn keyword_fields() {
+///     assert_is_identifier!(TS!(r#type), TS!(r#match));
+/// }
+/// ```
+///
+/// Asserting a string that isn't an identifier:
+///
+/// ```compile_fail
+/// use tstr::{assert_is_identifier, TS};
+///
+/// assert_is_identifier!(TS!("user-id"));
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`NamingConvention::IS_IDENTIFIER`]:
+/// ./trait.NamingConvention.html#associatedconstant.IS_IDENTIFIER
+/// [`assert_snake_case`]: ./macro.assert_snake_case.html
+#[macro_export]
+macro_rules! assert_is_identifier {
+    ($($type:ty),* $(,)?) => {
+        $(
+            const _: () = <$type as $crate::AssertNamingConvention>::ASSERT_IDENTIFIER;
+        )*
+    };
+}
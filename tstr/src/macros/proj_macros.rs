@@ -0,0 +1,200 @@
+/// Accesses a chain of fields with field syntax,
+/// by indexing each level with the type-level string of the field name.
+///
+/// `proj!(value.name.surname)` expands to `value[ts!(name)][ts!(surname)]`,
+/// so it can be used anywhere that indexing can,
+/// including assignments and in `&`/`&mut` borrows.
+///
+/// # Syntax
+///
+/// The value being projected must be a single token tree,
+/// like a variable, `self`, or a parenthesized expression (eg: `proj!((*foo).bar)`).
+///
+/// Each field after a `.` can be an identifier or an integer.
+/// Because `0.1` is tokenized as a float literal,
+/// consecutive numeric fields must be separated with a space: `proj!(tuple.0 .1)`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::Index;
+///
+/// use tstr::{declare_fields, proj, TS};
+///
+/// declare_fields! {
+///     struct Person {
+///         name: Name,
+///         age: u32,
+///     }
+/// }
+///
+/// declare_fields! {
+///     struct Name {
+///         first: &'static str,
+///         surname: &'static str,
+///     }
+/// }
+///
+/// let mut person = Person {
+///     name: Name { first: "Bob", surname: "Marley" },
+///     age: 36,
+/// };
+///
+/// assert_eq!(proj!(person.name.first), "Bob");
+/// assert_eq!(proj!(person.age), 36);
+///
+/// proj!(person.name.surname) = "Dylan";
+/// *&mut proj!(person.age) += 1;
+///
+/// assert_eq!(surname(&person), "Dylan");
+/// assert_eq!(proj!(person.age), 37);
+///
+/// // Generic access to the nested field
+/// fn surname<P, N>(person: &P) -> &'static str
+/// where
+///     P: Index<TS!(name), Output = N>,
+///     N: Index<TS!(surname), Output = &'static str>,
+/// {
+///     proj!(person.name.surname)
+/// }
+///
+/// ```
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! proj {
+    ($value:tt $(. $field:tt)+) => {
+        $value $([$crate::ts!($field)])+
+    };
+}
+
+/// Assigns to a chain of fields with field syntax,
+/// by mutably indexing each level with the type-level string of the field name.
+///
+/// `set_field!(value.a.b = expr)` expands to `value[ts!(a)][ts!(b)] = expr`,
+/// which uses the [`IndexMut`] impls of `value` and the intermediate fields.
+///
+/// For the syntax of the fields, look at the docs for [`proj`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::IndexMut;
+///
+/// use tstr::{declare_fields, proj, set_field, TS};
+///
+/// declare_fields! {
+///     struct Config {
+///         window: Window,
+///         title: String,
+///     }
+/// }
+///
+/// declare_fields! {
+///     struct Window {
+///         width: u32,
+///         height: u32,
+///     }
+/// }
+///
+/// let mut config = Config {
+///     window: Window { width: 640, height: 480 },
+///     title: String::new(),
+/// };
+///
+/// set_field!(config.window.width = 1280);
+/// set_field!(config.title = "hello".to_string());
+/// set_height(&mut config, 720);
+///
+/// assert_eq!(proj!(config.window.width), 1280);
+/// assert_eq!(proj!(config.window.height), 720);
+/// assert_eq!(proj!(config.title), "hello");
+///
+/// fn set_height<C, W>(config: &mut C, height: u32)
+/// where
+///     C: IndexMut<TS!(window), Output = W>,
+///     W: IndexMut<TS!(height), Output = u32>,
+/// {
+///     set_field!(config.window.height = height);
+/// }
+///
+/// ```
+///
+/// [`IndexMut`]: https://doc.rust-lang.org/core/ops/trait.IndexMut.html
+/// [`proj`]: ./macro.proj.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! set_field {
+    ($value:tt $(. $field:tt)+ = $expr:expr) => {
+        $value $([$crate::ts!($field)])+ = $expr
+    };
+}
+
+/// Declares local variables with references to several fields of a value,
+/// by indexing it with the type-level string of each field name.
+///
+/// `with_fields!(person => { name, surname: last })` expands to:
+/// ```text
+/// let name = &person[ts!(name)];
+/// let last = &person[ts!(surname)];
+/// ```
+///
+/// This mirrors destructuring a reference to a struct,
+/// but works with any type that implements [`Index`] for the names of the fields,
+/// including type parameters.
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::Index;
+///
+/// use tstr::{declare_fields, with_fields, TS};
+///
+/// declare_fields! {
+///     struct Person {
+///         name: String,
+///         surname: String,
+///         age: u32,
+///     }
+/// }
+///
+/// let person = Person {
+///     name: "Bob".to_string(),
+///     surname: "Marley".to_string(),
+///     age: 36,
+/// };
+///
+/// assert_eq!(greeting(&person), "Hello Bob Marley");
+///
+/// with_fields!(person => { age: years });
+/// assert_eq!(*years, 36);
+///
+/// fn greeting<P>(person: &P) -> String
+/// where
+///     P: Index<TS!(name), Output = String> + Index<TS!(surname), Output = String>,
+/// {
+///     with_fields!(person => { name, surname });
+///     format!("Hello {} {}", name, surname)
+/// }
+///
+/// ```
+///
+/// [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! with_fields {
+    ($value:expr => { $($field:ident $(: $binding:ident)?),* $(,)? }) => {
+        let value = &$value;
+        $( $crate::__priv_with_field!{value, $field $(: $binding)?} )*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_with_field {
+    ($value:ident, $field:ident) => {
+        let $field = &$value[$crate::ts!($field)];
+    };
+    ($value:ident, $field:ident : $binding:ident) => {
+        let $binding = &$value[$crate::ts!($field)];
+    };
+}
@@ -0,0 +1,77 @@
+/// A tuple of [`TStr`] types, with the type-level strings sorted.
+///
+/// # Arguments
+///
+/// This takes the same arguments as the [`TS`] macro,
+/// except for dotted paths (eg: `foo.bar`) and `@const` arguments.
+///
+/// The arguments are sorted lexicographically, comparing the UTF-8 bytes of the strings,
+/// in the same order as the [`SortTStrs`] type-level operator (when that's available).
+/// Repeated arguments are kept.
+///
+/// Like with the [`TS`] macro, a single argument evaluates to a [`TStr`],
+/// and multiple arguments evaluate to a tuple of [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TS, TS_sort};
+///
+/// let _: TS_sort!(foo, bar, baz) = <TS!(bar, baz, foo)>::default();
+///
+/// // sorting the same strings in any order produces the same type
+/// let _: TS_sort!(baz, foo, bar) = <TS!(bar, baz, foo)>::default();
+///
+/// // The equivalent representations of a string from the `TS` macro are sorted the same.
+/// let _: TS_sort!(10, "0", b) = <TS!(0, 10, b)>::default();
+///
+/// // uppercase letters are less than lowercase letters
+/// let _: TS_sort!(a, B, "") = <TS!("", B, a)>::default();
+///
+/// let _: TS_sort!(foo) = <TS!(foo)>::default();
+///
+/// ```
+///
+/// Dotted paths can't be sorted:
+///
+/// ```compile_fail
+/// let _: tstr::TS_sort!(foo.bar, baz) = Default::default();
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS`]: ./macro.TS.html#arguments
+/// [`SortTStrs`]: ./tlist/trait.SortTStrs.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! TS_sort {
+    ($($args:tt)*) => {
+        $crate::__ts_sort_impl!(($crate) $($args)*)
+    };
+}
+
+/// A tuple of [`TStr`] values, with the type-level strings sorted.
+///
+/// This takes the same arguments as the [`TS_sort`] macro,
+/// which has more details.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts, ts_sort};
+///
+/// assert_eq!(ts_sort!(qux, bar, foo), ts!(bar, foo, qux));
+/// assert_eq!(ts_sort!(b, "a", 3, "-5"), ts!("-5", 3, a, b));
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TS_sort`]: ./macro.TS_sort.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! ts_sort {
+    ($($args:tt)*) => {{
+        let __look_at_the_notes__ =
+            <$crate::__ts_sort_impl!(($crate) $($args)*) as $crate::MakeTStr>::MAKE;
+        __look_at_the_notes__
+    }};
+}
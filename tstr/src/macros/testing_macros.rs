@@ -0,0 +1,95 @@
+/// Asserts at compile-time that a type implements [`Index`]
+/// with the type-level string of each field name.
+///
+/// `assert_has_fields!(Person: name, surname)` fails to compile if `Person`
+/// doesn't implement `Index<TS!(name)>` and `Index<TS!(surname)>`.
+///
+/// Each field name can be followed by `: <type>`
+/// to also assert the `Output` type of the [`Index`] impl.
+///
+/// The type must not have generic parameters other than the ones it's used with.
+/// This can be used both in item position and in statement position.
+///
+/// For the error messages that failed assertions produce,
+/// look at the docs of the [`testing`] module.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{assert_has_fields, impl_index};
+///
+/// struct Tree<T> {
+///     value: T,
+///     children: Vec<Tree<T>>,
+/// }
+///
+/// impl_index! {
+///     impl[T] Tree<T> {
+///         value: T => &self.value,
+///         children: [Tree<T>] => &self.children,
+///     }
+/// }
+///
+/// assert_has_fields!(Tree<u32>: value: u32, children);
+///
+/// fn main() {
+///     assert_has_fields!(Tree<&str>: value: &str, children: [Tree<&str>]);
+/// }
+/// ```
+///
+/// [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+/// [`testing`]: ./testing/index.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! assert_has_fields {
+    ($type:ty : $($field:tt $(: $field_ty:ty)?),* $(,)?) => {
+        $(
+            $crate::__priv_assert_has_field! {assert_field assert_field_type, $type, $field $(: $field_ty)?}
+        )*
+    };
+}
+
+/// Asserts at compile-time that a type implements [`IndexMut`]
+/// with the type-level string of each field name.
+///
+/// This takes the same arguments as [`assert_has_fields`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{assert_has_fields, assert_has_fields_mut, declare_fields};
+///
+/// declare_fields! {
+///     struct Point {
+///         x: i32,
+///         y: i32,
+///     }
+/// }
+///
+/// assert_has_fields_mut!(Point: x: i32, y);
+/// ```
+///
+/// [`IndexMut`]: https://doc.rust-lang.org/core/ops/trait.IndexMut.html
+/// [`assert_has_fields`]: ./macro.assert_has_fields.html
+#[macro_export]
+#[cfg(feature = "proc_macros")]
+macro_rules! assert_has_fields_mut {
+    ($type:ty : $($field:tt $(: $field_ty:ty)?),* $(,)?) => {
+        $(
+            $crate::__priv_assert_has_field! {
+                assert_field_mut assert_field_type_mut, $type, $field $(: $field_ty)?
+            }
+        )*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __priv_assert_has_field {
+    ($assert:ident $_assert_type:ident, $type:ty, $field:tt) => {
+        const _: fn() = $crate::testing::$assert::<$type, $crate::TS!($field)>;
+    };
+    ($_assert:ident $assert_type:ident, $type:ty, $field:tt : $field_ty:ty) => {
+        const _: fn() = $crate::testing::$assert_type::<$type, $crate::TS!($field), $field_ty>;
+    };
+}
@@ -2,6 +2,23 @@ use crate::TStr;
 
 /// For constructing [`TStr`]s or collections of them.
 ///
+/// This is implemented for [`TStr`], tuples of up to 16 elements, and arrays.
+/// Without the `"min_const_generics"` feature it's only implemented for arrays
+/// of up to 32 elements.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{MakeTStr, TS};
+///
+/// type Keys = (TS!(foo), TS!(bar), [TS!(baz); 3]);
+///
+/// const KEYS: Keys = Keys::MAKE;
+///
+/// assert_eq!(KEYS.2.len(), 3);
+///
+/// ```
+///
 /// [`TStr`]: ./struct.TStr.html
 pub trait MakeTStr: Copy {
     /// Gets a value of this type
@@ -33,3 +50,39 @@ tuple_impl! {A B C D E}
 tuple_impl! {A B C D E F}
 tuple_impl! {A B C D E F G}
 tuple_impl! {A B C D E F G H}
+tuple_impl! {A B C D E F G H I}
+tuple_impl! {A B C D E F G H I J}
+tuple_impl! {A B C D E F G H I J K}
+tuple_impl! {A B C D E F G H I J K L}
+tuple_impl! {A B C D E F G H I J K L M}
+tuple_impl! {A B C D E F G H I J K L M N}
+tuple_impl! {A B C D E F G H I J K L M N O}
+tuple_impl! {A B C D E F G H I J K L M N O P}
+
+#[cfg(feature = "min_const_generics")]
+impl<T, const N: usize> MakeTStr for [T; N]
+where
+    T: MakeTStr,
+{
+    const MAKE: Self = [T::MAKE; N];
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+macro_rules! array_impls {
+    ($($len:literal)*) => (
+        $(
+            impl<T> MakeTStr for [T; $len]
+            where
+                T: MakeTStr,
+            {
+                const MAKE: Self = [T::MAKE; $len];
+            }
+        )*
+    )
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+array_impls! {
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+}
@@ -0,0 +1,293 @@
+//! Type-level maps from [`TStr`]s to types.
+//!
+//! This module is only available with the `"cmp_traits"` feature,
+//! and without the `"min_const_generics"` feature,
+//! since the map operations are only implemented for the default
+//! representation of type-level strings.
+//!
+//! # Example
+//!
+//! This example demonstrates a function that's generic over a schema,
+//! using a [`TMap`] of field names to field types.
+//!
+//! ```rust
+//! use tstr::maps::Get;
+//! use tstr::{TMap, TS};
+//!
+//! type Person = TMap!(name => &'static str, age => u32);
+//!
+//! assert_eq!(default_field::<Person, TS!(name)>(), "");
+//! assert_eq!(default_field::<Person, TS!(age)>(), 0);
+//!
+//! fn default_field<Schema, Field>() -> Schema::Output
+//! where
+//!     Schema: Get<Field>,
+//!     Schema::Output: Default,
+//! {
+//!     Default::default()
+//! }
+//!
+//! ```
+//!
+//! Getting a field that isn't in the schema is a compile-time error:
+//!
+//! ```compile_fail
+//! use tstr::maps::Get;
+//! use tstr::{TMap, TS};
+//!
+//! type Person = TMap!(name => &'static str, age => u32);
+//!
+//! default_field::<Person, TS!(id)>();
+//!
+//! fn default_field<Schema, Field>() -> Schema::Output
+//! where
+//!     Schema: Get<Field>,
+//!     Schema::Output: Default,
+//! {
+//!     Default::default()
+//! }
+//! ```
+//!
+//! [`TStr`]: ../struct.TStr.html
+//! [`TMap`]: ./struct.TMap.html
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use crate::{
+    bools::IfElse,
+    for_tupled_reprs::type_cmp::TStrEqType,
+    tlist::{TList, TNil},
+};
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait Sealed {}
+
+    #[doc(hidden)]
+    pub trait ListGet<K> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListInsert<K, V> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListRemove<K> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub struct Found<V>(V);
+
+    #[doc(hidden)]
+    pub struct NotFound;
+}
+use sealed::{Found, ListGet, ListInsert, ListRemove, NotFound, Sealed};
+
+/// A type-level map from [`TStr`]s to types.
+///
+/// `L` is a [`TList`] of `(key, value)` pairs,
+/// with the entries in the order that they were inserted into the map.
+///
+/// The [`TMap`] macro is the most convenient way to write the type of a map.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::{TMap, TS};
+///
+/// let map: TMap!(foo => u8, bar => String) = TMap::NEW;
+///
+/// // The TMap macro is sugar for this type
+/// let _: TMap<TList<(TS!(foo), u8), TList<(TS!(bar), String), TNil>>> = map;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`TList`]: ../tlist/struct.TList.html
+/// [`TMap`]: ../macro.TMap.html
+pub struct TMap<L>(PhantomData<fn() -> L>);
+
+impl<L> TMap<L> {
+    /// Constructs the TMap.
+    pub const NEW: Self = TMap(PhantomData);
+}
+
+/// For constructing a [`TMap`] in generic code,
+/// like the `Output` of the map operations.
+///
+/// This trait is sealed, it's only implemented for [`TMap`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::maps::{Insert, MakeTMap};
+/// use tstr::TS;
+///
+/// type Map = tstr::TMap!(foo => u8);
+///
+/// let _: tstr::TMap!(foo => u8, bar => u16) = <<Map as Insert<TS!(bar), u16>>::Output>::MAKE;
+///
+/// ```
+///
+/// [`TMap`]: ./struct.TMap.html
+pub trait MakeTMap: Sealed + Copy {
+    /// Gets a value of this type
+    const MAKE: Self;
+}
+
+/// Gets the type that `K` maps to in `Self`.
+///
+/// This is only implemented if `K` is a key of the map.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::maps::Get;
+/// use tstr::TS;
+///
+/// type Map = tstr::TMap!(foo => u8, bar => &'static str);
+///
+/// let _: <Map as Get<TS!(foo)>>::Output = 3u8;
+/// let _: <Map as Get<TS!(bar)>>::Output = "hello";
+///
+/// ```
+pub trait Get<K> {
+    /// The type that `K` maps to.
+    type Output;
+}
+
+impl<L, K, V> Get<K> for TMap<L>
+where
+    L: ListGet<K, Output = Found<V>>,
+{
+    type Output = V;
+}
+
+/// Inserts the `K` key with the `V` value into `Self`.
+///
+/// If `K` is already in the map, its value is replaced with `V`,
+/// otherwise the entry is added at the end of the map.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::maps::{Insert, MakeTMap};
+/// use tstr::TS;
+///
+/// type Map = tstr::TMap!(foo => u8);
+///
+/// let _: tstr::TMap!(foo => u8, bar => u16) = <<Map as Insert<TS!(bar), u16>>::Output>::MAKE;
+/// let _: tstr::TMap!(foo => u32) = <<Map as Insert<TS!(foo), u32>>::Output>::MAKE;
+///
+/// ```
+pub trait Insert<K, V> {
+    /// The map with the `K` key mapping to `V`.
+    type Output: MakeTMap;
+}
+
+impl<L, K, V> Insert<K, V> for TMap<L>
+where
+    L: ListInsert<K, V>,
+{
+    type Output = TMap<L::Output>;
+}
+
+/// Removes the `K` key from `Self`, doing nothing if `K` isn't in the map.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::maps::{Remove, MakeTMap};
+/// use tstr::TS;
+///
+/// type Map = tstr::TMap!(foo => u8, bar => u16);
+///
+/// let _: tstr::TMap!(bar => u16) = <<Map as Remove<TS!(foo)>>::Output>::MAKE;
+/// let _: tstr::TMap!(foo => u8, bar => u16) = <<Map as Remove<TS!(baz)>>::Output>::MAKE;
+///
+/// ```
+pub trait Remove<K> {
+    /// The map without the `K` key.
+    type Output: MakeTMap;
+}
+
+impl<L, K> Remove<K> for TMap<L>
+where
+    L: ListRemove<K>,
+{
+    type Output = TMap<L::Output>;
+}
+
+impl<K> ListGet<K> for TNil {
+    type Output = NotFound;
+}
+
+impl<HK, HV, T, K> ListGet<K> for TList<(HK, HV), T>
+where
+    HK: TStrEqType<K>,
+    T: ListGet<K>,
+    HK::Output: IfElse<Found<HV>, T::Output>,
+{
+    type Output = <HK::Output as IfElse<Found<HV>, T::Output>>::Output;
+}
+
+impl<K, V> ListInsert<K, V> for TNil {
+    type Output = TList<(K, V), TNil>;
+}
+
+impl<HK, HV, T, K, V> ListInsert<K, V> for TList<(HK, HV), T>
+where
+    HK: TStrEqType<K>,
+    T: ListInsert<K, V>,
+    HK::Output: IfElse<TList<(K, V), T>, TList<(HK, HV), T::Output>>,
+{
+    type Output = <HK::Output as IfElse<TList<(K, V), T>, TList<(HK, HV), T::Output>>>::Output;
+}
+
+impl<K> ListRemove<K> for TNil {
+    type Output = TNil;
+}
+
+impl<HK, HV, T, K> ListRemove<K> for TList<(HK, HV), T>
+where
+    HK: TStrEqType<K>,
+    T: ListRemove<K>,
+    HK::Output: IfElse<T::Output, TList<(HK, HV), T::Output>>,
+{
+    type Output = <HK::Output as IfElse<T::Output, TList<(HK, HV), T::Output>>>::Output;
+}
+
+impl<L> Sealed for TMap<L> {}
+
+impl<L> MakeTMap for TMap<L> {
+    const MAKE: Self = Self::NEW;
+}
+
+impl<L> Copy for TMap<L> {}
+
+impl<L> Clone for TMap<L> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L> Default for TMap<L> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::NEW
+    }
+}
+
+impl<L> Debug for TMap<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TMap").finish()
+    }
+}
@@ -0,0 +1,342 @@
+/// Queries whether a [`TStr`] follows common naming conventions.
+///
+/// This is implemented for all [`TStr`]s.
+///
+/// For requiring the string to follow a naming convention,
+/// you can use the [`AssertNamingConvention`] trait,
+/// or the [`assert_snake_case`], [`assert_kebab_case`], and [`assert_is_identifier`] macros.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{NamingConvention, TS};
+///
+/// assert!(<TS!(user_id)>::IS_SNAKE_CASE);
+/// assert!(<TS!(_id)>::IS_SNAKE_CASE);
+/// assert!(!<TS!(userId)>::IS_SNAKE_CASE);
+/// assert!(!<TS!("user-id")>::IS_SNAKE_CASE);
+///
+/// assert!(<TS!("user-id")>::IS_KEBAB_CASE);
+/// assert!(<TS!("2fa-code")>::IS_KEBAB_CASE);
+/// assert!(!<TS!("-user-id")>::IS_KEBAB_CASE);
+/// assert!(!<TS!(user_id)>::IS_KEBAB_CASE);
+///
+/// assert!(<TS!(userId)>::IS_IDENTIFIER);
+/// assert!(<TS!(_0)>::IS_IDENTIFIER);
+/// assert!(!<TS!("0a")>::IS_IDENTIFIER);
+/// assert!(!<TS!("_")>::IS_IDENTIFIER);
+/// assert!(!<TS!("")>::IS_IDENTIFIER);
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`AssertNamingConvention`]: ./trait.AssertNamingConvention.html
+/// [`assert_snake_case`]: ./macro.assert_snake_case.html
+/// [`assert_kebab_case`]: ./macro.assert_kebab_case.html
+/// [`assert_is_identifier`]: ./macro.assert_is_identifier.html
+pub trait NamingConvention {
+    /// Whether the string is in `snake_case`:
+    /// it's not empty, it only contains ASCII lowercase letters, digits, and `_`,
+    /// and it doesn't start with a digit.
+    const IS_SNAKE_CASE: bool;
+
+    /// Whether the string is in `kebab-case`:
+    /// it's not empty, it only contains ASCII lowercase letters, digits, and `-`,
+    /// and it doesn't start nor end with `-`.
+    const IS_KEBAB_CASE: bool;
+
+    /// Whether the string is an ASCII identifier:
+    /// it's not empty, it only contains ASCII letters, digits, and `_`,
+    /// it doesn't start with a digit, and it isn't `_`.
+    ///
+    /// Keywords are considered identifiers, since they can be used as raw identifiers.
+    const IS_IDENTIFIER: bool;
+}
+
+/// For asserting that a [`TStr`] follows common naming conventions.
+///
+/// This is blanket implemented for all types that implement [`NamingConvention`],
+/// and each assertion happens when its constant is used,
+/// which allows library authors to enforce the style of keys passed by users.
+///
+/// For asserting the naming convention of concrete strings,
+/// the [`assert_snake_case`], [`assert_kebab_case`], and [`assert_is_identifier`] macros
+/// are more convenient.
+///
+/// # Warning
+///
+/// Like the assertions in [`Assert`],
+/// the assertion might not be evaluated in functions that
+/// aren't reachable by public functions.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts, AssertNamingConvention};
+///
+/// let mut metrics = Metrics(Vec::new());
+/// metrics.record(ts!(request_count), 3);
+/// metrics.record(ts!(p99_latency_ms), 250);
+///
+/// struct Metrics(Vec<u64>);
+///
+/// impl Metrics {
+///     // metric names are required to be in snake_case
+///     fn record<K>(&mut self, _name: K, value: u64)
+///     where
+///         K: AssertNamingConvention,
+///     {
+///         let () = K::ASSERT_SNAKE_CASE;
+///         self.0.push(value);
+///     }
+/// }
+/// ```
+///
+/// Using a string that isn't in the naming convention causes a compile-time error:
+///
+/// ```compile_fail
+/// use tstr::{AssertNamingConvention, TS};
+///
+/// const _: () = <TS!(requestCount)>::ASSERT_SNAKE_CASE;
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`NamingConvention`]: ./trait.NamingConvention.html
+/// [`Assert`]: ./struct.Assert.html
+/// [`assert_snake_case`]: ./macro.assert_snake_case.html
+/// [`assert_kebab_case`]: ./macro.assert_kebab_case.html
+/// [`assert_is_identifier`]: ./macro.assert_is_identifier.html
+pub trait AssertNamingConvention: NamingConvention {
+    /// Asserts that `Self` is in `snake_case`, causing a compile-time error if it isn't.
+    ///
+    /// For the definition of `snake_case`, look at [`NamingConvention::IS_SNAKE_CASE`].
+    ///
+    /// [`NamingConvention::IS_SNAKE_CASE`]:
+    /// ./trait.NamingConvention.html#associatedconstant.IS_SNAKE_CASE
+    const ASSERT_SNAKE_CASE: ();
+
+    /// Asserts that `Self` is in `kebab-case`, causing a compile-time error if it isn't.
+    ///
+    /// For the definition of `kebab-case`, look at [`NamingConvention::IS_KEBAB_CASE`].
+    ///
+    /// [`NamingConvention::IS_KEBAB_CASE`]:
+    /// ./trait.NamingConvention.html#associatedconstant.IS_KEBAB_CASE
+    const ASSERT_KEBAB_CASE: ();
+
+    /// Asserts that `Self` is an identifier, causing a compile-time error if it isn't.
+    ///
+    /// For the definition of identifiers, look at [`NamingConvention::IS_IDENTIFIER`].
+    ///
+    /// [`NamingConvention::IS_IDENTIFIER`]:
+    /// ./trait.NamingConvention.html#associatedconstant.IS_IDENTIFIER
+    const ASSERT_IDENTIFIER: ();
+}
+
+impl<T> AssertNamingConvention for T
+where
+    T: ?Sized + NamingConvention,
+{
+    const ASSERT_SNAKE_CASE: () = {
+        #[allow(clippy::no_effect)]
+        ["Expected the string to be in snake_case"][!T::IS_SNAKE_CASE as usize];
+    };
+
+    const ASSERT_KEBAB_CASE: () = {
+        #[allow(clippy::no_effect)]
+        ["Expected the string to be in kebab-case"][!T::IS_KEBAB_CASE as usize];
+    };
+
+    const ASSERT_IDENTIFIER: () = {
+        #[allow(clippy::no_effect)]
+        ["Expected the string to be an identifier"][!T::IS_IDENTIFIER as usize];
+    };
+}
+
+pub(crate) mod sealed {
+    /// The kinds of characters in `Self` (a representation of a `TStr`),
+    /// as bit flags of the constants in this module.
+    #[doc(hidden)]
+    pub trait CharClasses {
+        /// The kinds of all the characters.
+        const ALL: u8;
+        /// The kind of the first character, `0` if there are no characters.
+        const FIRST: u8;
+        /// The kind of the last character, `0` if there are no characters.
+        const LAST: u8;
+        /// The amount of characters.
+        const LEN: usize;
+    }
+
+    pub const LOWER: u8 = 1;
+    pub const UPPER: u8 = 2;
+    pub const DIGIT: u8 = 4;
+    pub const UNDERSCORE: u8 = 8;
+    pub const DASH: u8 = 16;
+    pub const OTHER: u8 = 32;
+
+    /// The kind of a byte, `OTHER` for the bytes of non-ASCII characters.
+    pub const fn byte_class(b: u8) -> u8 {
+        let lower = (b >= b'a') & (b <= b'z');
+        let upper = (b >= b'A') & (b <= b'Z');
+        let digit = (b >= b'0') & (b <= b'9');
+        let underscore = b == b'_';
+        let dash = b == b'-';
+        let other = !(lower | upper | digit | underscore | dash);
+
+        (lower as u8 * LOWER)
+            | (upper as u8 * UPPER)
+            | (digit as u8 * DIGIT)
+            | (underscore as u8 * UNDERSCORE)
+            | (dash as u8 * DASH)
+            | (other as u8 * OTHER)
+    }
+
+    /// `first` if it's not `0`, otherwise `rest`.
+    #[cfg(any(not(feature = "const_generics"), feature = "dual_repr"))]
+    pub const fn first_of(first: u8, rest: u8) -> u8 {
+        first | (rest * (first == 0) as u8)
+    }
+
+    /// `last` if it's not `0`, otherwise `init`.
+    #[cfg(any(not(feature = "const_generics"), feature = "dual_repr"))]
+    pub const fn last_of(init: u8, last: u8) -> u8 {
+        last | (init * (last == 0) as u8)
+    }
+}
+use sealed::{CharClasses, DASH, DIGIT, LOWER, UNDERSCORE, UPPER};
+
+impl<T> NamingConvention for crate::TStr<T>
+where
+    T: CharClasses,
+{
+    const IS_SNAKE_CASE: bool =
+        (T::ALL & !(LOWER | DIGIT | UNDERSCORE) == 0) & (T::FIRST != 0) & (T::FIRST != DIGIT);
+
+    const IS_KEBAB_CASE: bool = (T::ALL & !(LOWER | DIGIT | DASH) == 0)
+        & (T::FIRST != 0)
+        & (T::FIRST != DASH)
+        & (T::LAST != DASH);
+
+    const IS_IDENTIFIER: bool = (T::ALL & !(LOWER | UPPER | DIGIT | UNDERSCORE) == 0)
+        & (T::FIRST != 0)
+        & (T::FIRST != DIGIT)
+        & !((T::ALL == UNDERSCORE) & (T::LEN == 1));
+}
+
+#[cfg(any(not(feature = "const_generics"), feature = "dual_repr"))]
+macro_rules! first_of {
+    () => { 0 };
+    ($first:ident $($rest:ident)*) => {
+        sealed::first_of($first::FIRST, first_of!($($rest)*))
+    };
+}
+
+#[cfg(any(not(feature = "const_generics"), feature = "dual_repr"))]
+macro_rules! last_of {
+    () => { 0 };
+    ($first:ident $($rest:ident)*) => {
+        sealed::last_of($first::LAST, last_of!($($rest)*))
+    };
+}
+
+#[cfg(any(not(feature = "const_generics"), feature = "dual_repr"))]
+macro_rules! tuple_impl {
+    ($($ty:ident)*) => (
+        #[doc(hidden)]
+        impl<$($ty,)*> CharClasses for ($($ty,)*)
+        where
+            $($ty: CharClasses,)*
+        {
+            const ALL: u8 = 0 $( | $ty::ALL )*;
+            const FIRST: u8 = first_of!($($ty)*);
+            const LAST: u8 = last_of!($($ty)*);
+            const LEN: usize = 0 $( + $ty::LEN )*;
+        }
+    )
+}
+
+#[cfg(any(not(feature = "const_generics"), feature = "dual_repr"))]
+mod tuple_impls {
+    use super::sealed::{self, CharClasses};
+
+    tuple_impl! {}
+    tuple_impl! {A }
+    tuple_impl! {A B}
+    tuple_impl! {A B C}
+    tuple_impl! {A B C D}
+    tuple_impl! {A B C D E}
+    tuple_impl! {A B C D E F}
+    tuple_impl! {A B C D E F G}
+    tuple_impl! {A B C D E F G H}
+}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+const fn char_class(c: char) -> u8 {
+    if c.is_ascii() {
+        sealed::byte_class(c as u8)
+    } else {
+        sealed::OTHER
+    }
+}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+macro_rules! impl_for_min_const {
+    ($( ($chars_structs:ident, [$first:ident $(,$chars:ident)*], $len:expr) ,)*) => {
+        $(
+            impl<const $first: char, $(const $chars: char,)*> CharClasses
+                for crate::$chars_structs<$first, $($chars,)*>
+            {
+                const ALL: u8 = char_class($first) $( | char_class($chars) )*;
+                const FIRST: u8 = char_class($first);
+                const LAST: u8 = char_class([$first, $($chars,)*][$len - 1]);
+                const LEN: usize = $len;
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+impl_for_min_const! {
+    (__a, [A], 1),
+    (__b, [A,B], 2),
+    (__c, [A,B,C], 3),
+    (__d, [A,B,C,D], 4),
+    (__e, [A,B,C,D,E], 5),
+    (__f, [A,B,C,D,E,F], 6),
+    (__g, [A,B,C,D,E,F,G], 7),
+    (__ , [A,B,C,D,E,F,G,H], 8),
+}
+
+#[cfg(feature = "const_generics")]
+macro_rules! impl_for_const {
+    () => {
+        const fn str_classes(s: &str) -> u8 {
+            let s = s.as_bytes();
+            let mut classes = 0;
+            let mut i = 0;
+            while i != s.len() {
+                classes |= sealed::byte_class(s[i]);
+                i += 1;
+            }
+            classes
+        }
+
+        const fn edge_class(s: &str, index: usize) -> u8 {
+            if s.is_empty() {
+                0
+            } else {
+                sealed::byte_class(s.as_bytes()[index])
+            }
+        }
+
+        impl<const S: &'static str> CharClasses for crate::___<S> {
+            const ALL: u8 = str_classes(S);
+            const FIRST: u8 = edge_class(S, 0);
+            const LAST: u8 = edge_class(S, S.len().wrapping_sub(1));
+            const LEN: usize = S.len();
+        }
+    };
+}
+
+#[cfg(feature = "const_generics")]
+impl_for_const! {}
@@ -0,0 +1,150 @@
+use core::ops::{Index, IndexMut};
+
+use crate::TStr;
+
+/// For indexing through nested values, with a path of type-level strings.
+///
+/// `Path` can be:
+///
+/// - A [`TStr`]: indexes `Self` with it.
+///
+/// - A tuple of `'static` paths (up to 8 elements), like the `TS!(foo.bar.baz)` type:
+/// indexes `Self` with the first path, then indexes the result with the rest of the paths.
+///
+/// - `()`: returns `self`.
+///
+/// # Example
+///
+/// This uses types from the `for_examples` module,
+/// which can be seen in the docs with the "for_examples" feature.
+///
+#[cfg_attr(feature = "for_examples", doc = "```rust")]
+#[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
+/// use tstr::for_examples::{Inner, Outer};
+/// use tstr::{NestedIndex, NestedIndexMut, TS, ts};
+///
+/// let mut outer = Outer::new(Inner::new(3, false), "hello");
+///
+/// assert_eq!(*outer.nested_index(ts!(inner.leaf)), 3);
+/// assert_eq!(*outer.nested_index(ts!(name)), "hello");
+///
+/// *outer.nested_index_mut(ts!(inner.leaf)) = 5;
+/// assert_eq!(get_copy(&outer, ts!(inner.leaf)), 5);
+///
+/// fn get_copy<T, P>(this: &T, path: P) -> T::Output
+/// where
+///     T: NestedIndex<P>,
+///     T::Output: Copy,
+/// {
+///     *this.nested_index(path)
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+pub trait NestedIndex<Path> {
+    /// The type of the value at the end of `Path`.
+    type Output: ?Sized;
+
+    /// Gets a reference to the value at the end of `path`.
+    fn nested_index(&self, path: Path) -> &Self::Output;
+}
+
+/// For mutably indexing through nested values, with a path of type-level strings.
+///
+/// For more details, and examples, you can look at [`NestedIndex`].
+///
+/// [`NestedIndex`]: ./trait.NestedIndex.html
+pub trait NestedIndexMut<Path>: NestedIndex<Path> {
+    /// Gets a mutable reference to the value at the end of `path`.
+    fn nested_index_mut(&mut self, path: Path) -> &mut Self::Output;
+}
+
+impl<This, T> NestedIndex<TStr<T>> for This
+where
+    This: ?Sized + Index<TStr<T>>,
+{
+    type Output = This::Output;
+
+    #[inline(always)]
+    fn nested_index(&self, path: TStr<T>) -> &Self::Output {
+        &self[path]
+    }
+}
+
+impl<This, T> NestedIndexMut<TStr<T>> for This
+where
+    This: ?Sized + IndexMut<TStr<T>>,
+{
+    #[inline(always)]
+    fn nested_index_mut(&mut self, path: TStr<T>) -> &mut Self::Output {
+        &mut self[path]
+    }
+}
+
+impl<This> NestedIndex<()> for This
+where
+    This: ?Sized,
+{
+    type Output = This;
+
+    #[inline(always)]
+    fn nested_index(&self, _path: ()) -> &Self::Output {
+        self
+    }
+}
+
+impl<This> NestedIndexMut<()> for This
+where
+    This: ?Sized,
+{
+    #[inline(always)]
+    fn nested_index_mut(&mut self, _path: ()) -> &mut Self::Output {
+        self
+    }
+}
+
+macro_rules! tuple_impl {
+    ($first:ident $($rem:ident)*) => (
+        impl<This, $first, $($rem),*> NestedIndex<($first, $($rem,)*)> for This
+        where
+            This: ?Sized + NestedIndex<$first>,
+            This::Output: NestedIndex<($($rem,)*)>,
+            $first: 'static,
+        {
+            type Output = <This::Output as NestedIndex<($($rem,)*)>>::Output;
+
+            #[inline(always)]
+            #[allow(non_snake_case)]
+            fn nested_index(&self, ($first, $($rem,)*): ($first, $($rem,)*)) -> &Self::Output {
+                let this = NestedIndex::<$first>::nested_index(self, $first);
+                NestedIndex::<($($rem,)*)>::nested_index(this, ($($rem,)*))
+            }
+        }
+
+        impl<This, $first, $($rem),*> NestedIndexMut<($first, $($rem,)*)> for This
+        where
+            This: ?Sized + NestedIndexMut<$first>,
+            This::Output: NestedIndexMut<($($rem,)*)>,
+            $first: 'static,
+        {
+            #[inline(always)]
+            #[allow(non_snake_case)]
+            fn nested_index_mut(
+                &mut self,
+                ($first, $($rem,)*): ($first, $($rem,)*),
+            ) -> &mut Self::Output {
+                let this = NestedIndexMut::<$first>::nested_index_mut(self, $first);
+                NestedIndexMut::<($($rem,)*)>::nested_index_mut(this, ($($rem,)*))
+            }
+        }
+    )
+}
+tuple_impl! {A}
+tuple_impl! {A B}
+tuple_impl! {A B C}
+tuple_impl! {A B C D}
+tuple_impl! {A B C D E}
+tuple_impl! {A B C D E F}
+tuple_impl! {A B C D E F G}
+tuple_impl! {A B C D E F G H}
@@ -0,0 +1,253 @@
+//! Type-level operators which build [`TStr`]s from the characters of other [`TStr`]s.
+//!
+//! This allows generic code to derive families of keys from a single key
+//! (eg: the `_internal_foo` key from the `foo` key),
+//! without requiring users to write every derived key.
+//!
+//! The operators recurse over the characters of the strings with [`ToCharList`],
+//! except with the `"nightly_generic_const_exprs"` feature,
+//! where they compute the string with const fns instead,
+//! and it's a compile-time error for the built string to be longer than 1024 bytes.
+//! This module is not available with the `"const_generics"` feature on its own.
+//!
+//! The [`SplitAt`] and [`Slice`] operators, which take `typenum` unsigned integers
+//! as positions in the string, also require the `"typenum"` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tstr::ops::{PushFront, Prepended};
+//! use tstr::{ts, MakeTStr, TS};
+//!
+//! let _: (TS!(len), TS!(_internal_len)) = with_internal(ts!(len));
+//!
+//! // Pairs a public key with the key of its internal counterpart
+//! fn with_internal<K>(key: K) -> (K, Prepended<K, TS!(_internal_)>)
+//! where
+//!     K: PushFront<TS!(_internal_)>,
+//!     Prepended<K, TS!(_internal_)>: MakeTStr,
+//! {
+//!     (key, MakeTStr::MAKE)
+//! }
+//!
+//! ```
+//!
+//! [`TStr`]: ../struct.TStr.html
+//! [`ToCharList`]: ../tlist/trait.ToCharList.html
+//! [`SplitAt`]: ./trait.SplitAt.html
+//! [`Slice`]: ./trait.Slice.html
+
+use crate::TStr;
+
+#[cfg(not(feature = "const_generics"))]
+mod impl_no_const_generics;
+
+#[cfg(feature = "nightly_generic_const_exprs")]
+mod impl_nightly_const_generics;
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// Type-level operator which prepends the `S` [`TStr`] to a [`TStr`].
+///
+/// `S` can be a single character (eg: `TS!(_)`), or a longer string (eg: `TS!(get_)`).
+///
+/// This trait is sealed, it's only implemented for [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::ops::{Prepended, PushFront};
+/// use tstr::TS;
+///
+/// let _: TS!(_foo) = <Prepended<TS!(foo), TS!(_)>>::NEW;
+/// let _: TS!(get_foo) = <TS!(foo) as PushFront<TS!(get_)>>::Output::NEW;
+/// let _: TS!("$1") = <Prepended<TS!(1), TS!("$")>>::NEW;
+/// let _: TS!(foo) = <Prepended<TS!(foo), TS!("")>>::NEW;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+pub trait PushFront<S>: Sealed {
+    /// The string with `S` at the start.
+    type Output;
+}
+
+/// The `T` [`TStr`] with the `S` [`TStr`] prepended.
+///
+/// For examples, look at the docs for [`PushFront`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`PushFront`]: ./trait.PushFront.html
+pub type Prepended<T, S> = <T as PushFront<S>>::Output;
+
+/// Type-level operator which appends the `S` [`TStr`] to a [`TStr`].
+///
+/// `S` can be a single character (eg: `TS!(_)`), or a longer string (eg: `TS!(_mut)`).
+///
+/// This trait is sealed, it's only implemented for [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::ops::{Appended, PushBack};
+/// use tstr::TS;
+///
+/// let _: TS!(foo_) = <Appended<TS!(foo), TS!(_)>>::NEW;
+/// let _: TS!(foo_mut) = <TS!(foo) as PushBack<TS!(_mut)>>::Output::NEW;
+/// let _: TS!("size?") = <Appended<TS!(size), TS!("?")>>::NEW;
+/// let _: TS!(foo) = <Appended<TS!(foo), TS!("")>>::NEW;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+pub trait PushBack<S>: Sealed {
+    /// The string with `S` at the end.
+    type Output;
+}
+
+/// The `T` [`TStr`] with the `S` [`TStr`] appended.
+///
+/// For examples, look at the docs for [`PushBack`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`PushBack`]: ./trait.PushBack.html
+pub type Appended<T, S> = <T as PushBack<S>>::Output;
+
+impl<T> Sealed for TStr<T> {}
+
+/// Type-level operator which splits a [`TStr`] into the first `N` characters
+/// and the rest of the string, where `N` is a `typenum` unsigned integer.
+///
+/// With the default representation, `N` counts the UTF-8 bytes of the string,
+/// and it's a compile-time error to split inside of a non-ASCII character.
+/// With the `"min_const_generics"` feature, `N` counts the `char`s of the string,
+/// except with the `"nightly_generic_const_exprs"` feature, where it counts the UTF-8 bytes.
+///
+/// It's a compile-time error for `N` to be larger than the length of the string.
+///
+/// This trait is sealed, it's only implemented for [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::ops::{Split, SplitAt};
+/// use tstr::{ts, TS};
+///
+/// use typenum::{U0, U3, U6};
+///
+/// let _: Split<TS!(foobar), U3> = ts!(foo, bar);
+/// let _: <TS!(foobar) as SplitAt<U0>>::Output = ts!("", foobar);
+/// let _: Split<TS!(foobar), U6> = ts!(foobar, "");
+///
+/// ```
+///
+/// Splitting at a position past the end of the string:
+///
+/// ```compile_fail
+/// use tstr::ops::Split;
+/// use tstr::{ts, TS};
+///
+/// let _: Split<TS!(foo), typenum::U4> = ts!(foo, "");
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+#[cfg(feature = "typenum")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "typenum")))]
+pub trait SplitAt<N>: Sealed {
+    /// A pair of the first `N` characters and the rest of the string.
+    type Output;
+}
+
+/// The pair of the first `N` characters of the `T` [`TStr`], and the rest of it.
+///
+/// For examples, look at the docs for [`SplitAt`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`SplitAt`]: ./trait.SplitAt.html
+#[cfg(feature = "typenum")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "typenum")))]
+pub type Split<T, N> = <T as SplitAt<N>>::Output;
+
+/// Type-level operator which gets the characters of a [`TStr`] from
+/// `Start` up to (but excluding) `End`, where both are `typenum` unsigned integers.
+///
+/// The positions count characters the same way as [`SplitAt`],
+/// and it's a compile-time error for `Start` to be larger than `End`,
+/// or for `End` to be larger than the length of the string.
+///
+/// This trait is sealed, it's only implemented for [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::ops::{Slice, Sliced};
+/// use tstr::TS;
+///
+/// use typenum::{U0, U12, U2, U3, U6, U7};
+///
+/// // Getting the segments of a namespaced key
+/// let _: TS!(module) = <Sliced<TS!("module.field"), U0, U6>>::NEW;
+/// let _: TS!(field) = <Sliced<TS!("module.field"), U7, U12>>::NEW;
+///
+/// let _: TS!(o) = <TS!(foo) as Slice<U2, U3>>::Output::NEW;
+/// let _: TS!("") = <Sliced<TS!(foo), U3, U3>>::NEW;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`SplitAt`]: ./trait.SplitAt.html
+#[cfg(feature = "typenum")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "typenum")))]
+pub trait Slice<Start, End>: Sealed {
+    /// The characters from `Start` up to `End`.
+    type Output;
+}
+
+/// The characters of the `T` [`TStr`] from `Start` up to (but excluding) `End`.
+///
+/// For examples, look at the docs for [`Slice`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`Slice`]: ./trait.Slice.html
+#[cfg(feature = "typenum")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "typenum")))]
+pub type Sliced<T, Start, End> = <T as Slice<Start, End>>::Output;
+
+/// Type-level operator which reverses the characters of a [`TStr`].
+///
+/// With the default representation,
+/// the UTF-8 bytes of each non-ASCII character are kept in the same order,
+/// so that the reversed string is still valid UTF-8.
+///
+/// This trait is sealed, it's only implemented for [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::ops::{Reverse, Reversed};
+/// use tstr::TS;
+///
+/// let _: TS!(olleh) = <Reversed<TS!(hello)>>::NEW;
+/// let _: TS!("dl.eludom") = <TS!("module.ld") as Reverse>::Output::NEW;
+/// let _: TS!("údnañ") = <Reversed<TS!("ñandú")>>::NEW;
+/// let _: TS!("") = <Reversed<TS!("")>>::NEW;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+pub trait Reverse: Sealed {
+    /// The reversed string.
+    type Output;
+}
+
+/// The `T` [`TStr`] with its characters reversed.
+///
+/// For examples, look at the docs for [`Reverse`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`Reverse`]: ./trait.Reverse.html
+pub type Reversed<T> = <T as Reverse>::Output;
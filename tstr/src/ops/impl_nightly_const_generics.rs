@@ -0,0 +1,105 @@
+use crate::{
+    for_nightly_const_generics::{assert_fits, buffer_str, Buffer, MAX_LEN},
+    utils::concat_into,
+    TStr, ___,
+};
+
+#[cfg(feature = "typenum")]
+use crate::for_nightly_const_generics::str_slice;
+
+#[cfg(feature = "typenum")]
+use typenum::Unsigned;
+
+use super::{PushBack, PushFront, Reverse};
+
+#[cfg(feature = "typenum")]
+use super::{Slice, SplitAt};
+
+pub struct Concat<const L: &'static str, const R: &'static str>;
+
+impl<const L: &'static str, const R: &'static str> Concat<L, R> {
+    const BUFFER: &'static Buffer = &concat(L, R);
+    pub const STR: &'static str = buffer_str(Self::BUFFER);
+}
+
+const fn concat(left: &str, right: &str) -> Buffer {
+    assert_fits(left.len() + right.len());
+    concat_into::<MAX_LEN>(&[left, right])
+}
+
+pub struct Rev<const S: &'static str>;
+
+impl<const S: &'static str> Rev<S> {
+    const BUFFER: &'static Buffer = &reverse_into(S);
+    pub const STR: &'static str = buffer_str(Self::BUFFER);
+}
+
+// Reverses the chars of `string`, keeping the UTF-8 bytes of each char in the same order.
+const fn reverse_into(string: &str) -> Buffer {
+    let bytes = string.as_bytes();
+    assert_fits(bytes.len());
+    let mut buffer = [0u8; MAX_LEN];
+    let mut len = 0;
+
+    let mut end = bytes.len();
+    while end != 0 {
+        let mut start = end - 1;
+        while bytes[start] & 0b1100_0000 == 0b1000_0000 {
+            start -= 1;
+        }
+
+        let mut i = start;
+        while i != end {
+            buffer[len] = bytes[i];
+            len += 1;
+            i += 1;
+        }
+        end = start;
+    }
+
+    (buffer, len)
+}
+
+impl<const S: &'static str, const Z: &'static str> PushFront<TStr<___<Z>>> for TStr<___<S>>
+where
+    ___<{ Concat::<Z, S>::STR }>: Sized,
+{
+    type Output = TStr<___<{ Concat::<Z, S>::STR }>>;
+}
+
+impl<const S: &'static str, const Z: &'static str> PushBack<TStr<___<Z>>> for TStr<___<S>>
+where
+    ___<{ Concat::<S, Z>::STR }>: Sized,
+{
+    type Output = TStr<___<{ Concat::<S, Z>::STR }>>;
+}
+
+#[cfg(feature = "typenum")]
+impl<const S: &'static str, N> SplitAt<N> for TStr<___<S>>
+where
+    N: Unsigned,
+    ___<{ str_slice(S, 0, N::USIZE) }>: Sized,
+    ___<{ str_slice(S, N::USIZE, S.len()) }>: Sized,
+{
+    type Output = (
+        TStr<___<{ str_slice(S, 0, N::USIZE) }>>,
+        TStr<___<{ str_slice(S, N::USIZE, S.len()) }>>,
+    );
+}
+
+#[cfg(feature = "typenum")]
+impl<const S: &'static str, Start, End> Slice<Start, End> for TStr<___<S>>
+where
+    Start: Unsigned,
+    End: Unsigned,
+    ___<{ str_slice(S, Start::USIZE, End::USIZE) }>: Sized,
+{
+    type Output = TStr<___<{ str_slice(S, Start::USIZE, End::USIZE) }>>;
+}
+
+impl<const S: &'static str> Reverse for TStr<___<S>>
+where
+    ___<{ Rev::<S>::STR }>: Sized,
+{
+    type Output = TStr<___<{ Rev::<S>::STR }>>;
+}
@@ -0,0 +1,235 @@
+use crate::{
+    bools::{False, True},
+    tlist::{FromCharList, TList, TNil, ToCharList},
+    TStr,
+};
+
+#[cfg(feature = "typenum")]
+use typenum::{Sub1, UInt, UTerm, B1};
+
+#[cfg(feature = "typenum")]
+use core::ops::Sub;
+
+use super::{PushBack, PushFront, Reverse};
+
+#[cfg(feature = "typenum")]
+use super::{Slice, SplitAt};
+
+/// Appends the `Rest` list of characters to the `Self` list of characters.
+pub trait Append<Rest> {
+    type Output;
+}
+
+/// Splits the `Self` list of characters into the first `N` characters,
+/// and the rest of the characters.
+#[cfg(feature = "typenum")]
+pub trait SplitChars<N> {
+    type Left;
+    type Right;
+}
+
+/// Whether the type of a character is a UTF-8 continuation byte,
+/// which is never the case with the `"min_const_generics"` feature.
+pub trait CharByte {
+    type IsContinuation;
+}
+
+/// Reverses the `Self` list of characters, prepending them to `Acc`,
+/// where `Cur` is the list of the bytes of the current `char`.
+pub trait ReverseChars<Acc, Cur> {
+    type Output;
+}
+
+/// Implementation of `ReverseChars` for `TList<H, Tail>`,
+/// where `Self` is whether `H` is a UTF-8 continuation byte.
+pub trait ReverseCharIf<H, Tail, Acc, Cur> {
+    type Output;
+}
+
+impl<T, S> PushFront<S> for TStr<T>
+where
+    Self: ToCharList,
+    S: ToCharList,
+    S::Output: Append<<Self as ToCharList>::Output>,
+    <S::Output as Append<<Self as ToCharList>::Output>>::Output: FromCharList,
+{
+    type Output =
+        <<S::Output as Append<<Self as ToCharList>::Output>>::Output as FromCharList>::Output;
+}
+
+impl<T, S> PushBack<S> for TStr<T>
+where
+    Self: ToCharList,
+    S: ToCharList,
+    <Self as ToCharList>::Output: Append<S::Output>,
+    <<Self as ToCharList>::Output as Append<S::Output>>::Output: FromCharList,
+{
+    type Output =
+        <<<Self as ToCharList>::Output as Append<S::Output>>::Output as FromCharList>::Output;
+}
+
+#[cfg(feature = "typenum")]
+impl<T, N> SplitAt<N> for TStr<T>
+where
+    Self: ToCharList,
+    <Self as ToCharList>::Output: SplitChars<N>,
+    <<Self as ToCharList>::Output as SplitChars<N>>::Left: FromCharList,
+    <<Self as ToCharList>::Output as SplitChars<N>>::Right: FromCharList,
+{
+    type Output = (
+        <<<Self as ToCharList>::Output as SplitChars<N>>::Left as FromCharList>::Output,
+        <<<Self as ToCharList>::Output as SplitChars<N>>::Right as FromCharList>::Output,
+    );
+}
+
+#[cfg(feature = "typenum")]
+impl<T, Start, End> Slice<Start, End> for TStr<T>
+where
+    Self: ToCharList,
+    <Self as ToCharList>::Output: SplitChars<End>,
+    <<Self as ToCharList>::Output as SplitChars<End>>::Left: SplitChars<Start>,
+    <<<Self as ToCharList>::Output as SplitChars<End>>::Left as SplitChars<Start>>::Right:
+        FromCharList,
+{
+    type Output = <<<<Self as ToCharList>::Output as SplitChars<End>>::Left as SplitChars<
+        Start,
+    >>::Right as FromCharList>::Output;
+}
+
+impl<T> Reverse for TStr<T>
+where
+    Self: ToCharList,
+    <Self as ToCharList>::Output: ReverseChars<TNil, TNil>,
+    <<Self as ToCharList>::Output as ReverseChars<TNil, TNil>>::Output: FromCharList,
+{
+    type Output =
+        <<<Self as ToCharList>::Output as ReverseChars<TNil, TNil>>::Output as FromCharList>::Output;
+}
+
+impl<Rest> Append<Rest> for TNil {
+    type Output = Rest;
+}
+
+impl<H, Tail, Rest> Append<Rest> for TList<H, Tail>
+where
+    Tail: Append<Rest>,
+{
+    type Output = TList<H, Tail::Output>;
+}
+
+impl<Acc, Cur> ReverseChars<Acc, Cur> for TNil
+where
+    Cur: Append<Acc>,
+{
+    type Output = Cur::Output;
+}
+
+impl<H, Tail, Acc, Cur> ReverseChars<Acc, Cur> for TList<H, Tail>
+where
+    H: CharByte,
+    H::IsContinuation: ReverseCharIf<H, Tail, Acc, Cur>,
+{
+    type Output = <H::IsContinuation as ReverseCharIf<H, Tail, Acc, Cur>>::Output;
+}
+
+// `H` starts a `char`, so the bytes of the previous `char` are prepended to `Acc`
+impl<H, Tail, Acc, Cur> ReverseCharIf<H, Tail, Acc, Cur> for False
+where
+    Cur: Append<Acc>,
+    Tail: ReverseChars<Cur::Output, TList<H, TNil>>,
+{
+    type Output = Tail::Output;
+}
+
+// `H` continues the current `char`
+impl<H, Tail, Acc, Cur> ReverseCharIf<H, Tail, Acc, Cur> for True
+where
+    Cur: Append<TList<H, TNil>>,
+    Tail: ReverseChars<Acc, Cur::Output>,
+{
+    type Output = Tail::Output;
+}
+
+#[cfg(feature = "typenum")]
+impl SplitChars<UTerm> for TNil {
+    type Left = TNil;
+    type Right = TNil;
+}
+
+#[cfg(feature = "typenum")]
+impl<H, Tail> SplitChars<UTerm> for TList<H, Tail>
+where
+    H: CharByte<IsContinuation = False>,
+{
+    type Left = TNil;
+    type Right = Self;
+}
+
+#[cfg(feature = "typenum")]
+impl<H, Tail, U, B> SplitChars<UInt<U, B>> for TList<H, Tail>
+where
+    UInt<U, B>: Sub<B1>,
+    Tail: SplitChars<Sub1<UInt<U, B>>>,
+{
+    type Left = TList<H, Tail::Left>;
+    type Right = Tail::Right;
+}
+
+#[cfg(feature = "min_const_generics")]
+impl<const C: char> CharByte for crate::__a<C> {
+    type IsContinuation = False;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+macro_rules! char_byte_impls {
+    ($is_continuation:ident => $($byte:ident)*) => {
+        $(
+            impl CharByte for crate::$byte {
+                type IsContinuation = $is_continuation;
+            }
+        )*
+    };
+}
+
+// The bytes that aren't UTF-8 continuation bytes
+#[cfg(not(feature = "min_const_generics"))]
+char_byte_impls! {
+    False =>
+    __0x00 __0x01 __0x02 __0x03 __0x04 __0x05 __0x06 __0x07
+    __0x08 __0x09 __0x0A __0x0B __0x0C __0x0D __0x0E __0x0F
+    __0x10 __0x11 __0x12 __0x13 __0x14 __0x15 __0x16 __0x17
+    __0x18 __0x19 __0x1A __0x1B __0x1C __0x1D __0x1E __0x1F
+    __0x20 __0x21 __0x22 __0x23 __0x24 __0x25 __0x26 __0x27
+    __0x28 __0x29 __0x2A __0x2B __0x2C __0x2D __0x2E __0x2F
+    __0x30 __0x31 __0x32 __0x33 __0x34 __0x35 __0x36 __0x37
+    __0x38 __0x39 __0x3A __0x3B __0x3C __0x3D __0x3E __0x3F
+    __0x40 __0x41 __0x42 __0x43 __0x44 __0x45 __0x46 __0x47
+    __0x48 __0x49 __0x4A __0x4B __0x4C __0x4D __0x4E __0x4F
+    __0x50 __0x51 __0x52 __0x53 __0x54 __0x55 __0x56 __0x57
+    __0x58 __0x59 __0x5A __0x5B __0x5C __0x5D __0x5E __0x5F
+    __0x60 __0x61 __0x62 __0x63 __0x64 __0x65 __0x66 __0x67
+    __0x68 __0x69 __0x6A __0x6B __0x6C __0x6D __0x6E __0x6F
+    __0x70 __0x71 __0x72 __0x73 __0x74 __0x75 __0x76 __0x77
+    __0x78 __0x79 __0x7A __0x7B __0x7C __0x7D __0x7E __0x7F
+    __0xC0 __0xC1 __0xC2 __0xC3 __0xC4 __0xC5 __0xC6 __0xC7
+    __0xC8 __0xC9 __0xCA __0xCB __0xCC __0xCD __0xCE __0xCF
+    __0xD0 __0xD1 __0xD2 __0xD3 __0xD4 __0xD5 __0xD6 __0xD7
+    __0xD8 __0xD9 __0xDA __0xDB __0xDC __0xDD __0xDE __0xDF
+    __0xE0 __0xE1 __0xE2 __0xE3 __0xE4 __0xE5 __0xE6 __0xE7
+    __0xE8 __0xE9 __0xEA __0xEB __0xEC __0xED __0xEE __0xEF
+    __0xF0 __0xF1 __0xF2 __0xF3 __0xF4 __0xF5 __0xF6 __0xF7
+    __0xF8 __0xF9 __0xFA __0xFB __0xFC __0xFD __0xFE __0xFF
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+char_byte_impls! {
+    True =>
+    __0x80 __0x81 __0x82 __0x83 __0x84 __0x85 __0x86 __0x87
+    __0x88 __0x89 __0x8A __0x8B __0x8C __0x8D __0x8E __0x8F
+    __0x90 __0x91 __0x92 __0x93 __0x94 __0x95 __0x96 __0x97
+    __0x98 __0x99 __0x9A __0x9B __0x9C __0x9D __0x9E __0x9F
+    __0xA0 __0xA1 __0xA2 __0xA3 __0xA4 __0xA5 __0xA6 __0xA7
+    __0xA8 __0xA9 __0xAA __0xAB __0xAC __0xAD __0xAE __0xAF
+    __0xB0 __0xB1 __0xB2 __0xB3 __0xB4 __0xB5 __0xB6 __0xB7
+    __0xB8 __0xB9 __0xBA __0xBB __0xBC __0xBD __0xBE __0xBF
+}
@@ -42,7 +42,7 @@ macro_rules! declare_const_items {
         impl<const N: &'static str> Copy for ___<N> {}
         impl<const N: &'static str> Clone for ___<N> {
             fn clone(&self) -> Self {
-                ___
+                *self
             }
         }
     };
@@ -89,33 +89,85 @@ fn main() {
 
 */
 
-#[cfg(not(feature = "min_const_generics"))]
+#[cfg(any(not(feature = "min_const_generics"), feature = "dual_repr"))]
 macro_rules! create_unit_struct {
     ($( ($struct_:ident = $value:literal $(,$alias:ident)? ) ),* $(,)*) => {
         $(
-            #[doc(hidden)]
-            pub struct $struct_;
+            create_unit_struct!{@entry $struct_ = $value $(,$alias)?}
+        )*
+    };
+    (@entry $struct_:ident = $value:literal) => {
+        create_unit_struct!{@struct $struct_ = $value}
+    };
+    // With the `"min_const_generics"` feature, the `__a`/`__0`/etc names are
+    // used by the types of that representation, so only the `__0xNN` name is defined.
+    (@entry $struct_:ident = $value:literal, $alias:ident) => {
+        #[cfg(not(feature = "min_const_generics"))]
+        create_unit_struct!{@struct $struct_ = $value}
+
+        #[doc(hidden)]
+        #[cfg(not(feature = "min_const_generics"))]
+        pub type $alias=$struct_;
+
+        #[cfg(feature = "min_const_generics")]
+        create_unit_struct!{@struct $alias = $value}
+    };
+    (@struct $struct_:ident = $value:literal) => {
+        #[doc(hidden)]
+        pub struct $struct_;
+
+        impl crate::IsAscii for $struct_ {
+            const VALUE: bool = $value < 128;
+        }
+
+        impl crate::naming_conventions::sealed::CharClasses for $struct_ {
+            const ALL: u8 = crate::naming_conventions::sealed::byte_class($value);
+            const FIRST: u8 = Self::ALL;
+            const LAST: u8 = Self::ALL;
+            const LEN: usize = 1;
+        }
 
-            #[cfg(feature = "cmp_traits")]
-            impl crate::tstr_cmp::U8Repr for $struct_ {
-                const REPR: u8 = $value;
+        #[cfg(all(feature = "reprs", not(feature = "min_const_generics")))]
+        impl crate::reprs::ByteRepr for $struct_ {
+            const BYTE: u8 = $value;
+        }
+
+        #[cfg(all(feature = "dual_repr", feature = "const_generics"))]
+        impl crate::dual_repr::ByteValue for $struct_ {
+            const VALUE: u8 = $value;
+        }
+
+        #[cfg(feature = "serde")]
+        impl crate::serde_fields::StripPrefix for $struct_ {
+            #[inline]
+            fn strip_prefix(bytes: &[u8]) -> Option<&[u8]> {
+                crate::serde_fields::strip_bytes(bytes, &[$value])
             }
+        }
 
-            #[cfg(feature = "cmp_traits")]
-            impl crate::for_tupled_reprs::classify::Classify for $struct_{
-                type IsByte = crate::for_tupled_reprs::True;
-                type KindNumber = crate::for_tupled_reprs::classify::Chars1;
+        #[cfg(any(feature = "defmt", feature = "ufmt", feature = "alloc"))]
+        impl crate::fmt_impls::WriteRepr for $struct_ {
+            #[inline]
+            fn write_repr<Err_>(out: &mut crate::fmt_impls::StrWriter<'_, Err_>) -> Result<(), Err_> {
+                out.write_bytes(&[$value])
             }
+        }
 
-            $(
-                #[doc(hidden)]
-                pub type $alias=$struct_;
-            )?
-        )*
-    }
+        #[cfg(not(feature = "min_const_generics"))]
+        impl<Rest> crate::tlist::char_list::sealed::PushChars<Rest> for $struct_ {
+            type Output = crate::tlist::TList<$struct_, Rest>;
+        }
+
+        #[cfg(feature = "cmp_traits")]
+        #[cfg(not(feature = "min_const_generics"))]
+        impl crate::for_tupled_reprs::classify::Classify for $struct_{
+            type IsByte = crate::for_tupled_reprs::True;
+            type KindNumber = crate::for_tupled_reprs::classify::Chars1;
+        }
+    };
 }
 
-#[cfg(not(feature = "min_const_generics"))]
+#[cfg(any(not(feature = "min_const_generics"), feature = "dual_repr"))]
 create_unit_struct! {
     (__0x00 = 0),(__0x01 = 1),(__0x02 = 2),(__0x03 = 3),
     (__0x04 = 4),(__0x05 = 5),(__0x06 = 6),(__0x07 = 7),
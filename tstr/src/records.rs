@@ -0,0 +1,348 @@
+//! Anonymous records, with fields keyed by [`TStr`]s.
+//!
+//! This module is only available with the `"cmp_traits"` feature,
+//! and without the `"min_const_generics"` feature,
+//! since looking up fields requires comparing type-level strings,
+//! which is only implemented for the default representation of type-level strings.
+//!
+//! # Example
+//!
+//! This example demonstrates a function that takes named arguments,
+//! using a record to pass them.
+//!
+//! ```rust
+//! use std::ops::Index;
+//!
+//! use tstr::{record, ts, TS};
+//!
+//! assert_eq!(greet(&record!{name: "Bob", age: 21}), "Bob is 21 years old");
+//!
+//! // Records can have more fields than the function needs
+//! assert_eq!(
+//!     greet(&record!{age: 30, surname: "Marley", name: "Alice"}),
+//!     "Alice is 30 years old",
+//! );
+//!
+//! fn greet<R>(args: &R) -> String
+//! where
+//!     R: Index<TS!(name), Output = &'static str> + Index<TS!(age), Output = u32>,
+//! {
+//!     format!("{} is {} years old", args[ts!(name)], args[ts!(age)])
+//! }
+//!
+//! ```
+//!
+//! [`TStr`]: ../struct.TStr.html
+
+use core::{
+    mem,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    for_tupled_reprs::{type_cmp::TStrEqType, False, True},
+    Field,
+};
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait ListGetField<K> {
+        type Output;
+
+        fn get_field(&self) -> &Self::Output;
+
+        fn get_field_mut(&mut self) -> &mut Self::Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListGetFieldIf<K, IsKey> {
+        type Output;
+
+        fn get_field_if(&self) -> &Self::Output;
+
+        fn get_field_if_mut(&mut self) -> &mut Self::Output;
+    }
+}
+use sealed::{ListGetField, ListGetFieldIf};
+
+#[cfg(feature = "proc_macros")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "proc_macros")))]
+pub use tstr_proc_macros::IntoRecord;
+
+/// An anonymous record, with fields keyed by [`TStr`]s.
+///
+/// `L` is a list of [`Field`]s, nested like `(field0, (field1, (field2, ())))`.
+///
+/// The [`record`] macro is the most convenient way to construct a record,
+/// and the [`Record`](../macro.Record.html) macro is the most convenient way to write its type.
+///
+/// Every field of a record can be accessed by indexing it with the name of the field,
+/// as well as with the [`get`], [`get_mut`], and [`set`] methods.
+///
+/// If a name is used for multiple fields,
+/// only the first field with that name can be accessed.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::records::Record;
+/// use tstr::{record, ts, Field, TS};
+///
+/// let mut rec: tstr::Record!{name: &str, age: u32} = record!{name: "Bob", age: 21};
+///
+/// assert_eq!(rec[ts!(name)], "Bob");
+/// assert_eq!(*rec.get(ts!(age)), 21);
+///
+/// rec[ts!(age)] += 1;
+/// assert_eq!(rec[ts!(age)], 22);
+///
+/// assert_eq!(rec.set(ts!(name), "Alice"), "Bob");
+/// assert_eq!(rec[ts!(name)], "Alice");
+///
+/// // The Record macro is sugar for this type
+/// let _: Record<(Field<TS!(name), &str>, (Field<TS!(age), u32>, ()))> = rec;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`Field`]: ../struct.Field.html
+/// [`record`]: ../macro.record.html
+/// [`get`]: #method.get
+/// [`get_mut`]: #method.get_mut
+/// [`set`]: #method.set
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Record<L> {
+    fields: L,
+}
+
+impl<L> Record<L> {
+    /// Constructs a `Record` from a list of [`Field`]s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::records::Record;
+    /// use tstr::{ts, Field};
+    ///
+    /// let rec = Record::new((Field::new(ts!(x), 3), (Field::new(ts!(y), 5), ())));
+    ///
+    /// assert_eq!(rec[ts!(x)], 3);
+    /// assert_eq!(rec[ts!(y)], 5);
+    ///
+    /// ```
+    ///
+    /// [`Field`]: ../struct.Field.html
+    #[inline(always)]
+    pub const fn new(fields: L) -> Self {
+        Self { fields }
+    }
+
+    /// Gets a reference to the list of fields.
+    #[inline(always)]
+    pub fn fields(&self) -> &L {
+        &self.fields
+    }
+
+    /// Unwraps this into the list of fields.
+    #[inline(always)]
+    pub fn into_fields(self) -> L {
+        self.fields
+    }
+
+    /// Gets a reference to the field named `K`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::{record, ts};
+    ///
+    /// let rec = record!{foo: 3, bar: "hello"};
+    ///
+    /// assert_eq!(*rec.get(ts!(foo)), 3);
+    /// assert_eq!(*rec.get(ts!(bar)), "hello");
+    ///
+    /// ```
+    #[inline(always)]
+    pub fn get<K>(&self, key: K) -> &<Self as Index<K>>::Output
+    where
+        Self: Index<K>,
+    {
+        &self[key]
+    }
+
+    /// Gets a mutable reference to the field named `K`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::{record, ts};
+    ///
+    /// let mut rec = record!{foo: 3, bar: "hello"};
+    ///
+    /// *rec.get_mut(ts!(foo)) *= 2;
+    /// assert_eq!(rec[ts!(foo)], 6);
+    ///
+    /// ```
+    #[inline(always)]
+    pub fn get_mut<K>(&mut self, key: K) -> &mut <Self as Index<K>>::Output
+    where
+        Self: IndexMut<K>,
+    {
+        &mut self[key]
+    }
+
+    /// Replaces the value of the field named `K`, returning the previous value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::{record, ts};
+    ///
+    /// let mut rec = record!{foo: 3, bar: "hello"};
+    ///
+    /// assert_eq!(rec.set(ts!(bar), "world"), "hello");
+    /// assert_eq!(rec[ts!(bar)], "world");
+    ///
+    /// ```
+    #[inline(always)]
+    pub fn set<K>(
+        &mut self,
+        key: K,
+        value: <Self as Index<K>>::Output,
+    ) -> <Self as Index<K>>::Output
+    where
+        Self: IndexMut<K>,
+        <Self as Index<K>>::Output: Sized,
+    {
+        mem::replace(&mut self[key], value)
+    }
+}
+
+impl<L, K> Index<K> for Record<L>
+where
+    L: ListGetField<K>,
+{
+    type Output = L::Output;
+
+    #[inline(always)]
+    fn index(&self, _: K) -> &L::Output {
+        self.fields.get_field()
+    }
+}
+
+impl<L, K> IndexMut<K> for Record<L>
+where
+    L: ListGetField<K>,
+{
+    #[inline(always)]
+    fn index_mut(&mut self, _: K) -> &mut L::Output {
+        self.fields.get_field_mut()
+    }
+}
+
+impl<HK, HV, T, K> ListGetField<K> for (Field<HK, HV>, T)
+where
+    HK: TStrEqType<K>,
+    Self: ListGetFieldIf<K, HK::Output>,
+{
+    type Output = <Self as ListGetFieldIf<K, HK::Output>>::Output;
+
+    #[inline(always)]
+    fn get_field(&self) -> &Self::Output {
+        self.get_field_if()
+    }
+
+    #[inline(always)]
+    fn get_field_mut(&mut self) -> &mut Self::Output {
+        self.get_field_if_mut()
+    }
+}
+
+impl<HK, HV, T, K> ListGetFieldIf<K, True> for (Field<HK, HV>, T) {
+    type Output = HV;
+
+    #[inline(always)]
+    fn get_field_if(&self) -> &HV {
+        self.0.value()
+    }
+
+    #[inline(always)]
+    fn get_field_if_mut(&mut self) -> &mut HV {
+        self.0.value_mut()
+    }
+}
+
+impl<HK, HV, T, K> ListGetFieldIf<K, False> for (Field<HK, HV>, T)
+where
+    T: ListGetField<K>,
+{
+    type Output = T::Output;
+
+    #[inline(always)]
+    fn get_field_if(&self) -> &T::Output {
+        self.1.get_field()
+    }
+
+    #[inline(always)]
+    fn get_field_if_mut(&mut self) -> &mut T::Output {
+        self.1.get_field_mut()
+    }
+}
+
+/// For converting a type into the equivalent anonymous [`Record`].
+///
+/// # Deriving
+///
+/// This trait can be derived for structs with named fields,
+/// with the `#[derive(tstr::IntoRecord)]` derive macro
+/// (which requires the default `"proc_macros"` feature).
+///
+/// The derive generates:
+///
+/// - An impl of this trait,
+/// where the record has a field for each field of the struct, in the same order.
+///
+/// - A [`From`] impl to convert from the struct into the record.
+///
+/// - A [`From`] impl to convert from the record into the struct.
+///
+/// The derive generates code that refers to this crate as `::tstr`,
+/// which can be changed with the `#[tstr(crate = path::to::tstr)]` attribute.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::records::IntoRecord;
+/// use tstr::{record, ts, Record};
+///
+/// #[derive(Debug, PartialEq, IntoRecord)]
+/// struct Person<'a> {
+///     name: &'a str,
+///     age: u32,
+/// }
+///
+/// let rec = Person{name: "Bob", age: 21}.into_record();
+/// assert_eq!(rec, record!{name: "Bob", age: 21});
+///
+/// let mut rec: Record!{name: &str, age: u32} = Person{name: "Alice", age: 30}.into();
+/// rec[ts!(age)] += 1;
+///
+/// assert_eq!(Person::from(rec), Person{name: "Alice", age: 31});
+///
+/// assert_eq!(round_trip(Person{name: "Eve", age: 40}), Person{name: "Eve", age: 40});
+///
+/// fn round_trip<T: IntoRecord>(this: T) -> T {
+///     this.into_record().into()
+/// }
+///
+/// ```
+///
+/// [`Record`]: ./struct.Record.html
+/// [`From`]: https://doc.rust-lang.org/core/convert/trait.From.html
+pub trait IntoRecord: Sized {
+    /// The anonymous record equivalent to `Self`.
+    type Record: Into<Self>;
+
+    /// Converts `self` into the equivalent anonymous record.
+    fn into_record(self) -> Self::Record;
+}
@@ -0,0 +1,189 @@
+//! The building-block types of the representation of type-level strings
+//! that the enabled cargo features select.
+//!
+//! This module requires the `"reprs"` feature.
+//!
+//! # Stability
+//!
+//! **Everything in this module is exempt from semver guarantees**,
+//! the representation of type-level strings can change in any release,
+//! and depends on the enabled cargo features.
+//! The only exception is the `"stable_repr"` feature,
+//! which guarantees the [stable representation](../index.html#stable-representation).
+//!
+//! Code that uses this module should go through the [`TS`] macro
+//! whenever it doesn't need to name the representation.
+//!
+//! # Representations
+//!
+//! A type-level string is always a [`TStr<T>`], where `T` is an unspecified type
+//! that is computed from the string.
+//!
+//! ### Default representation
+//!
+//! Without the `"min_const_generics"` feature, `T` is computed like this:
+//!
+//! - The empty string is `()`.
+//!
+//! - Strings of 1 to 8 bytes are a tuple of the type of each byte,
+//! where the byte `0xNN` (in uppercase hexadecimal) is the `__0xNN` unit struct.
+//!
+//! - Longer strings are split into chunks of `P` bytes (the last chunk can be shorter),
+//! where `P` is the largest power of 8 that's smaller than the length of the string.
+//! Then the string is a tuple of the representation of each chunk.
+//!
+//! The value of each byte is available through the [`ByteRepr`] trait.
+//!
+//! ### `"min_const_generics"` representation
+//!
+//! With the `"min_const_generics"` feature (but not `"const_generics"`),
+//! `T` is computed like the default representation, except that it's split into `char`s,
+//! and that a chunk of 1 to 8 `char`s is one of the
+//! `__a<A>`, `__b<A, B>`, `__c<A, B, C>`, `__d`, `__e`, `__f`, `__g`, or `__<A, .., H>`
+//! structs, with a `char` const parameter for each `char`.
+//!
+//! ### `"const_generics"` representation
+//!
+//! With the `"const_generics"` feature, `T` is `___<S>`,
+//! where `S` is a `&'static str` const parameter with the string.
+//!
+//! # Example
+//!
+//! This example demonstrates a blanket impl over the type-level strings of two bytes,
+//! with the default representation.
+//!
+#![cfg_attr(not(feature = "min_const_generics"), doc = "```rust")]
+#![cfg_attr(feature = "min_const_generics", doc = "```ignore")]
+//! use tstr::reprs::{ByteRepr, __0x68, __0x69};
+//! use tstr::{TStr, TS};
+//!
+//! let _: TS!("hi") = TStr::<(__0x68, __0x69)>::NEW;
+//!
+//! assert_eq!(<TS!("hi") as TwoBytes>::BYTES, *b"hi");
+//! assert_eq!(<TS!("ok") as TwoBytes>::BYTES, *b"ok");
+//!
+//! trait TwoBytes {
+//!     const BYTES: [u8; 2];
+//! }
+//!
+//! impl<A, B> TwoBytes for TStr<(A, B)>
+//! where
+//!     A: ByteRepr,
+//!     B: ByteRepr,
+//! {
+//!     const BYTES: [u8; 2] = [A::BYTE, B::BYTE];
+//! }
+//!
+//! ```
+//!
+//! This example demonstrates the same impl with the `"min_const_generics"` representation.
+//!
+#![cfg_attr(
+    all(feature = "min_const_generics", not(feature = "const_generics")),
+    doc = "```rust"
+)]
+#![cfg_attr(
+    not(all(feature = "min_const_generics", not(feature = "const_generics"))),
+    doc = "```ignore"
+)]
+//! use tstr::reprs::__b;
+//! use tstr::{TStr, TS};
+//!
+//! let _: TS!("hi") = TStr::<__b<'h', 'i'>>::NEW;
+//!
+//! assert_eq!(<TS!("hi") as TwoChars>::CHARS, ['h', 'i']);
+//! assert_eq!(<TS!("ñu") as TwoChars>::CHARS, ['ñ', 'u']);
+//!
+//! trait TwoChars {
+//!     const CHARS: [char; 2];
+//! }
+//!
+//! impl<const A: char, const B: char> TwoChars for TStr<__b<A, B>> {
+//!     const CHARS: [char; 2] = [A, B];
+//! }
+//!
+//! ```
+//!
+//! This example demonstrates a blanket impl over all type-level strings,
+//! with the `"const_generics"` representation.
+//!
+#![cfg_attr(feature = "const_generics", doc = "```rust")]
+#![cfg_attr(not(feature = "const_generics"), doc = "```ignore")]
+#![cfg_attr(
+    feature = "nightly_const_generics",
+    doc = "# #![feature(adt_const_params, unsized_const_params)]"
+)]
+//! use tstr::reprs::___;
+//! use tstr::{TStr, TS};
+//!
+//! assert_eq!(<TS!("hello") as Len>::LEN, 5);
+//! assert_eq!(<TS!("") as Len>::LEN, 0);
+//!
+//! trait Len {
+//!     const LEN: usize;
+//! }
+//!
+//! impl<const S: &'static str> Len for TStr<___<S>> {
+//!     const LEN: usize = S.len();
+//! }
+//!
+//! ```
+//!
+//! [`TS`]: ../macro.TS.html
+//! [`TStr<T>`]: ../struct.TStr.html
+//! [`ByteRepr`]: ./trait.ByteRepr.html
+
+#[cfg(not(feature = "min_const_generics"))]
+#[doc(inline)]
+pub use crate::{
+    __0x00, __0x01, __0x02, __0x03, __0x04, __0x05, __0x06, __0x07, __0x08, __0x09, __0x0A, __0x0B,
+    __0x0C, __0x0D, __0x0E, __0x0F, __0x10, __0x11, __0x12, __0x13, __0x14, __0x15, __0x16, __0x17,
+    __0x18, __0x19, __0x1A, __0x1B, __0x1C, __0x1D, __0x1E, __0x1F, __0x20, __0x21, __0x22, __0x23,
+    __0x24, __0x25, __0x26, __0x27, __0x28, __0x29, __0x2A, __0x2B, __0x2C, __0x2D, __0x2E, __0x2F,
+    __0x30, __0x31, __0x32, __0x33, __0x34, __0x35, __0x36, __0x37, __0x38, __0x39, __0x3A, __0x3B,
+    __0x3C, __0x3D, __0x3E, __0x3F, __0x40, __0x41, __0x42, __0x43, __0x44, __0x45, __0x46, __0x47,
+    __0x48, __0x49, __0x4A, __0x4B, __0x4C, __0x4D, __0x4E, __0x4F, __0x50, __0x51, __0x52, __0x53,
+    __0x54, __0x55, __0x56, __0x57, __0x58, __0x59, __0x5A, __0x5B, __0x5C, __0x5D, __0x5E, __0x5F,
+    __0x60, __0x61, __0x62, __0x63, __0x64, __0x65, __0x66, __0x67, __0x68, __0x69, __0x6A, __0x6B,
+    __0x6C, __0x6D, __0x6E, __0x6F, __0x70, __0x71, __0x72, __0x73, __0x74, __0x75, __0x76, __0x77,
+    __0x78, __0x79, __0x7A, __0x7B, __0x7C, __0x7D, __0x7E, __0x7F, __0x80, __0x81, __0x82, __0x83,
+    __0x84, __0x85, __0x86, __0x87, __0x88, __0x89, __0x8A, __0x8B, __0x8C, __0x8D, __0x8E, __0x8F,
+    __0x90, __0x91, __0x92, __0x93, __0x94, __0x95, __0x96, __0x97, __0x98, __0x99, __0x9A, __0x9B,
+    __0x9C, __0x9D, __0x9E, __0x9F, __0xA0, __0xA1, __0xA2, __0xA3, __0xA4, __0xA5, __0xA6, __0xA7,
+    __0xA8, __0xA9, __0xAA, __0xAB, __0xAC, __0xAD, __0xAE, __0xAF, __0xB0, __0xB1, __0xB2, __0xB3,
+    __0xB4, __0xB5, __0xB6, __0xB7, __0xB8, __0xB9, __0xBA, __0xBB, __0xBC, __0xBD, __0xBE, __0xBF,
+    __0xC0, __0xC1, __0xC2, __0xC3, __0xC4, __0xC5, __0xC6, __0xC7, __0xC8, __0xC9, __0xCA, __0xCB,
+    __0xCC, __0xCD, __0xCE, __0xCF, __0xD0, __0xD1, __0xD2, __0xD3, __0xD4, __0xD5, __0xD6, __0xD7,
+    __0xD8, __0xD9, __0xDA, __0xDB, __0xDC, __0xDD, __0xDE, __0xDF, __0xE0, __0xE1, __0xE2, __0xE3,
+    __0xE4, __0xE5, __0xE6, __0xE7, __0xE8, __0xE9, __0xEA, __0xEB, __0xEC, __0xED, __0xEE, __0xEF,
+    __0xF0, __0xF1, __0xF2, __0xF3, __0xF4, __0xF5, __0xF6, __0xF7, __0xF8, __0xF9, __0xFA, __0xFB,
+    __0xFC, __0xFD, __0xFE, __0xFF,
+};
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+#[doc(inline)]
+pub use crate::{__a, __b, __c, __d, __e, __f, __g, __};
+
+#[cfg(feature = "const_generics")]
+#[doc(inline)]
+pub use crate::___;
+
+/// The value of a byte type of the default representation of type-level strings.
+///
+/// This is implemented for the `__0xNN` unit structs in this module,
+/// where `BYTE` is `0xNN`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::reprs::{ByteRepr, __0x41, __0xFF};
+///
+/// assert_eq!(__0x41::BYTE, b'A');
+/// assert_eq!(__0xFF::BYTE, 255);
+///
+/// ```
+#[cfg(not(feature = "min_const_generics"))]
+pub trait ByteRepr {
+    /// The value of the byte.
+    const BYTE: u8;
+}
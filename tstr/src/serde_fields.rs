@@ -0,0 +1,365 @@
+//! Helpers for manually implementing [`Deserialize`] for structs,
+//! using a [`TList`] of the [`TStr`]s of the names of the fields.
+//!
+//! This module requires the `"serde"` feature.
+//!
+//! Hand-written `Deserialize` impls for structs usually declare a `Field` enum,
+//! with a `Deserialize` impl that maps the name of each field to a variant.
+//! [`FieldSeed`] replaces that enum,
+//! deserializing a field identifier into its position in the list of field names.
+//!
+//! # Example
+//!
+//! ```rust
+//! use serde::de::{
+//!     self, value::MapDeserializer, Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor,
+//! };
+//!
+//! use tstr::serde_fields::FieldSeed;
+//! use tstr::tlist::{TList, TNil};
+//! use tstr::TS;
+//!
+//! use std::fmt;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Point {
+//!     x: u32,
+//!     y: u32,
+//! }
+//!
+//! type PointFields = TList<TS!(x), TList<TS!(y), TNil>>;
+//!
+//! impl<'de> Deserialize<'de> for Point {
+//!     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+//!     where
+//!         D: Deserializer<'de>,
+//!     {
+//!         deserializer.deserialize_struct("Point", &["x", "y"], PointVisitor)
+//!     }
+//! }
+//!
+//! struct PointVisitor;
+//!
+//! impl<'de> Visitor<'de> for PointVisitor {
+//!     type Value = Point;
+//!
+//!     fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         f.write_str("struct Point")
+//!     }
+//!
+//!     fn visit_map<A>(self, mut map: A) -> Result<Point, A::Error>
+//!     where
+//!         A: MapAccess<'de>,
+//!     {
+//!         let mut x = None;
+//!         let mut y = None;
+//!
+//!         while let Some(field) = map.next_key_seed(FieldSeed::<PointFields>::NEW)? {
+//!             match field {
+//!                 Some(0) => x = Some(map.next_value()?),
+//!                 Some(1) => y = Some(map.next_value()?),
+//!                 _ => {
+//!                     map.next_value::<IgnoredAny>()?;
+//!                 }
+//!             }
+//!         }
+//!
+//!         Ok(Point {
+//!             x: x.ok_or_else(|| de::Error::missing_field("x"))?,
+//!             y: y.ok_or_else(|| de::Error::missing_field("y"))?,
+//!         })
+//!     }
+//! }
+//!
+//! let map = MapDeserializer::<_, de::value::Error>::new(
+//!     vec![("y", 5u32), ("z", 8), ("x", 3)].into_iter()
+//! );
+//!
+//! assert_eq!(Point::deserialize(map).unwrap(), Point { x: 3, y: 5 });
+//!
+//! ```
+//!
+//! [`Deserialize`]: https://docs.rs/serde/1/serde/trait.Deserialize.html
+//! [`TList`]: ../tlist/struct.TList.html
+//! [`TStr`]: ../struct.TStr.html
+//! [`FieldSeed`]: ./struct.FieldSeed.html
+
+use crate::{
+    tlist::{TList, TNil},
+    TStr,
+};
+
+use core::{fmt, marker::PhantomData};
+
+use serde::de::{DeserializeSeed, Deserializer, Error, Visitor};
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait Sealed {}
+
+    /// Strips the string of `Self` (a representation of a `TStr`) from the start of `bytes`.
+    #[doc(hidden)]
+    pub trait StripPrefix {
+        fn strip_prefix(bytes: &[u8]) -> Option<&[u8]>;
+    }
+}
+use sealed::Sealed;
+
+#[doc(hidden)]
+pub use sealed::StripPrefix;
+
+/// A [`TList`] of the [`TStr`]s of the names of fields,
+/// for finding the position of a field name at runtime.
+///
+/// This trait is sealed, it's only implemented for [`TList`]s of [`TStr`]s, and [`TNil`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::serde_fields::FieldNames;
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::TS;
+///
+/// type Names = TList<TS!(foo), TList<TS!("bar baz"), TNil>>;
+///
+/// assert_eq!(Names::LEN, 2);
+/// assert_eq!(Names::index_of("foo"), Some(0));
+/// assert_eq!(Names::index_of("bar baz"), Some(1));
+/// assert_eq!(Names::index_of("bar"), None);
+///
+/// ```
+///
+/// [`TList`]: ../tlist/struct.TList.html
+/// [`TNil`]: ../tlist/struct.TNil.html
+/// [`TStr`]: ../struct.TStr.html
+pub trait FieldNames: Sealed {
+    /// The amount of field names.
+    const LEN: usize;
+
+    /// Gets the position of `name` in the list, returning `None` if it's not in the list.
+    fn index_of(name: &str) -> Option<usize> {
+        Self::index_of_bytes(name.as_bytes())
+    }
+
+    /// Gets the position of `name` in the list, returning `None` if it's not in the list.
+    fn index_of_bytes(name: &[u8]) -> Option<usize>;
+}
+
+impl Sealed for TNil {}
+
+impl FieldNames for TNil {
+    const LEN: usize = 0;
+
+    #[inline]
+    fn index_of_bytes(_: &[u8]) -> Option<usize> {
+        None
+    }
+}
+
+impl<R, T> Sealed for TList<TStr<R>, T> {}
+
+impl<R, T> FieldNames for TList<TStr<R>, T>
+where
+    R: StripPrefix,
+    T: FieldNames,
+{
+    const LEN: usize = 1 + T::LEN;
+
+    fn index_of_bytes(name: &[u8]) -> Option<usize> {
+        if R::strip_prefix(name) == Some(&[]) {
+            Some(0)
+        } else {
+            T::index_of_bytes(name).map(|i| i + 1)
+        }
+    }
+}
+
+/// A [`DeserializeSeed`] for the identifier of a field,
+/// which deserializes into the position of the field in the `Names` list,
+/// or `None` for fields that aren't in the list.
+///
+/// `Names` is a [`FieldNames`], a [`TList`] of the [`TStr`]s of the names of the fields.
+///
+/// Field identifiers are deserialized from strings, bytes, and integers,
+/// where integers are the position of the field.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::de::{value::Error, DeserializeSeed, IntoDeserializer};
+///
+/// use tstr::serde_fields::FieldSeed;
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::TS;
+///
+/// type Seed = FieldSeed<TList<TS!(secs), TList<TS!(nanos), TNil>>>;
+///
+/// let seed = |x: &str| Seed::NEW.deserialize(x.into_deserializer());
+///
+/// assert_eq!(seed("secs"), Ok::<_, Error>(Some(0)));
+/// assert_eq!(seed("nanos"), Ok(Some(1)));
+/// assert_eq!(seed("millis"), Ok(None));
+///
+/// let index = |x: u64| Seed::NEW.deserialize(x.into_deserializer());
+///
+/// assert_eq!(index(1), Ok::<_, Error>(Some(1)));
+/// assert_eq!(index(2), Ok(None));
+///
+/// ```
+///
+/// [`DeserializeSeed`]: https://docs.rs/serde/1/serde/de/trait.DeserializeSeed.html
+/// [`FieldNames`]: ./trait.FieldNames.html
+/// [`TList`]: ../tlist/struct.TList.html
+/// [`TStr`]: ../struct.TStr.html
+pub struct FieldSeed<Names>(PhantomData<fn() -> Names>);
+
+impl<Names> FieldSeed<Names> {
+    /// Constructs a `FieldSeed`.
+    pub const NEW: Self = FieldSeed(PhantomData);
+}
+
+impl<Names> Copy for FieldSeed<Names> {}
+
+impl<Names> Clone for FieldSeed<Names> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Names> Default for FieldSeed<Names> {
+    fn default() -> Self {
+        Self::NEW
+    }
+}
+
+impl<Names> fmt::Debug for FieldSeed<Names> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FieldSeed")
+    }
+}
+
+impl<'de, Names> DeserializeSeed<'de> for FieldSeed<Names>
+where
+    Names: FieldNames,
+{
+    type Value = Option<usize>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de, Names> Visitor<'de> for FieldSeed<Names>
+where
+    Names: FieldNames,
+{
+    type Value = Option<usize>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a field identifier")
+    }
+
+    fn visit_u64<E: Error>(self, index: u64) -> Result<Self::Value, E> {
+        Ok(if index < Names::LEN as u64 {
+            Some(index as usize)
+        } else {
+            None
+        })
+    }
+
+    fn visit_str<E: Error>(self, name: &str) -> Result<Self::Value, E> {
+        Ok(Names::index_of(name))
+    }
+
+    fn visit_bytes<E: Error>(self, name: &[u8]) -> Result<Self::Value, E> {
+        Ok(Names::index_of_bytes(name))
+    }
+}
+
+/// Strips `prefix` from the start of `bytes`
+#[inline]
+pub(crate) fn strip_bytes<'a>(bytes: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if bytes.starts_with(prefix) {
+        Some(&bytes[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+impl StripPrefix for () {
+    #[inline]
+    fn strip_prefix(bytes: &[u8]) -> Option<&[u8]> {
+        Some(bytes)
+    }
+}
+
+macro_rules! tuple_impl {
+    ($($ty:ident)*) => {
+        impl<$($ty,)*> StripPrefix for ($($ty,)*)
+        where
+            $($ty: StripPrefix,)*
+        {
+            #[inline]
+            fn strip_prefix(bytes: &[u8]) -> Option<&[u8]> {
+                $( let bytes = $ty::strip_prefix(bytes)?; )*
+                Some(bytes)
+            }
+        }
+    };
+}
+
+tuple_impl! {A}
+tuple_impl! {A B}
+tuple_impl! {A B C}
+tuple_impl! {A B C D}
+tuple_impl! {A B C D E}
+tuple_impl! {A B C D E F}
+tuple_impl! {A B C D E F G}
+tuple_impl! {A B C D E F G H}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+macro_rules! min_const_impls {
+    ($( ($chars_struct:ident, [$($chars:ident),*]) ,)*) => {
+        $(
+            impl<$(const $chars: char,)*> StripPrefix for crate::$chars_struct<$($chars,)*> {
+                #[inline]
+                fn strip_prefix(bytes: &[u8]) -> Option<&[u8]> {
+                    $(
+                        let bytes = strip_bytes(bytes, $chars.encode_utf8(&mut [0; 4]).as_bytes())?;
+                    )*
+                    Some(bytes)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+min_const_impls! {
+    (__a, [A]),
+    (__b, [A, B]),
+    (__c, [A, B, C]),
+    (__d, [A, B, C, D]),
+    (__e, [A, B, C, D, E]),
+    (__f, [A, B, C, D, E, F]),
+    (__g, [A, B, C, D, E, F, G]),
+    (__, [A, B, C, D, E, F, G, H]),
+}
+
+#[cfg(feature = "const_generics")]
+macro_rules! const_impls {
+    () => {
+        impl<const S: &'static str> StripPrefix for crate::___<S> {
+            #[inline]
+            fn strip_prefix(bytes: &[u8]) -> Option<&[u8]> {
+                strip_bytes(bytes, S.as_bytes())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "const_generics")]
+const_impls! {}
@@ -0,0 +1,642 @@
+//! Type-level sets of [`TStr`]s.
+//!
+//! This module is only available with the `"cmp_traits"` feature,
+//! and without the `"min_const_generics"` feature,
+//! since the set operations are only implemented for the default
+//! representation of type-level strings.
+//!
+//! # Example
+//!
+//! This example demonstrates a typestate builder,
+//! which tracks what fields have been initialized with a [`TStrSet`].
+//!
+//! ```rust
+//! use tstr::sets::{Contains, Insert, MakeTStrSet};
+//! use tstr::{TStrSet, TS};
+//!
+//! let builder = Builder::new().name("Bob").surname("Marley");
+//! assert_eq!(builder.build(), ("Bob", "Marley"));
+//!
+//! struct Builder<Init> {
+//!     name: &'static str,
+//!     surname: &'static str,
+//!     init: Init,
+//! }
+//!
+//! impl Builder<TStrSet!()> {
+//!     fn new() -> Self {
+//!         Builder { name: "", surname: "", init: TStrSet::NEW }
+//!     }
+//! }
+//!
+//! impl<Init> Builder<Init> {
+//!     fn name(self, name: &'static str) -> Builder<Init::Output>
+//!     where
+//!         Init: Insert<TS!(name)>,
+//!     {
+//!         Builder { name, surname: self.surname, init: MakeTStrSet::MAKE }
+//!     }
+//!
+//!     fn surname(self, surname: &'static str) -> Builder<Init::Output>
+//!     where
+//!         Init: Insert<TS!(surname)>,
+//!     {
+//!         Builder { name: self.name, surname, init: MakeTStrSet::MAKE }
+//!     }
+//!
+//!     fn build(self) -> (&'static str, &'static str)
+//!     where
+//!         Init: Contains<TS!(name)> + Contains<TS!(surname)>,
+//!     {
+//!         (self.name, self.surname)
+//!     }
+//! }
+//!
+//! ```
+//!
+//! Building without setting the surname is a compile-time error:
+//!
+//! ```compile_fail
+//! # use tstr::sets::{Contains, Insert, MakeTStrSet};
+//! # use tstr::{TStrSet, TS};
+//! #
+//! Builder::new().name("Bob").build();
+//! #
+//! # struct Builder<Init> {
+//! #     name: &'static str,
+//! #     surname: &'static str,
+//! #     init: Init,
+//! # }
+//! #
+//! # impl Builder<TStrSet!()> {
+//! #     fn new() -> Self {
+//! #         Builder { name: "", surname: "", init: TStrSet::NEW }
+//! #     }
+//! # }
+//! #
+//! # impl<Init> Builder<Init> {
+//! #     fn name(self, name: &'static str) -> Builder<Init::Output>
+//! #     where
+//! #         Init: Insert<TS!(name)>,
+//! #     {
+//! #         Builder { name, surname: self.surname, init: MakeTStrSet::MAKE }
+//! #     }
+//! #
+//! #     fn surname(self, surname: &'static str) -> Builder<Init::Output>
+//! #     where
+//! #         Init: Insert<TS!(surname)>,
+//! #     {
+//! #         Builder { name: self.name, surname, init: MakeTStrSet::MAKE }
+//! #     }
+//! #
+//! #     fn build(self) -> (&'static str, &'static str)
+//! #     where
+//! #         Init: Contains<TS!(name)> + Contains<TS!(surname)>,
+//! #     {
+//! #         (self.name, self.surname)
+//! #     }
+//! # }
+//! ```
+//!
+//! [`TStr`]: ../struct.TStr.html
+//! [`TStrSet`]: ./struct.TStrSet.html
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use crate::{
+    bools::{And, False, IfElse, Or, True},
+    for_tupled_reprs::type_cmp::TStrEqType,
+    tlist::{TList, TNil},
+};
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait Sealed {}
+
+    #[doc(hidden)]
+    pub trait ListContains<K> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListPushBack<K> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListRemove<K> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListAppend<R> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListIntersection<R> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListDifference<R> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListIsSubsetOf<R> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait ListIntersects<R> {
+        type Output;
+    }
+}
+use sealed::{
+    ListAppend, ListContains, ListDifference, ListIntersection, ListIntersects, ListIsSubsetOf,
+    ListPushBack, ListRemove, Sealed,
+};
+
+/// A type-level set of [`TStr`]s.
+///
+/// `L` is a [`TList`] of [`TStr`]s,
+/// with the elements in the order that they were inserted into the set.
+///
+/// The [`TStrSet`] macro is the most convenient way to write the type of a set.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::{TStrSet, TS, ts};
+///
+/// let set: TStrSet!(foo, bar) = TStrSet::NEW;
+///
+/// // Inserting an element that's already in the set returns the same set.
+/// let _: TStrSet!(foo, bar) = set.insert(ts!(foo));
+///
+/// let _: TStrSet!(foo, bar, baz) = set.insert(ts!(baz));
+///
+/// let _: TStrSet!(bar) = set.remove(ts!(foo));
+///
+/// // The TStrSet macro is sugar for this type
+/// let _: TStrSet<TList<TS!(foo), TList<TS!(bar), TNil>>> = set;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`TList`]: ../tlist/struct.TList.html
+/// [`TStrSet`]: ../macro.TStrSet.html
+pub struct TStrSet<L>(PhantomData<fn() -> L>);
+
+impl<L> TStrSet<L> {
+    /// Constructs the TStrSet.
+    pub const NEW: Self = TStrSet(PhantomData);
+
+    /// Inserts `key` into the set, returning the set with the element.
+    #[inline(always)]
+    pub fn insert<K>(self, _key: K) -> <Self as Insert<K>>::Output
+    where
+        Self: Insert<K>,
+    {
+        MakeTStrSet::MAKE
+    }
+
+    /// Removes `key` from the set, returning the set without the element.
+    #[inline(always)]
+    pub fn remove<K>(self, _key: K) -> <Self as Remove<K>>::Output
+    where
+        Self: Remove<K>,
+    {
+        MakeTStrSet::MAKE
+    }
+
+    /// Returns the union of this set and `other`.
+    #[inline(always)]
+    pub fn union<R>(self, _other: R) -> <Self as Union<R>>::Output
+    where
+        Self: Union<R>,
+    {
+        MakeTStrSet::MAKE
+    }
+
+    /// Returns the intersection of this set and `other`.
+    #[inline(always)]
+    pub fn intersection<R>(self, _other: R) -> <Self as Intersection<R>>::Output
+    where
+        Self: Intersection<R>,
+    {
+        MakeTStrSet::MAKE
+    }
+
+    /// Returns the elements of this set that aren't in `other`.
+    #[inline(always)]
+    pub fn difference<R>(self, _other: R) -> <Self as Difference<R>>::Output
+    where
+        Self: Difference<R>,
+    {
+        MakeTStrSet::MAKE
+    }
+}
+
+/// For constructing a [`TStrSet`] in generic code,
+/// like the `Output` of the set operations.
+///
+/// This trait is sealed, it's only implemented for [`TStrSet`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::sets::{Insert, MakeTStrSet};
+/// use tstr::TS;
+///
+/// type Set = tstr::TStrSet!(foo);
+///
+/// let _: tstr::TStrSet!(foo, bar) = <<Set as Insert<TS!(bar)>>::Output>::MAKE;
+///
+/// ```
+///
+/// [`TStrSet`]: ./struct.TStrSet.html
+pub trait MakeTStrSet: Sealed + Copy {
+    /// Gets a value of this type
+    const MAKE: Self;
+}
+
+/// Asserts that `K` is an element of `Self`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::sets::Contains;
+/// use tstr::TS;
+///
+/// type Set = tstr::TStrSet!(foo, bar);
+///
+/// assert_contains::<Set, TS!(foo)>();
+/// assert_contains::<Set, TS!(bar)>();
+///
+/// fn assert_contains<S, K>()
+/// where
+///     S: Contains<K>
+/// {}
+///
+/// ```
+///
+/// Asserting that an element that isn't in the set is in it
+/// is a compile-time error:
+///
+/// ```compile_fail
+/// use tstr::sets::Contains;
+/// use tstr::TS;
+///
+/// assert_contains::<tstr::TStrSet!(foo, bar), TS!(baz)>();
+///
+/// fn assert_contains<S, K>()
+/// where
+///     S: Contains<K>
+/// {}
+/// ```
+pub trait Contains<K> {}
+
+impl<L, K> Contains<K> for TStrSet<L> where L: ListContains<K, Output = True> {}
+
+/// Inserts `K` into `Self`, doing nothing if `K` is already in the set.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::sets::{Insert, MakeTStrSet};
+/// use tstr::TS;
+///
+/// type Set = tstr::TStrSet!(foo);
+///
+/// let _: tstr::TStrSet!(foo, bar) = <<Set as Insert<TS!(bar)>>::Output>::MAKE;
+/// let _: tstr::TStrSet!(foo) = <<Set as Insert<TS!(foo)>>::Output>::MAKE;
+///
+/// ```
+pub trait Insert<K> {
+    /// The set with `K` in it.
+    type Output: MakeTStrSet;
+}
+
+impl<L, K, B, P> Insert<K> for TStrSet<L>
+where
+    L: ListContains<K, Output = B> + ListPushBack<K, Output = P>,
+    B: IfElse<L, P>,
+{
+    type Output = TStrSet<B::Output>;
+}
+
+/// Removes `K` from `Self`, doing nothing if `K` isn't in the set.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::sets::{Remove, MakeTStrSet};
+/// use tstr::TS;
+///
+/// type Set = tstr::TStrSet!(foo, bar, baz);
+///
+/// let _: tstr::TStrSet!(foo, baz) = <<Set as Remove<TS!(bar)>>::Output>::MAKE;
+/// let _: tstr::TStrSet!(foo, bar, baz) = <<Set as Remove<TS!(qux)>>::Output>::MAKE;
+///
+/// ```
+pub trait Remove<K> {
+    /// The set without `K` in it.
+    type Output: MakeTStrSet;
+}
+
+impl<L, K> Remove<K> for TStrSet<L>
+where
+    L: ListRemove<K>,
+{
+    type Output = TStrSet<L::Output>;
+}
+
+/// The union of the `Self` and `Rhs` sets.
+///
+/// The elements of `Self` come first, followed by the elements of `Rhs`
+/// that aren't in `Self`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::sets::{Union, MakeTStrSet};
+///
+/// type Left = tstr::TStrSet!(foo, bar);
+/// type Right = tstr::TStrSet!(bar, baz);
+///
+/// let _: tstr::TStrSet!(foo, bar, baz) = <<Left as Union<Right>>::Output>::MAKE;
+///
+/// ```
+pub trait Union<Rhs> {
+    /// The set with the elements of both `Self` and `Rhs`.
+    type Output: MakeTStrSet;
+}
+
+impl<L, R, D> Union<TStrSet<R>> for TStrSet<L>
+where
+    R: ListDifference<L, Output = D>,
+    L: ListAppend<D>,
+{
+    type Output = TStrSet<L::Output>;
+}
+
+/// The intersection of the `Self` and `Rhs` sets.
+///
+/// The elements keep the order they have in `Self`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::sets::{Intersection, MakeTStrSet};
+///
+/// type Left = tstr::TStrSet!(foo, bar, baz);
+/// type Right = tstr::TStrSet!(baz, qux, foo);
+///
+/// let _: tstr::TStrSet!(foo, baz) = <<Left as Intersection<Right>>::Output>::MAKE;
+///
+/// ```
+pub trait Intersection<Rhs> {
+    /// The set with the elements that are in both `Self` and `Rhs`.
+    type Output: MakeTStrSet;
+}
+
+impl<L, R> Intersection<TStrSet<R>> for TStrSet<L>
+where
+    L: ListIntersection<R>,
+{
+    type Output = TStrSet<L::Output>;
+}
+
+/// The difference of the `Self` and `Rhs` sets.
+///
+/// The elements keep the order they have in `Self`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::sets::{Difference, MakeTStrSet};
+///
+/// type Left = tstr::TStrSet!(foo, bar, baz);
+/// type Right = tstr::TStrSet!(bar, qux);
+///
+/// let _: tstr::TStrSet!(foo, baz) = <<Left as Difference<Right>>::Output>::MAKE;
+///
+/// ```
+pub trait Difference<Rhs> {
+    /// The set with the elements of `Self` that aren't in `Rhs`.
+    type Output: MakeTStrSet;
+}
+
+impl<L, R> Difference<TStrSet<R>> for TStrSet<L>
+where
+    L: ListDifference<R>,
+{
+    type Output = TStrSet<L::Output>;
+}
+
+/// Asserts that every element of `Self` is in `Rhs`.
+///
+/// # Example
+///
+/// This example demonstrates a function that requires
+/// a set to have at least some fields.
+///
+/// ```rust
+/// use tstr::sets::IsSubsetOf;
+///
+/// type Required = tstr::TStrSet!(name, age);
+///
+/// has_required_fields::<tstr::TStrSet!(age, name)>();
+/// has_required_fields::<tstr::TStrSet!(id, name, age)>();
+///
+/// fn has_required_fields<S>()
+/// where
+///     Required: IsSubsetOf<S>
+/// {}
+///
+/// ```
+///
+/// Passing a set without "age" is a compile-time error:
+///
+/// ```compile_fail
+/// use tstr::sets::IsSubsetOf;
+///
+/// type Required = tstr::TStrSet!(name, age);
+///
+/// has_required_fields::<tstr::TStrSet!(id, name)>();
+///
+/// fn has_required_fields<S>()
+/// where
+///     Required: IsSubsetOf<S>
+/// {}
+/// ```
+pub trait IsSubsetOf<Rhs> {}
+
+impl<L, R> IsSubsetOf<TStrSet<R>> for TStrSet<L> where L: ListIsSubsetOf<R, Output = True> {}
+
+/// Asserts that `Self` and `Rhs` have no elements in common.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::sets::IsDisjointFrom;
+///
+/// assert_disjoint::<tstr::TStrSet!(foo, bar), tstr::TStrSet!(baz)>();
+/// assert_disjoint::<tstr::TStrSet!(), tstr::TStrSet!(baz)>();
+///
+/// fn assert_disjoint<L, R>()
+/// where
+///     L: IsDisjointFrom<R>
+/// {}
+///
+/// ```
+///
+/// Asserting that sets which both contain "bar" are disjoint is a compile-time error:
+///
+/// ```compile_fail
+/// use tstr::sets::IsDisjointFrom;
+///
+/// assert_disjoint::<tstr::TStrSet!(foo, bar), tstr::TStrSet!(bar)>();
+///
+/// fn assert_disjoint<L, R>()
+/// where
+///     L: IsDisjointFrom<R>
+/// {}
+/// ```
+pub trait IsDisjointFrom<Rhs> {}
+
+impl<L, R> IsDisjointFrom<TStrSet<R>> for TStrSet<L> where L: ListIntersects<R, Output = False> {}
+
+impl<K> ListContains<K> for TNil {
+    type Output = False;
+}
+
+impl<H, T, K> ListContains<K> for TList<H, T>
+where
+    H: TStrEqType<K>,
+    T: ListContains<K>,
+    H::Output: Or<T::Output>,
+{
+    type Output = <H::Output as Or<T::Output>>::Output;
+}
+
+impl<K> ListPushBack<K> for TNil {
+    type Output = TList<K, TNil>;
+}
+
+impl<H, T, K> ListPushBack<K> for TList<H, T>
+where
+    T: ListPushBack<K>,
+{
+    type Output = TList<H, T::Output>;
+}
+
+impl<K> ListRemove<K> for TNil {
+    type Output = TNil;
+}
+
+impl<H, T, K> ListRemove<K> for TList<H, T>
+where
+    H: TStrEqType<K>,
+    T: ListRemove<K>,
+    H::Output: IfElse<T::Output, TList<H, T::Output>>,
+{
+    type Output = <H::Output as IfElse<T::Output, TList<H, T::Output>>>::Output;
+}
+
+impl<R> ListAppend<R> for TNil {
+    type Output = R;
+}
+
+impl<H, T, R> ListAppend<R> for TList<H, T>
+where
+    T: ListAppend<R>,
+{
+    type Output = TList<H, T::Output>;
+}
+
+impl<R> ListIntersection<R> for TNil {
+    type Output = TNil;
+}
+
+impl<H, T, R> ListIntersection<R> for TList<H, T>
+where
+    R: ListContains<H>,
+    T: ListIntersection<R>,
+    R::Output: IfElse<TList<H, T::Output>, T::Output>,
+{
+    type Output = <R::Output as IfElse<TList<H, T::Output>, T::Output>>::Output;
+}
+
+impl<R> ListDifference<R> for TNil {
+    type Output = TNil;
+}
+
+impl<H, T, R> ListDifference<R> for TList<H, T>
+where
+    R: ListContains<H>,
+    T: ListDifference<R>,
+    R::Output: IfElse<T::Output, TList<H, T::Output>>,
+{
+    type Output = <R::Output as IfElse<T::Output, TList<H, T::Output>>>::Output;
+}
+
+impl<R> ListIsSubsetOf<R> for TNil {
+    type Output = True;
+}
+
+impl<H, T, R> ListIsSubsetOf<R> for TList<H, T>
+where
+    R: ListContains<H>,
+    T: ListIsSubsetOf<R>,
+    R::Output: And<T::Output>,
+{
+    type Output = <R::Output as And<T::Output>>::Output;
+}
+
+impl<R> ListIntersects<R> for TNil {
+    type Output = False;
+}
+
+impl<H, T, R> ListIntersects<R> for TList<H, T>
+where
+    R: ListContains<H>,
+    T: ListIntersects<R>,
+    R::Output: Or<T::Output>,
+{
+    type Output = <R::Output as Or<T::Output>>::Output;
+}
+
+impl<L> Sealed for TStrSet<L> {}
+
+impl<L> MakeTStrSet for TStrSet<L> {
+    const MAKE: Self = Self::NEW;
+}
+
+impl<L> Copy for TStrSet<L> {}
+
+impl<L> Clone for TStrSet<L> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L> Default for TStrSet<L> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::NEW
+    }
+}
+
+impl<L> Debug for TStrSet<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TStrSet").finish()
+    }
+}
@@ -0,0 +1,113 @@
+//! Compile-time assertions that types can be accessed by the [`TStr`]s of field names,
+//! for tests of types that implement [`Index`] with them (eg: by derives or macros).
+//!
+//! The [`assert_has_fields`] and [`assert_has_fields_mut`] macros fail to compile
+//! when a type doesn't implement [`Index`] or [`IndexMut`] for the name of a field,
+//! with an error that mentions the type, the [`TStr`] of the field name,
+//! and the function in this module whose bound isn't satisfied.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tstr::{assert_has_fields, assert_has_fields_mut, declare_fields};
+//!
+//! declare_fields! {
+//!     struct Person {
+//!         name: String,
+//!         surname: String,
+//!         age: u32,
+//!     }
+//! }
+//!
+//! assert_has_fields!(Person: name, surname);
+//! assert_has_fields_mut!(Person: name: String, age: u32);
+//!
+//! ```
+//!
+//! Asserting that a type has a field that it doesn't have:
+//!
+//! ```compile_fail
+//! use tstr::{assert_has_fields, declare_fields};
+//!
+//! declare_fields! {
+//!     struct Person {
+//!         name: String,
+//!     }
+//! }
+//!
+//! // error: the type `Person` cannot be indexed by `TStr<...>`
+//! assert_has_fields!(Person: name, surname);
+//!
+//! ```
+//!
+//! Asserting the wrong type for a field:
+//!
+//! ```compile_fail
+//! use tstr::{assert_has_fields, declare_fields};
+//!
+//! declare_fields! {
+//!     struct Person {
+//!         age: u32,
+//!     }
+//! }
+//!
+//! // error: type mismatch resolving `<Person as Index<TStr<...>>>::Output == u64`
+//! assert_has_fields!(Person: age: u64);
+//!
+//! ```
+//!
+//! [`TStr`]: ../struct.TStr.html
+//! [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+//! [`IndexMut`]: https://doc.rust-lang.org/core/ops/trait.IndexMut.html
+//! [`assert_has_fields`]: ../macro.assert_has_fields.html
+//! [`assert_has_fields_mut`]: ../macro.assert_has_fields_mut.html
+
+use core::ops::{Index, IndexMut};
+
+/// Asserts that `T` implements `Index<K>`, at compile-time.
+///
+/// # Example
+///
+/// This uses types from the `for_examples` module,
+/// which can be seen in the docs with the "for_examples" feature.
+///
+#[cfg_attr(feature = "for_examples", doc = "```rust")]
+#[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
+/// use tstr::for_examples::Foo;
+/// use tstr::testing::assert_field;
+/// use tstr::TS;
+///
+/// assert_field::<Foo, TS!(bar)>();
+/// ```
+#[inline(always)]
+pub fn assert_field<T, K>()
+where
+    T: ?Sized + Index<K>,
+{
+}
+
+/// Asserts that `T` implements `Index<K, Output = Out>`, at compile-time.
+#[inline(always)]
+pub fn assert_field_type<T, K, Out>()
+where
+    T: ?Sized + Index<K, Output = Out>,
+    Out: ?Sized,
+{
+}
+
+/// Asserts that `T` implements `IndexMut<K>`, at compile-time.
+#[inline(always)]
+pub fn assert_field_mut<T, K>()
+where
+    T: ?Sized + IndexMut<K>,
+{
+}
+
+/// Asserts that `T` implements `IndexMut<K, Output = Out>`, at compile-time.
+#[inline(always)]
+pub fn assert_field_type_mut<T, K, Out>()
+where
+    T: ?Sized + IndexMut<K, Output = Out>,
+    Out: ?Sized,
+{
+}
@@ -0,0 +1,81 @@
+//! Type-level lists, used by the type-level collections of this crate.
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+#[cfg(not(feature = "const_generics"))]
+pub(crate) mod char_list;
+
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+mod sort;
+
+#[cfg(not(feature = "const_generics"))]
+#[cfg_attr(feature = "docsrs", doc(cfg(not(feature = "const_generics"))))]
+pub use self::char_list::{FromCharList, ToCharList};
+
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(feature = "cmp_traits", not(feature = "min_const_generics"))))
+)]
+pub use self::sort::SortTStrs;
+
+/// A type-level list, with `H` as the first element and `T` as the rest of the list.
+///
+/// The end of a list is represented with [`TNil`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::TS;
+///
+/// // A list of the "foo", "bar", and "baz" type-level strings, in that order.
+/// type List = TList<TS!(foo), TList<TS!(bar), TList<TS!(baz), TNil>>>;
+///
+/// let _: List = TList::NEW;
+///
+/// ```
+///
+/// [`TNil`]: ./struct.TNil.html
+pub struct TList<H, T>(PhantomData<fn() -> (H, T)>);
+
+impl<H, T> TList<H, T> {
+    /// Constructs the TList.
+    pub const NEW: Self = TList(PhantomData);
+}
+
+/// The empty type-level list, which terminates every [`TList`].
+///
+/// [`TList`]: ./struct.TList.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TNil;
+
+impl TNil {
+    /// Constructs the TNil.
+    pub const NEW: Self = TNil;
+}
+
+impl<H, T> Copy for TList<H, T> {}
+
+impl<H, T> Clone for TList<H, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H, T> Default for TList<H, T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::NEW
+    }
+}
+
+impl<H, T> Debug for TList<H, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TList").finish()
+    }
+}
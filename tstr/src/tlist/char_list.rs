@@ -0,0 +1,348 @@
+use crate::{
+    tlist::{TList, TNil},
+    TStr,
+};
+
+pub(crate) mod sealed {
+    #[doc(hidden)]
+    pub trait Sealed {}
+
+    /// Prepends the characters of `Self` (a representation of a `TStr`) to `Rest`.
+    #[doc(hidden)]
+    pub trait PushChars<Rest> {
+        type Output;
+    }
+
+    /// Prepends the characters of every node in the `Self` list to `Rest`.
+    #[doc(hidden)]
+    pub trait PushNodes<Rest> {
+        type Output;
+    }
+
+    /// Groups a list of characters into a list of leaves, of up to 8 characters each.
+    #[doc(hidden)]
+    pub trait ChunkLeaves {
+        type Output;
+    }
+
+    /// Groups a list of nodes into tuples of up to 8 nodes,
+    /// leaving a trailing lone node ungrouped.
+    #[doc(hidden)]
+    pub trait GroupNodes {
+        type Output;
+    }
+
+    /// Groups a list of nodes until there's a single node left.
+    #[doc(hidden)]
+    pub trait BuildRepr {
+        type Output;
+    }
+}
+use sealed::{BuildRepr, ChunkLeaves, GroupNodes, PushChars, PushNodes, Sealed};
+
+/// Type-level operator which converts a [`TStr`] into a [`TList`] of its characters.
+///
+/// The list is the same type for every way to write the same string,
+/// and can be converted back into a [`TStr`] with [`FromCharList`].
+/// This allows implementing recursive type-level algorithms over strings
+/// without depending on the representation of [`TStr`].
+///
+/// The elements of the list are unspecified types,
+/// each representing one character of the string
+/// (with the default representation, one UTF-8 byte of the string,
+/// so non-ASCII characters are multiple elements).
+///
+/// Recursing over the characters of long strings can require
+/// raising the `#![recursion_limit]` of the crate.
+///
+/// This is only available without the `"const_generics"` feature,
+/// since `&'static str` const parameters can't be split into characters at the type level.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{FromCharList, TList, TNil, ToCharList};
+/// use tstr::TS;
+///
+/// type Reversed<S> = <<<S as ToCharList>::Output as Reverse<TNil>>::Output as FromCharList>::Output;
+///
+/// let _: TS!("olleh") = <Reversed<TS!("hello")>>::NEW;
+/// let _: TS!("") = <Reversed<TS!("")>>::NEW;
+///
+/// // Reverses a list, prepending its elements to `Acc`
+/// trait Reverse<Acc> {
+///     type Output;
+/// }
+///
+/// impl<Acc> Reverse<Acc> for TNil {
+///     type Output = Acc;
+/// }
+///
+/// impl<H, T, Acc> Reverse<Acc> for TList<H, T>
+/// where
+///     T: Reverse<TList<H, Acc>>,
+/// {
+///     type Output = T::Output;
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`TList`]: ./struct.TList.html
+/// [`FromCharList`]: ./trait.FromCharList.html
+pub trait ToCharList {
+    /// The list of characters.
+    type Output;
+}
+
+impl<T> ToCharList for TStr<T>
+where
+    T: PushChars<TNil>,
+{
+    type Output = T::Output;
+}
+
+/// Type-level operator which converts a [`TList`] of characters back into a [`TStr`].
+///
+/// This is the inverse of [`ToCharList`],
+/// producing the same [`TStr`] type as the [`TS`] macro does for the string.
+///
+/// This trait is sealed, it's only implemented for [`TList`]s and [`TNil`].
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{FromCharList, TList, ToCharList};
+/// use tstr::TS;
+///
+/// type Chars<S> = <S as ToCharList>::Output;
+/// type FromChars<L> = <L as FromCharList>::Output;
+///
+/// // Prepends the characters of `A` to the characters of `B`
+/// type Concat<A, B> = FromChars<<Chars<A> as Prepend<Chars<B>>>::Output>;
+///
+/// let _: TS!("foobar") = <Concat<TS!(foo), TS!(bar)>>::NEW;
+/// let _: TS!("hello, world") = <Concat<TS!("hello, "), TS!("world")>>::NEW;
+///
+/// trait Prepend<Rest> {
+///     type Output;
+/// }
+///
+/// impl<Rest> Prepend<Rest> for tstr::tlist::TNil {
+///     type Output = Rest;
+/// }
+///
+/// impl<H, T, Rest> Prepend<Rest> for TList<H, T>
+/// where
+///     T: Prepend<Rest>,
+/// {
+///     type Output = TList<H, T::Output>;
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`TList`]: ./struct.TList.html
+/// [`TNil`]: ./struct.TNil.html
+/// [`ToCharList`]: ./trait.ToCharList.html
+/// [`TS`]: ../macro.TS.html
+pub trait FromCharList: Sealed {
+    /// The type-level string.
+    type Output;
+}
+
+impl Sealed for TNil {}
+
+impl<H, T> Sealed for TList<H, T> {}
+
+impl<L> FromCharList for L
+where
+    L: Sealed + ChunkLeaves,
+    L::Output: BuildRepr,
+{
+    type Output = TStr<<L::Output as BuildRepr>::Output>;
+}
+
+// Writing nested `TList`s, `cons!(A, B; Tail)` is `TList<A, TList<B, Tail>>`
+macro_rules! cons {
+    (; $tail:ty) => ($tail);
+    ($first:ty $(, $rest:ty)* ; $tail:ty) => (TList<$first, cons!($($rest),* ; $tail)>);
+}
+
+macro_rules! tuple_impls {
+    ($(($($elem:ident)*))*) => {
+        $(
+            impl<$($elem,)* Rest> PushChars<Rest> for ($($elem,)*)
+            where
+                cons!($($elem),* ; TNil): PushNodes<Rest>,
+            {
+                type Output = <cons!($($elem),* ; TNil) as PushNodes<Rest>>::Output;
+            }
+        )*
+    };
+}
+
+macro_rules! group_impls {
+    ($(($($elem:ident)*))*) => {
+        $(
+            impl<$($elem,)*> GroupNodes for cons!($($elem),* ; TNil) {
+                type Output = TList<($($elem,)*), TNil>;
+            }
+        )*
+    };
+}
+
+tuple_impls! {
+    (A)
+    (A B)
+    (A B C)
+    (A B C D)
+    (A B C D E)
+    (A B C D E F)
+    (A B C D E F G)
+    (A B C D E F G H)
+}
+
+group_impls! {
+    (A B)
+    (A B C)
+    (A B C D)
+    (A B C D E)
+    (A B C D E F)
+    (A B C D E F G)
+    (A B C D E F G H)
+}
+
+impl<Rest> PushChars<Rest> for () {
+    type Output = Rest;
+}
+
+impl<Rest> PushNodes<Rest> for TNil {
+    type Output = Rest;
+}
+
+impl<H, T, Rest> PushNodes<Rest> for TList<H, T>
+where
+    T: PushNodes<Rest>,
+    H: PushChars<T::Output>,
+{
+    type Output = H::Output;
+}
+
+impl GroupNodes for TNil {
+    type Output = TNil;
+}
+
+impl<A> GroupNodes for TList<A, TNil> {
+    type Output = TList<A, TNil>;
+}
+
+impl<A, B, C, D, E, F, G, H, I, R> GroupNodes for cons!(A, B, C, D, E, F, G, H; TList<I, R>)
+where
+    TList<I, R>: GroupNodes,
+{
+    type Output = TList<(A, B, C, D, E, F, G, H), <TList<I, R> as GroupNodes>::Output>;
+}
+
+impl BuildRepr for TNil {
+    type Output = ();
+}
+
+impl<A> BuildRepr for TList<A, TNil> {
+    type Output = A;
+}
+
+impl<A, B, R> BuildRepr for TList<A, TList<B, R>>
+where
+    Self: GroupNodes,
+    <Self as GroupNodes>::Output: BuildRepr,
+{
+    type Output = <<Self as GroupNodes>::Output as BuildRepr>::Output;
+}
+
+impl ChunkLeaves for TNil {
+    type Output = TNil;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+mod tupled_impls {
+    use super::{ChunkLeaves, TList, TNil};
+
+    macro_rules! chunk_impls {
+        ($(($($elem:ident)*))*) => {
+            $(
+                impl<$($elem,)*> ChunkLeaves for cons!($($elem),* ; TNil) {
+                    type Output = TList<($($elem,)*), TNil>;
+                }
+            )*
+        };
+    }
+
+    chunk_impls! {
+        (A)
+        (A B)
+        (A B C)
+        (A B C D)
+        (A B C D E)
+        (A B C D E F)
+        (A B C D E F G)
+        (A B C D E F G H)
+    }
+
+    impl<A, B, C, D, E, F, G, H, I, R> ChunkLeaves for cons!(A, B, C, D, E, F, G, H; TList<I, R>)
+    where
+        TList<I, R>: ChunkLeaves,
+    {
+        type Output = TList<(A, B, C, D, E, F, G, H), <TList<I, R> as ChunkLeaves>::Output>;
+    }
+}
+
+#[cfg(feature = "min_const_generics")]
+macro_rules! min_const_impls {
+    ($( ($chars_struct:ident, [$($chars:ident),*]) ,)*) => {
+        $(
+            impl<$(const $chars: char,)* Rest> PushChars<Rest> for crate::$chars_struct<$($chars,)*> {
+                type Output = cons!($(crate::__a<$chars>),* ; Rest);
+            }
+
+            impl<$(const $chars: char,)*> ChunkLeaves for cons!($(crate::__a<$chars>),* ; TNil) {
+                type Output = TList<crate::$chars_struct<$($chars,)*>, TNil>;
+            }
+        )*
+
+        impl<
+            const A: char,
+            const B: char,
+            const C: char,
+            const D: char,
+            const E: char,
+            const F: char,
+            const G: char,
+            const H: char,
+            I,
+            R,
+        > ChunkLeaves
+            for cons!(
+                crate::__a<A>, crate::__a<B>, crate::__a<C>, crate::__a<D>,
+                crate::__a<E>, crate::__a<F>, crate::__a<G>, crate::__a<H>;
+                TList<I, R>
+            )
+        where
+            TList<I, R>: ChunkLeaves,
+        {
+            type Output = TList<crate::__<A, B, C, D, E, F, G, H>, <TList<I, R> as ChunkLeaves>::Output>;
+        }
+    };
+}
+
+#[cfg(feature = "min_const_generics")]
+min_const_impls! {
+    (__a, [A]),
+    (__b, [A,B]),
+    (__c, [A,B,C]),
+    (__d, [A,B,C,D]),
+    (__e, [A,B,C,D,E]),
+    (__f, [A,B,C,D,E,F]),
+    (__g, [A,B,C,D,E,F,G]),
+    (__ , [A,B,C,D,E,F,G,H]),
+}
@@ -0,0 +1,101 @@
+use crate::{
+    for_tupled_reprs::{type_cmp::TStrCmpType, Equal, Greater, Less},
+    tlist::{TList, TNil},
+};
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait Sealed {}
+
+    #[doc(hidden)]
+    pub trait ListInsertSorted<K> {
+        type Output;
+    }
+
+    #[doc(hidden)]
+    pub trait InsertSortedBranch<K, H, T> {
+        type Output;
+    }
+}
+use sealed::{InsertSortedBranch, ListInsertSorted, Sealed};
+
+/// Type-level operator which sorts a [`TList`] of [`TStr`]s lexicographically,
+/// comparing the UTF-8 bytes of the strings.
+///
+/// Lists with the same `TStr`s in different orders are sorted into the same type,
+/// which is useful for giving a canonical type to a collection of keys.
+///
+/// This trait is sealed, it's only implemented for [`TList`]s of [`TStr`]s and [`TNil`].
+///
+/// This is only available with the `"cmp_traits"` feature,
+/// and without the `"min_const_generics"` feature,
+/// since it's only implemented for the default representation of type-level strings.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::tlist::{SortTStrs, TList, TNil};
+/// use tstr::TS;
+///
+/// type Sorted<L> = <L as SortTStrs>::Output;
+///
+/// type FooBarBaz = TList<TS!(foo), TList<TS!(bar), TList<TS!(baz), TNil>>>;
+/// type BazFooBar = TList<TS!(baz), TList<TS!(foo), TList<TS!(bar), TNil>>>;
+///
+/// type Expected = TList<TS!(bar), TList<TS!(baz), TList<TS!(foo), TNil>>>;
+///
+/// let _: Expected = <Sorted<FooBarBaz>>::NEW;
+/// let _: Expected = <Sorted<BazFooBar>>::NEW;
+///
+/// ```
+///
+/// [`TList`]: ./struct.TList.html
+/// [`TNil`]: ./struct.TNil.html
+/// [`TStr`]: ../struct.TStr.html
+pub trait SortTStrs: Sealed {
+    /// The sorted list.
+    type Output;
+}
+
+impl Sealed for TNil {}
+
+impl<H, T> Sealed for TList<H, T> {}
+
+impl SortTStrs for TNil {
+    type Output = TNil;
+}
+
+impl<H, T> SortTStrs for TList<H, T>
+where
+    T: SortTStrs,
+    T::Output: ListInsertSorted<H>,
+{
+    type Output = <T::Output as ListInsertSorted<H>>::Output;
+}
+
+impl<K> ListInsertSorted<K> for TNil {
+    type Output = TList<K, TNil>;
+}
+
+impl<K, H, T> ListInsertSorted<K> for TList<H, T>
+where
+    K: TStrCmpType<H>,
+    K::Output: InsertSortedBranch<K, H, T>,
+{
+    type Output = <K::Output as InsertSortedBranch<K, H, T>>::Output;
+}
+
+impl<K, H, T> InsertSortedBranch<K, H, T> for Less {
+    type Output = TList<K, TList<H, T>>;
+}
+
+impl<K, H, T> InsertSortedBranch<K, H, T> for Equal {
+    type Output = TList<K, TList<H, T>>;
+}
+
+impl<K, H, T> InsertSortedBranch<K, H, T> for Greater
+where
+    T: ListInsertSorted<K>,
+{
+    type Output = TList<H, T::Output>;
+}
@@ -1,9 +1,12 @@
 mod sealed {
     #[doc(hidden)]
+    #[allow(dead_code)]
     pub trait Sealed: Sized {}
 }
 use sealed::Sealed;
 
+use core::num::NonZeroUsize;
+
 /// Converts a [`TStr`] to unsigned integers.
 ///
 /// # Example
@@ -29,6 +32,47 @@ use sealed::Sealed;
 /// assert_eq!(ts!(0xF).to_u128(), 15);
 /// assert_eq!(ts!(0b10000).to_u128(), 16);
 ///
+/// assert_eq!(Zero::NONZERO_USIZE, None);
+/// assert_eq!(N8::NONZERO_USIZE.map(|n| n.get()), Some(8));
+///
+/// assert_eq!(N13::U8, 13u8);
+/// assert_eq!(N16::U64, 16u64);
+/// assert_eq!(ts!(65535).to_u16(), 65535u16);
+///
+/// ```
+///
+/// ### Leading zeros
+///
+/// `ToUint::DIGITS` and `ToUint::LEADING_ZEROS` count the digits of the string,
+/// distinguishing strings that have the same numeric value.
+///
+/// ```rust
+/// use tstr::{ToUint, TS};
+///
+/// type Seven = TS!(7);
+/// type PaddedSeven = TS!("007");
+///
+/// assert_eq!(Seven::U128, PaddedSeven::U128);
+///
+/// assert_eq!(Seven::DIGITS, 1);
+/// assert_eq!(Seven::LEADING_ZEROS, 0);
+///
+/// assert_eq!(PaddedSeven::DIGITS, 3);
+/// assert_eq!(PaddedSeven::LEADING_ZEROS, 2);
+///
+/// assert_eq!(<TS!("000")>::LEADING_ZEROS, 2);
+/// assert_eq!(<TS!(0)>::LEADING_ZEROS, 0);
+///
+/// ```
+///
+/// ### Out of range
+///
+/// Using the narrow-width constants with a value that doesn't fit is a compile-time error.
+///
+/// ```compile_fail
+/// use tstr::{ToUint, TS};
+///
+/// const _: u8 = <TS!(256)>::U8;
 /// ```
 ///
 /// [`TStr`]: ./struct.TStr.html
@@ -38,12 +82,47 @@ pub trait ToUint: Sized {
     /// By default this value is a saturated cast from `Self::U128`.
     const USIZE: usize = u128_as_usize(Self::U128);
 
+    /// The `usize` value of the type, if it's non-zero.
+    ///
+    /// By default this value is computed from `Self::USIZE`.
+    const NONZERO_USIZE: Option<NonZeroUsize> = usize_to_nonzero(Self::USIZE);
+
+    /// The `u8` value of the type.
+    ///
+    /// Using this constant causes a compile-time error if `Self::U128` doesn't fit in a `u8`.
+    const U8: u8 = u128_to_u8(Self::U128);
+
+    /// The `u16` value of the type.
+    ///
+    /// Using this constant causes a compile-time error if `Self::U128` doesn't fit in a `u16`.
+    const U16: u16 = u128_to_u16(Self::U128);
+
+    /// The `u32` value of the type.
+    ///
+    /// Using this constant causes a compile-time error if `Self::U128` doesn't fit in a `u32`.
+    const U32: u32 = u128_to_u32(Self::U128);
+
+    /// The `u64` value of the type.
+    ///
+    /// Using this constant causes a compile-time error if `Self::U128` doesn't fit in a `u64`.
+    const U64: u64 = u128_to_u64(Self::U128);
+
     /// The `u128` value of the type.
     const U128: u128;
 
-    #[doc(hidden)]
+    /// The amount of digits in the string, including leading zeros.
+    ///
+    /// This is `3` for both `TS!("007")` and `TS!(100)`.
     const DIGITS: u32;
 
+    /// The amount of leading zeros in the string,
+    /// not counting the last digit of a string that only contains zeros.
+    ///
+    /// This allows distinguishing strings with the same value, like `"007"` and `"7"`.
+    ///
+    /// By default this value is computed from `Self::DIGITS` and `Self::U128`.
+    const LEADING_ZEROS: u32 = leading_zeros(Self::DIGITS, Self::U128);
+
     /// Gets the usize value of this type
     ///
     /// By default this value is a saturated cast from `Self::U128`.
@@ -51,6 +130,41 @@ pub trait ToUint: Sized {
         Self::USIZE
     }
 
+    /// Gets the usize value of this type, if it's non-zero.
+    ///
+    /// By default this value is computed from `Self::USIZE`.
+    fn to_nonzero_usize(&self) -> Option<NonZeroUsize> {
+        Self::NONZERO_USIZE
+    }
+
+    /// Gets the u8 value of this type
+    ///
+    /// Causes a compile-time error if `Self::U128` doesn't fit in a `u8`.
+    fn to_u8(&self) -> u8 {
+        Self::U8
+    }
+
+    /// Gets the u16 value of this type
+    ///
+    /// Causes a compile-time error if `Self::U128` doesn't fit in a `u16`.
+    fn to_u16(&self) -> u16 {
+        Self::U16
+    }
+
+    /// Gets the u32 value of this type
+    ///
+    /// Causes a compile-time error if `Self::U128` doesn't fit in a `u32`.
+    fn to_u32(&self) -> u32 {
+        Self::U32
+    }
+
+    /// Gets the u64 value of this type
+    ///
+    /// Causes a compile-time error if `Self::U128` doesn't fit in a `u64`.
+    fn to_u64(&self) -> u64 {
+        Self::U64
+    }
+
     /// Gets the u128 value of this type
     fn to_u128(&self) -> u128 {
         Self::U128
@@ -108,7 +222,117 @@ impl_for_const! {}
 #[cfg(not(feature = "const_generics"))]
 mod impl_no_const_generics;
 
+#[cfg(feature = "min_const_generics")]
+mod radix;
+
+#[cfg(feature = "min_const_generics")]
+pub use self::radix::ToUintRadix;
+
+macro_rules! count_at_least_pow_ten {
+    ($n:ident; $($power:literal)*) => {
+        0 $( + ($n >= POW_TEN[$power]) as u32 )*
+    };
+}
+
+// The amount of digits of `n` without leading zeros, `0` for zero.
+const fn significant_digits(n: u128) -> u32 {
+    count_at_least_pow_ten! {
+        n;
+        0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19
+        20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38
+    }
+}
+
+const fn leading_zeros(digits: u32, n: u128) -> u32 {
+    // the last zero of a string that only contains zeros is its significant digit
+    digits - significant_digits(n) - ((n == 0) & (digits != 0)) as u32
+}
+
+/*
+fn main(){
+    let mut accum = 1u128;
+    println!("    {},", accum);
+    while let Some(next) = accum.checked_mul(10) {
+        println!("    {},", next);
+        accum = next;
+    }
+}
+*/
+const POW_TEN: &[u128; 39] = &[
+    1,
+    10,
+    100,
+    1000,
+    10000,
+    100000,
+    1000000,
+    10000000,
+    100000000,
+    1000000000,
+    10000000000,
+    100000000000,
+    1000000000000,
+    10000000000000,
+    100000000000000,
+    1000000000000000,
+    10000000000000000,
+    100000000000000000,
+    1000000000000000000,
+    10000000000000000000,
+    100000000000000000000,
+    1000000000000000000000,
+    10000000000000000000000,
+    100000000000000000000000,
+    1000000000000000000000000,
+    10000000000000000000000000,
+    100000000000000000000000000,
+    1000000000000000000000000000,
+    10000000000000000000000000000,
+    100000000000000000000000000000,
+    1000000000000000000000000000000,
+    10000000000000000000000000000000,
+    100000000000000000000000000000000,
+    1000000000000000000000000000000000,
+    10000000000000000000000000000000000,
+    100000000000000000000000000000000000,
+    1000000000000000000000000000000000000,
+    10000000000000000000000000000000000000,
+    100000000000000000000000000000000000000,
+];
+
+#[allow(clippy::legacy_numeric_constants)]
 const fn u128_as_usize(n: u128) -> usize {
     const MAXU: u128 = usize::max_value() as u128;
     [n, MAXU][(n > MAXU) as usize] as usize
 }
+
+const fn usize_to_nonzero(n: usize) -> Option<NonZeroUsize> {
+    // The `| (n == 0) as usize` ensures that `new_unchecked` is never passed a zero,
+    // since both array elements are evaluated.
+    [
+        None,
+        Some(unsafe { NonZeroUsize::new_unchecked(n | (n == 0) as usize) }),
+    ][(n != 0) as usize]
+}
+
+macro_rules! declare_narrowing_fns {
+    ($($fn_name:ident -> $ty:ident, $msg:literal;)*) => {
+        $(
+            #[allow(clippy::legacy_numeric_constants)]
+            const fn $fn_name(n: u128) -> $ty {
+                // This has the effect of panicking when `n` doesn't fit in the return type.
+                #[allow(clippy::no_effect)]
+                [$msg][(n > $ty::max_value() as u128) as usize];
+
+                n as $ty
+            }
+        )*
+    };
+}
+
+declare_narrowing_fns! {
+    u128_to_u8 -> u8, "Expected the number to fit in a u8";
+    u128_to_u16 -> u16, "Expected the number to fit in a u16";
+    u128_to_u32 -> u32, "Expected the number to fit in a u32";
+    u128_to_u64 -> u64, "Expected the number to fit in a u64";
+}
@@ -1,4 +1,4 @@
-use super::{Sealed, ToUint};
+use super::{Sealed, ToUint, POW_TEN};
 
 #[cfg(not(feature = "min_const_generics"))]
 macro_rules! impl_to_digit {
@@ -95,58 +95,6 @@ impl_to_digits_const! {
     ]
 }
 
-/*
-fn main(){
-    let mut accum = 1u128;
-    println!("    {},", accum);
-    while let Some(next) = accum.checked_mul(10) {
-        println!("    {},", next);
-        accum = next;
-    }
-}
-*/
-const POW_TEN: &[u128; 39] = &[
-    1,
-    10,
-    100,
-    1000,
-    10000,
-    100000,
-    1000000,
-    10000000,
-    100000000,
-    1000000000,
-    10000000000,
-    100000000000,
-    1000000000000,
-    10000000000000,
-    100000000000000,
-    1000000000000000,
-    10000000000000000,
-    100000000000000000,
-    1000000000000000000,
-    10000000000000000000,
-    100000000000000000000,
-    1000000000000000000000,
-    10000000000000000000000,
-    100000000000000000000000,
-    1000000000000000000000000,
-    10000000000000000000000000,
-    100000000000000000000000000,
-    1000000000000000000000000000,
-    10000000000000000000000000000,
-    100000000000000000000000000000,
-    1000000000000000000000000000000,
-    10000000000000000000000000000000,
-    100000000000000000000000000000000,
-    1000000000000000000000000000000000,
-    10000000000000000000000000000000000,
-    100000000000000000000000000000000000,
-    1000000000000000000000000000000000000,
-    10000000000000000000000000000000000000,
-    100000000000000000000000000000000000000,
-];
-
 const fn ten_pow(power: u32) -> u128 {
     POW_TEN[power as usize]
 }
@@ -0,0 +1,227 @@
+use super::{u128_as_usize, u128_to_u16, u128_to_u32, u128_to_u64, u128_to_u8};
+
+/// Converts a [`TStr`] of digits in the `RADIX` base to unsigned integers.
+///
+/// Digits greater than 9 are the ASCII letters, in either case
+/// (eg: `TS!("ff")` is 255 in base 16, `TS!(z)` is 35 in base 36).
+///
+/// This requires the `"min_const_generics"` feature.
+///
+/// Using the constants of this trait with a `RADIX` outside of `2..=36`,
+/// or with a string that has characters that aren't digits in that base,
+/// is a compile-time error.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts, ToUintRadix, TS};
+///
+/// type Red = TS!("ff0000");
+///
+/// assert_eq!(<Red as ToUintRadix<16>>::U32, 0xff0000);
+/// assert_eq!(<TS!("DEADbeef") as ToUintRadix<16>>::U32, 0xDEAD_BEEF);
+/// assert_eq!(<TS!(1010) as ToUintRadix<2>>::U8, 0b1010);
+/// assert_eq!(<TS!(777) as ToUintRadix<8>>::U16, 0o777);
+/// assert_eq!(<TS!(100) as ToUintRadix<10>>::USIZE, 100);
+///
+/// assert_eq!(register_address(ts!("1f")), 31);
+///
+/// fn register_address<K: ToUintRadix<16>>(_key: K) -> u16 {
+///     K::U16
+/// }
+///
+/// ```
+///
+/// ### Invalid digit
+///
+/// ```compile_fail
+/// use tstr::{ToUintRadix, TS};
+///
+/// const _: u128 = <TS!("12") as ToUintRadix<2>>::U128;
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "min_const_generics")))]
+pub trait ToUintRadix<const RADIX: u32>: Sized {
+    /// The `usize` value of the type.
+    ///
+    /// By default this value is a saturated cast from `Self::U128`.
+    const USIZE: usize = u128_as_usize(Self::U128);
+
+    /// The `u8` value of the type.
+    ///
+    /// Using this constant causes a compile-time error if `Self::U128` doesn't fit in a `u8`.
+    const U8: u8 = u128_to_u8(Self::U128);
+
+    /// The `u16` value of the type.
+    ///
+    /// Using this constant causes a compile-time error if `Self::U128` doesn't fit in a `u16`.
+    const U16: u16 = u128_to_u16(Self::U128);
+
+    /// The `u32` value of the type.
+    ///
+    /// Using this constant causes a compile-time error if `Self::U128` doesn't fit in a `u32`.
+    const U32: u32 = u128_to_u32(Self::U128);
+
+    /// The `u64` value of the type.
+    ///
+    /// Using this constant causes a compile-time error if `Self::U128` doesn't fit in a `u64`.
+    const U64: u64 = u128_to_u64(Self::U128);
+
+    /// The `u128` value of the type.
+    const U128: u128;
+
+    #[doc(hidden)]
+    const DIGITS: u32;
+
+    /// Gets the usize value of this type
+    ///
+    /// By default this value is a saturated cast from `Self::U128`.
+    fn to_usize(&self) -> usize {
+        Self::USIZE
+    }
+
+    /// Gets the u128 value of this type
+    fn to_u128(&self) -> u128 {
+        Self::U128
+    }
+}
+
+#[allow(clippy::manual_range_contains)]
+const fn digit_value(c: char, radix: u32) -> u128 {
+    // This has the effect of panicking on radixes outside of `2..=36`.
+    #[allow(clippy::no_effect)]
+    ["Expected the radix to be in the 2..=36 range"][(radix < 2 || radix > 36) as usize];
+
+    let value = match c {
+        '0'..='9' => c as u32 - '0' as u32,
+        'a'..='z' => c as u32 - 'a' as u32 + 10,
+        'A'..='Z' => c as u32 - 'A' as u32 + 10,
+        _ => radix,
+    };
+
+    // This has the effect of panicking on characters that aren't digits in the radix.
+    #[allow(clippy::no_effect)]
+    ["Expected all characters to be digits in the radix"][(value >= radix) as usize];
+
+    value as u128
+}
+
+// Appends `digits` digits with the `value` value to `accum`.
+//
+// This multiplies `accum` once per digit instead of computing a power of the radix,
+// so that leading zeros can't overflow.
+#[cfg(not(feature = "const_generics"))]
+const fn append_digits(mut accum: u128, radix: u32, digits: u32, value: u128) -> u128 {
+    let mut i = 0;
+    while i != digits {
+        accum *= radix as u128;
+        i += 1;
+    }
+    accum + value
+}
+
+impl<T, const RADIX: u32> ToUintRadix<RADIX> for crate::TStr<T>
+where
+    T: ToUintRadix<RADIX>,
+{
+    const U128: u128 = T::U128;
+    const DIGITS: u32 = T::DIGITS;
+}
+
+#[cfg(feature = "const_generics")]
+macro_rules! impl_for_const {
+    () => {
+        const fn str_to_u128(s: &str, radix: u32) -> u128 {
+            let s = s.as_bytes();
+            let mut out = 0u128;
+            let mut index = 0usize;
+
+            while index < s.len() {
+                out = out * radix as u128 + digit_value(s[index] as char, radix);
+                index += 1;
+            }
+            out
+        }
+
+        impl<const S: &'static str, const RADIX: u32> ToUintRadix<RADIX> for crate::___<S> {
+            const U128: u128 = str_to_u128(S, RADIX);
+            const DIGITS: u32 = S.len() as u32;
+        }
+    };
+}
+
+#[cfg(feature = "const_generics")]
+impl_for_const! {}
+
+#[cfg(not(feature = "const_generics"))]
+macro_rules! impl_for_chars {
+    ($( ($chars_structs:ident, [$($chars:ident),*], $len:expr) ,)*) => {
+        $(
+            impl<$(const $chars: char,)* const RADIX: u32> ToUintRadix<RADIX>
+                for crate::$chars_structs<$($chars,)*>
+            {
+                const U128: u128 = {
+                    let mut sum = 0u128;
+                    $(
+                        sum = append_digits(sum, RADIX, 1, digit_value($chars, RADIX));
+                    )*
+                    sum
+                };
+                const DIGITS: u32 = $len;
+            }
+        )*
+    }
+}
+
+#[cfg(not(feature = "const_generics"))]
+impl_for_chars! {
+    (__a, [A], 1),
+    (__b, [A,B], 2),
+    (__c, [A,B,C], 3),
+    (__d, [A,B,C,D], 4),
+    (__e, [A,B,C,D,E], 5),
+    (__f, [A,B,C,D,E,F], 6),
+    (__g, [A,B,C,D,E,F,G], 7),
+    (__ , [A,B,C,D,E,F,G,H], 8),
+}
+
+#[cfg(not(feature = "const_generics"))]
+macro_rules! tuple_impl {
+    ($($ty:ident)*) => (
+        #[doc(hidden)]
+        impl<$($ty,)* const RADIX: u32> ToUintRadix<RADIX> for ($($ty,)*)
+        where
+            $($ty: ToUintRadix<RADIX>,)*
+        {
+            const U128: u128 = {
+                #[allow(unused_mut)]
+                let mut sum = 0u128;
+                $(
+                    sum = append_digits(sum, RADIX, $ty::DIGITS, $ty::U128);
+                )*
+                sum
+            };
+            const DIGITS: u32 = 0 $( + $ty::DIGITS )*;
+        }
+    )
+}
+
+#[cfg(not(feature = "const_generics"))]
+tuple_impl! {}
+#[cfg(not(feature = "const_generics"))]
+tuple_impl! {A }
+#[cfg(not(feature = "const_generics"))]
+tuple_impl! {A B}
+#[cfg(not(feature = "const_generics"))]
+tuple_impl! {A B C}
+#[cfg(not(feature = "const_generics"))]
+tuple_impl! {A B C D}
+#[cfg(not(feature = "const_generics"))]
+tuple_impl! {A B C D E}
+#[cfg(not(feature = "const_generics"))]
+tuple_impl! {A B C D E F}
+#[cfg(not(feature = "const_generics"))]
+tuple_impl! {A B C D E F G}
+#[cfg(not(feature = "const_generics"))]
+tuple_impl! {A B C D E F G H}
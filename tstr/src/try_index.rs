@@ -0,0 +1,113 @@
+use core::ops::{Index, IndexMut};
+
+use crate::TStr;
+
+/// For fallibly indexing with a type-level string,
+/// returning `None` when the value isn't there.
+///
+/// This is implemented for every type that implements [`Index`] for a [`TStr`],
+/// always returning `Some`.
+/// Types that can't implement [`Index`] for a key
+/// (because the value for that key can be missing)
+/// can implement this trait instead.
+///
+/// # Example
+///
+/// This uses types from the `for_examples` module,
+/// which can be seen in the docs with the "for_examples" feature.
+///
+#[cfg_attr(feature = "for_examples", doc = "```rust")]
+#[cfg_attr(not(feature = "for_examples"), doc = "```ignore")]
+/// use tstr::for_examples::Foo;
+/// use tstr::{TryIndex, TryIndexMut, TS, ts};
+///
+/// let mut config = Config { port: None };
+///
+/// assert_eq!(get_copy(&config, ts!(port)), None);
+///
+/// assert!(config.try_index_mut(ts!(port)).is_none());
+///
+/// config.port = Some(80);
+/// assert_eq!(get_copy(&config, ts!(port)), Some(80));
+///
+/// *config.try_index_mut(ts!(port)).unwrap() += 1;
+/// assert_eq!(get_copy(&config, ts!(port)), Some(81));
+///
+/// // `TryIndex` is implemented for all types that implement `Index` for `TStr`s
+/// assert_eq!(get_copy(&Foo::new(3, 5, "8"), ts!(bar)), Some(3));
+///
+/// fn get_copy<T, K>(this: &T, key: K) -> Option<T::Output>
+/// where
+///     T: TryIndex<K>,
+///     T::Output: Copy,
+/// {
+///     this.try_index(key).copied()
+/// }
+///
+/// struct Config {
+///     port: Option<u16>,
+/// }
+///
+/// impl TryIndex<TS!(port)> for Config {
+///     type Output = u16;
+///
+///     fn try_index(&self, _: TS!(port)) -> Option<&u16> {
+///         self.port.as_ref()
+///     }
+/// }
+///
+/// impl TryIndexMut<TS!(port)> for Config {
+///     fn try_index_mut(&mut self, _: TS!(port)) -> Option<&mut u16> {
+///         self.port.as_mut()
+///     }
+/// }
+///
+/// ```
+///
+/// [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+/// [`TStr`]: ./struct.TStr.html
+pub trait TryIndex<K> {
+    /// The type of the value for the `K` key.
+    type Output: ?Sized;
+
+    /// Gets a reference to the value for `key`, if there is one.
+    fn try_index(&self, key: K) -> Option<&Self::Output>;
+}
+
+/// For fallibly mutably indexing with a type-level string,
+/// returning `None` when the value isn't there.
+///
+/// This is implemented for every type that implements [`IndexMut`] for a [`TStr`],
+/// always returning `Some`.
+///
+/// For more details, and examples, you can look at [`TryIndex`].
+///
+/// [`IndexMut`]: https://doc.rust-lang.org/core/ops/trait.IndexMut.html
+/// [`TStr`]: ./struct.TStr.html
+/// [`TryIndex`]: ./trait.TryIndex.html
+pub trait TryIndexMut<K>: TryIndex<K> {
+    /// Gets a mutable reference to the value for `key`, if there is one.
+    fn try_index_mut(&mut self, key: K) -> Option<&mut Self::Output>;
+}
+
+impl<This, T> TryIndex<TStr<T>> for This
+where
+    This: ?Sized + Index<TStr<T>>,
+{
+    type Output = This::Output;
+
+    #[inline(always)]
+    fn try_index(&self, key: TStr<T>) -> Option<&Self::Output> {
+        Some(&self[key])
+    }
+}
+
+impl<This, T> TryIndexMut<TStr<T>> for This
+where
+    This: ?Sized + IndexMut<TStr<T>>,
+{
+    #[inline(always)]
+    fn try_index_mut(&mut self, key: TStr<T>) -> Option<&mut Self::Output> {
+        Some(&mut self[key])
+    }
+}
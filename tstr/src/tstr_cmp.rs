@@ -3,6 +3,9 @@ use crate::TStr;
 #[cfg(not(feature = "const_generics"))]
 mod impl_no_const_generics;
 
+#[cfg(feature = "nightly_generic_const_exprs")]
+mod impl_nightly_const_generics;
+
 /// For equality comparison between type-level strings.
 ///
 /// # Examples
@@ -68,6 +71,23 @@ mod impl_no_const_generics;
 /// ```
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "cmp_traits")))]
 pub trait TStrEq<Rhs>: Sized {
+    /// Whether `Self` equals `Rhs`, as a type-level boolean.
+    ///
+    /// This is [`True`] if the strings are equal, and [`False`] if they aren't,
+    /// allowing trait impls to be selected based on whether the strings are equal.
+    ///
+    /// This is only available without the `"min_const_generics"` feature,
+    /// since it's only implemented for the default representation of type-level strings.
+    ///
+    /// [`True`]: ./struct.True.html
+    /// [`False`]: ./struct.False.html
+    #[cfg(not(feature = "min_const_generics"))]
+    #[cfg_attr(
+        feature = "docsrs",
+        doc(cfg(all(feature = "cmp_traits", not(feature = "min_const_generics"))))
+    )]
+    type Output: crate::Boolean;
+
     /// Whether `Self` equals `Rhs`
     const EQ: bool;
 
@@ -87,15 +107,13 @@ pub trait TStrEq<Rhs>: Sized {
     }
 }
 
-#[cfg(not(feature = "min_const_generics"))]
-pub trait U8Repr {
-    const REPR: u8;
-}
-
 impl<T, U> TStrEq<TStr<U>> for TStr<T>
 where
     T: TStrEq<U>,
 {
+    #[cfg(not(feature = "min_const_generics"))]
+    type Output = T::Output;
+
     const EQ: bool = T::EQ;
 }
 
@@ -156,6 +174,106 @@ where
     const CMP: core::cmp::Ordering = T::CMP;
 }
 
+/// Type-level operator for getting the lesser and greater of two type-level strings,
+/// comparing their UTF-8 bytes lexicographically.
+///
+/// The [`TStrMin`] and [`TStrMax`] type aliases are more convenient to use than this trait.
+///
+/// This is only available with the `"cmp_traits"` feature,
+/// and either without the `"min_const_generics"` feature or with the `"nightly_generic_const_exprs"` feature,
+/// since it's only implemented for the default representation of type-level strings,
+/// and for the `&'static str` const parameter with `generic_const_exprs`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TStrMax, TStrMin, TS, ts};
+///
+/// let _: TS!(bar) = <TStrMin<TS!(foo), TS!(bar)>>::NEW;
+/// let _: TS!(foo) = <TStrMax<TS!(foo), TS!(bar)>>::NEW;
+///
+/// // short strings can be greater than longer strings
+/// let _: TS!(aaaaaa) = <TStrMin<TS!(foo), TS!(aaaaaa)>>::NEW;
+/// let _: TS!(foo) = <TStrMax<TS!(foo), TS!(aaaaaa)>>::NEW;
+///
+/// ```
+///
+/// [`TStrMin`]: ./type.TStrMin.html
+/// [`TStrMax`]: ./type.TStrMax.html
+#[cfg(any(
+    not(feature = "min_const_generics"),
+    feature = "nightly_generic_const_exprs"
+))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(
+        feature = "cmp_traits",
+        any(
+            not(feature = "min_const_generics"),
+            feature = "nightly_generic_const_exprs"
+        )
+    )))
+)]
+pub trait TStrMinMax<Rhs> {
+    /// The lesser of `Self` and `Rhs`, which is `Self` if they're equal.
+    type Min;
+
+    /// The greater of `Self` and `Rhs`, which is `Rhs` if they're equal.
+    type Max;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+impl<L, R> TStrMinMax<R> for L
+where
+    L: crate::for_tupled_reprs::type_cmp::TStrCmpType<R>,
+    L::Output: impl_no_const_generics::SelectMinMax<L, R>,
+{
+    type Min = <L::Output as impl_no_const_generics::SelectMinMax<L, R>>::Min;
+    type Max = <L::Output as impl_no_const_generics::SelectMinMax<L, R>>::Max;
+}
+
+/// The lesser of the `A` and `B` type-level strings.
+///
+/// For examples, look at the docs for [`TStrMinMax`].
+///
+/// [`TStrMinMax`]: ./trait.TStrMinMax.html
+#[cfg(any(
+    not(feature = "min_const_generics"),
+    feature = "nightly_generic_const_exprs"
+))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(
+        feature = "cmp_traits",
+        any(
+            not(feature = "min_const_generics"),
+            feature = "nightly_generic_const_exprs"
+        )
+    )))
+)]
+pub type TStrMin<A, B> = <A as TStrMinMax<B>>::Min;
+
+/// The greater of the `A` and `B` type-level strings.
+///
+/// For examples, look at the docs for [`TStrMinMax`].
+///
+/// [`TStrMinMax`]: ./trait.TStrMinMax.html
+#[cfg(any(
+    not(feature = "min_const_generics"),
+    feature = "nightly_generic_const_exprs"
+))]
+#[cfg_attr(
+    feature = "docsrs",
+    doc(cfg(all(
+        feature = "cmp_traits",
+        any(
+            not(feature = "min_const_generics"),
+            feature = "nightly_generic_const_exprs"
+        )
+    )))
+)]
+pub type TStrMax<A, B> = <A as TStrMinMax<B>>::Max;
+
 #[cfg(feature = "const_generics")]
 macro_rules! impl_const_generics {
     () => {
@@ -0,0 +1,29 @@
+use crate::{utils::str_cmp, TStr, ___};
+
+use super::TStrMinMax;
+
+pub struct Bool<const B: bool>;
+
+// `Self` is whether `L` is greater than `R`
+pub trait SelectMinMax<L, R> {
+    type Min;
+    type Max;
+}
+
+impl<L, R> SelectMinMax<L, R> for Bool<true> {
+    type Min = R;
+    type Max = L;
+}
+
+impl<L, R> SelectMinMax<L, R> for Bool<false> {
+    type Min = L;
+    type Max = R;
+}
+
+impl<const S: &'static str, const Z: &'static str> TStrMinMax<TStr<___<Z>>> for TStr<___<S>>
+where
+    Bool<{ str_cmp(S, Z).is_gt() }>: SelectMinMax<Self, TStr<___<Z>>>,
+{
+    type Min = <Bool<{ str_cmp(S, Z).is_gt() }> as SelectMinMax<Self, TStr<___<Z>>>>::Min;
+    type Max = <Bool<{ str_cmp(S, Z).is_gt() }> as SelectMinMax<Self, TStr<___<Z>>>>::Max;
+}
@@ -1,34 +1,42 @@
 use crate::for_tupled_reprs::{
-    classify::{
-        Classify, HasSameKindNumber, Tuple0, Tuple1, Tuple2, Tuple3, Tuple4, Tuple5, Tuple6,
-        Tuple7, Tuple8,
-    },
-    False, True,
+    classify::{Classify, Tuple0, Tuple1, Tuple2, Tuple3, Tuple4, Tuple5, Tuple6, Tuple7, Tuple8},
+    False,
 };
 
+#[cfg(feature = "min_const_generics")]
+use crate::for_tupled_reprs::{classify::HasSameKindNumber, True};
+
 #[allow(unused_imports)]
 use crate::for_tupled_reprs::classify::{
     Chars1, Chars2, Chars3, Chars4, Chars5, Chars6, Chars7, Chars8,
 };
 
 #[cfg(not(feature = "min_const_generics"))]
-use super::U8Repr;
+use crate::{for_tupled_reprs::type_cmp::ReprEq, Boolean};
 
 use super::TStrEq;
 
+#[cfg(feature = "min_const_generics")]
 pub trait PrivTStrEq<R, EqKind> {
     const EQ: bool;
 }
+
+#[cfg(feature = "min_const_generics")]
 impl<L, R> PrivTStrEq<R, False> for L {
     const EQ: bool = false;
 }
 
+#[cfg(feature = "min_const_generics")]
 pub trait TStrEqTupInner<R, IsByteL, IsByteR> {
     const EQ_INNER: bool;
 }
+
+#[cfg(feature = "min_const_generics")]
 impl<L, R> TStrEqTupInner<R, False, True> for L {
     const EQ_INNER: bool = false;
 }
+
+#[cfg(feature = "min_const_generics")]
 impl<L, R> TStrEqTupInner<R, True, False> for L {
     const EQ_INNER: bool = false;
 }
@@ -92,6 +100,7 @@ macro_rules! tuple_impl {
             type KindNumber = $tup_kind_num;
         }
 
+        #[cfg(feature = "min_const_generics")]
         impl<$fty, $($ty,)* DI, Rhs> TStrEq<Rhs> for ($fty, $($ty,)*)
         where
             Self: HasSameKindNumber<Rhs, DoesIt = DI>,
@@ -100,6 +109,20 @@ macro_rules! tuple_impl {
             const EQ: bool = <Self as PrivTStrEq<Rhs, DI>>::EQ;
         }
 
+        // With the tupled representation, the `bool` is derived from the type-level boolean,
+        // so that the strings are only compared once.
+        #[cfg(not(feature = "min_const_generics"))]
+        impl<$fty, $($ty,)* Rhs> TStrEq<Rhs> for ($fty, $($ty,)*)
+        where
+            Self: ReprEq<Rhs>,
+            <Self as ReprEq<Rhs>>::Output: Boolean,
+        {
+            type Output = <Self as ReprEq<Rhs>>::Output;
+
+            const EQ: bool = <<Self as ReprEq<Rhs>>::Output as Boolean>::VALUE;
+        }
+
+        #[cfg(feature = "min_const_generics")]
         impl<$fty, $($ty,)* $ofty, $($oty,)*>
             PrivTStrEq<($ofty, $($oty,)*), True>
         for ($fty, $($ty,)*)
@@ -111,22 +134,7 @@ macro_rules! tuple_impl {
             const EQ: bool = Self::EQ_INNER;
         }
 
-        #[cfg(not(feature = "min_const_generics"))]
-        impl<$fty, $($ty,)* $ofty, $($oty,)*>
-            TStrEqTupInner<($ofty, $($oty,)*), True, True>
-        for ($fty, $($ty,)*)
-        where
-            $fty: U8Repr,
-            $ofty: U8Repr,
-            $($ty: U8Repr,)*
-            $($oty: U8Repr,)*
-        {
-            const EQ_INNER: bool = {
-                $fty::REPR == $ofty::REPR
-                $( && $ty::REPR == $oty::REPR )*
-            };
-        }
-
+        #[cfg(feature = "min_const_generics")]
         impl<$fty, $($ty,)* $ofty, $($oty,)*>
             TStrEqTupInner<($ofty, $($oty,)*), False, False>
         for ($fty, $($ty,)*)
@@ -172,6 +180,7 @@ impl Classify for () {
     type KindNumber = Tuple0;
 }
 
+#[cfg(feature = "min_const_generics")]
 impl<Rhs, DI> TStrEq<Rhs> for ()
 where
     Self: HasSameKindNumber<Rhs, DoesIt = DI>,
@@ -180,6 +189,43 @@ where
     const EQ: bool = <Self as PrivTStrEq<Rhs, DI>>::EQ;
 }
 
+#[cfg(not(feature = "min_const_generics"))]
+impl<Rhs> TStrEq<Rhs> for ()
+where
+    Self: ReprEq<Rhs>,
+    <Self as ReprEq<Rhs>>::Output: Boolean,
+{
+    type Output = <Self as ReprEq<Rhs>>::Output;
+
+    const EQ: bool = <<Self as ReprEq<Rhs>>::Output as Boolean>::VALUE;
+}
+
+#[cfg(feature = "min_const_generics")]
 impl PrivTStrEq<(), True> for () {
     const EQ: bool = true;
 }
+
+/// Selects the lesser and greater of `L` and `R`, where `Self` is the ordering of `L` to `R`.
+#[cfg(not(feature = "min_const_generics"))]
+pub trait SelectMinMax<L, R> {
+    type Min;
+    type Max;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+impl<L, R> SelectMinMax<L, R> for crate::for_tupled_reprs::Less {
+    type Min = L;
+    type Max = R;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+impl<L, R> SelectMinMax<L, R> for crate::for_tupled_reprs::Equal {
+    type Min = L;
+    type Max = R;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+impl<L, R> SelectMinMax<L, R> for crate::for_tupled_reprs::Greater {
+    type Min = R;
+    type Max = L;
+}
@@ -0,0 +1,195 @@
+#[cfg(feature = "proc_macros")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "proc_macros")))]
+pub use tstr_proc_macros::TStrKeys;
+
+/// Reflection information about the variants of an enum, using [`TStr`]s of their names.
+///
+/// # Deriving
+///
+/// This trait can be derived for enums,
+/// with the `#[derive(tstr::TStrKeys)]` derive macro
+/// (which requires the default `"proc_macros"` feature).
+///
+/// The derive generates:
+///
+/// - An impl of this trait.
+///
+/// - An impl of [`ToTStr`], which passes the [`TStr`] of the name of the current variant
+/// to a [`VariantVisitor`].
+///
+/// - An impl of [`FromTStr`] for the [`TStr`] of the name of every variant without fields.
+///
+/// - An associated constant with the [`TStr`] of the name of every variant,
+/// named like the variant in `SCREAMING_SNAKE_CASE` with a `_KEY` suffix
+/// (`WaitingForInput` gets a `WAITING_FOR_INPUT_KEY` constant),
+/// with the same visibility as the enum.
+///
+/// The derive generates code that refers to this crate as `::tstr`,
+/// which can be changed with the `#[tstr(crate = path::to::tstr)]` attribute.
+///
+/// # Example
+///
+/// This example demonstrates a state machine, with the transitions keyed by
+/// the names of the variants.
+///
+/// ```rust
+/// use tstr::tlist::{TList, TNil};
+/// use tstr::{FromTStr, TStrKeys, TS};
+///
+/// #[derive(Debug, PartialEq, TStrKeys)]
+/// enum State {
+///     Idle,
+///     Running,
+///     Failed(String),
+/// }
+///
+/// assert_eq!(State::VARIANT_NAMES, ["Idle", "Running", "Failed"]);
+/// assert_eq!(State::Failed("oops".into()).variant_name(), "Failed");
+///
+/// let _: <State as TStrKeys>::Keys =
+///     TList::<TS!(Idle), TList<TS!(Running), TList<TS!(Failed), TNil>>>::NEW;
+///
+/// // variants without fields can be constructed from the TStr of their name
+/// assert_eq!(State::from_tstr(State::IDLE_KEY), State::Idle);
+/// assert_eq!(transition(State::Idle, State::RUNNING_KEY), State::Running);
+///
+/// fn transition<K>(_from: State, to: K) -> State
+/// where
+///     State: FromTStr<K>,
+/// {
+///     State::from_tstr(to)
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`ToTStr`]: ./trait.ToTStr.html
+/// [`FromTStr`]: ./trait.FromTStr.html
+/// [`VariantVisitor`]: ./trait.VariantVisitor.html
+pub trait TStrKeys {
+    /// A [`TList`] of the [`TStr`]s of the names of the variants, in declaration order.
+    ///
+    /// [`TList`]: ./tlist/struct.TList.html
+    /// [`TStr`]: ./struct.TStr.html
+    type Keys;
+
+    /// The names of the variants, in declaration order.
+    const VARIANT_NAMES: &'static [&'static str];
+
+    /// Gets the name of the variant of `self`.
+    fn variant_name(&self) -> &'static str;
+}
+
+/// For constructing the variant named `K`, where `K` is a [`TStr`].
+///
+/// This is implemented by the [`TStrKeys`] derive,
+/// for every variant without fields.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{FromTStr, TStrKeys, ts};
+///
+/// #[derive(Debug, PartialEq, TStrKeys)]
+/// enum Direction {
+///     Up,
+///     Down,
+/// }
+///
+/// assert_eq!(Direction::from_tstr(ts!(Up)), Direction::Up);
+/// assert_eq!(Direction::from_tstr(ts!(Down)), Direction::Down);
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TStrKeys`]: ./trait.TStrKeys.html
+pub trait FromTStr<K>: Sized {
+    /// Constructs the variant named `K`.
+    fn from_tstr(key: K) -> Self;
+}
+
+/// Visitor for the [`TStr`] of the name of a variant, passed to [`ToTStr::to_tstr`].
+///
+/// `K` is the [`TStr`] of the name of the variant.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TStrKeys, ToTStr, VariantVisitor, TS};
+///
+/// #[derive(TStrKeys)]
+/// enum Event {
+///     Click { x: u32, y: u32 },
+///     KeyPress(char),
+/// }
+///
+/// let mut counter = ClickCounter(0);
+///
+/// Event::Click{x: 3, y: 5}.to_tstr(&mut counter);
+/// Event::KeyPress('a').to_tstr(&mut counter);
+/// Event::Click{x: 8, y: 13}.to_tstr(&mut counter);
+///
+/// assert_eq!(counter.0, 2);
+///
+/// struct ClickCounter(u32);
+///
+/// impl VariantVisitor<TS!(Click)> for ClickCounter {
+///     fn visit(&mut self, _: TS!(Click), _: &'static str) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// impl VariantVisitor<TS!(KeyPress)> for ClickCounter {
+///     fn visit(&mut self, _: TS!(KeyPress), _: &'static str) {}
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`ToTStr::to_tstr`]: ./trait.ToTStr.html#tymethod.to_tstr
+pub trait VariantVisitor<K> {
+    /// Visits the variant named `name`, with `name_str` as the name of the variant as a string.
+    fn visit(&mut self, name: K, name_str: &'static str);
+}
+
+/// For passing the [`TStr`] of the name of the current variant to a `V` visitor.
+///
+/// This is implemented by the [`TStrKeys`] derive,
+/// requiring `V` to implement [`VariantVisitor`] for the name of every variant.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{TStrKeys, ToTStr, VariantVisitor};
+///
+/// #[derive(TStrKeys)]
+/// enum Shape {
+///     Circle(f64),
+///     Square(f64),
+/// }
+///
+/// let mut names = Names(Vec::new());
+/// Shape::Square(1.0).to_tstr(&mut names);
+/// Shape::Circle(2.0).to_tstr(&mut names);
+///
+/// assert_eq!(names.0, ["Square", "Circle"]);
+///
+/// struct Names(Vec<&'static str>);
+///
+/// impl<K> VariantVisitor<K> for Names {
+///     fn visit(&mut self, _: K, name_str: &'static str) {
+///         self.0.push(name_str);
+///     }
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`TStrKeys`]: ./trait.TStrKeys.html
+/// [`VariantVisitor`]: ./trait.VariantVisitor.html
+pub trait ToTStr<V> {
+    /// Calls `visitor` with the [`TStr`] of the name of the variant of `self`.
+    ///
+    /// [`TStr`]: ./struct.TStr.html
+    fn to_tstr(&self, visitor: &mut V);
+}
@@ -5,6 +5,40 @@ use core::{
 
 /// A type-level string type, similar to a `&'static str` const parameter.
 ///
+/// # Const construction
+///
+/// `TStr`s are zero-sized, and can be constructed in every const context,
+/// regardless of the enabled features:
+///
+/// - The [`ts`] macro can be used in `const` and `static` items.
+///
+/// - Generic code can construct a `TStr<T>` with the [`NEW`](#associatedconstant.NEW)
+/// associated constant, which doesn't have any requirements on `T`.
+///
+/// ```rust
+/// use tstr::{TStr, TS, ts};
+///
+/// const FOO: TS!(foo) = ts!(foo);
+/// static BAR: TS!("bar baz") = ts!("bar baz");
+/// const PAIR: (TS!(a), TS!(0)) = ts!(a, 0);
+///
+/// struct Key<T> {
+///     name: TStr<T>,
+/// }
+///
+/// impl<T> Key<T> {
+///     const KEY: Self = Key { name: TStr::NEW };
+/// }
+///
+/// fn key_name<T>() -> TStr<T> {
+///     Key::<T>::KEY.name
+/// }
+///
+/// let _: TS!(hello) = key_name();
+/// # let _ = (FOO, BAR, PAIR);
+///
+/// ```
+///
 /// # Examples
 ///
 /// ### Accessing Fields
@@ -89,11 +123,15 @@ use core::{
 ///
 /// ```
 ///
+/// [`ts`]: ./macro.ts.html
 pub struct TStr<T>(pub(crate) PhantomData<fn() -> T>);
 
 impl<T> TStr<T> {
     /// Constructs the TStr.
     ///
+    /// This can be used in const contexts of generic code,
+    /// without requiring any trait bounds.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -104,6 +142,8 @@ impl<T> TStr<T> {
     /// let foo_1: FOO = TStr::NEW;
     /// let foo_2 = FOO::NEW; // The same as the previous statement
     ///
+    /// const FOO_3: FOO = TStr::NEW;
+    ///
     /// ```
     pub const NEW: Self = TStr(PhantomData);
 }
@@ -147,6 +187,21 @@ macro_rules! const_generics_using {
             /// The `&'static str` value of this `TStr`.
             const STR: &'static str;
 
+            /// The UTF-8 bytes of this `TStr`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use tstr::{StrValue, TS};
+            ///
+            /// const HEADER: &[u8] = <TS!("GET ") as StrValue>::BYTES;
+            ///
+            /// assert_eq!(HEADER, b"GET ");
+            /// assert_eq!(<TS!("ñ") as StrValue>::BYTES, [0xC3, 0xB1]);
+            ///
+            /// ```
+            const BYTES: &'static [u8] = Self::STR.as_bytes();
+
             /// Gets the `&'static str` value of this `TStr`.
             fn to_str(self) -> &'static str {
                 Self::STR
@@ -178,6 +233,84 @@ macro_rules! const_generics_using {
             ///
             /// ```
             pub const STR: &'static str = <Self as StrValue>::STR;
+
+            /// The UTF-8 bytes of this `TStr`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use tstr::TS;
+            ///
+            /// type FOO = TS!(foo);
+            ///
+            /// assert_eq!(FOO::BYTES, b"foo");
+            ///
+            /// ```
+            pub const BYTES: &'static [u8] = <Self as StrValue>::BYTES;
+        }
+
+        /// For converting a [`TStr`] into an `[u8; N]` array of its UTF-8 bytes.
+        ///
+        /// `N` must be the length of the string in bytes,
+        /// using any other length is a compile-time error.
+        /// `N` can usually be inferred from the type that the array is used as.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use tstr::{ToArray, TS, ts};
+        ///
+        /// const KEY: [u8; 4] = <TS!(name) as ToArray<4>>::ARRAY;
+        /// assert_eq!(KEY, *b"name");
+        ///
+        /// // the lengths of the arrays are inferred
+        /// let packet: ([u8; 3], [u8; 2]) = (ts!(foo).to_array(), ts!("ñ").to_array());
+        /// assert_eq!(packet, (*b"foo", [0xC3, 0xB1]));
+        ///
+        /// ```
+        ///
+        /// Using the wrong array length:
+        ///
+        /// ```compile_fail
+        /// use tstr::{ToArray, ts};
+        ///
+        /// let array: [u8; 2] = ts!(foo).to_array();
+        /// ```
+        ///
+        /// [`TStr`]: ./struct.TStr.html
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+        pub trait ToArray<const N: usize>: StrValue {
+            /// The UTF-8 bytes of this `TStr`, as an array.
+            const ARRAY: [u8; N];
+
+            /// Gets the UTF-8 bytes of this `TStr`, as an array.
+            fn to_array(self) -> [u8; N] {
+                Self::ARRAY
+            }
+        }
+
+        #[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+        impl<const S: &'static str, const N: usize> ToArray<N> for TStr<crate::___<S>> {
+            const ARRAY: [u8; N] = str_to_array::<N>(S);
+        }
+
+        const fn str_to_array<const N: usize>(string: &'static str) -> [u8; N] {
+            if string.len() != N {
+                crate::asserts::const_panic(&[
+                    "expected the array length to be the length of the string `",
+                    string,
+                    "`",
+                ]);
+            }
+
+            let bytes = string.as_bytes();
+            let mut array = [0u8; N];
+            let mut i = 0;
+            while i != N {
+                array[i] = bytes[i];
+                i += 1;
+            }
+            array
         }
     };
 }
@@ -217,8 +350,8 @@ impl<T> core::cmp::Eq for TStr<T> {}
 
 impl<T> core::cmp::PartialOrd for TStr<T> {
     #[inline(always)]
-    fn partial_cmp(&self, _other: &Self) -> Option<core::cmp::Ordering> {
-        Some(core::cmp::Ordering::Equal)
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -0,0 +1,50 @@
+//! Implementations of [`Index`] and [`IndexMut`] for tuples,
+//! with the [`TStr`] of the position of each element.
+//!
+//! [`Index`]: https://doc.rust-lang.org/core/ops/trait.Index.html
+//! [`IndexMut`]: https://doc.rust-lang.org/core/ops/trait.IndexMut.html
+//! [`TStr`]: ../struct.TStr.html
+
+use core::ops::{Index, IndexMut};
+
+use crate::TS;
+
+macro_rules! tuple_impls {
+    ($( $tparams:tt { $($index:tt : $elem:ident)* } )*) => {
+        $($(
+            tuple_impls!{@elem $tparams $index $elem}
+        )*)*
+    };
+    (@elem [$($tparams:ident)*] $index:tt $elem:ident) => {
+        impl<$($tparams,)*> Index<TS!($index)> for ($($tparams,)*) {
+            type Output = $elem;
+
+            #[inline(always)]
+            fn index(&self, _: TS!($index)) -> &$elem {
+                &self.$index
+            }
+        }
+
+        impl<$($tparams,)*> IndexMut<TS!($index)> for ($($tparams,)*) {
+            #[inline(always)]
+            fn index_mut(&mut self, _: TS!($index)) -> &mut $elem {
+                &mut self.$index
+            }
+        }
+    };
+}
+
+tuple_impls! {
+    [A] {0: A}
+    [A B] {0: A 1: B}
+    [A B C] {0: A 1: B 2: C}
+    [A B C D] {0: A 1: B 2: C 3: D}
+    [A B C D E] {0: A 1: B 2: C 3: D 4: E}
+    [A B C D E F] {0: A 1: B 2: C 3: D 4: E 5: F}
+    [A B C D E F G] {0: A 1: B 2: C 3: D 4: E 5: F 6: G}
+    [A B C D E F G H] {0: A 1: B 2: C 3: D 4: E 5: F 6: G 7: H}
+    [A B C D E F G H I] {0: A 1: B 2: C 3: D 4: E 5: F 6: G 7: H 8: I}
+    [A B C D E F G H I J] {0: A 1: B 2: C 3: D 4: E 5: F 6: G 7: H 8: I 9: J}
+    [A B C D E F G H I J K] {0: A 1: B 2: C 3: D 4: E 5: F 6: G 7: H 8: I 9: J 10: K}
+    [A B C D E F G H I J K L] {0: A 1: B 2: C 3: D 4: E 5: F 6: G 7: H 8: I 9: J 10: K 11: L}
+}
@@ -0,0 +1,337 @@
+//! Type-level conversions between numeric [`TStr`]s and the unsigned integers of
+//! the [`typenum`] crate.
+//!
+//! This module requires the `"typenum"` feature,
+//! and is only available without the `"const_generics"` feature,
+//! since the conversions recurse over the characters of the string with [`ToCharList`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use tstr::typenum_uints::{TStrOfTypenum, TypenumOf};
+//! use tstr::{ts, TS};
+//!
+//! use typenum::{Prod, Unsigned, U16, U3};
+//!
+//! // Multiplies two numeric `TStr`s with typenum
+//! type Mul<A, B> = TStrOfTypenum<Prod<TypenumOf<A>, TypenumOf<B>>>;
+//!
+//! let _: TS!(48) = <Mul<TS!(16), TS!(3)>>::NEW;
+//!
+//! assert_eq!(<TypenumOf<TS!(16)>>::USIZE, 16);
+//! let _: U16 = <TypenumOf<TS!(16)>>::new();
+//! let _: TS!(3) = <TStrOfTypenum<U3>>::NEW;
+//!
+//! ```
+//!
+//! [`typenum`]: https://docs.rs/typenum/
+//! [`TStr`]: ../struct.TStr.html
+//! [`ToCharList`]: ../tlist/trait.ToCharList.html
+
+use crate::{
+    tlist::{FromCharList, TList, TNil, ToCharList},
+    TStr,
+};
+
+use core::ops::{Add, Div, Mul, Rem};
+
+#[cfg(not(feature = "min_const_generics"))]
+use typenum::Add1;
+use typenum::{Eq, IsEqual, Mod, Prod, Quot, Sum, UInt, UTerm, Unsigned, B0, B1, U0, U10};
+
+mod sealed {
+    #[doc(hidden)]
+    pub trait Sealed {}
+
+    /// Adds the digits of the `Self` list of characters to `Acc` (multiplied by 10 per digit).
+    #[doc(hidden)]
+    pub trait FoldDigits<Acc> {
+        type Output;
+    }
+
+    /// Prepends the characters of the decimal digits of `Self` to `Rest`.
+    #[doc(hidden)]
+    pub trait PushDigits<Rest> {
+        type Output;
+    }
+
+    /// Implementation of `PushDigits` for `N`, where `Self` is whether `N` is zero.
+    #[doc(hidden)]
+    pub trait PushDigitsIf<N, Rest> {
+        type Output;
+    }
+
+    /// Replaces an empty list of characters with the list of the `0` character.
+    #[doc(hidden)]
+    pub trait ZeroIfEmpty {
+        type Output;
+    }
+
+    /// Adds the length of the `Self` list of characters to `Acc`.
+    #[doc(hidden)]
+    #[cfg(not(feature = "min_const_generics"))]
+    pub trait CountChars<Acc> {
+        type Output;
+    }
+
+    /// Maps the type of the character of a decimal digit to its typenum type.
+    #[doc(hidden)]
+    pub trait CharDigit {
+        type Typenum;
+    }
+
+    /// Maps a typenum integer below 10 to the type of the character of that digit.
+    #[doc(hidden)]
+    pub trait TypenumDigit {
+        type Char;
+    }
+}
+#[cfg(not(feature = "min_const_generics"))]
+use sealed::CountChars;
+use sealed::{CharDigit, FoldDigits, PushDigits, PushDigitsIf, Sealed, TypenumDigit, ZeroIfEmpty};
+
+/// Type-level operator which converts a [`TStr`] of a decimal integer
+/// into the equivalent `typenum` unsigned integer.
+///
+/// The string must only contain decimal digits (leading zeros are allowed),
+/// using any other string is a compile-time error.
+///
+/// This trait is sealed, it's only implemented for [`TStr`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::typenum_uints::{ToTypenum, TypenumOf};
+/// use tstr::TS;
+///
+/// use typenum::{Unsigned, U0, U1000, U255};
+///
+/// let _: U0 = <TypenumOf<TS!(0)>>::new();
+/// let _: U255 = <TypenumOf<TS!(255)>>::new();
+/// let _: U1000 = <TS!(1000) as ToTypenum>::Output::new();
+///
+/// assert_eq!(<TypenumOf<TS!(300)>>::U32, 300);
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+pub trait ToTypenum: Sealed {
+    /// The `typenum` unsigned integer.
+    type Output: Unsigned;
+}
+
+/// The `typenum` unsigned integer of the `S` [`TStr`].
+///
+/// For examples, look at the docs for [`ToTypenum`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`ToTypenum`]: ./trait.ToTypenum.html
+pub type TypenumOf<S> = <S as ToTypenum>::Output;
+
+/// Type-level operator which converts a `typenum` unsigned integer
+/// into the [`TStr`] of its decimal representation, without leading zeros.
+///
+/// This trait is sealed, it's only implemented for `typenum` unsigned integers.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::typenum_uints::{FromTypenum, TStrOfTypenum};
+/// use tstr::TS;
+///
+/// use typenum::{Sum, U0, U1024, U3};
+///
+/// let _: TS!(0) = <TStrOfTypenum<U0>>::NEW;
+/// let _: TS!(1024) = <U1024 as FromTypenum>::Output::NEW;
+/// let _: TS!(1027) = <TStrOfTypenum<Sum<U1024, U3>>>::NEW;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+pub trait FromTypenum: Sealed {
+    /// The type-level string.
+    type Output;
+}
+
+/// The [`TStr`] of the decimal representation of the `N` `typenum` unsigned integer.
+///
+/// For examples, look at the docs for [`FromTypenum`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`FromTypenum`]: ./trait.FromTypenum.html
+pub type TStrOfTypenum<N> = <N as FromTypenum>::Output;
+
+/// Type-level operator which gets the length of a [`TStr`] in bytes,
+/// as a `typenum` unsigned integer.
+///
+/// This allows using the length of a [`TStr`] as the length of a `generic_array::GenericArray`,
+/// eg: to have a buffer with exactly enough capacity for the string.
+///
+/// This trait is sealed, it's only implemented for [`TStr`]s,
+/// and is not available with the `"min_const_generics"` feature,
+/// because that representation stores `char`s instead of bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::typenum_uints::{ByteLen, ByteLenOf};
+/// use tstr::TS;
+///
+/// use typenum::{Unsigned, U0, U3, U7};
+///
+/// let _: U0 = <ByteLenOf<TS!("")>>::new();
+/// let _: U3 = <ByteLenOf<TS!(foo)>>::new();
+/// let _: U7 = <TS!("ñandú") as ByteLen>::Output::new();
+///
+/// assert_eq!(<ByteLenOf<TS!(hello_world)>>::USIZE, 11);
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+#[cfg(not(feature = "min_const_generics"))]
+#[cfg_attr(feature = "docsrs", doc(cfg(not(feature = "min_const_generics"))))]
+pub trait ByteLen: Sealed {
+    /// The length of the string in bytes.
+    type Output: Unsigned;
+}
+
+/// The length in bytes of the `S` [`TStr`], as a `typenum` unsigned integer.
+///
+/// For examples, look at the docs for [`ByteLen`].
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`ByteLen`]: ./trait.ByteLen.html
+#[cfg(not(feature = "min_const_generics"))]
+#[cfg_attr(feature = "docsrs", doc(cfg(not(feature = "min_const_generics"))))]
+pub type ByteLenOf<S> = <S as ByteLen>::Output;
+
+impl<T> Sealed for TStr<T> {}
+
+impl<T, H, Tail> ToTypenum for TStr<T>
+where
+    Self: ToCharList<Output = TList<H, Tail>>,
+    TList<H, Tail>: FoldDigits<U0>,
+    <TList<H, Tail> as FoldDigits<U0>>::Output: Unsigned,
+{
+    type Output = <TList<H, Tail> as FoldDigits<U0>>::Output;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+impl<T> ByteLen for TStr<T>
+where
+    Self: ToCharList,
+    <Self as ToCharList>::Output: CountChars<U0>,
+    <<Self as ToCharList>::Output as CountChars<U0>>::Output: Unsigned,
+{
+    type Output = <<Self as ToCharList>::Output as CountChars<U0>>::Output;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+impl<Acc> CountChars<Acc> for TNil {
+    type Output = Acc;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+impl<H, Tail, Acc> CountChars<Acc> for TList<H, Tail>
+where
+    Acc: Add<B1>,
+    Tail: CountChars<Add1<Acc>>,
+{
+    type Output = Tail::Output;
+}
+
+impl<Acc> FoldDigits<Acc> for TNil {
+    type Output = Acc;
+}
+
+impl<H, Tail, Acc> FoldDigits<Acc> for TList<H, Tail>
+where
+    H: CharDigit,
+    Acc: Mul<U10>,
+    Prod<Acc, U10>: Add<H::Typenum>,
+    Tail: FoldDigits<Sum<Prod<Acc, U10>, H::Typenum>>,
+{
+    type Output = Tail::Output;
+}
+
+impl Sealed for UTerm {}
+
+impl<U, B> Sealed for UInt<U, B> {}
+
+// These impls are generic over `N`, instead of being implemented for `UInt<U, B>`,
+// because bounding `UInt<U, B>` by typenum's division operator overflows the recursion limit.
+impl<N> FromTypenum for N
+where
+    N: Sealed + PushDigits<TNil>,
+    N::Output: ZeroIfEmpty,
+    <N::Output as ZeroIfEmpty>::Output: FromCharList,
+{
+    type Output = <<N::Output as ZeroIfEmpty>::Output as FromCharList>::Output;
+}
+
+impl<N, Rest> PushDigits<Rest> for N
+where
+    N: IsEqual<U0>,
+    Eq<N, U0>: PushDigitsIf<N, Rest>,
+{
+    type Output = <Eq<N, U0> as PushDigitsIf<N, Rest>>::Output;
+}
+
+impl<N, Rest> PushDigitsIf<N, Rest> for B1 {
+    type Output = Rest;
+}
+
+impl<N, Rest> PushDigitsIf<N, Rest> for B0
+where
+    N: Div<U10> + Rem<U10>,
+    Mod<N, U10>: TypenumDigit,
+    Quot<N, U10>: PushDigits<TList<<Mod<N, U10> as TypenumDigit>::Char, Rest>>,
+{
+    type Output =
+        <Quot<N, U10> as PushDigits<TList<<Mod<N, U10> as TypenumDigit>::Char, Rest>>>::Output;
+}
+
+impl ZeroIfEmpty for TNil {
+    type Output = TList<<U0 as TypenumDigit>::Char, TNil>;
+}
+
+impl<H, Tail> ZeroIfEmpty for TList<H, Tail> {
+    type Output = Self;
+}
+
+macro_rules! digit_impls {
+    ($($char:literal $byte:ident $typenum:ident,)*) => {
+        $(
+            #[cfg(not(feature = "min_const_generics"))]
+            impl CharDigit for crate::$byte {
+                type Typenum = typenum::$typenum;
+            }
+
+            #[cfg(feature = "min_const_generics")]
+            impl CharDigit for crate::__a<$char> {
+                type Typenum = typenum::$typenum;
+            }
+
+            impl TypenumDigit for typenum::$typenum {
+                #[cfg(not(feature = "min_const_generics"))]
+                type Char = crate::$byte;
+
+                #[cfg(feature = "min_const_generics")]
+                type Char = crate::__a<$char>;
+            }
+        )*
+    };
+}
+
+digit_impls! {
+    '0' __0x30 U0,
+    '1' __0x31 U1,
+    '2' __0x32 U2,
+    '3' __0x33 U3,
+    '4' __0x34 U4,
+    '5' __0x35 U5,
+    '6' __0x36 U6,
+    '7' __0x37 U7,
+    '8' __0x38 U8,
+    '9' __0x39 U9,
+}
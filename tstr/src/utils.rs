@@ -72,8 +72,558 @@ pub const fn u8_slice_eq(left: &[u8], right: &[u8]) -> bool {
     true
 }
 
+/// A const equivalent of `str::eq_ignore_ascii_case`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::str_eq_ignore_ascii_case;
+///
+/// const LOWER: &str = "content-type";
+/// const MIXED: &str = "Content-Type";
+/// const OTHER: &str = "Content-Length";
+///
+/// const LOWER_EQ_MIXED: bool = str_eq_ignore_ascii_case(LOWER, MIXED);
+/// assert!( LOWER_EQ_MIXED );
+///
+/// const LOWER_EQ_OTHER: bool = str_eq_ignore_ascii_case(LOWER, OTHER);
+/// assert!( !LOWER_EQ_OTHER );
+///
+/// ```
+///
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+#[inline]
+pub const fn str_eq_ignore_ascii_case(left: &str, right: &str) -> bool {
+    u8_slice_eq_ignore_ascii_case(left.as_bytes(), right.as_bytes())
+}
+
+/// A const equivalent of `<[u8]>::eq_ignore_ascii_case`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::u8_slice_eq_ignore_ascii_case;
+///
+/// const LOWER: &[u8] = b"path";
+/// const UPPER: &[u8] = b"PATH";
+/// const OTHER: &[u8] = b"HOME";
+///
+/// const LOWER_EQ_UPPER: bool = u8_slice_eq_ignore_ascii_case(LOWER, UPPER);
+/// assert!( LOWER_EQ_UPPER );
+///
+/// const LOWER_EQ_OTHER: bool = u8_slice_eq_ignore_ascii_case(LOWER, OTHER);
+/// assert!( !LOWER_EQ_OTHER );
+///
+/// ```
+///
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+pub const fn u8_slice_eq_ignore_ascii_case(left: &[u8], right: &[u8]) -> bool {
+    // `u8::to_ascii_lowercase` is only const since Rust 1.52
+    const fn to_lower(b: u8) -> u8 {
+        if b'A' <= b && b <= b'Z' {
+            b + (b'a' - b'A')
+        } else {
+            b
+        }
+    }
+
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i != left.len() {
+        if to_lower(left[i]) != to_lower(right[i]) {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// A const equivalent of `str::starts_with`, taking a `&str` pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::str_starts_with;
+///
+/// const FOO: &str = "foo_bar";
+///
+/// const STARTS_FOO: bool = str_starts_with(FOO, "foo");
+/// assert!( STARTS_FOO );
+///
+/// const STARTS_BAR: bool = str_starts_with(FOO, "bar");
+/// assert!( !STARTS_BAR );
+///
+/// ```
+///
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+#[inline]
+pub const fn str_starts_with(string: &str, prefix: &str) -> bool {
+    u8_slice_starts_with(string.as_bytes(), prefix.as_bytes())
+}
+
+/// A const equivalent of `str::ends_with`, taking a `&str` pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::str_ends_with;
+///
+/// const FOO: &str = "foo_bar";
+///
+/// const ENDS_BAR: bool = str_ends_with(FOO, "bar");
+/// assert!( ENDS_BAR );
+///
+/// const ENDS_FOO: bool = str_ends_with(FOO, "foo");
+/// assert!( !ENDS_FOO );
+///
+/// ```
+///
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+#[inline]
+pub const fn str_ends_with(string: &str, suffix: &str) -> bool {
+    u8_slice_ends_with(string.as_bytes(), suffix.as_bytes())
+}
+
+/// A const equivalent of `<[u8]>::starts_with`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::u8_slice_starts_with;
+///
+/// const FOO: &[u8] = &[3, 5, 8, 13];
+///
+/// const STARTS_3_5: bool = u8_slice_starts_with(FOO, &[3, 5]);
+/// assert!( STARTS_3_5 );
+///
+/// const STARTS_5: bool = u8_slice_starts_with(FOO, &[5]);
+/// assert!( !STARTS_5 );
+///
+/// ```
+///
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+#[inline]
+pub const fn u8_slice_starts_with(slice: &[u8], prefix: &[u8]) -> bool {
+    if slice.len() < prefix.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i != prefix.len() {
+        if slice[i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// A const equivalent of `<[u8]>::ends_with`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::u8_slice_ends_with;
+///
+/// const FOO: &[u8] = &[3, 5, 8, 13];
+///
+/// const ENDS_8_13: bool = u8_slice_ends_with(FOO, &[8, 13]);
+/// assert!( ENDS_8_13 );
+///
+/// const ENDS_8: bool = u8_slice_ends_with(FOO, &[8]);
+/// assert!( !ENDS_8 );
+///
+/// ```
+///
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+#[inline]
+pub const fn u8_slice_ends_with(slice: &[u8], suffix: &[u8]) -> bool {
+    if slice.len() < suffix.len() {
+        return false;
+    }
+
+    let offset = slice.len() - suffix.len();
+    let mut i = 0;
+    while i != suffix.len() {
+        if slice[offset + i] != suffix[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// A const equivalent of `str::find`, taking a `&str` pattern.
+///
+/// Returns the byte index of the first occurrence of `needle` in `haystack`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::str_find;
+///
+/// const FOO: &str = "foo::bar::baz";
+///
+/// const FIND_COLONS: Option<usize> = str_find(FOO, "::");
+/// assert_eq!(FIND_COLONS, Some(3));
+///
+/// const FIND_QUX: Option<usize> = str_find(FOO, "qux");
+/// assert_eq!(FIND_QUX, None);
+///
+/// ```
+///
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+#[inline]
+pub const fn str_find(haystack: &str, needle: &str) -> Option<usize> {
+    u8_slice_find(haystack.as_bytes(), needle.as_bytes())
+}
+
+/// A const equivalent of `str::contains`, taking a `&str` pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::str_contains;
+///
+/// const CONTAINS_COLONS: bool = str_contains("foo::bar", "::");
+/// assert!( CONTAINS_COLONS );
+///
+/// const CONTAINS_QUX: bool = str_contains("foo::bar", "qux");
+/// assert!( !CONTAINS_QUX );
+///
+/// ```
+///
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+#[inline]
+pub const fn str_contains(haystack: &str, needle: &str) -> bool {
+    // `Option::is_some` is only const since Rust 1.48
+    #[allow(clippy::redundant_pattern_matching)]
+    match u8_slice_find(haystack.as_bytes(), needle.as_bytes()) {
+        Some(_) => true,
+        None => false,
+    }
+}
+
+/// Finds the index of the first occurrence of `needle` in `haystack`.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::u8_slice_find;
+///
+/// const FOO: &[u8] = &[3, 5, 8, 5, 8];
+///
+/// const FIND_5_8: Option<usize> = u8_slice_find(FOO, &[5, 8]);
+/// assert_eq!(FIND_5_8, Some(1));
+///
+/// const FIND_8_3: Option<usize> = u8_slice_find(FOO, &[8, 3]);
+/// assert_eq!(FIND_8_3, None);
+///
+/// ```
+///
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+pub const fn u8_slice_find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if haystack.len() < needle.len() {
+        return None;
+    }
+
+    let last_start = haystack.len() - needle.len();
+    let mut start = 0;
+    while start <= last_start {
+        let mut i = 0;
+        while i != needle.len() && haystack[start + i] == needle[i] {
+            i += 1;
+        }
+        if i == needle.len() {
+            return Some(start);
+        }
+        start += 1;
+    }
+
+    None
+}
+
+/// Whether `string` is an ASCII Rust identifier,
+/// which can be passed to the [`TS`] and [`ts`] macros without quotes.
+///
+/// This returns true for strings that start with an ASCII letter or `_`,
+/// followed by ASCII letters, digits, or `_`, excluding the `_` string.
+///
+/// Keywords are considered identifiers,
+/// because `TS!(r#type)` is the same type as `TS!("type")`.
+///
+/// This returns false for all non-ASCII strings,
+/// even those that are valid Rust identifiers.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::str_is_identifier;
+///
+/// const FOO: bool = str_is_identifier("foo_bar");
+/// assert!( FOO );
+///
+/// const PRIV: bool = str_is_identifier("_0");
+/// assert!( PRIV );
+///
+/// const NUM: bool = str_is_identifier("0foo");
+/// assert!( !NUM );
+///
+/// const PATH: bool = str_is_identifier("foo::bar");
+/// assert!( !PATH );
+///
+/// ```
+///
+/// [`TS`]: ../macro.TS.html
+/// [`ts`]: ../macro.ts.html
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+pub const fn str_is_identifier(string: &str) -> bool {
+    const fn is_ident_start(b: u8) -> bool {
+        b == b'_' || (b'a' <= b && b <= b'z') || (b'A' <= b && b <= b'Z')
+    }
+
+    const fn is_ident_continue(b: u8) -> bool {
+        is_ident_start(b) || (b'0' <= b && b <= b'9')
+    }
+
+    let bytes = string.as_bytes();
+
+    if bytes.is_empty() || !is_ident_start(bytes[0]) || (bytes.len() == 1 && bytes[0] == b'_') {
+        return false;
+    }
+
+    let mut i = 1;
+    while i != bytes.len() {
+        if !is_ident_continue(bytes[i]) {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Hashes `string` with the 64-bit [FNV-1a] hash function, in const contexts.
+///
+/// This is equivalent to `u8_slice_fnv1a_hash(string.as_bytes())`.
+///
+/// The returned hash is guaranteed to stay the same across versions and platforms.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::str_fnv1a_hash;
+///
+/// const EMPTY: u64 = str_fnv1a_hash("");
+/// assert_eq!(EMPTY, 0xcbf2_9ce4_8422_2325);
+///
+/// const FOO: u64 = str_fnv1a_hash("foo");
+/// assert_eq!(FOO, 0xdcb2_7518_fed9_d577);
+///
+/// ```
+///
+/// [FNV-1a]: http://www.isthe.com/chongo/tech/comp/fnv/index.html
+#[cfg(feature = "rust_1_46")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+#[inline]
+pub const fn str_fnv1a_hash(string: &str) -> u64 {
+    u8_slice_fnv1a_hash(string.as_bytes())
+}
+
+/// Hashes `slice` with the 64-bit [FNV-1a] hash function, in const contexts.
+///
+/// The returned hash is guaranteed to stay the same across versions and platforms.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::u8_slice_fnv1a_hash;
+///
+/// const FOO: u64 = u8_slice_fnv1a_hash(b"foo");
+/// assert_eq!(FOO, 0xdcb2_7518_fed9_d577);
+///
+/// const BAR: u64 = u8_slice_fnv1a_hash(&[98, 97, 114]);
+/// assert_eq!(BAR, 0x0039_3419_1339_461a);
+///
+/// ```
+///
+/// [FNV-1a]: http://www.isthe.com/chongo/tech/comp/fnv/index.html
 #[cfg(feature = "rust_1_46")]
-pub use slice_cmp::{str_cmp, u8_slice_cmp};
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+pub const fn u8_slice_fnv1a_hash(slice: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    let mut i = 0;
+    while i != slice.len() {
+        hash ^= slice[i] as u64;
+        hash = hash.wrapping_mul(PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Compares `string` to the string of the `T` type-level string, in const contexts.
+///
+/// This is equivalent to `str_eq(string, T::STR)`,
+/// for mapping strings known at runtime to items keyed by [`TStr`] types.
+///
+/// # Example
+///
+#[cfg_attr(feature = "const_generics", doc = "```rust")]
+#[cfg_attr(not(feature = "const_generics"), doc = "```ignore")]
+/// use tstr::utils::tstr_matches;
+/// use tstr::TS;
+///
+/// const IS_NAME: bool = tstr_matches::<TS!(name)>("name");
+/// assert!( IS_NAME );
+///
+/// assert!( !tstr_matches::<TS!(name)>("surname") );
+///
+/// assert_eq!(handle("age", "36"), Some(36));
+/// assert_eq!(handle("height", "180"), None);
+///
+/// fn handle(key: &str, value: &str) -> Option<u32> {
+///     if tstr_matches::<TS!(age)>(key) {
+///         value.parse().ok()
+///     } else {
+///         None
+///     }
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+#[cfg(feature = "const_generics")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "const_generics")))]
+#[inline]
+pub const fn tstr_matches<T>(string: &str) -> bool
+where
+    T: crate::StrValue,
+{
+    str_eq(string, T::STR)
+}
+
+/// Concatenates `parts` into a `[u8; N]` buffer, in const contexts.
+///
+/// This returns the buffer and the length of the concatenated string,
+/// the bytes after that length are zeroes.
+/// This allows const code to assemble strings from multiple [`StrValue::STR`]s
+/// without allocating.
+///
+/// # Panics
+///
+/// This panics if the concatenated string is longer than `N` bytes,
+/// which is a compile-time error when this is called in a const.
+///
+/// # Example
+///
+#[cfg_attr(feature = "const_generics", doc = "```rust")]
+#[cfg_attr(not(feature = "const_generics"), doc = "```ignore")]
+/// use tstr::utils::concat_into;
+/// use tstr::{StrValue, TS};
+///
+/// const KEY: ([u8; 32], usize) = concat_into(&[<TS!(http)>::STR, ".", <TS!(requests)>::STR]);
+///
+/// let (buffer, len) = KEY;
+/// assert_eq!(std::str::from_utf8(&buffer[..len]).unwrap(), "http.requests");
+///
+/// ```
+///
+/// [`StrValue::STR`]: ../trait.StrValue.html#associatedconstant.STR
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "min_const_generics")))]
+pub const fn concat_into<const N: usize>(parts: &[&str]) -> ([u8; N], usize) {
+    let mut buffer = [0u8; N];
+    let mut len = 0;
+
+    let mut i = 0;
+    while i != parts.len() {
+        let (next_buffer, next_len) = copy_into(buffer, len, parts[i].as_bytes());
+        buffer = next_buffer;
+        len = next_len;
+        i += 1;
+    }
+
+    (buffer, len)
+}
+
+/// Concatenates the `parts` byte slices into a `[u8; N]` buffer, in const contexts.
+///
+/// This returns the buffer and the amount of bytes that were written to it,
+/// the bytes after that length are zeroes.
+///
+/// # Panics
+///
+/// This panics if the concatenated slices are longer than `N` bytes,
+/// which is a compile-time error when this is called in a const.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::utils::u8_slice_concat_into;
+///
+/// const BYTES: ([u8; 8], usize) = u8_slice_concat_into(&[&[3, 5], &[], &[8, 13, 21]]);
+///
+/// assert_eq!(BYTES, ([3, 5, 8, 13, 21, 0, 0, 0], 5));
+///
+/// ```
+///
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "min_const_generics")))]
+pub const fn u8_slice_concat_into<const N: usize>(parts: &[&[u8]]) -> ([u8; N], usize) {
+    let mut buffer = [0u8; N];
+    let mut len = 0;
+
+    let mut i = 0;
+    while i != parts.len() {
+        let (next_buffer, next_len) = copy_into(buffer, len, parts[i]);
+        buffer = next_buffer;
+        len = next_len;
+        i += 1;
+    }
+
+    (buffer, len)
+}
+
+// Copies `bytes` into `buffer` starting at `start`,
+// returning the buffer and the index after the last copied byte.
+//
+// The buffer is passed by value because `&mut` in const fns requires Rust 1.83.
+#[cfg(feature = "min_const_generics")]
+const fn copy_into<const N: usize>(
+    mut buffer: [u8; N],
+    start: usize,
+    bytes: &[u8],
+) -> ([u8; N], usize) {
+    let mut i = 0;
+    while i != bytes.len() {
+        buffer[start + i] = bytes[i];
+        i += 1;
+    }
+    (buffer, start + i)
+}
+
+#[cfg(feature = "rust_1_46")]
+pub use slice_cmp::{str_cmp, u8_slice_cmp, u8_slice_lex_cmp};
 
 #[cfg(feature = "rust_1_46")]
 mod slice_cmp {
@@ -129,32 +679,13 @@ mod slice_cmp {
     #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
     #[inline]
     pub const fn str_cmp(left: &str, right: &str) -> Ordering {
-        const fn str_cmp_inner(left: &[u8], right: &[u8]) -> u8 {
-            let left_len = left.len();
-            let right_len = right.len();
-            let (min_len, on_ne) = if left_len < right_len {
-                (left_len, LESS)
-            } else {
-                (right_len, GREATER)
-            };
-
-            let mut i = 0;
-            while i < min_len {
-                ret_if_ne! {left[i], right[i]}
-                i += 1;
-            }
-
-            if left_len == right_len {
-                EQUAL
-            } else {
-                on_ne
-            }
-        }
-
-        to_ordering(str_cmp_inner(left.as_bytes(), right.as_bytes()))
+        u8_slice_lex_cmp(left.as_bytes(), right.as_bytes())
     }
 
-    /// A const equivalent of `<[u8]>::cmp`.
+    /// Compares the lengths of the slices, and then their elements.
+    ///
+    /// This is not the order of `<[u8]>::cmp`, which compares the elements first,
+    /// use [`u8_slice_lex_cmp`] for that order.
     ///
     /// # Example
     ///
@@ -166,6 +697,7 @@ mod slice_cmp {
     /// const FOO: &[u8] = &[10, 20];
     /// const BAR: &[u8] = &[10, 20, 30, 40];
     /// const BAZ: &[u8] = &[3, 5];
+    /// const QUX: &[u8] = &[11];
     ///
     /// const FOO_CMP_FOO: Ordering = u8_slice_cmp(FOO, FOO);
     /// assert_eq!(FOO_CMP_FOO, Ordering::Equal);
@@ -176,8 +708,13 @@ mod slice_cmp {
     /// const FOO_CMP_BAZ: Ordering = u8_slice_cmp(FOO, BAZ);
     /// assert_eq!(FOO_CMP_BAZ, Ordering::Greater);
     ///
+    /// // The longer slice is greater, regardless of the elements
+    /// const FOO_CMP_QUX: Ordering = u8_slice_cmp(FOO, QUX);
+    /// assert_eq!(FOO_CMP_QUX, Ordering::Greater);
+    ///
     /// ```
     ///
+    /// [`u8_slice_lex_cmp`]: ./fn.u8_slice_lex_cmp.html
     #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
     #[inline]
     pub const fn u8_slice_cmp(left: &[u8], right: &[u8]) -> Ordering {
@@ -197,10 +734,68 @@ mod slice_cmp {
 
         to_ordering(u8_slice_cmp_inner(left, right))
     }
+
+    /// A const equivalent of `<[u8]>::cmp`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tstr::utils::u8_slice_lex_cmp;
+    ///
+    /// use std::cmp::Ordering;
+    ///
+    /// const FOO: &[u8] = &[10, 20];
+    /// const BAR: &[u8] = &[10, 20, 30, 40];
+    /// const BAZ: &[u8] = &[3, 5];
+    /// const QUX: &[u8] = &[11];
+    ///
+    /// const FOO_CMP_FOO: Ordering = u8_slice_lex_cmp(FOO, FOO);
+    /// assert_eq!(FOO_CMP_FOO, Ordering::Equal);
+    ///
+    /// const FOO_CMP_BAR: Ordering = u8_slice_lex_cmp(FOO, BAR);
+    /// assert_eq!(FOO_CMP_BAR, Ordering::Less);
+    ///
+    /// const FOO_CMP_BAZ: Ordering = u8_slice_lex_cmp(FOO, BAZ);
+    /// assert_eq!(FOO_CMP_BAZ, Ordering::Greater);
+    ///
+    /// // Like `<[u8]>::cmp`, this compares the elements before the lengths
+    /// const FOO_CMP_QUX: Ordering = u8_slice_lex_cmp(FOO, QUX);
+    /// assert_eq!(FOO_CMP_QUX, Ordering::Less);
+    ///
+    /// ```
+    ///
+    #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_46")))]
+    #[inline]
+    pub const fn u8_slice_lex_cmp(left: &[u8], right: &[u8]) -> Ordering {
+        const fn u8_slice_lex_cmp_inner(left: &[u8], right: &[u8]) -> u8 {
+            let left_len = left.len();
+            let right_len = right.len();
+            let (min_len, on_ne) = if left_len < right_len {
+                (left_len, LESS)
+            } else {
+                (right_len, GREATER)
+            };
+
+            let mut i = 0;
+            while i < min_len {
+                ret_if_ne! {left[i], right[i]}
+                i += 1;
+            }
+
+            if left_len == right_len {
+                EQUAL
+            } else {
+                on_ne
+            }
+        }
+
+        to_ordering(u8_slice_lex_cmp_inner(left, right))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "rust_1_46")]
     use super::*;
 
     #[test]
@@ -260,6 +855,42 @@ mod tests {
         assert_s_cmp!(&[0], &[0, 1], Less);
         assert_s_cmp!(&[0, 1], &[0, 1], Equal);
         assert_s_cmp!(&[0, 1], &[0, 2], Less);
+
+        // the lengths are compared before the elements
+        assert_eq!(u8_slice_cmp(&[1], &[0, 1]), Less);
+        assert_eq!(u8_slice_cmp(&[0, 2], &[1]), Greater);
+        assert_eq!(u8_slice_cmp(&[0, 2], &[0, 1, 5]), Less);
+    }
+
+    #[test]
+    #[cfg(feature = "rust_1_46")]
+    fn slice_lex_cmp_test() {
+        use core::cmp::{
+            Ord,
+            Ordering::{Equal, Greater, Less},
+        };
+
+        macro_rules! assert_s_cmp {
+            ($left:expr, $right:expr, $expected:expr) => {
+                assert_eq!(u8_slice_lex_cmp($left, $right), $expected);
+                assert_eq!(<[u8]>::cmp($left, $right), $expected);
+
+                assert_eq!(u8_slice_lex_cmp($right, $left), $expected.reverse());
+                assert_eq!(<[u8]>::cmp($right, $left), $expected.reverse());
+            };
+        }
+
+        assert_s_cmp!(&[], &[], Equal);
+        assert_s_cmp!(&[], &[0], Less);
+        assert_s_cmp!(&[0], &[], Greater);
+        assert_s_cmp!(&[0], &[0], Equal);
+        assert_s_cmp!(&[0], &[1], Less);
+        assert_s_cmp!(&[0], &[0, 1], Less);
+        assert_s_cmp!(&[0, 1], &[0, 1], Equal);
+        assert_s_cmp!(&[0, 1], &[0, 2], Less);
+        assert_s_cmp!(&[1], &[0, 1], Greater);
+        assert_s_cmp!(&[0, 2], &[1], Less);
+        assert_s_cmp!(&[0, 2], &[0, 1, 5], Greater);
     }
 
     #[test]
@@ -294,4 +925,155 @@ mod tests {
         assert_s_cmp!("299999", "12", Greater);
         assert_s_cmp!("01", "02", Less);
     }
+
+    #[test]
+    #[cfg(feature = "rust_1_46")]
+    fn starts_ends_with_test() {
+        macro_rules! assert_affixes {
+            ($string:expr, $affix:expr) => {{
+                let string: &str = $string;
+                let affix: &str = $affix;
+                let (sb, ab) = (string.as_bytes(), affix.as_bytes());
+
+                assert_eq!(str_starts_with(string, affix), string.starts_with(affix));
+                assert_eq!(str_ends_with(string, affix), string.ends_with(affix));
+                assert_eq!(u8_slice_starts_with(sb, ab), sb.starts_with(ab));
+                assert_eq!(u8_slice_ends_with(sb, ab), sb.ends_with(ab));
+            }};
+        }
+
+        for string in ["", "a", "ab", "ba", "aba", "foo::bar"].iter() {
+            for affix in ["", "a", "b", "ab", "ba", "aba", "abab", "foo", "bar", "::"].iter() {
+                assert_affixes!(string, affix);
+            }
+        }
+
+        assert!(str_starts_with("foo::bar", "foo::"));
+        assert!(str_ends_with("foo::bar", "::bar"));
+        assert!(!str_starts_with("foo", "foo::"));
+        assert!(!str_ends_with("bar", "::bar"));
+    }
+
+    #[test]
+    #[cfg(feature = "rust_1_46")]
+    fn find_test() {
+        let strings = ["", "a", "ab", "ba", "aba", "abab", "foo::bar", "foo:bar::"];
+        let needles = [
+            "", "a", "b", "ab", "ba", "aba", "abab", "bb", ":", "::", "bar",
+        ];
+
+        for string in strings.iter() {
+            for needle in needles.iter() {
+                assert_eq!(str_find(string, needle), string.find(needle));
+                assert_eq!(str_contains(string, needle), string.contains(needle));
+                assert_eq!(
+                    u8_slice_find(string.as_bytes(), needle.as_bytes()),
+                    string.find(needle),
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rust_1_46")]
+    fn is_identifier_test() {
+        let idents = [
+            "a", "A", "_a", "__", "_0", "a0", "foo_bar", "FooBar", "type",
+        ];
+        for ident in idents.iter() {
+            assert!(str_is_identifier(ident), "{}", ident);
+        }
+
+        let not_idents = [
+            "", "_", "0", "0a", "a-b", "a b", "a::b", "r#type", "é", "aé",
+        ];
+        for not_ident in not_idents.iter() {
+            assert!(!str_is_identifier(not_ident), "{}", not_ident);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rust_1_46")]
+    fn eq_ignore_ascii_case_test() {
+        let strings = [
+            "", "a", "A", "b", "ab", "aB", "Ab", "AB", "@", "`", "[", "{", "é", "É",
+        ];
+
+        for left in strings.iter() {
+            for right in strings.iter() {
+                let (lb, rb) = (left.as_bytes(), right.as_bytes());
+
+                assert_eq!(
+                    str_eq_ignore_ascii_case(left, right),
+                    left.eq_ignore_ascii_case(right),
+                    "{:?} {:?}",
+                    left,
+                    right,
+                );
+                assert_eq!(
+                    u8_slice_eq_ignore_ascii_case(lb, rb),
+                    lb.eq_ignore_ascii_case(rb),
+                    "{:?} {:?}",
+                    left,
+                    right,
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "min_const_generics")]
+    fn concat_into_test() {
+        const EMPTY: ([u8; 0], usize) = concat_into(&[]);
+        assert_eq!(EMPTY, ([], 0));
+
+        const EXACT: ([u8; 6], usize) = concat_into(&["foo", "", "bar"]);
+        assert_eq!(EXACT, (*b"foobar", 6));
+
+        const SHORTER: ([u8; 8], usize) = concat_into(&["a", "\u{e9}"]);
+        assert_eq!(SHORTER, ([b'a', 0xC3, 0xA9, 0, 0, 0, 0, 0], 3));
+
+        type Case<'a> = (&'a [&'a str], &'a [&'a [u8]], &'a str);
+
+        let cases: &[Case<'_>] = &[
+            (&[], &[], ""),
+            (&["a"], &[b"a"], "a"),
+            (
+                &["foo", "bar", "baz"],
+                &[b"foo", b"bar", b"baz"],
+                "foobarbaz",
+            ),
+        ];
+        for &(parts, byte_parts, expected) in cases {
+            let (buffer, len) = concat_into::<16>(parts);
+            assert_eq!(core::str::from_utf8(&buffer[..len]).unwrap(), expected);
+            assert_eq!(u8_slice_concat_into::<16>(byte_parts), (buffer, len));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "min_const_generics")]
+    #[should_panic]
+    fn concat_into_overflow_test() {
+        let _ = concat_into::<5>(&["foo", "bar"]);
+    }
+
+    #[test]
+    #[cfg(feature = "rust_1_46")]
+    fn fnv1a_hash_test() {
+        // Test vectors from the reference implementation of FNV-1a
+        let cases: &[(&str, u64)] = &[
+            ("", 0xcbf29ce484222325),
+            ("a", 0xaf63dc4c8601ec8c),
+            ("b", 0xaf63df4c8601f1a5),
+            ("foo", 0xdcb27518fed9d577),
+            ("foobar", 0x85944171f73967e8),
+            ("chongo was here!\n", 0x46810940eff5f915),
+        ];
+
+        for &(string, hash) in cases {
+            assert_eq!(str_fnv1a_hash(string), hash, "{:?}", string);
+            assert_eq!(u8_slice_fnv1a_hash(string.as_bytes()), hash, "{:?}", string);
+        }
+    }
 }
@@ -0,0 +1,126 @@
+#[cfg(feature = "proc_macros")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "proc_macros")))]
+pub use tstr_proc_macros::VariantAccess;
+
+/// For getting a reference to the field of the variant named `K`, where `K` is a [`TStr`].
+///
+/// This is the enum counterpart of indexing a struct with the [`TStr`] of a field name:
+/// it returns `None` when `self` is a different variant.
+///
+/// The payload is the single field of the variant,
+/// variants with more fields (or no fields) only implement [`IntoVariant`].
+///
+/// # Deriving
+///
+/// This trait can be derived for enums,
+/// with the `#[derive(tstr::VariantAccess)]` derive macro
+/// (which requires the default `"proc_macros"` feature).
+///
+/// The derive generates:
+///
+/// - An impl of [`IntoVariant`] for the [`TStr`] of the name of every variant,
+/// whose `Payload` is the type of the field if the variant has exactly one field,
+/// otherwise a tuple of the types of the fields, in declaration order.
+///
+/// - An impl of this trait for the [`TStr`] of the name of every variant with exactly one field.
+///
+/// The derive generates code that refers to this crate as `::tstr`,
+/// which can be changed with the `#[tstr(crate = path::to::tstr)]` attribute.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts, IntoVariant, VariantAccess, TS};
+///
+/// #[derive(Debug, PartialEq, VariantAccess)]
+/// enum Shape {
+///     Circle(f64),
+///     Square { side: f64 },
+///     Rectangle(f64, f64),
+///     Point,
+/// }
+///
+/// let mut shape = Shape::Circle(1.0);
+///
+/// assert_eq!(shape.variant(ts!(Circle)), Some(&1.0));
+/// assert_eq!(shape.variant(ts!(Square)), None);
+///
+/// if let Some(radius) = shape.variant_mut(ts!(Circle)) {
+///     *radius *= 2.0;
+/// }
+/// assert_eq!(shape.into_variant(ts!(Circle)), Some(2.0));
+///
+/// assert_eq!(Shape::Square{side: 3.0}.into_variant(ts!(Square)), Some(3.0));
+/// assert_eq!(Shape::Rectangle(3.0, 5.0).into_variant(ts!(Rectangle)), Some((3.0, 5.0)));
+/// assert_eq!(Shape::Point.into_variant(ts!(Point)), Some(()));
+/// assert_eq!(Shape::Point.into_variant(ts!(Circle)), None);
+///
+/// assert_eq!(circle_radius(&Shape::Circle(8.0)), 8.0);
+/// assert_eq!(circle_radius(&Shape::Point), 0.0);
+///
+/// fn circle_radius<S>(shape: &S) -> f64
+/// where
+///     S: VariantAccess<TS!(Circle), Payload = f64>,
+/// {
+///     shape.variant(ts!(Circle)).copied().unwrap_or(0.0)
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`IntoVariant`]: ./trait.IntoVariant.html
+pub trait VariantAccess<K>: IntoVariant<K> {
+    /// Gets a reference to the field of the `K` variant,
+    /// returning `None` if `self` is a different variant.
+    fn variant(&self, key: K) -> Option<&Self::Payload>;
+
+    /// Gets a mutable reference to the field of the `K` variant,
+    /// returning `None` if `self` is a different variant.
+    fn variant_mut(&mut self, key: K) -> Option<&mut Self::Payload>;
+}
+
+/// For converting an enum into the fields of the variant named `K`, where `K` is a [`TStr`].
+///
+/// This is implemented by the [`VariantAccess`] derive, for every variant.
+///
+/// # Example
+///
+/// ```rust
+/// use tstr::{ts, IntoVariant, VariantAccess};
+///
+/// #[derive(VariantAccess)]
+/// enum Message {
+///     Move { x: i32, y: i32 },
+///     Write(String),
+///     Quit,
+/// }
+///
+/// let messages = vec![
+///     Message::Move{x: 3, y: 5},
+///     Message::Write("hello".to_string()),
+///     Message::Move{x: 8, y: 13},
+///     Message::Quit,
+/// ];
+///
+/// assert_eq!(payloads(messages, ts!(Move)), [(3, 5), (8, 13)]);
+///
+/// fn payloads<T, K>(list: Vec<T>, key: K) -> Vec<T::Payload>
+/// where
+///     T: IntoVariant<K>,
+///     K: Copy,
+/// {
+///     list.into_iter().filter_map(|x| x.into_variant(key)).collect()
+/// }
+///
+/// ```
+///
+/// [`TStr`]: ./struct.TStr.html
+/// [`VariantAccess`]: ./trait.VariantAccess.html
+pub trait IntoVariant<K>: Sized {
+    /// The fields of the `K` variant.
+    type Payload;
+
+    /// Converts `self` into the fields of the `K` variant,
+    /// returning `None` if `self` is a different variant.
+    fn into_variant(self, key: K) -> Option<Self::Payload>;
+}
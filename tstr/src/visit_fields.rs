@@ -0,0 +1,126 @@
+#[cfg(feature = "proc_macros")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "proc_macros")))]
+pub use tstr_proc_macros::VisitFields;
+
+/// A visitor of the named fields of a type, called by [`VisitFields::visit_fields`].
+///
+/// `K` is the [`TStr`] of the name of the field, and `T` is the type of the field.
+///
+/// Since the visitor is called for each field with a different `K` and `T`,
+/// visitors usually implement this trait generically,
+/// requiring the traits that they use on `T`.
+///
+/// # Example
+///
+/// This example demonstrates a visitor that formats the fields of a struct.
+///
+/// ```rust
+/// use std::fmt::{Debug, Write};
+///
+/// use tstr::{FieldVisitor, VisitFields};
+///
+/// #[derive(VisitFields)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+///     label: &'static str,
+/// }
+///
+/// assert_eq!(
+///     pretty_print(&Point{x: 3, y: 5, label: "foo"}),
+///     r#"(x: 3, y: 5, label: "foo")"#,
+/// );
+///
+/// fn pretty_print<T>(this: &T) -> String
+/// where
+///     T: VisitFields<Printer>,
+/// {
+///     let mut printer = Printer{ out: String::new() };
+///     this.visit_fields(&mut printer);
+///     format!("({})", printer.out)
+/// }
+///
+/// struct Printer {
+///     out: String,
+/// }
+///
+/// impl<K, T> FieldVisitor<K, T> for Printer
+/// where
+///     T: ?Sized + Debug,
+/// {
+///     fn visit(&mut self, _: K, name_str: &'static str, value: &T) {
+///         if !self.out.is_empty() {
+///             self.out.push_str(", ");
+///         }
+///         let _ = write!(self.out, "{}: {:?}", name_str, value);
+///     }
+/// }
+///
+/// ```
+///
+/// [`VisitFields::visit_fields`]: ./trait.VisitFields.html#tymethod.visit_fields
+/// [`TStr`]: ./struct.TStr.html
+pub trait FieldVisitor<K, T: ?Sized> {
+    /// Visits the field named `name`, with `name_str` as the name of the field as a string.
+    fn visit(&mut self, name: K, name_str: &'static str, value: &T);
+}
+
+/// For types whose named fields can be visited by `V`.
+///
+/// # Deriving
+///
+/// This trait can be derived for structs with named fields,
+/// with the `#[derive(tstr::VisitFields)]` derive macro
+/// (which requires the default `"proc_macros"` feature).
+///
+/// The derived impl requires `V` to implement [`FieldVisitor`] for every field,
+/// calling the visitor for each field in declaration order.
+///
+/// The derive generates code that refers to this crate as `::tstr`,
+/// which can be changed with the `#[tstr(crate = path::to::tstr)]` attribute.
+///
+/// # Example
+///
+/// This example demonstrates a visitor that validates the fields of a struct.
+///
+/// ```rust
+/// use tstr::{FieldVisitor, TS, VisitFields};
+///
+/// #[derive(VisitFields)]
+/// struct Account {
+///     name: String,
+///     email: String,
+///     age: u32,
+/// }
+///
+/// let mut validator = NonEmpty{ empty_fields: Vec::new() };
+///
+/// let account = Account{name: "Bob".into(), email: "".into(), age: 21};
+/// account.visit_fields(&mut validator);
+///
+/// assert_eq!(validator.empty_fields, ["email"]);
+///
+/// struct NonEmpty {
+///     empty_fields: Vec<&'static str>,
+/// }
+///
+/// impl<K> FieldVisitor<K, String> for NonEmpty {
+///     fn visit(&mut self, _: K, name_str: &'static str, value: &String) {
+///         if value.is_empty() {
+///             self.empty_fields.push(name_str);
+///         }
+///     }
+/// }
+///
+/// // `u32` fields are always valid
+/// impl<K> FieldVisitor<K, u32> for NonEmpty {
+///     fn visit(&mut self, _: K, _: &'static str, _: &u32) {}
+/// }
+///
+/// ```
+///
+/// [`FieldVisitor`]: ./trait.FieldVisitor.html
+pub trait VisitFields<V> {
+    /// Calls `visitor` for each field of `self`.
+    fn visit_fields(&self, visitor: &mut V);
+}
@@ -1,4 +1,4 @@
-use tstr::{alias, ts, TS};
+use tstr::{alias, ts, MakeTStr, TS};
 
 alias! {
     A = aaa;
@@ -30,4 +30,24 @@ fn alias_and_tups() {
 
     let _: (A, B) = Tup2;
     let _: (B, C, D) = Tup3;
+
+    let _: (A, B, A, A) = ts!(aaa, bbb, aaa, "aaa");
+    let _: (A, B, A) = ts!(aaa.bbb.aaa);
+}
+
+#[test]
+fn make_large_tuples_and_arrays() {
+    type Tup16 = (A, B, C, D, A, B, C, D, A, B, C, D, A, B, C, D);
+
+    let _: Tup16 = Tup16::MAKE;
+    let _: Tup16 =
+        ts!(aaa, bbb, ccc, ddd, aaa, bbb, ccc, ddd, aaa, bbb, ccc, ddd, aaa, bbb, ccc, ddd);
+
+    let _: [A; 0] = <[A; 0]>::MAKE;
+    let _: [A; 3] = <[A; 3]>::MAKE;
+    let _: [Tup2; 32] = <[Tup2; 32]>::MAKE;
+    let _: [[B; 2]; 2] = <[[B; 2]; 2]>::MAKE;
+
+    #[cfg(feature = "min_const_generics")]
+    let _: [C; 100] = <[C; 100]>::MAKE;
 }
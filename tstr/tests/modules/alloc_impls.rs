@@ -0,0 +1,63 @@
+use std::borrow::Cow;
+
+use tstr::{ts, TS};
+
+#[test]
+fn to_string() {
+    assert_eq!(ts!("").to_string(), "");
+    assert_eq!(ts!(a).to_string(), "a");
+    assert_eq!(ts!(hello).to_string(), "hello");
+    assert_eq!(ts!("hello world").to_string(), "hello world");
+    assert_eq!(ts!("ñá ü").to_string(), "ñá ü");
+    assert_eq!(ts!("0123456789").to_string(), "0123456789");
+
+    let long = ts!("a very long string that is split into many chunks, ñandú");
+    assert_eq!(
+        long.to_string(),
+        "a very long string that is split into many chunks, ñandú"
+    );
+}
+
+#[test]
+fn into_string() {
+    fn into_string<T: Into<String>>(value: T) -> String {
+        value.into()
+    }
+
+    assert_eq!(into_string(ts!(foo)), "foo");
+    assert_eq!(into_string(<TS!("bar baz")>::NEW), "bar baz");
+    assert_eq!(String::from(ts!("")), "");
+}
+
+#[test]
+fn into_cow() {
+    let foo: Cow<'static, str> = ts!(foo).into();
+    assert_eq!(foo, "foo");
+
+    let empty: Cow<'static, str> = ts!("").into();
+    assert_eq!(empty, "");
+
+    let non_ascii = Cow::<'static, str>::from(ts!("ñ"));
+    assert_eq!(non_ascii, "ñ");
+
+    #[cfg(feature = "const_generics")]
+    match foo {
+        Cow::Borrowed(string) => assert_eq!(string, "foo"),
+        Cow::Owned(_) => panic!("expected a borrowed string"),
+    }
+}
+
+#[test]
+#[cfg(feature = "const_generics")]
+fn erased_tstr() {
+    use tstr::ErasedTStr;
+
+    let erased = ErasedTStr::from(ts!(hello));
+
+    assert_eq!(String::from(erased), "hello");
+
+    match Cow::<'static, str>::from(erased) {
+        Cow::Borrowed(string) => assert_eq!(string, "hello"),
+        Cow::Owned(_) => panic!("expected a borrowed string"),
+    }
+}
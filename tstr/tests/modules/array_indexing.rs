@@ -0,0 +1,61 @@
+use std::ops::{Index, IndexMut};
+
+use tstr::{ts, ToUint, TS};
+
+#[test]
+fn index_array() {
+    let mut array = [3u32, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377];
+
+    assert_eq!(array[ts!(0)], 3);
+    assert_eq!(array[ts!(4)], 21);
+    assert_eq!(array[ts!(10)], 377);
+
+    array[ts!(0)] = 1000;
+    array[ts!(10)] += 1;
+    assert_eq!(array[ts!(0)], 1000);
+    assert_eq!(array[ts!(10)], 378);
+
+    // usize indexing is unaffected
+    assert_eq!(array[4], 21);
+    assert_eq!(array[1..3], [5, 8]);
+}
+
+#[test]
+fn index_array_generic() {
+    fn get_with_index<T, K>(this: &T, index: K) -> (&T::Output, usize)
+    where
+        T: Index<K>,
+        K: ToUint,
+    {
+        (&this[index], K::USIZE)
+    }
+
+    fn replace<T, K>(this: &mut T, index: K, value: T::Output) -> T::Output
+    where
+        T: IndexMut<K>,
+        T::Output: Sized,
+    {
+        std::mem::replace(&mut this[index], value)
+    }
+
+    let mut array = ["foo", "bar", "baz", "qux"];
+
+    assert_eq!(get_with_index(&array, ts!(1)), (&"bar", 1));
+    assert_eq!(get_with_index(&array, ts!(3)), (&"qux", 3));
+
+    assert_eq!(replace(&mut array, ts!(2), "hello"), "baz");
+    assert_eq!(array, ["foo", "bar", "hello", "qux"]);
+}
+
+#[test]
+fn index_bound() {
+    fn sum_two<T>(this: &T) -> u32
+    where
+        T: Index<TS!(0), Output = u32> + Index<TS!(1), Output = u32>,
+    {
+        this[ts!(0)] + this[ts!(1)]
+    }
+
+    assert_eq!(sum_two(&[3, 5]), 8);
+    assert_eq!(sum_two(&[8, 13, 21]), 21);
+}
@@ -0,0 +1,63 @@
+use tstr::{
+    case::{CamelCase, SnakeCase, ToCamelCase, ToSnakeCase},
+    TS,
+};
+
+fn assert_snake<S, Out>()
+where
+    S: ToSnakeCase<Output = Out>,
+{
+}
+
+fn assert_camel<S, Out>()
+where
+    S: ToCamelCase<Output = Out>,
+{
+}
+
+#[test]
+fn snake_case() {
+    assert_snake::<TS!(""), TS!("")>();
+    assert_snake::<TS!(A), TS!(a)>();
+    assert_snake::<TS!(a), TS!(a)>();
+    assert_snake::<TS!(Id), TS!(id)>();
+    assert_snake::<TS!(FooBar), TS!(foo_bar)>();
+    assert_snake::<TS!(fooBar), TS!(foo_bar)>();
+    assert_snake::<TS!(foo_bar), TS!(foo_bar)>();
+    assert_snake::<TS!(WaitingForInput), TS!(waiting_for_input)>();
+    assert_snake::<TS!(Http2Client), TS!(http2_client)>();
+    assert_snake::<TS!(ABC), TS!(a_b_c)>();
+    assert_snake::<TS!("ÑandúBar"), TS!("Ñandú_bar")>();
+    assert_snake::<
+        TS!(AVeryLongVariantNameWithManyWords),
+        TS!(a_very_long_variant_name_with_many_words),
+    >();
+
+    let _: TS!(user_id) = <SnakeCase<TS!(UserId)>>::NEW;
+}
+
+#[test]
+fn camel_case() {
+    assert_camel::<TS!(""), TS!("")>();
+    assert_camel::<TS!(a), TS!(A)>();
+    assert_camel::<TS!(A), TS!(A)>();
+    assert_camel::<TS!(id), TS!(Id)>();
+    assert_camel::<TS!(foo_bar), TS!(FooBar)>();
+    assert_camel::<TS!(FooBar), TS!(FooBar)>();
+    assert_camel::<TS!(waiting_for_input), TS!(WaitingForInput)>();
+    assert_camel::<TS!(_leading), TS!(Leading)>();
+    assert_camel::<TS!(trailing_), TS!(Trailing)>();
+    assert_camel::<TS!(double__under), TS!(DoubleUnder)>();
+    assert_camel::<TS!(http_2_client), TS!(Http2Client)>();
+    assert_camel::<TS!("___"), TS!("")>();
+    assert_camel::<TS!(a_very_long_field_name_with_many_words), TS!(AVeryLongFieldNameWithManyWords)>(
+    );
+
+    let _: TS!(UserId) = <CamelCase<TS!(user_id)>>::NEW;
+}
+
+#[test]
+fn round_trip() {
+    let _: TS!(foo_bar_baz) = <SnakeCase<CamelCase<TS!(foo_bar_baz)>>>::NEW;
+    let _: TS!(FooBarBaz) = <CamelCase<SnakeCase<TS!(FooBarBaz)>>>::NEW;
+}
@@ -0,0 +1,118 @@
+use tstr::tlist::{FromCharList, TList, TNil, ToCharList};
+use tstr::TS;
+
+type Chars<S> = <S as ToCharList>::Output;
+type FromChars<L> = <L as FromCharList>::Output;
+
+fn round_trip<S>()
+where
+    S: ToCharList,
+    S::Output: FromCharList<Output = S>,
+{
+}
+
+trait Len {
+    const VALUE: usize;
+}
+
+impl Len for TNil {
+    const VALUE: usize = 0;
+}
+
+impl<H, T: Len> Len for TList<H, T> {
+    const VALUE: usize = 1 + T::VALUE;
+}
+
+fn len<S>() -> usize
+where
+    S: ToCharList,
+    S::Output: Len,
+{
+    <S::Output as Len>::VALUE
+}
+
+#[test]
+fn round_trip_lengths() {
+    round_trip::<TS!("")>();
+    round_trip::<TS!("a")>();
+    round_trip::<TS!("abcdefg")>();
+    round_trip::<TS!("abcdefgh")>();
+    round_trip::<TS!("abcdefghi")>();
+    round_trip::<TS!("abcdefghijklmno")>();
+    round_trip::<TS!("abcdefghijklmnop")>();
+    round_trip::<TS!("abcdefghijklmnopq")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz01")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz012")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz012345678")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789a")>(
+    );
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz01")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefg")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefgh")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghi")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnop")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopq")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789a")>();
+    round_trip::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwx")>();
+}
+
+#[test]
+fn char_list_length() {
+    assert_eq!(len::<TS!("")>(), 0);
+    assert_eq!(len::<TS!("a")>(), 1);
+    assert_eq!(len::<TS!("abcdefgh")>(), 8);
+    assert_eq!(len::<TS!("abcdefghi")>(), 9);
+    assert_eq!(
+        len::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz01")>(),
+        64
+    );
+    assert_eq!(
+        len::<TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz012")>(),
+        65
+    );
+
+    #[cfg(feature = "min_const_generics")]
+    assert_eq!(len::<TS!("Größe")>(), 5);
+
+    #[cfg(not(feature = "min_const_generics"))]
+    assert_eq!(len::<TS!("Größe")>(), 7);
+}
+
+#[test]
+fn different_spellings() {
+    let _: Chars<TS!(foo)> = <Chars<TS!("foo")>>::NEW;
+    let _: Chars<TS!(0)> = <Chars<TS!("0")>>::NEW;
+    let _: Chars<TS!(r#type)> = <Chars<TS!("type")>>::NEW;
+}
+
+#[test]
+fn empty() {
+    let _: TNil = <Chars<TS!("")>>::NEW;
+    let _: TS!("") = <FromChars<TNil>>::NEW;
+}
+
+#[test]
+fn split_and_join() {
+    type Join<A, B> = FromChars<<Chars<A> as Append<Chars<B>>>::Output>;
+
+    trait Append<Rest> {
+        type Output;
+    }
+
+    impl<Rest> Append<Rest> for TNil {
+        type Output = Rest;
+    }
+
+    impl<H, T: Append<Rest>, Rest> Append<Rest> for TList<H, T> {
+        type Output = TList<H, T::Output>;
+    }
+
+    let _: TS!("Größe") = <Join<TS!("Gr"), TS!("öße")>>::NEW;
+    let _: TS!("abcdefghi") = <Join<TS!("abcdefgh"), TS!("i")>>::NEW;
+    let _: TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz01234567") = <Join<
+        TS!("abcdefghijklmnopqrstuvwxyz0123456789abcdefghijklmnopqrstuvwxyz01"),
+        TS!("234567"),
+    >>::NEW;
+}
@@ -0,0 +1,38 @@
+use tstr::{ts, ts_chars, TS_chars, TS};
+
+#[test]
+fn chars_macros() {
+    let _: TS!(a) = ts_chars!(a);
+    let _: TS!(foo) = ts_chars!(f o o);
+    let _: TS!(Foo_Bar) = ts_chars!(F o o _ B a r);
+    let _: TS!("_") = ts_chars!(_);
+    let _: TS!(100) = ts_chars!(1 0 0);
+    let _: TS!(abc0123456789) = ts_chars!(a b c 0 1 2 3 4 5 6 7 8 9);
+    let _: TS!(ABCDEFGHIJKLMNOPQRSTUVWXYZ) =
+        ts_chars!(A B C D E F G H I J K L M N O P Q R S T U V W X Y Z);
+    let _: TS!(abcdefghijklmnopqrstuvwxyz) =
+        ts_chars!(a b c d e f g h i j k l m n o p q r s t u v w x y z);
+
+    let _: TS_chars!(f o o) = ts!(foo);
+}
+
+#[test]
+fn chars_macros_lengths() {
+    let _: TS!(aaaaaaa) = ts_chars!(a a a a a a a);
+    let _: TS!(aaaaaaaa) = ts_chars!(a a a a a a a a);
+    let _: TS!(aaaaaaaab) = ts_chars!(a a a a a a a a b);
+    let _: TS!(aaaaaaaabbbbbbbb) = ts_chars!(a a a a a a a a b b b b b b b b);
+    let _: TS!(aaaaaaaabbbbbbbbc) = ts_chars!(a a a a a a a a b b b b b b b b c);
+    let _: TS!(aaaaaaaabbbbbbbbccccccccddddddddeeeeeeeeffffffffgggggggghhhhhhhh) = ts_chars!(
+        a a a a a a a a b b b b b b b b c c c c c c c c d d d d d d d d
+        e e e e e e e e f f f f f f f f g g g g g g g g h h h h h h h h
+    );
+}
+
+#[test]
+fn chars_macros_tuples() {
+    let _: TS!(foo, bar) = ts_chars!(f o o, b a r);
+    let _: TS!(foo, bar, baz) = ts_chars!(f o o, b a r, b a z,);
+    let _: TS!(foo) = ts_chars!(f o o,);
+    let _: TS!() = ts_chars!();
+}
@@ -0,0 +1,77 @@
+#![allow(clippy::type_complexity)]
+
+use std::fmt::Debug;
+
+use tstr::tlist::{TList, TNil};
+use tstr::{Columns, TS};
+
+#[derive(Columns)]
+struct Empty {}
+
+#[derive(Columns)]
+#[allow(dead_code, non_snake_case)]
+pub struct Employee {
+    pub id: u64,
+    pub(crate) first_name: String,
+    hireDate: String,
+}
+
+#[derive(Columns)]
+#[columns(case = "snake_case")]
+#[allow(dead_code, non_snake_case)]
+struct Snake<'a, T: Clone, U = Vec<u8>>
+where
+    U: Debug,
+{
+    userId: &'a str,
+    HTTPStatus: T,
+    list_2: U,
+    r#type: u8,
+}
+
+#[derive(Columns)]
+#[columns(case = "SCREAMING_SNAKE_CASE")]
+#[allow(dead_code, non_snake_case)]
+struct Screaming {
+    id: u64,
+    firstName: String,
+    hire_date: String,
+}
+
+mod renamed {
+    pub use tstr as other_tstr;
+}
+
+#[derive(Columns)]
+#[tstr(crate = renamed::other_tstr)]
+#[columns(case = "SCREAMING_SNAKE_CASE")]
+#[allow(dead_code)]
+struct Renamed {
+    foo: char,
+}
+
+#[test]
+fn column_names() {
+    assert_eq!(Empty::COLUMN_NAMES, [""; 0]);
+    assert_eq!(Employee::COLUMN_NAMES, ["id", "first_name", "hireDate"]);
+    assert_eq!(
+        <Snake<'_, u8>>::COLUMN_NAMES,
+        ["user_id", "http_status", "list_2", "type"]
+    );
+    assert_eq!(Screaming::COLUMN_NAMES, ["ID", "FIRST_NAME", "HIRE_DATE"]);
+    assert_eq!(Renamed::COLUMN_NAMES, ["FOO"]);
+}
+
+#[test]
+fn column_lists() {
+    let _: TNil = Empty::COLUMNS;
+
+    let _: TList<TS!(id), TList<TS!(first_name), TList<TS!(hireDate), TNil>>> = Employee::COLUMNS;
+
+    let _: TList<
+        TS!(user_id),
+        TList<TS!(http_status), TList<TS!(list_2), TList<TS!(type), TNil>>>,
+    > = <Snake<'_, u8>>::COLUMNS;
+
+    let _: TList<TS!(FOO), TNil> = Renamed::COLUMNS;
+}
@@ -1,4 +1,4 @@
-use tstr::{ts, TS};
+use tstr::{ts, ts_concat, TS_concat, TS};
 
 #[allow(dead_code)]
 type ConcatIdents = TS!("foobarbazqux");
@@ -23,6 +23,38 @@ fn test_concat() {
     const _: ConcatWithNum = ts!(concat!(0b1_1, ",", 0o_7, ",15,31"));
 }
 
+#[test]
+fn test_concat_std_literals() {
+    let _: TS!("prefix_name") = ts!(concat!("prefix_", "name"));
+    let _: TS!("a-b") = ts!(concat!('a', '-', 'b'));
+    let _: TS!("A\n'") = ts!(concat!('\x41', '\n', '\''));
+    let _: TS!("1.5,10.5,1e3,2.5") = ts!(concat!(1.5, ",", 1_0.5, ",", 1e3, ",", 2.5f32));
+    let _: TS!("-1,-31,-1.5") = ts!(concat!(-1, ",", -0x1F, ",", -1.5));
+    let _: TS!("truefalse") = ts!(concat!(true, false));
+    let _: TS!("1,42,-7,10") = ts!(concat!(1u8, ",", 42usize, ",", -7i32, ",", 0xAisize));
+}
+
+#[test]
+fn test_concat_macros() {
+    const _: ConcatIdents = ts_concat!(foo, bar, baz, qux);
+    const _: ConcatIdents = <TS_concat!(foo, "bar", concat!(baz, qux),)>::NEW;
+    let _: TS!(get_name) = ts_concat!(get_, name);
+    let _: TS!(field_0) = ts_concat!(field_, 0);
+    let _: TS!(r#type) = ts_concat!(r#type);
+    let _: TS!("") = ts_concat!();
+}
+
+macro_rules! getter_name {
+    ($field:ident) => {
+        TS_concat!(get_, $field)
+    };
+}
+
+#[test]
+fn test_concat_macros_in_macro_rules() {
+    let _: TS!(get_name) = <getter_name!(name)>::NEW;
+}
+
 #[test]
 fn test_stringify() {
     let _: TS!("0b11") = ts!(stringify!(0b11));
@@ -31,3 +63,18 @@ fn test_stringify() {
     let _: TS!("0x1F") = ts!(stringify!(0x1F));
     let _: TS!(r#""hello""#) = ts!(stringify!("hello"));
 }
+
+macro_rules! stringify_fragments {
+    ($ty:ty, $expr:expr, $ident:ident, $($tt:tt)*) => {
+        let _: TS!("Vec<u8>") = ts!(stringify!($ty));
+        let _: TS!("1 + 2") = ts!(stringify!($expr));
+        let _: TS!("foo") = ts!(stringify!($ident));
+        let _: TS!("a b") = ts!(stringify!($($tt)*));
+        let _: TS!("get_Vec<u8>") = ts!(concat!("get_", stringify!($ty)));
+    };
+}
+
+#[test]
+fn test_stringify_macro_fragments() {
+    stringify_fragments!(Vec<u8>, 1 + 2, foo, a b);
+}
@@ -0,0 +1,69 @@
+use tstr::{ts, StrValue, TS};
+
+const FOO: &str = "foo";
+
+mod keys {
+    pub const NAME: &str = "name";
+    pub const EMPTY: &str = "";
+    pub const NON_ASCII: &str = "ñandú";
+
+    pub struct Keys;
+
+    impl Keys {
+        pub const ASSOC: &'static str = "assoc";
+    }
+}
+
+#[test]
+fn const_args() {
+    let _: TS!(foo) = <TS!(@const FOO)>::NEW;
+    let _: TS!(name) = <TS!(@const keys::NAME)>::NEW;
+    let _: TS!(name) = <TS!(@const crate::modules::const_args::keys::NAME)>::NEW;
+    let _: TS!("") = <TS!(@const keys::EMPTY)>::NEW;
+    let _: TS!("ñandú") = <TS!(@const keys::NON_ASCII)>::NEW;
+    let _: TS!(assoc) = <TS!(@const keys::Keys::ASSOC)>::NEW;
+
+    assert_eq!(ts!(@const FOO), ts!(foo));
+    assert_eq!(ts!(@const keys::NAME).to_str(), "name");
+    assert_eq!(ts!(@const keys::Keys::ASSOC).to_str(), "assoc");
+}
+
+#[test]
+fn const_arg_bytes() {
+    const NAME: &[u8] = <TS!(@const keys::NAME) as StrValue>::BYTES;
+
+    assert_eq!(NAME, b"name");
+    assert_eq!(<TS!(@const keys::EMPTY)>::BYTES, b"");
+    assert_eq!(<TS!(@const keys::NON_ASCII)>::BYTES, "ñandú".as_bytes());
+}
+
+#[test]
+fn const_arg_array() {
+    use tstr::ToArray;
+
+    const NAME: [u8; 4] = <TS!(@const keys::NAME) as ToArray<4>>::ARRAY;
+    assert_eq!(NAME, *b"name");
+
+    let empty: [u8; 0] = ts!(@const keys::EMPTY).to_array();
+    assert_eq!(empty, []);
+
+    let non_ascii: [u8; 7] = ts!(@const keys::NON_ASCII).to_array();
+    assert_eq!(&non_ascii[..], "ñandú".as_bytes());
+}
+
+#[test]
+fn const_arg_matches() {
+    use tstr::utils::tstr_matches;
+
+    const MATCHES: [bool; 2] = [
+        tstr_matches::<TS!(@const keys::NAME)>("name"),
+        tstr_matches::<TS!(@const keys::NAME)>("nam"),
+    ];
+    assert_eq!(MATCHES, [true, false]);
+
+    assert!(!tstr_matches::<TS!(@const keys::NAME)>("names"));
+    assert!(!tstr_matches::<TS!(@const keys::NAME)>(""));
+    assert!(tstr_matches::<TS!(@const keys::EMPTY)>(""));
+    assert!(tstr_matches::<TS!(@const keys::NON_ASCII)>("ñandú"));
+    assert!(!tstr_matches::<TS!(@const keys::NON_ASCII)>("nandu"));
+}
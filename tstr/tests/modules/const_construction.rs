@@ -0,0 +1,57 @@
+// Tests that `TStr`s can be constructed in const contexts with every combination of features.
+#![allow(clippy::type_complexity)]
+
+use tstr::{ts, MakeTStr, TStr, TS};
+
+const IDENT: TS!(foo) = ts!(foo);
+const INTEGER: TS!(100) = ts!(100);
+const STRING: TS!("hello world") = ts!("hello world");
+const NON_ASCII: TS!("ñandú") = ts!("ñandú");
+const LONG: TS!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-") =
+    ts!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-");
+const CONCAT: TS!("a-12.5") = ts!(concat!('a', -1, 2.5));
+const PATH: TS!(Vec<u8>) = ts!(Vec<u8>);
+const LIST: (TS!(foo), TS!(0), TS!(bar)) = ts!(foo, 0, "bar");
+const DOTTED: TS!(foo.bar) = ts!(foo.bar);
+const NEW: TS!(new) = TStr::NEW;
+const MAKE: TS!(make) = MakeTStr::MAKE;
+
+static STATIC_IDENT: TS!(foo) = ts!(foo);
+static STATIC_LIST: (TS!(a), TS!(b)) = ts!(a, b);
+static STATIC_NEW: TS!(new) = TStr::NEW;
+
+struct Named<T> {
+    name: TStr<T>,
+}
+
+impl<T> Named<T> {
+    const NEW: Self = Named { name: TStr::NEW };
+}
+
+fn generic_new<T>() -> TStr<T> {
+    Named::<T>::NEW.name
+}
+
+const fn generic_const_fn<T>() -> TStr<T> {
+    TStr::NEW
+}
+
+#[test]
+fn const_items() {
+    let _ = (
+        IDENT, INTEGER, STRING, NON_ASCII, LONG, CONCAT, PATH, LIST, DOTTED, NEW, MAKE,
+    );
+    let _: TS!(foo) = STATIC_IDENT;
+    let _: (TS!(a), TS!(b)) = STATIC_LIST;
+    let _: TS!(new) = STATIC_NEW;
+}
+
+#[test]
+fn generic_const() {
+    let _: TS!(foo) = generic_new();
+    let _: TS!("") = generic_new();
+    let _: TS!(bar) = generic_const_fn();
+    let _: TS!("hello world") = Named::NEW.name;
+
+    assert_eq!(std::mem::size_of::<Named<TS!(foo)>>(), 0);
+}
@@ -0,0 +1,131 @@
+use std::ops::{Index, IndexMut};
+
+use tstr::{ts, ContainsFields, ContainsFieldsMut, TS};
+
+struct Point {
+    x: u32,
+    y: u32,
+    name: &'static str,
+}
+
+impl Index<TS!(x)> for Point {
+    type Output = u32;
+
+    fn index(&self, _: TS!(x)) -> &u32 {
+        &self.x
+    }
+}
+
+impl IndexMut<TS!(x)> for Point {
+    fn index_mut(&mut self, _: TS!(x)) -> &mut u32 {
+        &mut self.x
+    }
+}
+
+impl Index<TS!(y)> for Point {
+    type Output = u32;
+
+    fn index(&self, _: TS!(y)) -> &u32 {
+        &self.y
+    }
+}
+
+impl IndexMut<TS!(y)> for Point {
+    fn index_mut(&mut self, _: TS!(y)) -> &mut u32 {
+        &mut self.y
+    }
+}
+
+impl Index<TS!(name)> for Point {
+    type Output = &'static str;
+
+    fn index(&self, _: TS!(name)) -> &&'static str {
+        &self.name
+    }
+}
+
+const fn point() -> Point {
+    Point {
+        x: 3,
+        y: 5,
+        name: "point",
+    }
+}
+
+fn assert_contains<T, K0, K1, K2>()
+where
+    T: ContainsFields<K0, K1, K2>,
+{
+}
+
+fn assert_contains_mut<T, K0, K1, K2>()
+where
+    T: ContainsFieldsMut<K0, K1, K2>,
+{
+}
+
+#[test]
+fn contains_fields_bounds() {
+    assert_contains::<Point, TS!(x), TS!(x), TS!(x)>();
+    assert_contains::<Point, TS!(x), TS!(y), TS!(name)>();
+    assert_contains::<Point, TS!(name), TS!(y), TS!(x)>();
+
+    assert_contains_mut::<Point, TS!(x), TS!(x), TS!(x)>();
+    assert_contains_mut::<Point, TS!(y), TS!(x), TS!(y)>();
+}
+
+#[test]
+fn contains_fields_access() {
+    fn describe<T>(this: &T) -> String
+    where
+        T: ContainsFields<TS!(x), TS!(y), TS!(name)>,
+        <T as Index<TS!(x)>>::Output: std::fmt::Display,
+        <T as Index<TS!(y)>>::Output: std::fmt::Display,
+        <T as Index<TS!(name)>>::Output: std::fmt::Display,
+    {
+        format!("{}({}, {})", &this[ts!(name)], &this[ts!(x)], &this[ts!(y)])
+    }
+
+    fn name_only<T>(this: &T) -> &<T as Index<TS!(name)>>::Output
+    where
+        T: ContainsFields<TS!(name)>,
+    {
+        &this[ts!(name)]
+    }
+
+    assert_eq!(describe(&point()), "point(3, 5)");
+    assert_eq!(*name_only(&point()), "point");
+}
+
+#[test]
+fn contains_fields_mut_access() {
+    fn swap_xy<T, U>(this: &mut T)
+    where
+        T: ContainsFieldsMut<TS!(x), TS!(y)>,
+        T: Index<TS!(x), Output = U> + Index<TS!(y), Output = U>,
+        U: Copy,
+    {
+        let x = this[ts!(x)];
+        this[ts!(x)] = this[ts!(y)];
+        this[ts!(y)] = x;
+    }
+
+    let mut point = point();
+    swap_xy(&mut point);
+    assert_eq!((point.x, point.y), (5, 3));
+}
+
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+#[test]
+fn contains_fields_record() {
+    fn sum_xy<T>(this: &T) -> u32
+    where
+        T: ContainsFields<TS!(x), TS!(y)>,
+        T: Index<TS!(x), Output = u32> + Index<TS!(y), Output = u32>,
+    {
+        this[ts!(x)] + this[ts!(y)]
+    }
+
+    assert_eq!(sum_xy(&tstr::record! {x: 3, y: 5}), 8);
+    assert_eq!(sum_xy(&tstr::record! {name: "hi", y: 8, x: 13}), 21);
+}
@@ -0,0 +1,70 @@
+use tstr::{declare_fields, ts, TS};
+
+use std::ops::{Index, IndexMut};
+
+declare_fields! {
+    struct Empty {}
+}
+
+declare_fields! {
+    /// Docs
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Point {
+        /// The x coordinate
+        pub x: u32,
+        #[allow(dead_code)]
+        pub(crate) y: i64
+    }
+}
+
+declare_fields! {
+    struct Wrapper<T, U,> {
+        first: T,
+        second: Option<U>,
+        r#type: &'static str,
+    }
+}
+
+fn assert_field<T, N, F>(_: &T, _: N)
+where
+    T: Index<N, Output = F> + IndexMut<N>,
+{
+}
+
+#[test]
+fn declared_struct() {
+    let _ = Empty {};
+
+    let mut point = Point { x: 3, y: -5 };
+    assert_eq!(point.clone(), Point { x: 3, y: -5 });
+
+    assert_field::<_, _, u32>(&point, ts!(x));
+    assert_field::<_, _, i64>(&point, ts!(y));
+
+    assert_eq!(point[ts!(x)], 3);
+    assert_eq!(point[ts!(y)], -5);
+
+    point[ts!(x)] += 10;
+    point[ts!(y)] *= 2;
+    assert_eq!(point, Point { x: 13, y: -10 });
+}
+
+#[test]
+fn declared_generic_struct() {
+    let mut wrapper = Wrapper {
+        first: 'a',
+        second: Some(8u8),
+        r#type: "wrapper",
+    };
+
+    assert_field::<_, TS!(first), char>(&wrapper, ts!(first));
+    assert_field::<_, TS!(second), Option<u8>>(&wrapper, ts!(second));
+    assert_field::<_, TS!("type"), &'static str>(&wrapper, ts!("type"));
+
+    wrapper[ts!(first)] = 'b';
+    wrapper[ts!(second)] = None;
+
+    assert_eq!(wrapper[ts!(first)], 'b');
+    assert_eq!(wrapper[ts!(second)], None);
+    assert_eq!(wrapper[ts!("type")], "wrapper");
+}
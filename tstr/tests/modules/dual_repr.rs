@@ -0,0 +1,77 @@
+use tstr::ts_tupled;
+
+#[cfg(not(feature = "min_const_generics"))]
+#[test]
+fn same_as_default_repr() {
+    use tstr::{ts, TS_tupled, TS};
+
+    let _: TS!(foo) = ts_tupled!(foo);
+    let _: TS_tupled!(foo) = ts!(foo);
+    let _: TS_tupled!("") = ts!("");
+    let _: TS_tupled!(foo, bar) = ts!(foo.bar);
+}
+
+#[cfg(feature = "min_const_generics")]
+#[test]
+fn tupled_repr() {
+    use tstr::{__0x61, __0x62, __0x63, TStr};
+
+    let _: TStr<()> = ts_tupled!("");
+    let _: TStr<(__0x61,)> = ts_tupled!(a);
+    let _: TStr<(__0x61, __0x62, __0x63)> = ts_tupled!(abc);
+    type A8 = (
+        __0x61,
+        __0x61,
+        __0x61,
+        __0x61,
+        __0x61,
+        __0x61,
+        __0x61,
+        __0x61,
+    );
+    let _: TStr<(A8, (__0x62,))> = ts_tupled!(aaaaaaaab);
+    let _: (TStr<(__0x61,)>, TStr<(__0x62,)>) = ts_tupled!(a, b);
+}
+
+#[cfg(feature = "const_generics")]
+mod conversions {
+    use tstr::{StrValue, TStr};
+
+    macro_rules! round_trip {
+        ($($string:tt)*) => {$({
+            let consted: TS!($string) = <TS!($string)>::from(ts_tupled!($string));
+            let tupled: TS_tupled!($string) = consted.into();
+            assert_eq!(tupled, ts_tupled!($string));
+            assert_eq!(consted.to_str(), $string);
+        })*};
+    }
+
+    use tstr::{ts_tupled, TS_tupled, TS};
+
+    #[test]
+    fn round_trips() {
+        round_trip! {
+            ""
+            "a"
+            "foo"
+            "abcdefgh"
+            "abcdefghi"
+            "Größe"
+            "人"
+            "0123456789012345678901234567890123456789012345678901234567890123"
+            "01234567890123456789012345678901234567890123456789012345678901234"
+        }
+    }
+
+    #[test]
+    fn generic_conversion() {
+        fn to_str<T, U>(this: TStr<T>) -> &'static str
+        where
+            U: From<TStr<T>> + StrValue,
+        {
+            U::from(this).to_str()
+        }
+
+        assert_eq!(to_str::<_, TS!(hello)>(ts_tupled!(hello)), "hello");
+    }
+}
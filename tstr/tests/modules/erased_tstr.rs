@@ -0,0 +1,72 @@
+use tstr::{ts, ErasedTStr, TS};
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+};
+
+#[test]
+fn erased_eq_ord() {
+    let foo = ErasedTStr::from(ts!(foo));
+    let bar = ErasedTStr::from(ts!(bar));
+
+    assert_eq!(foo, ErasedTStr::from(ts!(foo)));
+    assert_eq!(foo, ErasedTStr::new("foo"));
+    assert_ne!(foo, bar);
+
+    assert_eq!(foo.cmp(&bar), Ordering::Greater);
+    assert_eq!(bar.cmp(&foo), Ordering::Less);
+    assert_eq!(foo.partial_cmp(&foo), Some(Ordering::Equal));
+
+    assert_eq!(ErasedTStr::from(ts!("")).as_str(), "");
+    assert_eq!(ErasedTStr::from(ts!(ñ)).as_str(), "ñ");
+}
+
+#[test]
+fn erased_hash() {
+    const FOO: ErasedTStr = ErasedTStr::new(<TS!(foo)>::STR);
+
+    assert_eq!(
+        FOO.precomputed_hash(),
+        ErasedTStr::from(ts!(foo)).precomputed_hash()
+    );
+    assert_ne!(
+        FOO.precomputed_hash(),
+        ErasedTStr::new("fo").precomputed_hash()
+    );
+    assert_ne!(
+        ErasedTStr::new("").precomputed_hash(),
+        ErasedTStr::new("\0").precomputed_hash()
+    );
+
+    let mut map = HashMap::new();
+    map.insert(ErasedTStr::from(ts!(foo)), 3);
+    map.insert(ErasedTStr::from(ts!(bar)), 5);
+    map.insert(ErasedTStr::from(ts!(foo)), 8);
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&FOO], 8);
+    assert_eq!(map[&ErasedTStr::new("bar")], 5);
+}
+
+#[test]
+fn erased_collections() {
+    let map = vec![
+        (ErasedTStr::from(ts!(c)), 0),
+        (ErasedTStr::from(ts!(a)), 1),
+        (ErasedTStr::from(ts!(b)), 2),
+    ]
+    .into_iter()
+    .collect::<BTreeMap<_, _>>();
+
+    let keys = map.keys().map(|k| k.as_str()).collect::<Vec<_>>();
+    assert_eq!(keys, ["a", "b", "c"]);
+}
+
+#[test]
+fn erased_fmt() {
+    let foo = ErasedTStr::from(ts!("foo bar"));
+
+    assert_eq!(format!("{:?}", foo), r#"ErasedTStr("foo bar")"#);
+    assert_eq!(format!("{}", foo), "foo bar");
+}
@@ -0,0 +1,54 @@
+use tstr::{ts, Field, NestedIndex, NestedIndexMut, TS};
+
+#[test]
+fn field_accessors() {
+    let mut field = Field::new(ts!(foo), 3u32);
+    let _: &Field<TS!(foo), u32> = &field;
+
+    assert_eq!(field.name(), ts!(foo));
+    assert_eq!(*field.value(), 3);
+    assert_eq!(*field, 3);
+    assert_eq!(field[ts!(foo)], 3);
+
+    *field.value_mut() += 1;
+    assert_eq!(*field, 4);
+
+    *field += 1;
+    assert_eq!(*field, 5);
+
+    field[ts!(foo)] += 1;
+    assert_eq!(field.into_inner(), 6);
+}
+
+#[test]
+fn field_constructors() {
+    const CONST_FIELD: Field<TS!(bar), &str> = Field::new(ts!(bar), "hello");
+    assert_eq!(*CONST_FIELD, "hello");
+
+    let field = Field::<TS!(bar), _>::from_value("hello");
+    assert_eq!(field, CONST_FIELD);
+
+    let default: Field<TS!(baz), u8> = Default::default();
+    assert_eq!(*default, 0);
+}
+
+#[test]
+fn field_cmp() {
+    let a = Field::new(ts!(foo), 3u8);
+    let b = Field::new(ts!(foo), 5u8);
+
+    assert!(a < b);
+    assert_ne!(a, b);
+    assert_eq!(a, a.clone());
+    assert_eq!(a.max(b), b);
+}
+
+#[test]
+fn field_nested_index() {
+    let mut nested = Field::new(ts!(outer), Field::new(ts!(inner), 8u16));
+
+    assert_eq!(*nested.nested_index(ts!(outer.inner)), 8);
+
+    *nested.nested_index_mut(ts!(outer.inner)) = 13;
+    assert_eq!(nested.into_inner().into_inner(), 13);
+}
@@ -0,0 +1,115 @@
+use std::fmt::Debug;
+
+use tstr::tlist::{TList, TNil};
+use tstr::{Fields, TS};
+
+#[derive(Fields)]
+struct Empty {}
+
+#[derive(Fields)]
+#[allow(dead_code)]
+pub struct Point {
+    /// The x coordinate
+    pub x: u32,
+    pub(crate) y: i64,
+}
+
+#[derive(Fields)]
+#[allow(dead_code)]
+struct Generic<'a, T: Clone, U = Vec<u8>>
+where
+    U: Debug,
+{
+    reference: &'a str,
+    value: T,
+    list: U,
+}
+
+#[derive(Fields)]
+#[allow(dead_code)]
+struct RawIdents {
+    r#type: u8,
+    r#match: bool,
+}
+
+mod renamed {
+    pub use tstr as other_tstr;
+}
+
+#[derive(Fields)]
+#[tstr(crate = renamed::other_tstr)]
+#[allow(dead_code)]
+struct Renamed {
+    foo: char,
+}
+
+#[derive(Fields)]
+#[allow(dead_code)]
+struct LongNames {
+    a_very_long_field_name_that_is_used_to_test_that_long_names_are_handled_by_the_derive_macros_of_tstr:
+        u8,
+    short: bool,
+    a_very_long_field_name_that_is_used_to_test_that_long_names_are_handled_by_the_derive_macros_of_tstr_again:
+        char,
+}
+
+fn field_names<T: Fields>() -> &'static [&'static str] {
+    T::FIELD_NAMES
+}
+
+fn assert_list<T, L>()
+where
+    T: Fields<List = L>,
+{
+}
+
+#[test]
+fn fields_names() {
+    assert_eq!(Empty::FIELD_NAMES, [""; 0]);
+    assert_eq!(Point::FIELD_NAMES, ["x", "y"]);
+    assert_eq!(
+        field_names::<Generic<'_, u8>>(),
+        ["reference", "value", "list"]
+    );
+    assert_eq!(RawIdents::FIELD_NAMES, ["type", "match"]);
+    assert_eq!(Renamed::FIELD_NAMES, ["foo"]);
+    assert_eq!(
+        LongNames::FIELD_NAMES,
+        [
+            "a_very_long_field_name_that_is_used_to_test_that_long_names_are_handled_by_the_derive_macros_of_tstr",
+            "short",
+            "a_very_long_field_name_that_is_used_to_test_that_long_names_are_handled_by_the_derive_macros_of_tstr_again",
+        ]
+    );
+}
+
+#[test]
+fn fields_list() {
+    assert_list::<Empty, TNil>();
+    assert_list::<Point, TList<(TS!(x), u32), TList<(TS!(y), i64), TNil>>>();
+    assert_list::<
+        Generic<'static, char, bool>,
+        TList<
+            (TS!(reference), &'static str),
+            TList<(TS!(value), char), TList<(TS!(list), bool), TNil>>,
+        >,
+    >();
+    assert_list::<RawIdents, TList<(TS!("type"), u8), TList<(TS!("match"), bool), TNil>>>();
+    assert_list::<Renamed, TList<(TS!(foo), char), TNil>>();
+    assert_list::<
+        LongNames,
+        TList<
+            (TS!(a_very_long_field_name_that_is_used_to_test_that_long_names_are_handled_by_the_derive_macros_of_tstr), u8),
+            TList<
+                (TS!(short), bool),
+                TList<
+                    (
+                        TS!(a_very_long_field_name_that_is_used_to_test_that_long_names_are_handled_by_the_derive_macros_of_tstr_again),
+                        char,
+                    ),
+                    TNil,
+                >,
+            >,
+        >,
+    >();
+}
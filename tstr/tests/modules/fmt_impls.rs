@@ -0,0 +1,63 @@
+use tstr::{ts, TS};
+
+use ufmt::{uDebug, uDisplay, uwrite};
+
+fn display<T: uDisplay>(value: T) -> String {
+    let mut out = String::new();
+    uwrite!(out, "{}", value).unwrap();
+    out
+}
+
+fn debug<T: uDebug>(value: T) -> String {
+    let mut out = String::new();
+    uwrite!(out, "{:?}", value).unwrap();
+    out
+}
+
+#[test]
+fn ufmt_display() {
+    assert_eq!(display(ts!("")), "");
+    assert_eq!(display(ts!(a)), "a");
+    assert_eq!(display(ts!(hello)), "hello");
+    assert_eq!(display(ts!("hello world")), "hello world");
+    assert_eq!(display(ts!("ñá ü")), "ñá ü");
+    assert_eq!(display(ts!("0123456789")), "0123456789");
+}
+
+#[test]
+fn ufmt_debug() {
+    assert_eq!(debug(ts!("")), r#""""#);
+    assert_eq!(debug(ts!(foo)), r#""foo""#);
+    assert_eq!(debug(ts!("a\"b\n")), r#""a\"b\n""#);
+    assert_eq!(debug(ts!("ñ")), r#""ñ""#);
+}
+
+#[test]
+fn ufmt_long_strings() {
+    // long enough that the string is written in multiple chunks,
+    // with multi-byte characters split between the chunks
+    macro_rules! test_long {
+        ($($str:literal)*) => {$(
+            assert_eq!(display(<TS!($str)>::NEW), $str);
+            assert_eq!(debug(<TS!($str)>::NEW), format!("{:?}", $str));
+        )*};
+    }
+
+    test_long! {
+        "0123456789012345678901234567890123456789012345678901234567890123456789"
+        "012345678901234567890123456789012345678901234567890123456789012ñ3456789"
+        "ñññññññññññññññññññññññññññññññññññññññññññññññññññññññññññññññññññññññ"
+        "€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€€"
+        "🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀"
+    }
+}
+
+#[test]
+fn defmt_format() {
+    fn assert_format<T: defmt::Format>(_: T) {}
+
+    assert_format(ts!(""));
+    assert_format(ts!(foo));
+    assert_format(ts!("hello, world"));
+    assert_format(<TS!("🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀🦀")>::NEW);
+}
@@ -0,0 +1,47 @@
+#![allow(clippy::type_complexity)]
+
+use tstr::{ts, ts_format, TS_format, TS};
+
+#[test]
+fn placeholders() {
+    let _: TS!("") = ts_format!("");
+    let _: TS!(hello) = ts_format!("hello");
+    let _: TS!(foo_bar) = ts_format!("{}_{}", foo, bar);
+    let _: TS!(bar_foo) = ts_format!("{1}_{0}", foo, bar);
+    let _: TS!("foo-foo") = ts_format!("{0}-{0}", foo);
+    let _: TS!("a.b.a") = ts_format!("{}.{}.{0}", a, b);
+    let _: TS!("{}") = ts_format!("{{}}");
+    let _: TS!("{foo}") = ts_format!("{{{}}}", foo);
+    let _: TS!(foo) = ts_format!(r#"{}"#, foo);
+    let _: TS!("ñandú_1") = ts_format!("{}_{}", "ñandú", 1);
+    let _: TS!(trailing) = ts_format!("{}", trailing,);
+}
+
+#[test]
+fn argument_kinds() {
+    let _: TS!("s_16_-3_1.5_c_true") =
+        ts_format!("{}_{}_{}_{}_{}_{}", "s", 0x10, -3, 1.5, 'c', true);
+    let _: TS!(foo_bar) = ts_format!("{}_{}", TS!(foo), ts!(bar));
+    let _: TS!(foo_10) = ts_format!("{}_{}", TS!("foo"), TS!(0xA));
+    let _: TS!(ab_Vec) = ts_format!("{}_{}", concat!(a, b), stringify!(Vec));
+    let _: TS!(get_set_x) = ts_format!("{}_{}", format!("get_{}", set), x);
+}
+
+#[test]
+fn nested_in_ts() {
+    let _: TS_format!("{}_{}", foo, 0) = <TS!(foo_0)>::NEW;
+    let _: (TS!(id), TS!(user_id)) = ts!(id, format!("user_{}", id));
+    let _: TS!(format_) = ts!(concat!(format, "_"));
+    let _: TS!(format) = ts!(format);
+}
+
+macro_rules! getter_name {
+    ($field:ident) => {
+        TS_format!("get_{}", $field)
+    };
+}
+
+#[test]
+fn in_macro_rules() {
+    let _: TS!(get_name) = <getter_name!(name)>::NEW;
+}
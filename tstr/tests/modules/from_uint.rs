@@ -0,0 +1,24 @@
+use tstr::{FromUint, TStrOfUint, ToUint, Uint, TS};
+
+macro_rules! assert_from_uint {
+    ($($n:literal)*) => {
+        $(
+            let _: TStrOfUint<$n> = <TS!($n)>::NEW;
+            assert_eq!(<TStrOfUint<$n>>::USIZE, $n);
+            assert_eq!(<Uint<$n> as FromUint>::NEW, <TS!($n)>::NEW);
+        )*
+    };
+}
+
+#[test]
+fn from_uint() {
+    assert_from_uint! {0 1 2 9 10 11 99 100 101 128 200 254 255}
+}
+
+#[test]
+fn from_uint_round_trip() {
+    const N: usize = <TS!(42)>::USIZE;
+
+    let _: TStrOfUint<N> = <TS!(42)>::NEW;
+    let _: TStrOfUint<{ N + 1 }> = <TS!(43)>::NEW;
+}
@@ -0,0 +1,101 @@
+use frunk::labelled::{field_with_name, Field as FrunkField};
+
+use tstr::frunk_fields::LabelTStr;
+use tstr::{ts, Field, TS};
+
+#[cfg(not(feature = "min_const_generics"))]
+#[test]
+#[allow(clippy::type_complexity)]
+fn char_labels() {
+    use frunk::labelled::chars::*;
+
+    let _: LabelTStr<(a,)> = ts!(a);
+    let _: LabelTStr<(n, a, m, e)> = ts!(name);
+    let _: LabelTStr<(A, b, C, d, E, f, G, h)> = ts!(AbCdEfGh);
+    let _: LabelTStr<(A, b, C, d, E, f, G, h, I)> = ts!(AbCdEfGhI);
+    let _: LabelTStr<(__, _0, _1, _2, _3, _4, _5, _6, _7, _8, _9, __)> = ts!(_0123456789_);
+    let _: LabelTStr<(
+        a,
+        b,
+        c,
+        d,
+        e,
+        f,
+        g,
+        h,
+        i,
+        j,
+        k,
+        l,
+        m,
+        n,
+        o,
+        p,
+        q,
+        r,
+        s,
+        t,
+        u,
+        v,
+        w,
+        x,
+        y,
+        z,
+    )> = ts!(abcdefghijklmnopqrstuvwxyz);
+
+    #[rustfmt::skip]
+    let _: LabelTStr<(
+        a, b, c, d, e, f, g, h, a, b, c, d, e, f, g, h,
+        a, b, c, d, e, f, g, h, a, b, c, d, e, f, g, h,
+        a, b, c, d, e, f, g, h, a, b, c, d, e, f, g, h,
+        a, b, c, d, e, f, g, h, a, b, c, d, e, f, g, h,
+    )> = ts!("abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh");
+
+    let field: Field<TS!(foo), u32> = field_with_name::<(f, o, o), _>("foo", 3).into();
+    assert_eq!(field.into_inner(), 3);
+}
+
+#[test]
+#[allow(clippy::type_complexity)]
+fn tstr_labels() {
+    let _: LabelTStr<TS!(foo)> = ts!(foo);
+    let _: LabelTStr<TS!("hello world")> = ts!("hello world");
+
+    let frunk_field: FrunkField<TS!(bar), &str> = field_with_name("bar", "baz");
+    let field = Field::from(frunk_field);
+    assert_eq!(field.name(), ts!(bar));
+    assert_eq!(*field.value(), "baz");
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+fn into_frunk_field() {
+    let frunk_field = FrunkField::from(Field::new(ts!(foo), 8));
+    let _: FrunkField<TS!(foo), i32> = frunk_field;
+    assert_eq!(frunk_field.name, "foo");
+    assert_eq!(frunk_field.value, 8);
+}
+
+#[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+#[test]
+fn hlist_into_record() {
+    use frunk::hlist;
+    use frunk::hlist::HNil;
+    use frunk::labelled::chars::*;
+
+    use tstr::records::Record;
+
+    let _: Record<()> = HNil.into();
+
+    let list = hlist![
+        field_with_name::<(x,), _>("x", 3u8),
+        field_with_name::<TS!(y), _>("y", "foo"),
+        field_with_name::<(l, o, n, g, __, n, a, m, e), _>("long_name", 'c'),
+    ];
+
+    let record: tstr::Record! {x: u8, y: &str, long_name: char} = list.into();
+
+    assert_eq!(record[ts!(x)], 3);
+    assert_eq!(record[ts!(y)], "foo");
+    assert_eq!(record[ts!(long_name)], 'c');
+}
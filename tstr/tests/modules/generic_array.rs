@@ -0,0 +1,54 @@
+#![allow(clippy::type_complexity)]
+
+use tstr::{ts, ByteArrayOf, TS};
+
+use generic_array::GenericArray;
+use typenum::{U0, U11, U3};
+
+#[test]
+fn to_generic_array() {
+    let empty: GenericArray<u8, U0> = ts!("").to_generic_array();
+    assert_eq!(empty.as_slice(), b"");
+
+    let foo: GenericArray<u8, U3> = ts!(foo).to_generic_array();
+    assert_eq!(foo.as_slice(), b"foo");
+
+    let hello: GenericArray<u8, U11> = ts!("hello world").to_generic_array();
+    assert_eq!(hello.as_slice(), b"hello world");
+
+    assert_eq!(
+        ts!("ñandú").to_generic_array().as_slice(),
+        "ñandú".as_bytes()
+    );
+}
+
+#[test]
+fn long_strings() {
+    let long = ts!("0123456789_0123456789_0123456789_0123456789_0123456789_0123456789_");
+    assert_eq!(
+        long.to_generic_array().as_slice(),
+        b"0123456789_0123456789_0123456789_0123456789_0123456789_0123456789_"
+    );
+
+    // longer than the buffer that `to_generic_array` uses internally
+    let non_ascii = ts!("ñññññññññññññññññññññññññññññññññññññññññññññññññ");
+    assert_eq!(
+        non_ascii.to_generic_array().as_slice(),
+        "ñññññññññññññññññññññññññññññññññññññññññññññññññ".as_bytes()
+    );
+}
+
+#[test]
+fn byte_array_of() {
+    struct Packet {
+        key: ByteArrayOf<TS!(key)>,
+        other: ByteArrayOf<TS!(other)>,
+    }
+
+    let packet = Packet {
+        key: ts!(key).to_generic_array(),
+        other: ts!(other).to_generic_array(),
+    };
+    assert_eq!(packet.key.as_slice(), b"key");
+    assert_eq!(packet.other.as_slice(), b"other");
+}
@@ -0,0 +1,92 @@
+use tstr::{impl_index, ts, TS};
+
+use std::ops::Index;
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl_index! {
+    Point {
+        x: u32 => &self.x,
+        y: u32 => &self.y
+    }
+}
+
+static ORIGIN: u32 = 0;
+
+struct Constant;
+
+impl_index! {
+    Constant {}
+}
+
+impl_index! {
+    Constant {
+        origin: u32 => &ORIGIN,
+        "hello world": str => "hello",
+    }
+}
+
+struct Wrapper<'a, T, const N: usize> {
+    array: [T; N],
+    slice: &'a [T],
+}
+
+impl_index! {
+    impl['a, T: Copy, const N: usize] Wrapper<'a, T, N> {
+        array: [T] => &self.array,
+        slice: [T] => self.slice,
+        0: T => &self.array[0],
+        last: T => match self.slice.last() {
+            Some(x) => x,
+            None => &self.array[N - 1],
+        },
+        parenthesized: T => &(self.array)[2],
+        braced: T => {
+            let array = &self.array;
+            &array[1]
+        },
+    }
+}
+
+fn assert_field<T, N, F>(_: &T, _: N)
+where
+    T: Index<N, Output = F>,
+    F: ?Sized,
+{
+}
+
+#[test]
+fn impl_index_named() {
+    let point = Point { x: 3, y: 5 };
+    assert_eq!(point[ts!(x)], 3);
+    assert_eq!(point[ts!(y)], 5);
+    assert_field::<_, TS!(x), u32>(&point, ts!(x));
+
+    assert_eq!(Constant[ts!(origin)], 0);
+    assert_eq!(&Constant[ts!("hello world")], "hello");
+}
+
+#[test]
+fn impl_index_generic() {
+    let slice = &[13, 21][..];
+    let wrapper = Wrapper {
+        array: [3, 5, 8],
+        slice,
+    };
+
+    assert_eq!(wrapper[ts!(array)], [3, 5, 8]);
+    assert_eq!(wrapper[ts!(slice)], [13, 21]);
+    assert_eq!(wrapper[ts!(0)], 3);
+    assert_eq!(wrapper[ts!(last)], 21);
+    assert_eq!(wrapper[ts!(parenthesized)], 8);
+    assert_eq!(wrapper[ts!(braced)], 5);
+
+    let empty = Wrapper {
+        array: [3, 5, 8],
+        slice: &[],
+    };
+    assert_eq!(empty[ts!(last)], 8);
+}
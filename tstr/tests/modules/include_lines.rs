@@ -0,0 +1,22 @@
+use tstr::{include_tstr_lines, MakeTStr, TS};
+
+#[test]
+fn include_lines() {
+    type Permissions = include_tstr_lines!("tests/data/permissions.txt");
+
+    let _: Permissions = <TS!(read, write, delete, admin)>::default();
+    let _: TS!(read, write, delete, admin) = Permissions::MAKE;
+}
+
+#[test]
+fn include_lines_in_impls() {
+    trait Keys {
+        const COUNT: usize;
+    }
+
+    impl Keys for include_tstr_lines!("tests/data/permissions.txt") {
+        const COUNT: usize = 4;
+    }
+
+    assert_eq!(<TS!(read, write, delete, admin) as Keys>::COUNT, 4);
+}
@@ -0,0 +1,120 @@
+use std::fmt::Debug;
+
+use tstr::records::IntoRecord;
+use tstr::{record, ts, Record};
+
+#[derive(Debug, PartialEq, IntoRecord)]
+struct Empty {}
+
+#[derive(Debug, PartialEq, IntoRecord)]
+pub struct Point {
+    /// The x coordinate
+    pub x: u32,
+    #[allow(dead_code)]
+    pub(crate) y: u32,
+}
+
+#[derive(Debug, IntoRecord)]
+struct Generic<'a, T: Clone, U = Vec<u8>>
+where
+    U: Debug,
+{
+    reference: &'a str,
+    value: T,
+    list: U,
+    func: fn(u8) -> Option<u8>,
+}
+
+#[derive(Debug, PartialEq, IntoRecord)]
+struct RawIdents {
+    r#type: u8,
+    r#match: bool,
+}
+
+mod renamed {
+    pub use tstr as other_tstr;
+}
+
+#[derive(Debug, PartialEq, IntoRecord)]
+#[tstr(crate = renamed::other_tstr)]
+struct Renamed {
+    foo: char,
+}
+
+fn round_trip<T>(this: T) -> T
+where
+    T: IntoRecord,
+{
+    this.into_record().into()
+}
+
+#[test]
+fn into_record_empty() {
+    assert_eq!(Empty {}.into_record(), record! {});
+    assert_eq!(Empty::from(record! {}), Empty {});
+}
+
+#[test]
+fn into_record_point() {
+    let rec: Record! {x: u32, y: u32} = Point { x: 3, y: 5 }.into();
+    assert_eq!(rec, record! {x: 3, y: 5});
+    assert_eq!(rec[ts!(x)], 3);
+    assert_eq!(rec[ts!(y)], 5);
+
+    assert_eq!(Point::from(rec), Point { x: 3, y: 5 });
+    assert_eq!(round_trip(Point { x: 8, y: 13 }), Point { x: 8, y: 13 });
+}
+
+#[test]
+fn into_record_generic() {
+    fn double(x: u8) -> Option<u8> {
+        x.checked_mul(2)
+    }
+
+    let gen = Generic {
+        reference: "hello",
+        value: 'c',
+        list: vec![3u8, 5],
+        func: double,
+    };
+
+    let mut rec = gen.into_record();
+    assert_eq!(rec[ts!(reference)], "hello");
+    assert_eq!(rec[ts!(value)], 'c');
+    assert_eq!(rec[ts!(list)], [3, 5]);
+    assert_eq!((rec[ts!(func)])(100), Some(200));
+
+    rec[ts!(list)].push(8);
+
+    let gen = Generic::from(rec);
+    assert_eq!(gen.list, [3, 5, 8]);
+
+    let gen = Generic {
+        reference: "world",
+        value: 10u64,
+        list: "list",
+        func: double,
+    };
+    let rec: Record! {reference: &str, value: u64, list: &str, func: fn(u8) -> Option<u8>} =
+        gen.into();
+    assert_eq!(rec[ts!(list)], "list");
+}
+
+#[test]
+fn into_record_raw_idents() {
+    let rec = RawIdents {
+        r#type: 3,
+        r#match: true,
+    }
+    .into_record();
+
+    assert_eq!(rec, record! {"type": 3, "match": true});
+    assert_eq!(rec[ts!("type")], 3);
+    assert!(rec[ts!("match")]);
+}
+
+#[test]
+fn into_record_renamed_crate() {
+    assert_eq!(Renamed { foo: 'a' }.into_record(), record! {foo: 'a'});
+    assert_eq!(round_trip(Renamed { foo: 'b' }), Renamed { foo: 'b' });
+}
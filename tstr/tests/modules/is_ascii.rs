@@ -0,0 +1,35 @@
+use tstr::{IsAscii, TStrAsciiOnly, TS};
+
+fn is_ascii<T: IsAscii>() -> bool {
+    T::VALUE
+}
+
+#[test]
+fn is_ascii_test() {
+    assert!(is_ascii::<TS!("")>());
+    assert!(is_ascii::<TS!(a)>());
+    assert!(is_ascii::<TS!("\u{0}")>());
+    assert!(is_ascii::<TS!("\u{7F}")>());
+    assert!(is_ascii::<TS!(0)>());
+    assert!(is_ascii::<TS!("hello world")>());
+    assert!(is_ascii::<TS!("abcdefghijklmnopqrstuvwxyz0123456789")>());
+
+    assert!(!is_ascii::<TS!("\u{80}")>());
+    assert!(!is_ascii::<TS!("ñ")>());
+    assert!(!is_ascii::<TS!("abcdefghijklmnopqrstuvwxyz012345678ñ")>());
+    assert!(!is_ascii::<TS!("ñbcdefghijklmnopqrstuvwxyz0123456789")>());
+    assert!(!is_ascii::<TS!("abcdefghijklmnopqrñtuvwxyz0123456789")>());
+}
+
+#[test]
+fn assert_ascii_test() {
+    const _: () = <TS!("Content-Type")>::ASSERT_ASCII;
+    const _: () = <TS!("abcdefghijklmnopqrstuvwxyz0123456789")>::ASSERT_ASCII;
+
+    fn takes_ascii<K: TStrAsciiOnly>(_: K) -> bool {
+        let () = K::ASSERT_ASCII;
+        K::VALUE
+    }
+
+    assert!(takes_ascii(tstr::ts!(foo_bar)));
+}
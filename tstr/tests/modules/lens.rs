@@ -0,0 +1,78 @@
+use tstr::{declare_fields, ts, Lens, TS};
+
+declare_fields! {
+    #[derive(Debug, PartialEq)]
+    struct Outer {
+        inner: Inner,
+        name: String,
+    }
+}
+
+declare_fields! {
+    #[derive(Debug, PartialEq)]
+    struct Inner {
+        leaf: u32,
+        list: Vec<u8>,
+    }
+}
+
+fn outer() -> Outer {
+    Outer {
+        inner: Inner {
+            leaf: 3,
+            list: vec![5, 8],
+        },
+        name: "foo".to_string(),
+    }
+}
+
+#[test]
+fn lens_get_set() {
+    let mut outer = outer();
+
+    let name: Lens<TS!(name)> = Lens::NEW;
+    assert_eq!(name.get(&outer), "foo");
+
+    name.get_mut(&mut outer).push_str("bar");
+    assert_eq!(name.set(&mut outer, "baz".to_string()), "foobar");
+    assert_eq!(outer.name, "baz");
+
+    let leaf = Lens::new(ts!(inner.leaf));
+    assert_eq!(*leaf.get(&outer), 3);
+    assert_eq!(leaf.set(&mut outer, 13), 3);
+    assert_eq!(outer.inner.leaf, 13);
+
+    let root = Lens::new(());
+    assert_eq!(root.get(&outer), &outer);
+}
+
+#[test]
+#[allow(clippy::type_complexity)]
+fn lens_compose() {
+    let mut outer = outer();
+
+    const INNER: Lens<TS!(inner)> = Lens::NEW;
+    let list = INNER.compose(Lens::new(ts!(list)));
+
+    list.get_mut(&mut outer).push(13);
+    assert_eq!(*list.get(&outer), [5, 8, 13]);
+
+    let _: Lens<(TS!(inner), TS!(list))> = list;
+    assert_eq!(list.into_path(), (ts!(inner), ts!(list)));
+
+    // composing composed lenses
+    let nested = Lens::new(()).compose(INNER).compose(Lens::new(ts!(leaf)));
+    assert_eq!(nested.set(&mut outer, 21), 3);
+    assert_eq!(
+        outer.inner,
+        Inner {
+            leaf: 21,
+            list: vec![5, 8, 13]
+        }
+    );
+}
+
+#[test]
+fn lens_is_zero_sized() {
+    assert_eq!(std::mem::size_of::<Lens<TS!(inner.leaf)>>(), 0);
+}
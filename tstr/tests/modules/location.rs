@@ -0,0 +1,81 @@
+#[cfg(feature = "rust_1_88")]
+mod file_and_line {
+    use tstr::{ts_file, ts_line, TS_file, TS_line, ToUint};
+
+    fn file_key(_: TS_file!()) -> &'static str {
+        "this file"
+    }
+
+    #[test]
+    fn file_macros() {
+        let _: TS_file!() = ts_file!();
+        assert_eq!(file_key(ts_file!()), "this file");
+    }
+
+    #[cfg(feature = "const_generics")]
+    #[test]
+    fn file_str() {
+        use tstr::StrValue;
+
+        assert_eq!(ts_file!().to_str(), file!());
+        assert_eq!(<TS_file!()>::STR, file!());
+    }
+
+    #[test]
+    fn line_macros() {
+        assert_eq!(ts_line!().to_u32(), line!());
+        assert_eq!(<TS_line!()>::U32, line!());
+
+        let _: TS_line!() = ts_line!();
+
+        let first = ts_line!().to_u32();
+        let second = ts_line!().to_u32();
+        assert_eq!(first + 1, second);
+    }
+}
+
+#[cfg(feature = "const_generics")]
+mod module_path {
+    use tstr::{ts_module_path, StrValue, TS_module_path};
+
+    mod inner {
+        pub fn key() -> tstr::TS_module_path!() {
+            tstr::ts_module_path!()
+        }
+    }
+
+    #[test]
+    fn module_path_macros() {
+        let _: TS_module_path!() = ts_module_path!();
+
+        assert_eq!(ts_module_path!().to_str(), module_path!());
+        assert_eq!(<TS_module_path!()>::STR, module_path!());
+
+        assert_eq!(inner::key().to_str(), concat!(module_path!(), "::inner"));
+    }
+}
+
+#[cfg(feature = "rust_1_88")]
+mod crate_path {
+    mod reexport {
+        pub use tstr as __tstr;
+    }
+
+    macro_rules! wrapped_line {
+        () => {
+            reexport::__tstr::ts_line!(crate = reexport::__tstr)
+        };
+    }
+
+    #[test]
+    fn crate_argument() {
+        use tstr::{ts_file, ts_line, TS_file, TS_line, ToUint};
+
+        let _: TS_file!(crate = reexport::__tstr) = ts_file!(crate = ::tstr);
+        let _: TS_line!(crate = reexport::__tstr) = ts_line!(crate = reexport::__tstr);
+        assert_eq!(ts_line!(crate = reexport::__tstr).to_u32(), line!());
+
+        // The line is that of the macro definition
+        assert!(wrapped_line!().to_u32() < line!());
+    }
+}
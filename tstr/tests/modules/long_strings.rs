@@ -124,6 +124,32 @@ long_str_test! {
      (Len504Plus<ZeroToSeven>, __a<'9'>),
 }
 
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+#[allow(dead_code)]
+type EAcute8 = __<'é', 'é', 'é', 'é', 'é', 'é', 'é', 'é'>;
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+#[allow(dead_code)]
+type EAcute64 = (
+    EAcute8,
+    EAcute8,
+    EAcute8,
+    EAcute8,
+    EAcute8,
+    EAcute8,
+    EAcute8,
+    EAcute8,
+);
+
+// A 200 char string, which is chunked into a tuple of 64, 64, 64, and 8 char segments.
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+const _: TStr<(EAcute64, EAcute64, EAcute64, EAcute8)> = ts!(
+    "éééééééééééééééééééééééééééééééééééééééééééééééééééééééééééééééé\
+     éééééééééééééééééééééééééééééééééééééééééééééééééééééééééééééééé\
+     éééééééééééééééééééééééééééééééééééééééééééééééééééééééééééééééé\
+     éééééééé"
+);
+
 // Just making sure that this module is compiled.
 #[test]
 fn testing_long_strings() {}
@@ -0,0 +1,58 @@
+use tstr::maps::{Get, Insert, MakeTMap, Remove};
+use tstr::tlist::{TList, TNil};
+use tstr::{TMap, TS};
+
+type GetOut<M, K> = <M as Get<K>>::Output;
+type InsertOut<M, K, V> = <M as Insert<K, V>>::Output;
+type RemoveOut<M, K> = <M as Remove<K>>::Output;
+
+type Len9A = TS!("aaaaaaaaa");
+type Len9B = TS!("aaaaaaaab");
+type Len65 = TS!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-");
+
+#[test]
+fn map_get() {
+    type Map = TMap!(
+        "" => (),
+        a => u8,
+        ab => u16,
+        "aaaaaaaaa" => u32,
+        "aaaaaaaab" => u64,
+        "-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-" => bool,
+    );
+
+    let _: GetOut<Map, TS!("")> = ();
+    let _: GetOut<Map, TS!(a)> = 0u8;
+    let _: GetOut<Map, TS!(ab)> = 0u16;
+    let _: GetOut<Map, Len9A> = 0u32;
+    let _: GetOut<Map, Len9B> = 0u64;
+    let _: GetOut<Map, Len65> = false;
+}
+
+#[test]
+fn map_insert() {
+    let _: TMap<TNil> = <TMap!()>::MAKE;
+    type Entry = (TS!(a), u8);
+    let _: TMap<TList<Entry, TNil>> = <InsertOut<TMap!(), TS!(a), u8>>::MAKE;
+
+    let _: TMap!(a => u8, b => u16) = <InsertOut<TMap!(a => u8), TS!(b), u16>>::MAKE;
+    let _: TMap!(a => u32, b => u16) = <InsertOut<TMap!(a => u8, b => u16), TS!(a), u32>>::MAKE;
+    let _: TMap!(a => u8, b => u32) = <InsertOut<TMap!(a => u8, b => u16), TS!(b), u32>>::MAKE;
+
+    let _: TMap!("aaaaaaaaa" => u8, "aaaaaaaab" => u16) =
+        <InsertOut<TMap!("aaaaaaaaa" => u8), Len9B, u16>>::MAKE;
+    let _: TMap!("aaaaaaaaa" => u16) = <InsertOut<TMap!("aaaaaaaaa" => u8), Len9A, u16>>::MAKE;
+}
+
+#[test]
+fn map_remove() {
+    let _: TMap!() = <RemoveOut<TMap!(), TS!(a)>>::MAKE;
+    let _: TMap!() = <RemoveOut<TMap!(a => u8), TS!(a)>>::MAKE;
+    let _: TMap!(a => u8) = <RemoveOut<TMap!(a => u8), TS!(b)>>::MAKE;
+    let _: TMap!(b => u16, c => u32) =
+        <RemoveOut<TMap!(a => u8, b => u16, c => u32), TS!(a)>>::MAKE;
+    let _: TMap!(a => u8, c => u32) = <RemoveOut<TMap!(a => u8, b => u16, c => u32), TS!(b)>>::MAKE;
+
+    let _: TMap!("aaaaaaaaa" => u8) =
+        <RemoveOut<TMap!("aaaaaaaaa" => u8, "aaaaaaaab" => u16), Len9B>>::MAKE;
+}
@@ -0,0 +1,106 @@
+use tstr::{
+    assert_is_identifier, assert_kebab_case, assert_snake_case, AssertNamingConvention,
+    NamingConvention, TS,
+};
+
+fn is_snake_case<S: NamingConvention>() -> bool {
+    S::IS_SNAKE_CASE
+}
+
+fn is_kebab_case<S: NamingConvention>() -> bool {
+    S::IS_KEBAB_CASE
+}
+
+fn is_identifier<S: NamingConvention>() -> bool {
+    S::IS_IDENTIFIER
+}
+
+fn conventions<S: NamingConvention>() -> [bool; 3] {
+    [S::IS_SNAKE_CASE, S::IS_KEBAB_CASE, S::IS_IDENTIFIER]
+}
+
+#[test]
+fn snake_case() {
+    assert!(is_snake_case::<TS!(a)>());
+    assert!(is_snake_case::<TS!(user_id)>());
+    assert!(is_snake_case::<TS!(_id)>());
+    assert!(is_snake_case::<TS!(_)>());
+    assert!(is_snake_case::<TS!(p99)>());
+    assert!(is_snake_case::<
+        TS!(a_very_long_snake_case_field_name_with_many_words),
+    >());
+
+    assert!(!is_snake_case::<TS!("")>());
+    assert!(!is_snake_case::<TS!(userId)>());
+    assert!(!is_snake_case::<TS!(USER_ID)>());
+    assert!(!is_snake_case::<TS!("user-id")>());
+    assert!(!is_snake_case::<TS!("user id")>());
+    assert!(!is_snake_case::<TS!("9lives")>());
+    assert!(!is_snake_case::<TS!("größe")>());
+    assert!(!is_snake_case::<
+        TS!(a_very_long_snake_case_field_name_with_a_Capital),
+    >());
+}
+
+#[test]
+fn kebab_case() {
+    assert!(is_kebab_case::<TS!(a)>());
+    assert!(is_kebab_case::<TS!("content-type")>());
+    assert!(is_kebab_case::<TS!("2fa-code")>());
+    assert!(is_kebab_case::<
+        TS!("a-very-long-kebab-case-header-name-with-many-words"),
+    >());
+
+    assert!(!is_kebab_case::<TS!("")>());
+    assert!(!is_kebab_case::<TS!("-")>());
+    assert!(!is_kebab_case::<TS!("-content-type")>());
+    assert!(!is_kebab_case::<TS!("content-type-")>());
+    assert!(!is_kebab_case::<TS!("Content-Type")>());
+    assert!(!is_kebab_case::<TS!(content_type)>());
+    assert!(!is_kebab_case::<
+        TS!("a-very-long-kebab-case-header-name-with-many-words-"),
+    >());
+}
+
+#[test]
+fn identifier() {
+    assert!(is_identifier::<TS!(a)>());
+    assert!(is_identifier::<TS!(userId)>());
+    assert!(is_identifier::<TS!(USER_ID)>());
+    assert!(is_identifier::<TS!(_0)>());
+    assert!(is_identifier::<TS!(__)>());
+    assert!(is_identifier::<TS!(r#type)>());
+
+    assert!(!is_identifier::<TS!("")>());
+    assert!(!is_identifier::<TS!(_)>());
+    assert!(!is_identifier::<TS!("0a")>());
+    assert!(!is_identifier::<TS!("user-id")>());
+    assert!(!is_identifier::<TS!("user.id")>());
+    assert!(!is_identifier::<TS!("ñandú")>());
+}
+
+#[test]
+fn generic() {
+    assert_eq!(conventions::<TS!(user_id)>(), [true, false, true]);
+    assert_eq!(conventions::<TS!("user-id")>(), [false, true, false]);
+    assert_eq!(conventions::<TS!(userid)>(), [true, true, true]);
+    assert_eq!(conventions::<TS!(UserId)>(), [false, false, true]);
+
+    fn snake_key<K: AssertNamingConvention>(_: K) -> bool {
+        let () = K::ASSERT_SNAKE_CASE;
+        K::IS_SNAKE_CASE
+    }
+    assert!(snake_key(tstr::ts!(user_id)));
+}
+
+assert_snake_case!(TS!(user_id), TS!(created_at),);
+assert_kebab_case!(TS!("user-id"));
+assert_is_identifier!(TS!(userId), TS!(user_id));
+
+#[test]
+fn assertion_macros_in_statements() {
+    assert_snake_case!(TS!(name));
+    assert_kebab_case!(TS!("x-request-id"), TS!(accept));
+    assert_is_identifier!(TS!(Name));
+    assert_snake_case!();
+}
@@ -0,0 +1,142 @@
+#![allow(clippy::type_complexity)]
+
+use tstr::{
+    ops::{Appended, Prepended, PushBack, PushFront, Reverse, Reversed},
+    TS,
+};
+
+fn assert_push_front<T, S, Out>()
+where
+    T: PushFront<S, Output = Out>,
+{
+}
+
+fn assert_push_back<T, S, Out>()
+where
+    T: PushBack<S, Output = Out>,
+{
+}
+
+fn assert_reverse<T, Out>()
+where
+    T: Reverse<Output = Out>,
+{
+}
+
+#[test]
+fn push_front() {
+    assert_push_front::<TS!(""), TS!(""), TS!("")>();
+    assert_push_front::<TS!(""), TS!(a), TS!(a)>();
+    assert_push_front::<TS!(a), TS!(""), TS!(a)>();
+    assert_push_front::<TS!(foo), TS!(_), TS!(_foo)>();
+    assert_push_front::<TS!(foo), TS!(_internal_), TS!(_internal_foo)>();
+    assert_push_front::<TS!(ndú), TS!("ña"), TS!("ñandú")>();
+    assert_push_front::<
+        TS!(with_many_words),
+        TS!(a_very_long_field_name_),
+        TS!(a_very_long_field_name_with_many_words),
+    >();
+
+    let _: TS!(get_foo) = <Prepended<TS!(foo), TS!(get_)>>::NEW;
+}
+
+#[test]
+fn push_back() {
+    assert_push_back::<TS!(""), TS!(""), TS!("")>();
+    assert_push_back::<TS!(""), TS!(a), TS!(a)>();
+    assert_push_back::<TS!(a), TS!(""), TS!(a)>();
+    assert_push_back::<TS!(foo), TS!(_), TS!(foo_)>();
+    assert_push_back::<TS!(foo), TS!(_mut), TS!(foo_mut)>();
+    assert_push_back::<TS!("ña"), TS!(ndú), TS!("ñandú")>();
+    assert_push_back::<
+        TS!(a_very_long_field_name_),
+        TS!(with_many_words),
+        TS!(a_very_long_field_name_with_many_words),
+    >();
+
+    let _: TS!(foo_mut) = <Appended<TS!(foo), TS!(_mut)>>::NEW;
+}
+
+#[test]
+fn nested() {
+    let _: TS!(_foo_) = <Appended<Prepended<TS!(foo), TS!(_)>, TS!(_)>>::NEW;
+    let _: TS!(__foo) = <Prepended<Prepended<TS!(foo), TS!(_)>, TS!(_)>>::NEW;
+}
+
+#[test]
+fn reverse() {
+    assert_reverse::<TS!(""), TS!("")>();
+    assert_reverse::<TS!(a), TS!(a)>();
+    assert_reverse::<TS!(ab), TS!(ba)>();
+    assert_reverse::<TS!(hello), TS!(olleh)>();
+    assert_reverse::<TS!("ñ"), TS!("ñ")>();
+    assert_reverse::<TS!("ñandú"), TS!("údnañ")>();
+    assert_reverse::<TS!("a€𝄞b"), TS!("b𝄞€a")>();
+    assert_reverse::<
+        TS!(a_very_long_field_name_with_many_words),
+        TS!(sdrow_ynam_htiw_eman_dleif_gnol_yrev_a),
+    >();
+
+    let _: TS!(foo) = <Reversed<Reversed<TS!(foo)>>>::NEW;
+    let _: TS!(_oof) = <Reversed<Appended<TS!(foo), TS!(_)>>>::NEW;
+}
+
+#[cfg(feature = "typenum")]
+mod split {
+    use tstr::{
+        ops::{Slice, Sliced, Split, SplitAt},
+        ts, TS,
+    };
+
+    use typenum::{U0, U1, U10, U12, U2, U3, U4, U6, U7};
+
+    fn assert_split_at<T, N, Out>()
+    where
+        T: SplitAt<N, Output = Out>,
+    {
+    }
+
+    fn assert_slice<T, Start, End, Out>()
+    where
+        T: Slice<Start, End, Output = Out>,
+    {
+    }
+
+    #[test]
+    fn split_at() {
+        assert_split_at::<TS!(""), U0, (TS!(""), TS!(""))>();
+        assert_split_at::<TS!(a), U0, (TS!(""), TS!(a))>();
+        assert_split_at::<TS!(a), U1, (TS!(a), TS!(""))>();
+        assert_split_at::<TS!(foobar), U3, (TS!(foo), TS!(bar))>();
+        assert_split_at::<TS!("module.field"), U6, (TS!(module), TS!(".field"))>();
+        assert_split_at::<
+            TS!(a_very_long_field_name_with_many_words),
+            U10,
+            (TS!(a_very_lon), TS!(g_field_name_with_many_words)),
+        >();
+
+        let _: Split<TS!(foobar), U2> = ts!(fo, obar);
+    }
+
+    #[test]
+    fn split_non_ascii() {
+        #[cfg(any(not(feature = "min_const_generics"), feature = "const_generics"))]
+        assert_split_at::<TS!("ñandú"), U3, (TS!("ña"), TS!("ndú"))>();
+
+        #[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+        assert_split_at::<TS!("ñandú"), U3, (TS!("ñan"), TS!("dú"))>();
+    }
+
+    #[test]
+    fn slice() {
+        assert_slice::<TS!(""), U0, U0, TS!("")>();
+        assert_slice::<TS!(foo), U0, U3, TS!(foo)>();
+        assert_slice::<TS!(foo), U1, U2, TS!(o)>();
+        assert_slice::<TS!(foo), U2, U2, TS!("")>();
+        assert_slice::<TS!("module.field"), U0, U6, TS!(module)>();
+        assert_slice::<TS!("module.field"), U7, U12, TS!(field)>();
+        assert_slice::<TS!("module.field"), U4, U7, TS!("le.")>();
+
+        let _: TS!(bar) = <Sliced<TS!(foobarbaz), U3, U6>>::NEW;
+    }
+}
@@ -41,6 +41,36 @@ test_case! {16, (__0x31, __0x36), __b<'1', '6'>, "16"}
 test_case! {1_6, (__0x31, __0x36), __b<'1', '6'>, "16"}
 test_case! {128, (__0x31, __0x32, __0x38), __c<'1', '2', '8'>, "128"}
 test_case! {1__2_8, (__0x31, __0x32, __0x38), __c<'1', '2', '8'>, "128"}
+test_case! {3u8, (__0x33, ), __a<'3'>, "3"}
+test_case! {42usize, (__0x34, __0x32), __b<'4', '2'>, "42"}
+test_case! {42_i32, (__0x34, __0x32), __b<'4', '2'>, "42"}
+test_case! {0x2Au64, (__0x34, __0x32), __b<'4', '2'>, "42"}
+test_case! {0b101010_u128, (__0x34, __0x32), __b<'4', '2'>, "42"}
+test_case! {0o52isize, (__0x34, __0x32), __b<'4', '2'>, "42"}
+test_case! {0i8, (__0x30, ), __a<'0'>, "0"}
+test_case! {1.5, (__0x31, __0x2E, __0x35), __c<'1', '.', '5'>, "1.5"}
+test_case! {0.25, (__0x30, __0x2E, __0x32, __0x35), __d<'0', '.', '2', '5'>, "0.25"}
+test_case! {1_0.5f32, (__0x31, __0x30, __0x2E, __0x35), __d<'1', '0', '.', '5'>, "10.5"}
+test_case! {2.5e3, (__0x32, __0x2E, __0x35, __0x65, __0x33), __e<'2', '.', '5', 'e', '3'>, "2.5e3"}
+test_case! {1e3_f64, (__0x31, __0x65, __0x33), __c<'1', 'e', '3'>, "1e3"}
+test_case! {
+    1_000_000,
+    (__0x31, __0x30, __0x30, __0x30, __0x30, __0x30, __0x30),
+    __g<'1', '0', '0', '0', '0', '0', '0'>,
+    "1000000",
+}
+test_case! {
+    0xF_4240,
+    (__0x31, __0x30, __0x30, __0x30, __0x30, __0x30, __0x30),
+    __g<'1', '0', '0', '0', '0', '0', '0'>,
+    "1000000",
+}
+test_case! {
+    1_000_000_000,
+    ((__0x31, __0x30, __0x30, __0x30, __0x30, __0x30, __0x30, __0x30), (__0x30, __0x30)),
+    (__<'1', '0', '0', '0', '0', '0', '0', '0'>, __b<'0', '0'>),
+    "1000000000",
+}
 
 test_case! {
     foo_bar_baz,
@@ -58,6 +88,10 @@ test_case! {
 
 test_case! {_0, (__0x5F, __0x30, ), __b<'_', '0'>, "_0"}
 
+test_case! {true, (__t, __r, __u, __e), __d<'t', 'r', 'u', 'e'>, "true"}
+test_case! {false, (__f, __a, __l, __s, __e), __e<'f', 'a', 'l', 's', 'e'>, "false"}
+test_case! {fn, (__f, __n), __b<'f', 'n'>, "fn"}
+
 test_case! {r#_0, (__0x5F, __0x30, ), __b<'_', '0'>, "_0"}
 
 test_case! {
@@ -69,3 +103,49 @@ test_case! {
 // Just making sure that this module is compiled.
 #[test]
 fn testing_other_args() {}
+
+#[test]
+fn forwarded_suffixed_literals() {
+    macro_rules! forward {
+        ($lit:literal) => {
+            ts!($lit)
+        };
+    }
+
+    let _: TS!(7) = forward!(7u8);
+    let _: TS!(42) = forward!(42usize);
+    let _: TS!(255) = forward!(0xFF_u32);
+    let _: TS!("1.5") = forward!(1.5);
+    let _: TS!("0.5") = forward!(0.5f64);
+    let _: TS!("true") = forward!(true);
+    let _: TS!("false") = forward!(false);
+    let _: TS!("-5") = forward!(-5);
+    let _: TS!("-2.5") = forward!(-2.5f32);
+}
+
+type TypeFn = TS!(type.fn);
+
+type Offsets = TS!(-1, 0, -2);
+
+#[test]
+fn negative_numbers() {
+    let _: TS!("-1") = ts!(-1);
+    let _: TS!("-1") = <TS!(-1)>::NEW;
+    let _: TS!("-16") = ts!(-0x10);
+    let _: TS!("-12") = ts!(-1_2i64);
+    let _: TS!("-1.5") = ts!(-1.5);
+    let _: Offsets = ts!(-1, 0, -2);
+    let _: Offsets = (ts!("-1"), ts!(0), ts!("-2"));
+}
+
+#[test]
+fn raw_identifiers() {
+    let _: TS!("type") = ts!(r#type);
+    let _: TS!("fn") = <TS!(r#fn)>::NEW;
+    let _: TS!("type") = ts!("type");
+    let _: TypeFn = ts!(r#type.r#fn);
+    let _: TS!("typefn") = ts!(concat!(r#type, r#fn));
+
+    // `stringify!` keeps the `r#` prefix
+    let _: TS!("r#type") = ts!(stringify!(r#type));
+}
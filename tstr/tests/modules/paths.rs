@@ -0,0 +1,89 @@
+use tstr::{ts, NestedIndex, NestedIndexMut, TS};
+
+use std::ops::{Index, IndexMut};
+
+// rustfmt would add spaces before the numeric segments of `a.0.1`
+#[rustfmt::skip]
+#[test]
+fn path_types() {
+    let _: TS!(a, b) = ts!(a.b);
+    let _: TS!(a, b, c) = ts!(a.b.c);
+    let _: TS!(a, 0) = ts!(a.0);
+    let _: TS!(a, 0, 1) = ts!(a.0.1);
+    let _: TS!(a, 0, 1, b) = ts!(a.0.1.b);
+    let _: TS!(a, 10, 200) = ts!(a.10.200);
+    let _: TS!("hello world", foo) = ts!("hello world".foo);
+    let _: TS!(foobar, baz) = ts!(concat!(foo, bar).baz);
+
+    type A = TS!(a);
+    type C = TS!(c);
+    type AB = TS!(a, b);
+    type BC = TS!(b, c);
+    let _: (AB, C) = ts!(a.b, c);
+    let _: (A, BC) = ts!(a, b.c);
+}
+
+struct Outer {
+    inner: Inner,
+    name: &'static str,
+}
+
+struct Inner {
+    leaf: u32,
+    pair: (u8, u16),
+}
+
+macro_rules! impl_index {
+    ($self:ty, $field:ident, $name:ty, $ty:ty) => {
+        impl Index<$name> for $self {
+            type Output = $ty;
+
+            fn index(&self, _: $name) -> &$ty {
+                &self.$field
+            }
+        }
+
+        impl IndexMut<$name> for $self {
+            fn index_mut(&mut self, _: $name) -> &mut $ty {
+                &mut self.$field
+            }
+        }
+    };
+}
+
+impl_index! {Outer, inner, TS!(inner), Inner}
+impl_index! {Outer, name, TS!(name), &'static str}
+impl_index! {Inner, leaf, TS!(leaf), u32}
+impl_index! {Inner, pair, TS!(pair), (u8, u16)}
+
+#[test]
+fn nested_index() {
+    let mut outer = Outer {
+        inner: Inner {
+            leaf: 3,
+            pair: (5, 8),
+        },
+        name: "hello",
+    };
+
+    assert_eq!(*outer.nested_index(ts!(name)), "hello");
+    assert_eq!(*outer.nested_index(ts!(inner.leaf)), 3);
+    assert_eq!(*outer.nested_index(ts!(inner.pair)), (5, 8));
+    assert_eq!(outer.nested_index(()).name, "hello");
+
+    *outer.nested_index_mut(ts!(inner.leaf)) = 13;
+    *outer.nested_index_mut(ts!(name)) = "world";
+    assert_eq!(outer.inner.leaf, 13);
+    assert_eq!(outer.name, "world");
+
+    assert_eq!(get_copy(&outer, ts!(inner.leaf)), 13);
+    assert_eq!(get_copy(&outer, (ts!(inner), ts!(pair))), (5, 8));
+}
+
+fn get_copy<T, P>(this: &T, path: P) -> T::Output
+where
+    T: NestedIndex<P>,
+    T::Output: Copy,
+{
+    *this.nested_index(path)
+}
@@ -0,0 +1,157 @@
+use tstr::{declare_fields, proj, set_field, with_fields};
+
+declare_fields! {
+    #[derive(Debug, PartialEq)]
+    struct Outer {
+        inner: Inner,
+        name: &'static str,
+    }
+}
+
+declare_fields! {
+    #[derive(Debug, PartialEq)]
+    struct Inner {
+        leaf: u32,
+        r#type: (u8, u16),
+    }
+}
+
+impl Outer {
+    fn leaf(&self) -> u32 {
+        proj!(self.inner.leaf)
+    }
+}
+
+#[test]
+fn proj_read() {
+    let outer = Outer {
+        inner: Inner {
+            leaf: 3,
+            r#type: (5, 8),
+        },
+        name: "foo",
+    };
+
+    assert_eq!(proj!(outer.name), "foo");
+    assert_eq!(proj!(outer.inner.leaf), 3);
+    assert_eq!(proj!(outer.inner.r#type), (5, 8));
+    assert_eq!(outer.leaf(), 3);
+
+    let reference = &outer;
+    assert_eq!(proj!((*reference).inner.leaf), 3);
+    assert_eq!(&proj!(outer.inner), &outer.inner);
+}
+
+#[test]
+fn proj_write() {
+    let mut outer = Outer {
+        inner: Inner {
+            leaf: 3,
+            r#type: (5, 8),
+        },
+        name: "foo",
+    };
+
+    proj!(outer.name) = "bar";
+    proj!(outer.inner.leaf) += 10;
+    *&mut proj!(outer.inner.r#type) = (13, 21);
+
+    assert_eq!(
+        outer,
+        Outer {
+            inner: Inner {
+                leaf: 13,
+                r#type: (13, 21),
+            },
+            name: "bar",
+        }
+    );
+}
+
+#[cfg(feature = "tuple_indexing")]
+#[test]
+fn proj_tuple_fields() {
+    let mut tuple = ((3u8, "foo"), 'c');
+
+    assert_eq!(proj!(tuple.0 .1), "foo");
+    assert_eq!(proj!(tuple.1), 'c');
+
+    proj!(tuple.0 .0) = 5;
+    assert_eq!(tuple, ((5, "foo"), 'c'));
+}
+
+#[test]
+fn set_fields() {
+    let mut outer = Outer {
+        inner: Inner {
+            leaf: 3,
+            r#type: (5, 8),
+        },
+        name: "foo",
+    };
+
+    set_field!(outer.name = "bar");
+    set_field!(outer.inner.leaf = proj!(outer.inner.leaf) * 2);
+    set_field!(outer.inner.r#type = (13, 21));
+
+    {
+        let reference = &mut outer;
+        set_field!((*reference).inner.leaf = 100);
+    }
+
+    assert_eq!(
+        outer,
+        Outer {
+            inner: Inner {
+                leaf: 100,
+                r#type: (13, 21),
+            },
+            name: "bar",
+        }
+    );
+}
+
+#[cfg(feature = "tuple_indexing")]
+#[test]
+fn set_tuple_fields() {
+    let mut tuple = ((3u8, "foo"), 'c');
+
+    set_field!(tuple.0 .1 = "bar");
+    set_field!(tuple.1 = 'd');
+
+    assert_eq!(tuple, ((3, "bar"), 'd'));
+}
+
+#[test]
+fn with_fields_bindings() {
+    let outer = Outer {
+        inner: Inner {
+            leaf: 3,
+            r#type: (5, 8),
+        },
+        name: "foo",
+    };
+
+    with_fields!(outer => { name, inner });
+    assert_eq!(*name, "foo");
+    assert_eq!(inner.leaf, 3);
+
+    with_fields!(outer.inner => { leaf: value, r#type, });
+    assert_eq!(*value, 3);
+    assert_eq!(*r#type, (5, 8));
+
+    with_fields!(outer => {});
+}
+
+#[test]
+fn with_fields_shadowing() {
+    let value = Inner {
+        leaf: 3,
+        r#type: (5, 8),
+    };
+
+    // the local variable of the macro doesn't shadow the caller's `value`
+    with_fields!(value => { leaf });
+    assert_eq!(*leaf, 3);
+    assert_eq!(value.leaf, 3);
+}
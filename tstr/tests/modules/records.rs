@@ -0,0 +1,84 @@
+use std::ops::{Index, IndexMut};
+
+use tstr::{record, ts, Field, Record, TS};
+
+#[test]
+fn record_index() {
+    let mut rec = record! {
+        "": (),
+        a: 3u8,
+        ab: 5u16,
+        "aaaaaaaaa": "hello",
+        "aaaaaaaab": 'c',
+        "-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-": false,
+        0: [1, 2],
+    };
+
+    assert_eq!(rec[ts!("")], ());
+    assert_eq!(rec[ts!(a)], 3u8);
+    assert_eq!(rec[ts!(ab)], 5u16);
+    assert_eq!(rec[ts!("aaaaaaaaa")], "hello");
+    assert_eq!(rec[ts!("aaaaaaaab")], 'c');
+    assert!(!rec[ts!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-")]);
+    assert_eq!(rec[ts!(0)], [1, 2]);
+
+    rec[ts!(a)] += 10;
+    rec[ts!("aaaaaaaaa")] = "world";
+    rec[ts!(0)][1] = 20;
+
+    assert_eq!(rec[ts!(a)], 13u8);
+    assert_eq!(rec[ts!("aaaaaaaaa")], "world");
+    assert_eq!(rec[ts!(0)], [1, 20]);
+}
+
+#[test]
+fn record_get_set() {
+    let mut rec = record! {name: "Bob", age: 21u32};
+
+    assert_eq!(*rec.get(ts!(name)), "Bob");
+    assert_eq!(*rec.get(ts!(age)), 21);
+
+    *rec.get_mut(ts!(age)) += 1;
+    assert_eq!(rec[ts!(age)], 22);
+
+    assert_eq!(rec.set(ts!(name), "Alice"), "Bob");
+    assert_eq!(rec.set(ts!(age), 30), 22);
+    assert_eq!(rec, record! {name: "Alice", age: 30});
+}
+
+#[test]
+fn record_repeated_key() {
+    let mut rec = record! {foo: 3, bar: 5, foo: 8};
+
+    assert_eq!(rec[ts!(foo)], 3);
+    rec[ts!(foo)] = 13;
+    assert_eq!(rec.into_fields().1 .1 .0.into_inner(), 8);
+}
+
+#[test]
+fn record_type_macro() {
+    let _: Record! {} = record! {};
+    let _: Record! {} = Record::new(());
+
+    let rec: Record! {foo: u8, "bar": &str, 10: bool,} = record! {foo: 3, bar: "hi", 10: true,};
+
+    type Fields<'a> = (
+        Field<TS!(foo), u8>,
+        (Field<TS!(bar), &'a str>, (Field<TS!(10), bool>, ())),
+    );
+    let _: Record<Fields<'_>> = rec;
+}
+
+#[test]
+fn record_generic_access() {
+    fn sum_xy<R>(rec: &mut R) -> u32
+    where
+        R: IndexMut<TS!(x), Output = u32> + Index<TS!(y), Output = u32>,
+    {
+        rec[ts!(x)] += 1;
+        rec[ts!(x)] + rec[ts!(y)]
+    }
+
+    assert_eq!(sum_xy(&mut record! {x: 3, y: 5}), 9);
+    assert_eq!(sum_xy(&mut record! {y: 5, z: "z", x: 10}), 16);
+}
@@ -0,0 +1,55 @@
+#[allow(unused_imports)]
+use tstr::{reprs, ts, TStr, TS};
+
+#[cfg(not(feature = "min_const_generics"))]
+#[test]
+fn default_repr() {
+    use tstr::reprs::{__0x00, __0x30, __0x41, __0x5F, __0x61, __0x7A, __0xFF, ByteRepr};
+
+    let _: TS!("") = TStr::<()>::NEW;
+    let _: TS!("a") = TStr::<(__0x61,)>::NEW;
+    let _: TS!("0A_z") = TStr::<(__0x30, __0x41, __0x5F, __0x7A)>::NEW;
+    let _: TS!("aaaaaaaaa") = TStr::<(
+        (
+            __0x61,
+            __0x61,
+            __0x61,
+            __0x61,
+            __0x61,
+            __0x61,
+            __0x61,
+            __0x61,
+        ),
+        (__0x61,),
+    )>::NEW;
+
+    // the aliases of alphanumeric bytes are the same types
+    let _: TStr<(tstr::__a, tstr::__0)> = TStr::<(__0x61, __0x30)>::NEW;
+
+    assert_eq!(__0x00::BYTE, 0);
+    assert_eq!(__0x30::BYTE, b'0');
+    assert_eq!(__0x41::BYTE, b'A');
+    assert_eq!(__0x5F::BYTE, b'_');
+    assert_eq!(__0x61::BYTE, b'a');
+    assert_eq!(__0x7A::BYTE, b'z');
+    assert_eq!(__0xFF::BYTE, 255);
+}
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+#[test]
+fn min_const_generics_repr() {
+    use tstr::reprs::{__a, __c, __};
+
+    let _: TS!("a") = TStr::<__a<'a'>>::NEW;
+    let _: TS!("ñu0") = TStr::<__c<'ñ', 'u', '0'>>::NEW;
+    let _: TS!("aaaaaaaaa") = TStr::<(__<'a', 'a', 'a', 'a', 'a', 'a', 'a', 'a'>, __a<'a'>)>::NEW;
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+fn const_generics_repr() {
+    use tstr::reprs::___;
+
+    let _: TS!("") = TStr::<___<"">>::NEW;
+    let _: TS!("hello") = TStr::<___<"hello">>::NEW;
+}
@@ -0,0 +1,134 @@
+use serde::de::{
+    self,
+    value::{Error, MapDeserializer},
+    Deserialize, DeserializeSeed, Deserializer, IgnoredAny, IntoDeserializer, MapAccess, Visitor,
+};
+
+use tstr::{
+    serde_fields::{FieldNames, FieldSeed},
+    tlist::{TList, TNil},
+    TS,
+};
+
+use std::fmt;
+
+type Names = TList<
+    TS!(a),
+    TList<TS!(""), TList<TS!(hello_world_and_more), TList<TS!("ñó"), TList<TS!(12345678), TNil>>>>,
+>;
+
+#[test]
+fn index_of_test() {
+    assert_eq!(<TNil as FieldNames>::LEN, 0);
+    assert_eq!(TNil::index_of("a"), None);
+
+    assert_eq!(Names::LEN, 5);
+    assert_eq!(Names::index_of("a"), Some(0));
+    assert_eq!(Names::index_of(""), Some(1));
+    assert_eq!(Names::index_of("hello_world_and_more"), Some(2));
+    assert_eq!(Names::index_of("ñó"), Some(3));
+    assert_eq!(Names::index_of("12345678"), Some(4));
+
+    assert_eq!(Names::index_of("b"), None);
+    assert_eq!(Names::index_of("ab"), None);
+    assert_eq!(Names::index_of("hello_world_and_mor"), None);
+    assert_eq!(Names::index_of("hello_world_and_moree"), None);
+    assert_eq!(Names::index_of("ñ"), None);
+    assert_eq!(Names::index_of("1234567"), None);
+
+    assert_eq!(Names::index_of_bytes(b"a"), Some(0));
+    assert_eq!(Names::index_of_bytes(&[0xC3]), None);
+}
+
+#[test]
+fn seed_test() {
+    let seed = FieldSeed::<Names>::NEW;
+
+    let from_str = |x: &str| seed.deserialize(x.into_deserializer());
+    assert_eq!(from_str("hello_world_and_more"), Ok::<_, Error>(Some(2)));
+    assert_eq!(from_str("ñó"), Ok(Some(3)));
+    assert_eq!(from_str("nope"), Ok(None));
+
+    let from_u64 = |x: u64| seed.deserialize(x.into_deserializer());
+    assert_eq!(from_u64(0), Ok::<_, Error>(Some(0)));
+    assert_eq!(from_u64(4), Ok(Some(4)));
+    assert_eq!(from_u64(5), Ok(None));
+
+    let from_bytes = |x: &[u8]| seed.deserialize(de::value::BytesDeserializer::<Error>::new(x));
+    assert_eq!(from_bytes(b"12345678"), Ok(Some(4)));
+    assert_eq!(from_bytes(b"z"), Ok(None));
+
+    assert!(seed
+        .deserialize(true.into_deserializer())
+        .map_err(|_: Error| ())
+        .is_err());
+}
+
+#[derive(Debug, PartialEq)]
+struct Rgb {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+type RgbFields = TList<TS!(red), TList<TS!(green), TList<TS!(blue), TNil>>>;
+
+impl<'de> Deserialize<'de> for Rgb {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Rgb", &["red", "green", "blue"], RgbVisitor)
+    }
+}
+
+struct RgbVisitor;
+
+impl<'de> Visitor<'de> for RgbVisitor {
+    type Value = Rgb;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("struct Rgb")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Rgb, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut fields = [None; 3];
+
+        while let Some(field) = map.next_key_seed(FieldSeed::<RgbFields>::NEW)? {
+            match field {
+                Some(i) => fields[i] = Some(map.next_value()?),
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let [red, green, blue] = fields;
+        Ok(Rgb {
+            red: red.ok_or_else(|| de::Error::missing_field("red"))?,
+            green: green.ok_or_else(|| de::Error::missing_field("green"))?,
+            blue: blue.ok_or_else(|| de::Error::missing_field("blue"))?,
+        })
+    }
+}
+
+#[test]
+fn deserialize_struct_test() {
+    let deserialize = |entries: Vec<(&'static str, u8)>| {
+        Rgb::deserialize(MapDeserializer::<_, Error>::new(entries.into_iter()))
+    };
+
+    assert_eq!(
+        deserialize(vec![("blue", 3), ("alpha", 255), ("red", 5), ("green", 8)]),
+        Ok(Rgb {
+            red: 5,
+            green: 8,
+            blue: 3
+        }),
+    );
+
+    assert!(deserialize(vec![("blue", 3), ("red", 5)]).is_err());
+}
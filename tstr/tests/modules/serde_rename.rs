@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use tstr::tlist::{TList, TNil};
+use tstr::{ts, Fields, IntoVariant, TStrKeys, VariantAccess, TS};
+
+#[derive(Serialize, Deserialize, Fields)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+struct User {
+    user_id: u64,
+    #[serde(rename = "name")]
+    display_name: String,
+    #[serde(default, rename(serialize = "mail", deserialize = "email"))]
+    email_address: String,
+    r#type: u8,
+}
+
+#[derive(Serialize, Deserialize, Fields)]
+#[allow(dead_code)]
+struct Plain {
+    #[serde(default)]
+    first_field: u8,
+    #[serde(rename = r#"second "field""#)]
+    second_field: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, TStrKeys, VariantAccess)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Event {
+    KeyPressed(char),
+    #[serde(rename = "click")]
+    MouseClicked {
+        x: u32,
+        y: u32,
+    },
+    WindowClosed,
+}
+
+#[derive(Serialize, Deserialize, TStrKeys)]
+#[serde(rename_all = "kebab-case")]
+#[allow(dead_code)]
+enum Kebab {
+    FooBar,
+    Baz,
+}
+
+#[test]
+fn renamed_fields() {
+    assert_eq!(User::FIELD_NAMES, ["userId", "name", "mail", "type"]);
+
+    let _: <User as Fields>::List = TList::<
+        (TS!(userId), u64),
+        TList<(TS!(name), String), TList<(TS!(mail), String), TList<(TS!(type), u8), TNil>>>,
+    >::NEW;
+
+    assert_eq!(Plain::FIELD_NAMES, ["first_field", "second \"field\""]);
+}
+
+#[test]
+fn renamed_variants() {
+    assert_eq!(
+        Event::VARIANT_NAMES,
+        ["KEY_PRESSED", "click", "WINDOW_CLOSED"]
+    );
+    assert_eq!(Event::WindowClosed.variant_name(), "WINDOW_CLOSED");
+
+    // the constants are named after the Rust names of the variants
+    let _: TS!(KEY_PRESSED) = Event::KEY_PRESSED_KEY;
+    let _: TS!(click) = Event::MOUSE_CLICKED_KEY;
+
+    assert_eq!(
+        Event::KeyPressed('a').into_variant(ts!(KEY_PRESSED)),
+        Some('a')
+    );
+    assert_eq!(
+        Event::MouseClicked { x: 3, y: 5 }.into_variant(ts!(click)),
+        Some((3, 5))
+    );
+
+    assert_eq!(Kebab::VARIANT_NAMES, ["foo-bar", "baz"]);
+}
@@ -0,0 +1,147 @@
+use tstr::sets::{
+    Contains, Difference, Insert, Intersection, IsDisjointFrom, IsSubsetOf, MakeTStrSet, Remove,
+    Union,
+};
+use tstr::tlist::{TList, TNil};
+use tstr::{TStrSet, TS};
+
+fn assert_contains<S: Contains<K>, K>() {}
+
+type InsertOut<S, K> = <S as Insert<K>>::Output;
+type RemoveOut<S, K> = <S as Remove<K>>::Output;
+
+type Len8 = TS!("aaaaaaaa");
+type Len9A = TS!("aaaaaaaaa");
+type Len9B = TS!("aaaaaaaab");
+type Len64 = TS!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa");
+type Len65 = TS!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-");
+
+#[test]
+fn set_contains() {
+    type Set = TStrSet!(
+        "",
+        a,
+        ab,
+        "aaaaaaaa",
+        "aaaaaaaaa",
+        "-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa"
+    );
+
+    assert_contains::<Set, TS!("")>();
+    assert_contains::<Set, TS!(a)>();
+    assert_contains::<Set, TS!(ab)>();
+    assert_contains::<Set, Len8>();
+    assert_contains::<Set, Len9A>();
+    assert_contains::<Set, Len64>();
+}
+
+#[test]
+fn set_insert() {
+    let _: TStrSet<TNil> = <TStrSet!()>::MAKE;
+    let _: TStrSet<TList<TS!(a), TNil>> = <InsertOut<TStrSet!(), TS!(a)>>::MAKE;
+
+    let _: TStrSet!(a, b) = <InsertOut<TStrSet!(a), TS!(b)>>::MAKE;
+    let _: TStrSet!(a, b) = <InsertOut<TStrSet!(a, b), TS!(a)>>::MAKE;
+    let _: TStrSet!(a, b) = <InsertOut<TStrSet!(a, b), TS!(b)>>::MAKE;
+    let _: TStrSet!(a, b, ab) = <InsertOut<TStrSet!(a, b), TS!(ab)>>::MAKE;
+    let _: TStrSet!(ab, a) = <InsertOut<TStrSet!(ab), TS!(a)>>::MAKE;
+
+    let _: TStrSet!("aaaaaaaaa", "aaaaaaaab") = <InsertOut<TStrSet!("aaaaaaaaa"), Len9B>>::MAKE;
+    let _: TStrSet!("aaaaaaaaa") = <InsertOut<TStrSet!("aaaaaaaaa"), Len9A>>::MAKE;
+    let _: TStrSet!("aaaaaaaa", "aaaaaaaaa") = <InsertOut<TStrSet!("aaaaaaaa"), Len9A>>::MAKE;
+    let _: TStrSet!(
+        "-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa",
+        "-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-"
+    ) = <InsertOut<
+        TStrSet!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa"),
+        Len65,
+    >>::MAKE;
+    let _: TStrSet!(
+        "-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-",
+        "-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa"
+    ) = <InsertOut<
+        TStrSet!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-"),
+        Len64,
+    >>::MAKE;
+}
+
+#[test]
+fn set_remove() {
+    let _: TStrSet!() = <RemoveOut<TStrSet!(), TS!(a)>>::MAKE;
+    let _: TStrSet!() = <RemoveOut<TStrSet!(a), TS!(a)>>::MAKE;
+    let _: TStrSet!(a) = <RemoveOut<TStrSet!(a), TS!(b)>>::MAKE;
+    let _: TStrSet!(b, c) = <RemoveOut<TStrSet!(a, b, c), TS!(a)>>::MAKE;
+    let _: TStrSet!(a, c) = <RemoveOut<TStrSet!(a, b, c), TS!(b)>>::MAKE;
+    let _: TStrSet!(a, b) = <RemoveOut<TStrSet!(a, b, c), TS!(c)>>::MAKE;
+
+    let _: TStrSet!("aaaaaaaaa") = <RemoveOut<TStrSet!("aaaaaaaaa", "aaaaaaaab"), Len9B>>::MAKE;
+    let _: TStrSet!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa") = <RemoveOut<
+        TStrSet!(
+            "-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa",
+            "-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-"
+        ),
+        Len65,
+    >>::MAKE;
+}
+
+type UnionOut<L, R> = <L as Union<R>>::Output;
+type IntersectionOut<L, R> = <L as Intersection<R>>::Output;
+type DifferenceOut<L, R> = <L as Difference<R>>::Output;
+
+fn assert_subset<L: IsSubsetOf<R>, R>() {}
+fn assert_disjoint<L: IsDisjointFrom<R>, R>() {}
+
+#[test]
+fn set_union() {
+    let _: TStrSet!() = <UnionOut<TStrSet!(), TStrSet!()>>::MAKE;
+    let _: TStrSet!(a) = <UnionOut<TStrSet!(a), TStrSet!()>>::MAKE;
+    let _: TStrSet!(a) = <UnionOut<TStrSet!(), TStrSet!(a)>>::MAKE;
+    let _: TStrSet!(a, b) = <UnionOut<TStrSet!(a, b), TStrSet!(b, a)>>::MAKE;
+    let _: TStrSet!(a, b, c) = <UnionOut<TStrSet!(a, b), TStrSet!(b, c)>>::MAKE;
+    let _: TStrSet!(c, b, a) = <UnionOut<TStrSet!(c), TStrSet!(b, c, a)>>::MAKE;
+    let _: TStrSet!("aaaaaaaaa", "aaaaaaaab") =
+        <UnionOut<TStrSet!("aaaaaaaaa"), TStrSet!("aaaaaaaab", "aaaaaaaaa")>>::MAKE;
+
+    let _: TStrSet!(a, b, c) = TStrSet::<TNil>::NEW
+        .union(<TStrSet!(a, b)>::NEW)
+        .union(<TStrSet!(c)>::NEW);
+}
+
+#[test]
+fn set_intersection() {
+    let _: TStrSet!() = <IntersectionOut<TStrSet!(), TStrSet!(a)>>::MAKE;
+    let _: TStrSet!() = <IntersectionOut<TStrSet!(a), TStrSet!()>>::MAKE;
+    let _: TStrSet!() = <IntersectionOut<TStrSet!(a), TStrSet!(b)>>::MAKE;
+    let _: TStrSet!(a, c) = <IntersectionOut<TStrSet!(a, b, c), TStrSet!(c, d, a)>>::MAKE;
+    let _: TStrSet!("aaaaaaaab") =
+        <IntersectionOut<TStrSet!("aaaaaaaaa", "aaaaaaaab"), TStrSet!("aaaaaaaab")>>::MAKE;
+
+    let _: TStrSet!(b) = <TStrSet!(a, b)>::NEW.intersection(<TStrSet!(b, c)>::NEW);
+}
+
+#[test]
+fn set_difference() {
+    let _: TStrSet!() = <DifferenceOut<TStrSet!(), TStrSet!(a)>>::MAKE;
+    let _: TStrSet!(a) = <DifferenceOut<TStrSet!(a), TStrSet!()>>::MAKE;
+    let _: TStrSet!() = <DifferenceOut<TStrSet!(a), TStrSet!(a)>>::MAKE;
+    let _: TStrSet!(b) = <DifferenceOut<TStrSet!(a, b, c), TStrSet!(c, d, a)>>::MAKE;
+    let _: TStrSet!("aaaaaaaaa") =
+        <DifferenceOut<TStrSet!("aaaaaaaaa", "aaaaaaaab"), TStrSet!("aaaaaaaab")>>::MAKE;
+
+    let _: TStrSet!(a) = <TStrSet!(a, b)>::NEW.difference(<TStrSet!(b, c)>::NEW);
+}
+
+#[test]
+fn set_predicates() {
+    assert_subset::<TStrSet!(), TStrSet!()>();
+    assert_subset::<TStrSet!(), TStrSet!(a)>();
+    assert_subset::<TStrSet!(a), TStrSet!(a)>();
+    assert_subset::<TStrSet!(b, a), TStrSet!(a, b, c)>();
+    assert_subset::<TStrSet!("aaaaaaaab"), TStrSet!("aaaaaaaaa", "aaaaaaaab")>();
+
+    assert_disjoint::<TStrSet!(), TStrSet!()>();
+    assert_disjoint::<TStrSet!(a), TStrSet!()>();
+    assert_disjoint::<TStrSet!(), TStrSet!(a)>();
+    assert_disjoint::<TStrSet!(a, b), TStrSet!(c, d)>();
+    assert_disjoint::<TStrSet!("aaaaaaaaa"), TStrSet!("aaaaaaaab")>();
+}
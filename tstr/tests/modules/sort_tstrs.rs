@@ -0,0 +1,77 @@
+use tstr::tlist::{SortTStrs, TList, TNil};
+use tstr::{TStrMax, TStrMin, TStrMinMax, TS};
+
+fn assert_sorted<L: SortTStrs<Output = Expected>, Expected>() {}
+
+type L1<A> = TList<A, TNil>;
+type L2<A, B> = TList<A, L1<B>>;
+type L3<A, B, C> = TList<A, L2<B, C>>;
+type L4<A, B, C, D> = TList<A, L3<B, C, D>>;
+
+type Len8 = TS!("aaaaaaaa");
+type Len9 = TS!("aaaaaaaaa");
+type Len9B = TS!("aaaaaaaab");
+type Len64 = TS!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa");
+type Len65 = TS!("-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-aaaaaaa-");
+
+#[test]
+fn sort_short() {
+    assert_sorted::<TNil, TNil>();
+    assert_sorted::<L1<TS!(a)>, L1<TS!(a)>>();
+
+    assert_sorted::<L2<TS!(a), TS!(b)>, L2<TS!(a), TS!(b)>>();
+    assert_sorted::<L2<TS!(b), TS!(a)>, L2<TS!(a), TS!(b)>>();
+
+    assert_sorted::<L3<TS!(c), TS!(a), TS!(b)>, L3<TS!(a), TS!(b), TS!(c)>>();
+    assert_sorted::<L3<TS!(b), TS!(c), TS!(a)>, L3<TS!(a), TS!(b), TS!(c)>>();
+    assert_sorted::<L3<TS!(c), TS!(b), TS!(a)>, L3<TS!(a), TS!(b), TS!(c)>>();
+
+    // duplicates are kept
+    assert_sorted::<L3<TS!(a), TS!(b), TS!(a)>, L3<TS!(a), TS!(a), TS!(b)>>();
+}
+
+#[test]
+fn sort_lexicographic() {
+    // prefixes are less than the strings that they're a prefix of
+    assert_sorted::<L3<TS!(ab), TS!(a), TS!("")>, L3<TS!(""), TS!(a), TS!(ab)>>();
+
+    // shorter strings can be greater than longer strings
+    assert_sorted::<L2<TS!(foo), TS!(aaaaaa)>, L2<TS!(aaaaaa), TS!(foo)>>();
+
+    // uppercase letters are less than lowercase letters
+    assert_sorted::<L2<TS!(a), TS!(B)>, L2<TS!(B), TS!(a)>>();
+
+    // non-ASCII chars are greater than all ASCII chars
+    assert_sorted::<L3<TS!("ü"), TS!("é"), TS!(z)>, L3<TS!(z), TS!("é"), TS!("ü")>>();
+
+    assert_sorted::<L2<TS!(10), TS!(0)>, L2<TS!(0), TS!(10)>>();
+    assert_sorted::<L2<TS!(9), TS!(10)>, L2<TS!(10), TS!(9)>>();
+}
+
+#[test]
+fn sort_long() {
+    assert_sorted::<L4<TS!(b), Len9B, Len8, Len9>, L4<Len8, Len9, Len9B, TS!(b)>>();
+    assert_sorted::<L3<Len65, TS!(a), Len64>, L3<Len64, Len65, TS!(a)>>();
+    assert_sorted::<L2<TS!(aaaaaaac), Len9B>, L2<Len9B, TS!(aaaaaaac)>>();
+}
+
+fn assert_min_max<A, B, Min, Max>()
+where
+    A: TStrMinMax<B, Min = Min, Max = Max>,
+{
+}
+
+#[test]
+fn min_max() {
+    let _: TS!(a) = <TStrMin<TS!(a), TS!(b)>>::NEW;
+    let _: TS!(a) = <TStrMin<TS!(b), TS!(a)>>::NEW;
+    let _: TS!(b) = <TStrMax<TS!(a), TS!(b)>>::NEW;
+    let _: TS!(b) = <TStrMax<TS!(b), TS!(a)>>::NEW;
+
+    assert_min_max::<TS!(a), TS!(a), TS!(a), TS!(a)>();
+    assert_min_max::<TS!(""), TS!(a), TS!(""), TS!(a)>();
+    assert_min_max::<TS!(ab), TS!(a), TS!(a), TS!(ab)>();
+    assert_min_max::<TS!(foo), TS!(aaaaaa), TS!(aaaaaa), TS!(foo)>();
+    assert_min_max::<Len9B, Len9, Len9, Len9B>();
+    assert_min_max::<Len64, Len65, Len64, Len65>();
+}
@@ -0,0 +1,80 @@
+// These types must only change in semver-incompatible releases.
+use tstr::*;
+
+const _: TStr<()> = ts!("");
+
+const _: TStr<(__0x61,)> = ts!(a);
+
+const _: TStr<(__0x30, __0x5F, __0x41, __0x7A)> = ts!("0_Az");
+
+const _: TStr<(__0xC3, __0xA9)> = ts!("é");
+
+type Bytes8 = (
+    __0x61,
+    __0x62,
+    __0x63,
+    __0x64,
+    __0x65,
+    __0x66,
+    __0x67,
+    __0x68,
+);
+
+const _: TStr<Bytes8> = ts!(abcdefgh);
+
+const _: TStr<(Bytes8, (__0x69,))> = ts!(abcdefghi);
+
+type Bytes64 = (
+    Bytes8,
+    Bytes8,
+    Bytes8,
+    Bytes8,
+    Bytes8,
+    Bytes8,
+    Bytes8,
+    Bytes8,
+);
+
+const _: TStr<Bytes64> = ts!("abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh");
+
+const _: TStr<(Bytes64, (__0x30,))> =
+    ts!("abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh0");
+
+// Strings longer than 64 bytes are nested one more level for every factor of 8
+type Bytes512 = (
+    Bytes64,
+    Bytes64,
+    Bytes64,
+    Bytes64,
+    Bytes64,
+    Bytes64,
+    Bytes64,
+    Bytes64,
+);
+
+const _: TStr<Bytes512> = ts!(concat!(
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+));
+
+const _: TStr<(Bytes512, (__0x30,))> = ts!(concat!(
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "abcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefghabcdefgh",
+    "0",
+));
+
+// Just making sure that this module is compiled.
+#[test]
+fn testing_stable_repr() {}
@@ -27,6 +27,15 @@ test_case!("b", (__b,), __a<'b'>, "b");
 // Testing two char strings
 test_case!("ab", (__a, __b), __b<'a','b'>, "ab");
 
+// Testing non-ASCII chars, which are one const parameter each with `min_const_generics`
+test_case!(
+    "héllo",
+    (__h, __0xC3, __0xA9, __l, __l, __o),
+    __e<'h', 'é', 'l', 'l', 'o'>,
+    "héllo"
+);
+test_case!("𣏦", (__0xF0, __0xA3, __0x8F, __0xA6), __a<'𣏦'>, "𣏦");
+
 #[cfg(not(feature = "min_const_generics"))]
 #[allow(dead_code)]
 type AllCharLengths = (
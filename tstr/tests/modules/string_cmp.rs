@@ -6,10 +6,29 @@ use std::cmp::{Ord, Ordering};
 #[cfg(feature = "const_generics")]
 use tstr::{StrValue, TStrOrd};
 
+#[cfg(not(feature = "min_const_generics"))]
+use tstr::{Boolean, False, True};
+
+#[cfg(feature = "nightly_generic_const_exprs")]
+use tstr::{TStrMax, TStrMin, TStrMinMax};
+
+// Asserts that `TStrEq::Output` is `$expected`, and agrees with `TStrEq::EQ`.
+#[cfg(not(feature = "min_const_generics"))]
+fn assert_output_is<L, R, Expected>()
+where
+    L: TStrEq<R, Output = Expected>,
+    Expected: Boolean,
+{
+    assert_eq!(Expected::VALUE, L::EQ);
+}
+
 macro_rules! assert_str_eq {
     ($left:ty, $right:ty) => {
         assert!(<$left as TStrEq<$right>>::EQ);
 
+        #[cfg(not(feature = "min_const_generics"))]
+        assert_output_is::<$left, $right, True>();
+
         #[cfg(feature = "const_generics")]
         assert_eq!(<$left as TStrOrd<$right>>::CMP, Ordering::Equal);
     };
@@ -19,6 +38,11 @@ macro_rules! assert_str_ne {
     ($left:ty, [$($right:ty),* $(,)*]) => {
         $(assert!(<$left as TStrEq<$right>>::NE);)*
 
+        #[cfg(not(feature = "min_const_generics"))]
+        {
+            $(assert_output_is::<$left, $right, False>();)*
+        }
+
         #[cfg(feature = "const_generics")]
         {
             $(
@@ -235,3 +259,64 @@ fn short_strs() {
         ]
     );
 }
+
+// Strings where the number of chars differs from the number of bytes,
+// which are split into chunks differently in the representations that
+// use chars (`"min_const_generics"`) and the ones that use bytes.
+type NonAscii1 = TS!("ñ");
+type NonAscii2 = TS!("ab");
+type NonAscii3 = TS!("añ");
+type NonAscii4 = TS!("ña");
+type NonAscii5 = TS!("ñandú ü");
+type NonAscii6 = TS!("nandu u");
+type NonAscii7 = TS!("ñañañaña");
+type NonAscii8 = TS!("ñañañañaa");
+type NonAscii9 = TS!("ñandúñandúñandúñandú");
+type NonAscii10 = TS!("ñandúñandúñandúñandu");
+
+#[test]
+fn non_ascii_strs() {
+    assert_str_eq!(NonAscii1, NonAscii1);
+    assert_str_eq!(NonAscii3, NonAscii3);
+    assert_str_eq!(NonAscii5, NonAscii5);
+    assert_str_eq!(NonAscii7, NonAscii7);
+    assert_str_eq!(NonAscii8, NonAscii8);
+    assert_str_eq!(NonAscii9, NonAscii9);
+
+    assert_str_ne!(NonAscii1, [Len1A, Len2A, NonAscii2, NonAscii3, NonAscii4]);
+    assert_str_ne!(NonAscii2, [NonAscii1, NonAscii3, NonAscii4, Len2A]);
+    assert_str_ne!(NonAscii3, [NonAscii1, NonAscii2, NonAscii4, Len3A]);
+    assert_str_ne!(
+        NonAscii5,
+        [NonAscii6, NonAscii7, NonAscii8, Len7A, Len8A, Len9A]
+    );
+    assert_str_ne!(NonAscii7, [NonAscii5, NonAscii8, Len8A, Len9A, Len17A]);
+    assert_str_ne!(NonAscii8, [NonAscii7, Len9A, Len17A]);
+    assert_str_ne!(NonAscii9, [NonAscii10, NonAscii8, Len17A, Len25A]);
+}
+
+// The default representation is tested in the `sort_tstrs` module,
+// this tests the const expression that compares the `&'static str` parameters.
+#[cfg(feature = "nightly_generic_const_exprs")]
+fn assert_min_max<A, B, Min, Max>()
+where
+    A: TStrMinMax<B, Min = Min, Max = Max>,
+{
+}
+
+#[cfg(feature = "nightly_generic_const_exprs")]
+#[test]
+fn min_max_const_exprs() {
+    let _: TS!(a) = <TStrMin<TS!(a), TS!(b)>>::NEW;
+    let _: TS!(a) = <TStrMin<TS!(b), TS!(a)>>::NEW;
+    let _: TS!(b) = <TStrMax<TS!(a), TS!(b)>>::NEW;
+    let _: TS!(b) = <TStrMax<TS!(b), TS!(a)>>::NEW;
+
+    assert_min_max::<TS!(a), TS!(a), TS!(a), TS!(a)>();
+    assert_min_max::<TS!(""), TS!(a), TS!(""), TS!(a)>();
+    assert_min_max::<TS!(ab), TS!(a), TS!(a), TS!(ab)>();
+    assert_min_max::<TS!(foo), TS!(aaaaaa), TS!(aaaaaa), TS!(foo)>();
+    assert_min_max::<Len9B, Len9A, Len9A, Len9B>();
+    assert_min_max::<Len64A, Len64B, Len64A, Len64B>();
+    assert_min_max::<NonAscii7, NonAscii8, NonAscii7, NonAscii8>();
+}
@@ -0,0 +1,58 @@
+use tstr::{assert_has_fields, assert_has_fields_mut, declare_fields, impl_index};
+
+declare_fields! {
+    struct Person {
+        name: String,
+        surname: String,
+        age: u32,
+    }
+}
+
+struct Tuple(u8, &'static str);
+
+impl_index! {
+    Tuple {
+        0: u8 => &self.0,
+        1: str => self.1,
+    }
+}
+
+struct Wrapper<T>(Vec<T>);
+
+impl_index! {
+    impl[T] Wrapper<T> {
+        items: [T] => &self.0,
+        "the items": Vec<T> => &self.0,
+    }
+}
+
+assert_has_fields!(Person: name, surname, age);
+assert_has_fields!(Person: name: String, age: u32,);
+assert_has_fields_mut!(Person: name, surname: String, age: u32);
+
+assert_has_fields!(Tuple: 0: u8, 1: str);
+assert_has_fields!(Wrapper<u8>: items: [u8], "the items");
+assert_has_fields!(Wrapper<&str>: items: [&str]);
+assert_has_fields!(Person:);
+
+#[test]
+fn statement_position() {
+    assert_has_fields!(Person: name);
+    assert_has_fields_mut!(Person: age: u32);
+    assert_has_fields!(Wrapper<Person>: "the items": Vec<Person>);
+
+    let tuple = Tuple(3, "foo");
+    assert_eq!(tuple[tstr::ts!(0)], 3);
+    assert_eq!(&tuple[tstr::ts!(1)], "foo");
+
+    let wrapper = Wrapper(vec![Person {
+        name: "Bob".into(),
+        surname: "Marley".into(),
+        age: 36,
+    }]);
+    let person = &wrapper[tstr::ts!(items)][0];
+    assert_eq!(
+        (&person.name[..], &person.surname[..], person.age),
+        ("Bob", "Marley", 36)
+    );
+}
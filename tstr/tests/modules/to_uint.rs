@@ -1,4 +1,6 @@
-use tstr::{ts, ToUint};
+#![allow(unexpected_cfgs)]
+
+use tstr::{ts, ToUint, TS};
 
 fn same<T: ToUint>(val: T) -> usize {
     let v128 = val.to_u128();
@@ -22,6 +24,44 @@ fn to_uint() {
     assert_eq!(same(ts!(513)), 513);
 }
 
+#[test]
+fn to_nonzero_usize() {
+    fn nonzero<T: ToUint>(val: T) -> Option<usize> {
+        assert_eq!(T::NONZERO_USIZE, val.to_nonzero_usize());
+        val.to_nonzero_usize().map(|n| n.get())
+    }
+
+    assert_eq!(nonzero(ts!(0)), None);
+    assert_eq!(nonzero(ts!(1)), Some(1));
+    assert_eq!(nonzero(ts!(10)), Some(10));
+    assert_eq!(nonzero(ts!(64)), Some(64));
+    assert_eq!(nonzero(ts!(513)), Some(513));
+}
+
+#[test]
+fn to_narrow_uints() {
+    type U8Max = TS!(255);
+    type U16Max = TS!(65535);
+    type U32Max = TS!(0xFFFF_FFFF);
+    type U64Max = TS!(0xFFFF_FFFF_FFFF_FFFF);
+
+    assert_eq!(<TS!(0)>::U8, 0);
+    assert_eq!(<TS!(0)>::U64, 0);
+    assert_eq!(U8Max::U8, 255);
+    assert_eq!(U8Max::U16, 255);
+    assert_eq!(U16Max::U16, 65535);
+    assert_eq!(U16Max::U32, 65535);
+    assert_eq!(U32Max::U32, 0xFFFF_FFFF);
+    assert_eq!(U32Max::U64, 0xFFFF_FFFF);
+    assert_eq!(U64Max::U64, 0xFFFF_FFFF_FFFF_FFFF);
+
+    assert_eq!(ts!(13).to_u8(), 13);
+    assert_eq!(ts!(513).to_u16(), 513);
+    assert_eq!(ts!(0x1_0000).to_u32(), 0x1_0000);
+    assert_eq!(ts!(0x1_0000_0000).to_u64(), 0x1_0000_0000);
+}
+
+#[allow(clippy::legacy_numeric_constants)]
 const UMAX: usize = std::usize::MAX;
 
 #[test]
@@ -63,3 +103,28 @@ fn to_usize() {
     assert_eq!(ts!(0x1_0000).to_usize(), UMAX);
     assert_eq!(ts!(0x1_0001).to_usize(), UMAX);
 }
+
+#[test]
+fn digits_and_leading_zeros() {
+    fn digits<T: ToUint>(_: T) -> (u32, u32) {
+        (T::DIGITS, T::LEADING_ZEROS)
+    }
+
+    assert_eq!(digits(ts!(0)), (1, 0));
+    assert_eq!(digits(ts!(7)), (1, 0));
+    assert_eq!(digits(ts!(100)), (3, 0));
+    assert_eq!(digits(ts!("00")), (2, 1));
+    assert_eq!(digits(ts!("000")), (3, 2));
+    assert_eq!(digits(ts!("007")), (3, 2));
+    assert_eq!(digits(ts!("0100")), (4, 1));
+    assert_eq!(digits(ts!("000000000123")), (12, 9));
+    assert_eq!(digits(ts!("00000000000000000000")), (20, 19));
+    assert_eq!(
+        digits(ts!("0340282366920938463463374607431768211455")),
+        (40, 1)
+    );
+    assert_eq!(
+        digits(ts!(340282366920938463463374607431768211455)),
+        (39, 0)
+    );
+}
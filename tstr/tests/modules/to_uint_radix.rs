@@ -0,0 +1,73 @@
+use tstr::{ts, ToUint, ToUintRadix, TS};
+
+fn hex<T: ToUintRadix<16>>(val: T) -> u128 {
+    assert_eq!(val.to_u128(), T::U128);
+    T::U128
+}
+
+#[test]
+fn hexadecimal() {
+    assert_eq!(hex(ts!(0)), 0);
+    assert_eq!(hex(ts!(9)), 9);
+    assert_eq!(hex(ts!(a)), 10);
+    assert_eq!(hex(ts!(F)), 15);
+    assert_eq!(hex(ts!(10)), 16);
+    assert_eq!(hex(ts!("ff")), 255);
+    assert_eq!(hex(ts!("FfFf")), 0xFFFF);
+    assert_eq!(hex(ts!("DEADbeef")), 0xDEAD_BEEF);
+    assert_eq!(hex(ts!("0123456789abcdef")), 0x0123_4567_89ab_cdef);
+    assert_eq!(hex(ts!("ffffffffffffffffffffffffffffffff")), u128::MAX);
+}
+
+#[test]
+fn other_radixes() {
+    assert_eq!(<TS!(0) as ToUintRadix<2>>::U8, 0);
+    assert_eq!(<TS!(1) as ToUintRadix<2>>::U8, 1);
+    assert_eq!(<TS!(1010) as ToUintRadix<2>>::U8, 0b1010);
+    assert_eq!(<TS!(11111111) as ToUintRadix<2>>::U8, 0xFF);
+    assert_eq!(<TS!(100000000) as ToUintRadix<2>>::U16, 256);
+
+    assert_eq!(<TS!(7) as ToUintRadix<8>>::U16, 7);
+    assert_eq!(<TS!(777) as ToUintRadix<8>>::U16, 0o777);
+    assert_eq!(<TS!(1234567) as ToUintRadix<8>>::U32, 0o1234567);
+
+    assert_eq!(<TS!(z) as ToUintRadix<36>>::U8, 35);
+    assert_eq!(<TS!(Z) as ToUintRadix<36>>::U8, 35);
+    assert_eq!(<TS!(zz) as ToUintRadix<36>>::U16, 36 * 36 - 1);
+    assert_eq!(<TS!(tstr) as ToUintRadix<36>>::U32, 1_390_383);
+}
+
+#[test]
+fn leading_zeros() {
+    assert_eq!(<TS!("0000000000ff") as ToUintRadix<16>>::U8, 255);
+    assert_eq!(
+        <TS!("00000000000000000000000000000001") as ToUintRadix<2>>::U8,
+        1
+    );
+    assert_eq!(
+        <TS!("0000000000000000000000000000000000000000") as ToUintRadix<16>>::U128,
+        0
+    );
+    assert_eq!(
+        <TS!("000000000000ffffffffffffffffffffffffffffffff") as ToUintRadix<16>>::U128,
+        u128::MAX
+    );
+}
+
+#[test]
+fn decimal_matches_to_uint() {
+    macro_rules! same_as_to_uint {
+        ($($digits:tt)*) => ($(
+            assert_eq!(
+                <TS!($digits) as ToUintRadix<10>>::U128,
+                <TS!($digits) as ToUint>::U128,
+            );
+            assert_eq!(
+                <TS!($digits) as ToUintRadix<10>>::USIZE,
+                <TS!($digits) as ToUint>::USIZE,
+            );
+        )*)
+    }
+
+    same_as_to_uint! {0 1 9 10 99 255 256 1000 65535 4294967296 "00042"}
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use tstr::{ts, TryIndex, TryIndexMut, TS};
+
+#[cfg(feature = "for_examples")]
+use tstr::for_examples::{Bar, Foo};
+
+struct Headers {
+    map: HashMap<&'static str, String>,
+}
+
+macro_rules! impl_try_index {
+    ($($key:ident)*) => {
+        $(
+            impl TryIndex<TS!($key)> for Headers {
+                type Output = str;
+
+                fn try_index(&self, _: TS!($key)) -> Option<&str> {
+                    self.map.get(stringify!($key)).map(|s| s.as_str())
+                }
+            }
+
+            impl TryIndexMut<TS!($key)> for Headers {
+                fn try_index_mut(&mut self, _: TS!($key)) -> Option<&mut str> {
+                    self.map.get_mut(stringify!($key)).map(|s| s.as_mut_str())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_index! {host accept}
+
+fn get_string<T, K>(this: &T, key: K) -> Option<String>
+where
+    T: TryIndex<K>,
+    T::Output: ToString,
+{
+    this.try_index(key).map(|x| x.to_string())
+}
+
+#[test]
+fn manual_impl() {
+    let mut headers = Headers {
+        map: HashMap::new(),
+    };
+
+    assert_eq!(headers.try_index(ts!(host)), None);
+    assert_eq!(headers.try_index(ts!(accept)), None);
+    assert_eq!(headers.try_index_mut(ts!(host)), None);
+
+    headers.map.insert("host", "example.com".into());
+    assert_eq!(
+        get_string(&headers, ts!(host)).as_deref(),
+        Some("example.com")
+    );
+    assert_eq!(get_string(&headers, ts!(accept)), None);
+
+    headers
+        .try_index_mut(ts!(host))
+        .unwrap()
+        .make_ascii_uppercase();
+    assert_eq!(headers.try_index(ts!(host)), Some("EXAMPLE.COM"));
+}
+
+#[cfg(feature = "for_examples")]
+#[test]
+fn blanket_impl() {
+    let mut foo = Foo::new(3, 5, "8");
+    let bar = Bar::new(13, false, Some('c'));
+
+    assert_eq!(foo.try_index(ts!(bar)), Some(&3));
+    assert_eq!(foo.try_index(ts!(baz)), Some(&5));
+    assert_eq!(get_string(&foo, ts!(qux)).as_deref(), Some("8"));
+    assert_eq!(get_string(&bar, ts!(baz)).as_deref(), Some("false"));
+
+    *foo.try_index_mut(ts!(bar)).unwrap() += 100;
+    assert_eq!(foo.try_index(ts!(bar)), Some(&103));
+}
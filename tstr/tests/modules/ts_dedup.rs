@@ -0,0 +1,30 @@
+use tstr::{ts, ts_dedup, TS_dedup, TS};
+
+#[test]
+fn dedup_types() {
+    let _: TS_dedup!() = <TS!()>::default();
+    let _: TS_dedup!(a) = <TS!(a)>::default();
+    let _: TS_dedup!(a, a) = <TS!(a)>::default();
+    let _: TS_dedup!(a, b) = <TS!(a, b)>::default();
+    let _: TS_dedup!(b, a, b, c, a,) = <TS!(b, a, c)>::default();
+    let _: TS_dedup!("", "", x) = <TS!("", x)>::default();
+}
+
+#[test]
+fn dedup_equivalent_args() {
+    let _: TS_dedup!(foo, "foo", r"foo", concat!(f, oo)) = <TS!(foo)>::default();
+    let _: TS_dedup!(100, 0x64, 0b1100100, "100") = <TS!(100)>::default();
+    let _: TS_dedup!(Vec<u8>, "Vec<u8>", Vec<u8>) = <TS!(Vec<u8>)>::default();
+}
+
+#[test]
+fn dedup_paths() {
+    let _: TS_dedup!(a.b, a.b, a, b) = <TS!(a.b, a, b)>::default();
+    let _: TS_dedup!(a.b, b.a, a.b.c) = <TS!(a.b, b.a, a.b.c)>::default();
+}
+
+#[test]
+fn dedup_values() {
+    assert_eq!(ts_dedup!(foo, bar, foo), ts!(foo, bar));
+    assert_eq!(ts_dedup!(foo, foo), ts!(foo));
+}
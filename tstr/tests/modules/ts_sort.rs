@@ -0,0 +1,38 @@
+use tstr::{ts, ts_sort, TS_sort, TS};
+
+#[test]
+fn sort_types() {
+    let _: TS_sort!() = <TS!()>::default();
+    let _: TS_sort!(a) = <TS!(a)>::default();
+    let _: TS_sort!(b, a) = <TS!(a, b)>::default();
+    let _: TS_sort!(c, a, b) = <TS!(a, b, c)>::default();
+    let _: TS_sort!(b, c, a,) = <TS!(a, b, c)>::default();
+
+    // duplicates are kept
+    let _: TS_sort!(a, b, a) = <TS!(a, a, b)>::default();
+}
+
+#[test]
+fn sort_lexicographic() {
+    // prefixes are less than the strings that they're a prefix of
+    let _: TS_sort!(ab, a, "") = <TS!("", a, ab)>::default();
+
+    // shorter strings can be greater than longer strings
+    let _: TS_sort!(foo, aaaaaaaaaaaa) = <TS!(aaaaaaaaaaaa, foo)>::default();
+
+    let _: TS_sort!(a, B) = <TS!(B, a)>::default();
+    let _: TS_sort!("ü", "é", z) = <TS!(z, "é", "ü")>::default();
+    let _: TS_sort!(9, 10, 0x0) = <TS!(0, 10, 9)>::default();
+}
+
+#[test]
+fn sort_argument_kinds() {
+    let _: TS_sort!(concat!(b, 1), r"b0", stringify!(a)) = <TS!(a, b0, b1)>::default();
+    let _: TS_sort!(Vec<u8>, Option<u8>) = <TS!("Option<u8>", "Vec<u8>")>::default();
+}
+
+#[test]
+fn sort_values() {
+    assert_eq!(ts_sort!(qux, bar, foo), ts!(bar, foo, qux));
+    assert_eq!(ts_sort!(foo), ts!(foo));
+}
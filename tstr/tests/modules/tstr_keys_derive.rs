@@ -0,0 +1,163 @@
+use std::fmt::Debug;
+
+use tstr::tlist::{TList, TNil};
+use tstr::{ts, FromTStr, TStrKeys, ToTStr, VariantVisitor, TS};
+
+#[derive(TStrKeys)]
+enum Empty {}
+
+#[derive(Debug, PartialEq, TStrKeys)]
+pub enum State {
+    /// Waiting for work
+    Idle,
+    WaitingForInput,
+    Running {
+        progress: u32,
+    },
+    Failed(String),
+}
+
+#[derive(Debug, PartialEq, TStrKeys)]
+#[allow(dead_code)]
+enum Generic<'a, T: Clone, U = Vec<u8>>
+where
+    U: Debug,
+{
+    Reference(&'a str),
+    Value(T),
+    List(U),
+    Nothing,
+}
+
+#[derive(Debug, PartialEq, TStrKeys)]
+#[allow(dead_code)]
+#[repr(u8)]
+enum Discriminants {
+    A = 3,
+    B = 5 + 8,
+    HTTPRequest,
+}
+
+#[derive(Debug, PartialEq, TStrKeys)]
+#[allow(non_camel_case_types)]
+enum RawIdents {
+    r#type,
+    r#match,
+}
+
+mod renamed {
+    pub use tstr as other_tstr;
+}
+
+#[derive(Debug, PartialEq, TStrKeys)]
+#[tstr(crate = renamed::other_tstr)]
+enum Renamed {
+    Foo,
+}
+
+#[derive(Debug, PartialEq, TStrKeys)]
+enum LongNames {
+    AVeryLongVariantNameThatIsUsedToTestThatLongNamesAreHandledByTheDeriveMacrosOfTheTstrCrateAgain,
+    Short,
+}
+
+fn assert_keys<T, L>()
+where
+    T: TStrKeys<Keys = L>,
+{
+}
+
+#[test]
+fn variant_names() {
+    assert_eq!(Empty::VARIANT_NAMES, [""; 0]);
+    assert_eq!(
+        State::VARIANT_NAMES,
+        ["Idle", "WaitingForInput", "Running", "Failed"]
+    );
+    assert_eq!(
+        <Generic<'_, u8>>::VARIANT_NAMES,
+        ["Reference", "Value", "List", "Nothing"]
+    );
+    assert_eq!(Discriminants::VARIANT_NAMES, ["A", "B", "HTTPRequest"]);
+    assert_eq!(RawIdents::VARIANT_NAMES, ["type", "match"]);
+    assert_eq!(Renamed::VARIANT_NAMES, ["Foo"]);
+}
+
+#[test]
+fn variant_name() {
+    assert_eq!(State::Idle.variant_name(), "Idle");
+    assert_eq!(State::WaitingForInput.variant_name(), "WaitingForInput");
+    assert_eq!(State::Running { progress: 3 }.variant_name(), "Running");
+    assert_eq!(State::Failed("oops".into()).variant_name(), "Failed");
+
+    assert_eq!(Generic::<u8>::Value(3).variant_name(), "Value");
+    assert_eq!(Generic::<u8>::Reference("foo").variant_name(), "Reference");
+    assert_eq!(Discriminants::B.variant_name(), "B");
+    assert_eq!(RawIdents::r#match.variant_name(), "match");
+}
+
+#[test]
+fn keys_list() {
+    assert_keys::<Empty, TNil>();
+    assert_keys::<
+        State,
+        TList<
+            TS!(Idle),
+            TList<TS!(WaitingForInput), TList<TS!(Running), TList<TS!(Failed), TNil>>>,
+        >,
+    >();
+    assert_keys::<RawIdents, TList<TS!("type"), TList<TS!("match"), TNil>>>();
+    assert_keys::<Renamed, TList<TS!(Foo), TNil>>();
+}
+
+#[test]
+fn from_tstr() {
+    assert_eq!(State::from_tstr(ts!(Idle)), State::Idle);
+    assert_eq!(
+        State::from_tstr(ts!(WaitingForInput)),
+        State::WaitingForInput
+    );
+    assert_eq!(Generic::<u8>::from_tstr(ts!(Nothing)), Generic::Nothing);
+    assert_eq!(
+        Discriminants::from_tstr(ts!(HTTPRequest)),
+        Discriminants::HTTPRequest
+    );
+    assert_eq!(RawIdents::from_tstr(ts!("type")), RawIdents::r#type);
+    assert_eq!(Renamed::from_tstr(ts!(Foo)), Renamed::Foo);
+}
+
+#[test]
+fn key_consts() {
+    let _: TS!(Idle) = State::IDLE_KEY;
+    let _: TS!(WaitingForInput) = State::WAITING_FOR_INPUT_KEY;
+    let _: TS!(Running) = State::RUNNING_KEY;
+    let _: TS!(Failed) = State::FAILED_KEY;
+    let _: TS!(Nothing) = Generic::<u8>::NOTHING_KEY;
+    let _: TS!(HTTPRequest) = Discriminants::HTTP_REQUEST_KEY;
+    let _: TS!("type") = RawIdents::TYPE_KEY;
+    let _: TS!(AVeryLongVariantNameThatIsUsedToTestThatLongNamesAreHandledByTheDeriveMacrosOfTheTstrCrateAgain) = LongNames::A_VERY_LONG_VARIANT_NAME_THAT_IS_USED_TO_TEST_THAT_LONG_NAMES_ARE_HANDLED_BY_THE_DERIVE_MACROS_OF_THE_TSTR_CRATE_AGAIN_KEY;
+    assert_eq!(LongNames::from_tstr(LongNames::SHORT_KEY), LongNames::Short);
+    assert_eq!(
+        LongNames::from_tstr(ts!(AVeryLongVariantNameThatIsUsedToTestThatLongNamesAreHandledByTheDeriveMacrosOfTheTstrCrateAgain)),
+        LongNames::AVeryLongVariantNameThatIsUsedToTestThatLongNamesAreHandledByTheDeriveMacrosOfTheTstrCrateAgain
+    );
+}
+
+struct Recorder(Vec<&'static str>);
+
+impl<K> VariantVisitor<K> for Recorder {
+    fn visit(&mut self, _: K, name_str: &'static str) {
+        self.0.push(name_str);
+    }
+}
+
+#[test]
+fn to_tstr() {
+    let mut recorder = Recorder(Vec::new());
+    State::Running { progress: 3 }.to_tstr(&mut recorder);
+    State::Idle.to_tstr(&mut recorder);
+    Generic::<u8>::List(vec![3]).to_tstr(&mut recorder);
+    RawIdents::r#type.to_tstr(&mut recorder);
+
+    assert_eq!(recorder.0, ["Running", "Idle", "List", "type"]);
+}
@@ -0,0 +1,76 @@
+use std::ops::{Index, IndexMut};
+
+use tstr::{ts, ToUint, TS};
+
+// Gets the element at the `K` index, along with `K` as an integer
+fn get_with_index<T, K>(this: &T, index: K) -> (&T::Output, usize)
+where
+    T: Index<K>,
+    K: ToUint,
+{
+    (&this[index], K::USIZE)
+}
+
+#[test]
+fn index_small_tuples() {
+    let single = ('a',);
+    assert_eq!(single[ts!(0)], 'a');
+
+    let pair = (3u8, "hello");
+    assert_eq!(pair[ts!(0)], 3);
+    assert_eq!(pair[ts!(1)], "hello");
+    assert_eq!(get_with_index(&pair, ts!(1)), (&"hello", 1));
+
+    let triple = (false, 5u16, 'c');
+    assert_eq!(get_with_index(&triple, ts!(0)), (&false, 0));
+    assert_eq!(get_with_index(&triple, ts!(1)), (&5, 1));
+    assert_eq!(get_with_index(&triple, ts!(2)), (&'c', 2));
+}
+
+#[test]
+fn index_largest_tuple() {
+    let mut tuple = (
+        0u8, 1u16, 2u32, 3u64, 4u128, 5usize, 6i8, 7i16, 8i32, 9i64, 10i128, "11",
+    );
+
+    assert_eq!(tuple[ts!(0)], 0);
+    assert_eq!(tuple[ts!(5)], 5);
+    assert_eq!(tuple[ts!(9)], 9);
+    assert_eq!(tuple[ts!(10)], 10);
+    assert_eq!(tuple[ts!(11)], "11");
+
+    tuple[ts!(10)] += 100;
+    tuple[ts!(11)] = "eleven";
+    assert_eq!(get_with_index(&tuple, ts!(10)), (&110, 10));
+    assert_eq!(get_with_index(&tuple, ts!(11)), (&"eleven", 11));
+}
+
+#[test]
+fn index_mut_generic() {
+    fn replace<T, K>(this: &mut T, index: K, value: T::Output) -> T::Output
+    where
+        T: IndexMut<K>,
+        T::Output: Sized,
+    {
+        std::mem::replace(&mut this[index], value)
+    }
+
+    let mut tuple = (3, String::from("foo"), 'x');
+
+    assert_eq!(replace(&mut tuple, ts!(1), "bar".into()), "foo");
+    assert_eq!(replace(&mut tuple, ts!(2), 'y'), 'x');
+    assert_eq!(tuple, (3, String::from("bar"), 'y'));
+}
+
+#[test]
+fn index_bound() {
+    fn first_two<T>(this: &T) -> (u32, u32)
+    where
+        T: Index<TS!(0), Output = u32> + Index<TS!(1), Output = u32>,
+    {
+        (this[ts!(0)], this[ts!(1)])
+    }
+
+    assert_eq!(first_two(&(3, 5)), (3, 5));
+    assert_eq!(first_two(&(8, 13, "hello", 'c')), (8, 13));
+}
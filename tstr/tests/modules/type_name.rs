@@ -0,0 +1,30 @@
+use tstr::{ts, ts_type_name, TS_type_name, TS};
+
+#[allow(dead_code)]
+struct Foo<T>(T);
+
+mod nested {
+    pub mod deeper {
+        #[allow(dead_code)]
+        pub struct Bar;
+    }
+}
+
+const _: TS!(Foo) = ts_type_name!(Foo);
+const _: TS!(Foo) = ts_type_name!(Foo<u8>);
+const _: TS!(Foo) = ts_type_name!(Foo::<u8>);
+const _: TS!(Foo) = ts_type_name!(Foo<Foo<u8>>);
+const _: TS!(Foo) = ts_type_name!(self::Foo<Foo<Foo<u8>>>);
+const _: TS!(Bar) = ts_type_name!(nested::deeper::Bar);
+const _: TS!(Bar) = ts_type_name!(crate::modules::type_name::nested::deeper::Bar);
+const _: TS!(Vec) = ts_type_name!(::std::vec::Vec<(u8, &'static str)>);
+const _: TS!(HashMap) = ts_type_name!(std::collections::HashMap<u8, u16>);
+const _: TS!(u8) = ts_type_name!(u8);
+
+#[test]
+fn type_name_types() {
+    let _: TS_type_name!(Foo<u32>) = ts!(Foo);
+    let _: TS_type_name!(nested::deeper::Bar) = ts!(Bar);
+    let _: TS_type_name!(::std::string::String) = ts!(String);
+    let _: TS_type_name!(Option::<Result<u8, ()>>) = ts!(Option);
+}
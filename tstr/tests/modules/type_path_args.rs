@@ -0,0 +1,47 @@
+use tstr::{ts, TS};
+
+#[test]
+fn type_paths() {
+    let _: TS!("Vec<u8>") = ts!(Vec<u8>);
+    let _: TS!("std::string::String") = ts!(std::string::String);
+    let _: TS!("::std::string::String") = ts!(::std::string::String);
+    let _: TS!("Option::<u8>") = ts!(Option::<u8>);
+    let _: TS!("HashMap<String, Vec<u8>>") = ts!(HashMap<String, Vec<u8>>);
+    let _: TS!("Foo<'a, T>") = ts!(Foo<'a, T>);
+    let _: TS!("Foo<&'static str, &mut [u8], *const T>") =
+        ts!(Foo<&'static str, &mut [u8], *const T>);
+    let _: TS!("Foo<(u8, u16), [u8; 4], ()>") = ts!(Foo<(u8, u16), [u8; 4], ()>);
+    let _: TS!("Box<dyn Fn(u8) -> u8 + Send>") = ts!(Box<dyn Fn(u8) -> u8 + Send>);
+    let _: TS!("Foo<Item = u8>") = ts!(Foo<Item = u8>);
+    let _: TS!("PhantomData<<T as Trait>::Assoc>") = ts!(PhantomData<<T as Trait>::Assoc>);
+}
+
+#[test]
+#[rustfmt::skip]
+fn type_path_spacing() {
+    let _: TS!(HashMap<String, Vec<u8>>) = ts!(HashMap<String,Vec<u8>>);
+    let _: TS!(HashMap<String, Vec<u8>>) = ts!(HashMap < String , Vec < u8 > >);
+    let _: TS!(std::string::String) = ts!(std :: string :: String);
+    let _: TS!(Foo<&'a mut [u8]>) = ts!(Foo<&'a mut[u8]>);
+    let _: TS!(Box<dyn Fn(u8) -> u8>) = ts!(Box<dyn Fn(u8)->u8>);
+}
+
+#[test]
+fn type_path_lists() {
+    let _: TS!("Vec<u8>", foo) = ts!(Vec<u8>, foo);
+    let _: TS!("HashMap<K, V>", "std::string::String") = ts!(HashMap<K, V>, std::string::String);
+    let _: TS!(foo, "Option<u8>", 0) = ts!(foo, Option<u8>, 0,);
+}
+
+macro_rules! type_path_fragments {
+    ($ty:ty, $path:path) => {
+        let _: TS!("Vec<u8>") = ts!($ty);
+        let _: TS!("std::string::String") = ts!($path);
+        let _: TS!("Vec<u8>", "std::string::String") = ts!($ty, $path);
+    };
+}
+
+#[test]
+fn type_path_macro_fragments() {
+    type_path_fragments! {Vec<u8>, std::string::String}
+}
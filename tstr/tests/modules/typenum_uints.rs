@@ -0,0 +1,84 @@
+use tstr::{
+    typenum_uints::{FromTypenum, TStrOfTypenum, ToTypenum, TypenumOf},
+    ToUint, TS,
+};
+
+use typenum::{Prod, Sum, Unsigned, U0, U1, U10, U100, U1000, U1024, U255, U256, U7, U9};
+
+fn assert_typenum<S, N>()
+where
+    S: ToTypenum<Output = N> + ToUint,
+    N: Unsigned,
+{
+    assert_eq!(S::USIZE, N::USIZE);
+}
+
+#[test]
+fn to_typenum() {
+    assert_typenum::<TS!(0), U0>();
+    assert_typenum::<TS!(1), U1>();
+    assert_typenum::<TS!(7), U7>();
+    assert_typenum::<TS!(9), U9>();
+    assert_typenum::<TS!(10), U10>();
+    assert_typenum::<TS!(100), U100>();
+    assert_typenum::<TS!(255), U255>();
+    assert_typenum::<TS!(256), U256>();
+    assert_typenum::<TS!(1000), U1000>();
+    assert_typenum::<TS!(1024), U1024>();
+    assert_typenum::<TS!(1000000), typenum::U1000000>();
+    assert_typenum::<TS!(65535), typenum::U65535>();
+
+    assert_typenum::<TS!("007"), U7>();
+    assert_typenum::<TS!("0000"), U0>();
+}
+
+fn assert_tstr<N, S>()
+where
+    N: FromTypenum<Output = S>,
+{
+}
+
+#[test]
+fn from_typenum() {
+    assert_tstr::<U0, TS!(0)>();
+    assert_tstr::<U1, TS!(1)>();
+    assert_tstr::<U9, TS!(9)>();
+    assert_tstr::<U10, TS!(10)>();
+    assert_tstr::<U100, TS!(100)>();
+    assert_tstr::<U255, TS!(255)>();
+    assert_tstr::<U1024, TS!(1024)>();
+    assert_tstr::<typenum::U1000000, TS!(1000000)>();
+    assert_tstr::<typenum::U65535, TS!(65535)>();
+}
+
+#[test]
+fn round_trip() {
+    let _: TS!(0) = <TStrOfTypenum<TypenumOf<TS!("000")>>>::NEW;
+    let _: TS!(42) = <TStrOfTypenum<TypenumOf<TS!("0042")>>>::NEW;
+    let _: TS!(513) = <TStrOfTypenum<TypenumOf<TS!(513)>>>::NEW;
+
+    let _: TS!(1034) = <TStrOfTypenum<Sum<TypenumOf<TS!(1024)>, U10>>>::NEW;
+    let _: TS!(2560) = <TStrOfTypenum<Prod<TypenumOf<TS!(256)>, U10>>>::NEW;
+}
+
+#[cfg(not(feature = "min_const_generics"))]
+#[test]
+fn byte_len() {
+    use tstr::typenum_uints::{ByteLen, ByteLenOf};
+
+    fn assert_len<S, N>()
+    where
+        S: ByteLen<Output = N>,
+        N: Unsigned,
+    {
+    }
+
+    assert_len::<TS!(""), U0>();
+    assert_len::<TS!(a), U1>();
+    assert_len::<TS!(abcdefg), U7>();
+    assert_len::<TS!("ñ"), typenum::U2>();
+    assert_len::<TS!("ñandú"), typenum::U7>();
+
+    assert_eq!(<ByteLenOf<TS!("0123456789abcdef")>>::USIZE, 16);
+    assert_eq!(<ByteLenOf<TS!("çç")>>::USIZE, 4);
+}
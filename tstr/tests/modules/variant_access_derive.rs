@@ -0,0 +1,183 @@
+use std::fmt::Debug;
+
+use tstr::{ts, IntoVariant, VariantAccess, TS};
+
+#[derive(VariantAccess)]
+enum Empty {}
+
+#[derive(Debug, PartialEq, VariantAccess)]
+enum Message {
+    /// Exits
+    Quit,
+    Move {
+        x: i32,
+        #[allow(dead_code)]
+        y: i32,
+    },
+    Write(String),
+    Color(u8, u8, u8),
+    Resize {
+        size: (u32, u32),
+    },
+    Nothing {},
+    Unit(),
+}
+
+#[derive(Debug, PartialEq, VariantAccess)]
+enum Generic<'a, T: Clone, U = Vec<u8>>
+where
+    U: Debug,
+{
+    Reference(&'a str),
+    Value(T),
+    List(U),
+    Pair(T, Option<U>),
+}
+
+#[derive(Debug, PartialEq, VariantAccess)]
+enum Single {
+    Only(Box<Single>),
+    End,
+}
+
+#[derive(Debug, PartialEq, VariantAccess)]
+#[allow(non_camel_case_types)]
+enum RawIdents {
+    r#type(u32),
+    r#match { r#ref: u32 },
+}
+
+mod renamed {
+    pub use tstr as other_tstr;
+}
+
+#[derive(Debug, PartialEq, VariantAccess)]
+#[tstr(crate = renamed::other_tstr)]
+enum Renamed {
+    Foo(u8),
+}
+
+fn assert_payload<T, K, P>()
+where
+    T: IntoVariant<K, Payload = P>,
+{
+}
+
+#[test]
+fn payload_types() {
+    let _ = |x: Empty| -> u8 { match x {} };
+
+    assert_payload::<Message, TS!(Quit), ()>();
+    assert_payload::<Message, TS!(Move), (i32, i32)>();
+    assert_payload::<Message, TS!(Write), String>();
+    assert_payload::<Message, TS!(Color), (u8, u8, u8)>();
+    assert_payload::<Message, TS!(Resize), (u32, u32)>();
+    assert_payload::<Message, TS!(Nothing), ()>();
+    assert_payload::<Message, TS!(Unit), ()>();
+
+    assert_payload::<Generic<'static, u16>, TS!(Reference), &'static str>();
+    assert_payload::<Generic<'static, u16>, TS!(List), Vec<u8>>();
+    assert_payload::<Generic<'static, u16, String>, TS!(Pair), (u16, Option<String>)>();
+
+    assert_payload::<RawIdents, TS!("match"), u32>();
+}
+
+#[test]
+fn into_variant() {
+    assert_eq!(Message::Quit.into_variant(ts!(Quit)), Some(()));
+    assert_eq!(Message::Quit.into_variant(ts!(Write)), None);
+    assert_eq!(
+        Message::Move { x: 3, y: 5 }.into_variant(ts!(Move)),
+        Some((3, 5))
+    );
+    assert_eq!(
+        Message::Write("hello".into()).into_variant(ts!(Write)),
+        Some("hello".to_string())
+    );
+    assert_eq!(
+        Message::Color(3, 5, 8).into_variant(ts!(Color)),
+        Some((3, 5, 8))
+    );
+    assert_eq!(Message::Color(3, 5, 8).into_variant(ts!(Move)), None);
+    assert_eq!(Message::Nothing {}.into_variant(ts!(Nothing)), Some(()));
+    assert_eq!(Message::Unit().into_variant(ts!(Unit)), Some(()));
+
+    assert_eq!(
+        Generic::<u8>::Pair(3, None).into_variant(ts!(Pair)),
+        Some((3, None))
+    );
+    assert_eq!(Generic::<u8>::Value(3).into_variant(ts!(Value)), Some(3));
+    assert_eq!(Generic::<u8>::Value(3).into_variant(ts!(List)), None);
+
+    assert_eq!(
+        Single::Only(Box::new(Single::End)).into_variant(ts!(Only)),
+        Some(Box::new(Single::End))
+    );
+    assert_eq!(RawIdents::r#type(3).into_variant(ts!("type")), Some(3));
+    assert_eq!(RawIdents::r#type(3).into_variant(ts!("match")), None);
+    assert_eq!(Renamed::Foo(5).into_variant(ts!(Foo)), Some(5));
+}
+
+#[test]
+fn variant_ref() {
+    let resize = Message::Resize { size: (3, 5) };
+    assert_eq!(resize.variant(ts!(Resize)), Some(&(3, 5)));
+    assert_eq!(resize.variant(ts!(Write)), None);
+
+    let write = Message::Write("hello".into());
+    assert_eq!(write.variant(ts!(Write)).map(|s| &**s), Some("hello"));
+    assert_eq!(write.variant(ts!(Resize)), None);
+
+    let reference = Generic::<u8>::Reference("foo");
+    assert_eq!(reference.variant(ts!(Reference)), Some(&"foo"));
+    assert_eq!(reference.variant(ts!(Value)), None);
+
+    let raw = RawIdents::r#match { r#ref: 8 };
+    assert_eq!(raw.variant(ts!("match")), Some(&8));
+    assert_eq!(raw.variant(ts!("type")), None);
+
+    assert_eq!(Renamed::Foo(13).variant(ts!(Foo)), Some(&13));
+}
+
+#[test]
+fn variant_mut() {
+    let mut message = Message::Move { x: 3, y: 5 };
+    assert_eq!(message.variant_mut(ts!(Write)), None);
+
+    message = Message::Write("hello".into());
+    message.variant_mut(ts!(Write)).unwrap().push_str(" world");
+    assert_eq!(message, Message::Write("hello world".into()));
+
+    let mut list = Generic::<u8, Vec<u8>>::List(vec![3]);
+    list.variant_mut(ts!(List)).unwrap().push(5);
+    assert_eq!(list.variant_mut(ts!(Value)), None);
+    assert_eq!(list, Generic::List(vec![3, 5]));
+
+    let mut single = Single::Only(Box::new(Single::End));
+    **single.variant_mut(ts!(Only)).unwrap() = Single::Only(Box::new(Single::End));
+    assert_eq!(
+        single
+            .variant(ts!(Only))
+            .and_then(|inner| inner.variant(ts!(Only))),
+        Some(&Box::new(Single::End))
+    );
+}
+
+fn get_or<T, K>(this: &T, key: K, default: T::Payload) -> T::Payload
+where
+    T: VariantAccess<K>,
+    T::Payload: Clone,
+{
+    this.variant(key).cloned().unwrap_or(default)
+}
+
+#[test]
+fn generic_access() {
+    assert_eq!(
+        get_or(&Message::Write("foo".into()), ts!(Write), String::new()),
+        "foo"
+    );
+    assert_eq!(get_or(&Message::Quit, ts!(Write), "bar".into()), "bar");
+    assert_eq!(get_or(&Generic::<u8>::Value(3), ts!(Value), 5), 3);
+    assert_eq!(get_or(&Generic::<u8>::List(vec![]), ts!(Value), 5), 5);
+}
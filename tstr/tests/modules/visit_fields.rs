@@ -0,0 +1,122 @@
+use std::fmt::Debug;
+
+use tstr::{FieldVisitor, VisitFields};
+
+#[derive(VisitFields)]
+struct Empty {}
+
+#[derive(VisitFields)]
+pub struct Point {
+    pub x: u32,
+    pub(crate) y: u32,
+}
+
+#[derive(VisitFields)]
+struct Generic<'a, T: Clone, U = Vec<u8>>
+where
+    U: Debug,
+{
+    reference: &'a str,
+    value: T,
+    list: U,
+}
+
+#[derive(VisitFields)]
+struct RawIdents {
+    r#type: u8,
+    r#match: bool,
+}
+
+mod renamed {
+    pub use tstr as other_tstr;
+}
+
+#[derive(VisitFields)]
+#[tstr(crate = renamed::other_tstr)]
+struct Renamed {
+    foo: char,
+}
+
+/// Records the name and debug output of every field.
+#[derive(Default)]
+struct Recorder {
+    fields: Vec<(&'static str, String)>,
+}
+
+impl<K, T> FieldVisitor<K, T> for Recorder
+where
+    T: ?Sized + Debug,
+{
+    fn visit(&mut self, _: K, name_str: &'static str, value: &T) {
+        self.fields.push((name_str, format!("{:?}", value)));
+    }
+}
+
+fn record_fields<T>(this: &T) -> Vec<(&'static str, String)>
+where
+    T: VisitFields<Recorder>,
+{
+    let mut recorder = Recorder::default();
+    this.visit_fields(&mut recorder);
+    recorder.fields
+}
+
+#[test]
+fn visit_fields_names_and_values() {
+    assert_eq!(record_fields(&Empty {}), []);
+
+    assert_eq!(
+        record_fields(&Point { x: 3, y: 5 }),
+        [("x", "3".to_string()), ("y", "5".to_string())]
+    );
+
+    assert_eq!(
+        record_fields(&Generic {
+            reference: "hello",
+            value: 'c',
+            list: vec![3u8],
+        }),
+        [
+            ("reference", r#""hello""#.to_string()),
+            ("value", "'c'".to_string()),
+            ("list", "[3]".to_string()),
+        ]
+    );
+
+    assert_eq!(
+        record_fields(&RawIdents {
+            r#type: 8,
+            r#match: true
+        }),
+        [("type", "8".to_string()), ("match", "true".to_string())]
+    );
+
+    assert_eq!(
+        record_fields(&Renamed { foo: 'a' }),
+        [("foo", "'a'".to_string())]
+    );
+}
+
+#[cfg(feature = "cmp_traits")]
+#[test]
+fn visit_fields_names_as_tstrs() {
+    use tstr::{TStrEq, TS};
+
+    /// Sums the `u32` fields, except for the `y` field.
+    struct SumExceptY(u32);
+
+    impl<K> FieldVisitor<K, u32> for SumExceptY
+    where
+        K: TStrEq<TS!(y)>,
+    {
+        fn visit(&mut self, _: K, _: &'static str, value: &u32) {
+            if !K::EQ {
+                self.0 += *value;
+            }
+        }
+    }
+
+    let mut sum = SumExceptY(0);
+    Point { x: 3, y: 5 }.visit_fields(&mut sum);
+    assert_eq!(sum.0, 3);
+}
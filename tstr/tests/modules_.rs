@@ -4,10 +4,93 @@ mod modules {
 
     mod alias_and_tuples;
 
+    #[cfg(feature = "alloc")]
+    mod alloc_impls;
+
+    #[cfg(feature = "array_indexing")]
+    mod array_indexing;
+
+    #[cfg(any(
+        not(feature = "min_const_generics"),
+        feature = "nightly_generic_const_exprs"
+    ))]
+    mod case_conversion;
+
+    #[cfg(not(feature = "const_generics"))]
+    mod char_list;
+
+    mod chars_macros;
+
+    mod columns_derive;
+
     mod concat_args;
 
+    #[cfg(feature = "const_generics")]
+    mod const_args;
+
+    mod const_construction;
+
+    mod contains_fields;
+
+    mod declare_fields;
+
+    #[cfg(feature = "dual_repr")]
+    mod dual_repr;
+
+    #[cfg(feature = "const_generics")]
+    mod erased_tstr;
+
+    mod field;
+
+    mod fields_derive;
+
+    #[cfg(all(feature = "defmt", feature = "ufmt"))]
+    mod fmt_impls;
+
+    mod format_args;
+
+    #[cfg(feature = "const_generics")]
+    mod from_uint;
+
+    #[cfg(feature = "frunk")]
+    mod frunk_fields;
+
+    #[cfg(all(feature = "generic_array", not(feature = "min_const_generics")))]
+    mod generic_array;
+
+    mod impl_index;
+
+    mod include_lines;
+
+    #[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+    mod into_record;
+
+    mod is_ascii;
+
+    mod lens;
+
+    #[cfg(any(feature = "rust_1_88", feature = "const_generics"))]
+    mod location;
+
     mod long_strings;
 
+    mod naming_conventions;
+
+    #[cfg(any(
+        not(feature = "const_generics"),
+        feature = "nightly_generic_const_exprs"
+    ))]
+    mod ops;
+
+    #[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+    mod maps;
+
+    #[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+    mod sort_tstrs;
+
+    #[cfg(feature = "stable_repr")]
+    mod stable_repr;
+
     mod string_args;
 
     #[cfg(feature = "cmp_traits")]
@@ -15,5 +98,51 @@ mod modules {
 
     mod other_args;
 
+    mod paths;
+
+    mod proj;
+
+    #[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+    mod records;
+
+    #[cfg(feature = "reprs")]
+    mod reprs;
+
+    #[cfg(feature = "serde")]
+    mod serde_fields;
+
+    #[cfg(all(feature = "serde_rename", feature = "serde"))]
+    mod serde_rename;
+
+    #[cfg(all(feature = "cmp_traits", not(feature = "min_const_generics")))]
+    mod sets;
+
+    mod testing_macros;
+
     mod to_uint;
+
+    #[cfg(feature = "min_const_generics")]
+    mod to_uint_radix;
+
+    mod try_index;
+
+    mod ts_dedup;
+
+    mod ts_sort;
+
+    mod tstr_keys_derive;
+
+    #[cfg(feature = "tuple_indexing")]
+    mod tuple_indexing;
+
+    mod type_name;
+
+    mod type_path_args;
+
+    #[cfg(all(feature = "typenum", not(feature = "const_generics")))]
+    mod typenum_uints;
+
+    mod variant_access_derive;
+
+    mod visit_fields;
 }
@@ -0,0 +1,305 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// Generates a module of `TStr` aliases from a file with a list of keys,
+/// for use in build scripts.
+///
+/// The generated file is written to `out_dir`, with the name of the keys file
+/// and the `.rs` extension (eg: `permissions.csv` generates `permissions.rs`),
+/// and the path to it is returned.
+///
+/// This also prints `cargo:rerun-if-changed` for the keys file,
+/// so that the build script reruns when it changes.
+///
+/// # Formats
+///
+/// The format of the keys file is determined by its extension:
+///
+/// - `.json`: an array of strings.
+///
+/// - `.csv`: the first column of every row after the header row.
+///
+/// - any other extension: one key per line,
+///   skipping empty lines and lines that start with `#`.
+///
+/// Leading and trailing whitespace is removed from keys in every format other than JSON.
+///
+/// To generate aliases from other formats (eg: TOML),
+/// you can parse the keys yourself and pass them to [`aliases_module`].
+///
+/// # Errors
+///
+/// This returns an error if the keys file can't be read or parsed,
+/// if the generated file can't be written,
+/// or for the same reasons as [`aliases_module`].
+///
+/// # Example
+///
+/// This `build.rs` reads the keys in `permissions.txt`:
+///
+/// ```rust,no_run
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+/// tstr_codegen::generate_aliases("permissions.txt", &out_dir).unwrap();
+/// ```
+///
+/// which can then be included in the crate
+/// (for the items that this generates, look at [`aliases_module`]):
+///
+/// ```rust,ignore
+/// pub mod permissions {
+///     include!(concat!(env!("OUT_DIR"), "/permissions.rs"));
+/// }
+/// ```
+///
+/// [`aliases_module`]: ./fn.aliases_module.html
+pub fn generate_aliases<P, D>(keys_path: P, out_dir: D) -> io::Result<PathBuf>
+where
+    P: AsRef<Path>,
+    D: AsRef<Path>,
+{
+    let keys_path = keys_path.as_ref();
+    println!("cargo:rerun-if-changed={}", keys_path.display());
+
+    let contents = fs::read_to_string(keys_path)?;
+
+    let extension = keys_path.extension().and_then(|x| x.to_str());
+    let keys = match extension {
+        Some("json") => parse_json_keys(&contents)?,
+        Some("csv") => parse_csv_keys(&contents),
+        _ => parse_line_keys(&contents),
+    };
+
+    let module = aliases_module(&keys)?;
+
+    let file_stem = keys_path
+        .file_stem()
+        .ok_or_else(|| invalid_data(format!("`{}` has no file name", keys_path.display())))?;
+
+    let mut out_path = out_dir.as_ref().join(file_stem);
+    out_path.set_extension("rs");
+    fs::write(&out_path, module)?;
+
+    Ok(out_path)
+}
+
+/// Generates the source code of a module of `TStr` aliases, one for each key.
+///
+/// For each key, this generates a type alias with the key in `UpperCamelCase`,
+/// and a constant with the key in `SCREAMING_SNAKE_CASE`,
+/// with words separated by non-alphanumeric characters and lowercase-to-uppercase transitions.
+///
+/// The generated code refers to tstr as `::tstr`.
+///
+/// # Errors
+///
+/// This returns an error if a key has no alphanumeric characters,
+/// or if two keys have the same alias (eg: `user_id` and `userId`).
+///
+/// # Example
+///
+/// ```rust
+/// let module = tstr_codegen::aliases_module(&["user_id", "display-name"]).unwrap();
+///
+/// assert_eq!(
+///     module,
+///     "\
+/// pub type UserId = ::tstr::TS!(\"user_id\");
+/// pub const USER_ID: UserId = <UserId>::NEW;
+///
+/// pub type DisplayName = ::tstr::TS!(\"display-name\");
+/// pub const DISPLAY_NAME: DisplayName = <DisplayName>::NEW;
+/// ",
+/// );
+///
+/// ```
+pub fn aliases_module<S>(keys: &[S]) -> io::Result<String>
+where
+    S: AsRef<str>,
+{
+    let mut out = String::new();
+    let mut aliases = BTreeSet::new();
+
+    for (i, key) in keys.iter().enumerate() {
+        let key = key.as_ref();
+        let words = split_words(key);
+        if words.is_empty() {
+            let msg = format!("the {:?} key has no alphanumeric characters", key);
+            return Err(invalid_data(msg));
+        }
+
+        let mut type_name = String::new();
+        let mut const_name = String::new();
+        if words[0].starts_with(|c: char| c.is_ascii_digit()) {
+            type_name.push('_');
+            const_name.push('_');
+        }
+        for (i, word) in words.iter().enumerate() {
+            let mut chars = word.chars();
+            type_name.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+            type_name.extend(chars.map(|c| c.to_ascii_lowercase()));
+
+            if i != 0 {
+                const_name.push('_');
+            }
+            const_name.push_str(&word.to_ascii_uppercase());
+        }
+
+        if !aliases.insert(type_name.clone()) {
+            let msg = format!("the {:?} key has the same alias as a previous key", key);
+            return Err(invalid_data(msg));
+        }
+
+        if i != 0 {
+            out.push('\n');
+        }
+        out.push_str(&format!(
+            "pub type {ty} = ::tstr::TS!({key:?});\n\
+             pub const {konst}: {ty} = <{ty}>::NEW;\n",
+            ty = type_name,
+            key = key,
+            konst = const_name,
+        ));
+    }
+
+    Ok(out)
+}
+
+/// Splits `key` into its ASCII alphanumeric words.
+fn split_words(key: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in key.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut start = 0;
+        let bytes = part.as_bytes();
+        for i in 1..bytes.len() {
+            if bytes[i - 1].is_ascii_lowercase() && bytes[i].is_ascii_uppercase() {
+                words.push(&part[start..i]);
+                start = i;
+            }
+        }
+        if start != part.len() {
+            words.push(&part[start..]);
+        }
+    }
+    words
+}
+
+fn parse_line_keys(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect()
+}
+
+fn parse_csv_keys(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split(',').next())
+        .map(|key| key.trim().trim_matches('"').trim())
+        .filter(|key| !key.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Parses a JSON array of strings.
+fn parse_json_keys(contents: &str) -> io::Result<Vec<String>> {
+    let mut chars = contents.trim().chars().peekable();
+    let mut keys = Vec::new();
+
+    let error = |msg: &str| invalid_data(format!("invalid JSON array of strings: {}", msg));
+
+    if chars.next() != Some('[') {
+        return Err(error("expected `[`"));
+    }
+
+    loop {
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            Some(']') if keys.is_empty() => break,
+            Some('"') => {
+                keys.push(parse_json_string(&mut chars).ok_or_else(|| error("invalid string"))?)
+            }
+            _ => return Err(error("expected a string")),
+        }
+
+        skip_whitespace(&mut chars);
+        match chars.next() {
+            Some(',') => {}
+            Some(']') => break,
+            _ => return Err(error("expected `,` or `]`")),
+        }
+    }
+
+    if chars.next().is_some() {
+        return Err(error("expected nothing after `]`"));
+    }
+
+    Ok(keys)
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+
+fn skip_whitespace(chars: &mut Chars<'_>) {
+    while let Some(c) = chars.peek() {
+        if !c.is_whitespace() {
+            break;
+        }
+        chars.next();
+    }
+}
+
+/// Parses a JSON string, after the opening `"`.
+fn parse_json_string(chars: &mut Chars<'_>) -> Option<String> {
+    let mut out = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                'u' => {
+                    let high = parse_hex4(chars)?;
+                    let code = if (0xD800..0xDC00).contains(&high) {
+                        if chars.next()? != '\\' || chars.next()? != 'u' {
+                            return None;
+                        }
+                        let low = parse_hex4(chars)?;
+                        if !(0xDC00..0xE000).contains(&low) {
+                            return None;
+                        }
+                        0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                    } else {
+                        high
+                    };
+                    out.push(std::char::from_u32(code)?);
+                }
+                c => out.push(match c {
+                    '"' | '\\' | '/' => c,
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    _ => return None,
+                }),
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+fn parse_hex4(chars: &mut Chars<'_>) -> Option<u32> {
+    let mut code = 0;
+    for _ in 0..4 {
+        code = code * 16 + chars.next()?.to_digit(16)?;
+    }
+    Some(code)
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, msg)
+}
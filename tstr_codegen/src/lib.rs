@@ -0,0 +1,117 @@
+//! For proc macros that generate [tstr] types and values.
+//!
+//! The representation of type-level strings in tstr is unstable,
+//! it changes depending on the release and the enabled cargo features.
+//! The functions in this crate generate invocations of tstr's own macros,
+//! so that the generated code always uses the representation that tstr was compiled with.
+//!
+//! The generated code requires the `"proc_macros"` feature of tstr,
+//! which is enabled by default.
+//!
+//! # Example
+//!
+//! ```rust
+//! use proc_macro2::{Span, TokenStream};
+//!
+//! let crate_path: TokenStream = "::tstr".parse().unwrap();
+//! let span = Span::call_site();
+//!
+//! let ty = tstr_codegen::tstr_type(&crate_path, "foo", span);
+//! assert_eq!(ty.to_string(), r#":: tstr :: TS ! ("foo")"#);
+//!
+//! let value = tstr_codegen::tstr_value(&crate_path, "bar", span);
+//! assert_eq!(value.to_string(), r#":: tstr :: ts ! ("bar")"#);
+//!
+//! let tuple = tstr_codegen::tstr_tuple_type(&crate_path, &["foo", "bar"], span);
+//! assert_eq!(tuple.to_string(), r#":: tstr :: TS ! ("foo" , "bar")"#);
+//!
+//! ```
+//!
+//! # Build scripts
+//!
+//! The [`generate_aliases`] function generates a module of `TStr` aliases and constants
+//! from a file with a list of keys (eg: the keys of a schema),
+//! and [`aliases_module`] generates the same module from keys parsed by the build script.
+//!
+//! [tstr]: https://docs.rs/tstr/
+//! [`generate_aliases`]: ./fn.generate_aliases.html
+//! [`aliases_module`]: ./fn.aliases_module.html
+#![forbid(unsafe_code)]
+
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use std::iter;
+
+mod aliases;
+
+#[cfg(test)]
+mod tests;
+
+pub use crate::aliases::{aliases_module, generate_aliases};
+
+/// Generates the type of a `TStr` for `string`,
+/// equivalent to `TS!("<string>")`.
+///
+/// `crate_path` is the path to the tstr crate, usually `::tstr`.
+pub fn tstr_type(crate_path: &TokenStream, string: &str, span: Span) -> TokenStream {
+    output_macro(crate_path, "TS", &[string], span)
+}
+
+/// Generates an expression for a `TStr` for `string`,
+/// equivalent to `ts!("<string>")`.
+///
+/// `crate_path` is the path to the tstr crate, usually `::tstr`.
+pub fn tstr_value(crate_path: &TokenStream, string: &str, span: Span) -> TokenStream {
+    output_macro(crate_path, "ts", &[string], span)
+}
+
+/// Generates the type of a tuple of `TStr`s, one for each string in `strings`,
+/// equivalent to `TS!("<string0>", "<string1>", ...)`.
+///
+/// `crate_path` is the path to the tstr crate, usually `::tstr`.
+pub fn tstr_tuple_type(crate_path: &TokenStream, strings: &[&str], span: Span) -> TokenStream {
+    output_macro(crate_path, "TS", strings, span)
+}
+
+/// Generates an expression for a tuple of `TStr`s, one for each string in `strings`,
+/// equivalent to `ts!("<string0>", "<string1>", ...)`.
+///
+/// `crate_path` is the path to the tstr crate, usually `::tstr`.
+pub fn tstr_tuple_value(crate_path: &TokenStream, strings: &[&str], span: Span) -> TokenStream {
+    output_macro(crate_path, "ts", strings, span)
+}
+
+fn output_macro(
+    crate_path: &TokenStream,
+    macro_name: &str,
+    strings: &[&str],
+    span: Span,
+) -> TokenStream {
+    let mut args = TokenStream::new();
+    for (i, string) in strings.iter().enumerate() {
+        if i != 0 {
+            args.extend(punct_token(',', Spacing::Alone, span));
+        }
+        let mut lit = Literal::string(string);
+        lit.set_span(span);
+        args.extend(iter::once(TokenTree::from(lit)));
+    }
+
+    let mut out = crate_path.clone();
+    out.extend(punct_token(':', Spacing::Joint, span));
+    out.extend(punct_token(':', Spacing::Alone, span));
+    out.extend(iter::once(TokenTree::from(Ident::new(macro_name, span))));
+    out.extend(punct_token('!', Spacing::Alone, span));
+
+    let mut group = Group::new(Delimiter::Parenthesis, args);
+    group.set_span(span);
+    out.extend(iter::once(TokenTree::from(group)));
+
+    out
+}
+
+fn punct_token(c: char, spacing: Spacing, span: Span) -> iter::Once<TokenTree> {
+    let mut punct = Punct::new(c, spacing);
+    punct.set_span(span);
+    iter::once(TokenTree::from(punct))
+}
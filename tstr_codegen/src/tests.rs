@@ -0,0 +1,167 @@
+use super::{
+    aliases_module, generate_aliases, tstr_tuple_type, tstr_tuple_value, tstr_type, tstr_value,
+};
+
+use proc_macro2::{Span, TokenStream};
+
+use std::{fs, io::ErrorKind};
+
+fn crate_path(path: &str) -> TokenStream {
+    path.parse().unwrap()
+}
+
+#[test]
+fn test_single_strings() {
+    let tstr = &crate_path("::tstr");
+    let span = Span::call_site();
+
+    assert_eq!(
+        tstr_type(tstr, "foo", span).to_string(),
+        r#":: tstr :: TS ! ("foo")"#
+    );
+    assert_eq!(
+        tstr_value(tstr, "foo", span).to_string(),
+        r#":: tstr :: ts ! ("foo")"#
+    );
+    assert_eq!(
+        tstr_type(tstr, "", span).to_string(),
+        r#":: tstr :: TS ! ("")"#
+    );
+}
+
+#[test]
+fn test_escaped_strings() {
+    let tstr = &crate_path("::tstr");
+    let span = Span::call_site();
+
+    assert_eq!(
+        tstr_type(tstr, "a\"b\\c", span).to_string(),
+        r#":: tstr :: TS ! ("a\"b\\c")"#
+    );
+    assert_eq!(
+        tstr_value(tstr, "a\nb", span).to_string(),
+        r#":: tstr :: ts ! ("a\nb")"#
+    );
+}
+
+#[test]
+fn test_tuples() {
+    let tstr = &crate_path("::tstr");
+    let span = Span::call_site();
+
+    assert_eq!(
+        tstr_tuple_type(tstr, &[], span).to_string(),
+        r#":: tstr :: TS ! ()"#
+    );
+    assert_eq!(
+        tstr_tuple_type(tstr, &["foo", "bar"], span).to_string(),
+        r#":: tstr :: TS ! ("foo" , "bar")"#
+    );
+    assert_eq!(
+        tstr_tuple_value(tstr, &["foo", "bar", "baz"], span).to_string(),
+        r#":: tstr :: ts ! ("foo" , "bar" , "baz")"#
+    );
+}
+
+#[test]
+fn test_crate_path() {
+    let span = Span::call_site();
+
+    assert_eq!(
+        tstr_type(&crate_path("my_crate::reexports::tstr"), "foo", span).to_string(),
+        r#"my_crate :: reexports :: tstr :: TS ! ("foo")"#
+    );
+}
+
+#[test]
+fn test_aliases_module() {
+    assert_eq!(aliases_module::<&str>(&[]).unwrap(), "");
+
+    assert_eq!(
+        aliases_module(&["userId", "HTTP-status", "2fa", "a.b c"]).unwrap(),
+        "\
+pub type UserId = ::tstr::TS!(\"userId\");
+pub const USER_ID: UserId = <UserId>::NEW;
+
+pub type HttpStatus = ::tstr::TS!(\"HTTP-status\");
+pub const HTTP_STATUS: HttpStatus = <HttpStatus>::NEW;
+
+pub type _2fa = ::tstr::TS!(\"2fa\");
+pub const _2FA: _2fa = <_2fa>::NEW;
+
+pub type ABC = ::tstr::TS!(\"a.b c\");
+pub const A_B_C: ABC = <ABC>::NEW;
+",
+    );
+
+    assert_eq!(
+        aliases_module(&["quote\"d"]).unwrap(),
+        "\
+pub type QuoteD = ::tstr::TS!(\"quote\\\"d\");
+pub const QUOTE_D: QuoteD = <QuoteD>::NEW;
+",
+    );
+}
+
+#[test]
+fn test_aliases_module_errors() {
+    let err = aliases_module(&["", "foo"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().contains("no alphanumeric"), "{}", err);
+
+    let err = aliases_module(&["user_id", "userId"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().contains("\"userId\""), "{}", err);
+}
+
+#[test]
+fn test_generate_aliases() {
+    let dir = std::env::temp_dir().join(format!("tstr_codegen_tests_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let expected = "\
+pub type Read = ::tstr::TS!(\"read\");
+pub const READ: Read = <Read>::NEW;
+
+pub type WriteAll = ::tstr::TS!(\"write all\");
+pub const WRITE_ALL: WriteAll = <WriteAll>::NEW;
+";
+
+    let files = [
+        ("lines.txt", "# permissions\nread\n\n  write all  \n"),
+        (
+            "table.csv",
+            "key,description\nread,Reads\n\"write all\",Writes\n",
+        ),
+        ("array.json", " [\"read\" , \"write\\u0020all\"]\n"),
+    ];
+    for &(name, contents) in &files {
+        let keys_path = dir.join(name);
+        fs::write(&keys_path, contents).unwrap();
+
+        let out_path = generate_aliases(&keys_path, &dir).unwrap();
+        assert_eq!(out_path.extension().unwrap(), "rs");
+        assert_eq!(out_path.file_stem(), keys_path.file_stem());
+        assert_eq!(fs::read_to_string(&out_path).unwrap(), expected, "{}", name);
+    }
+
+    for &contents in &[
+        "",
+        "[",
+        "[\"foo\",]",
+        "[\"foo\" \"bar\"]",
+        "[1]",
+        "[\"\\x\"]",
+    ] {
+        let keys_path = dir.join("invalid.json");
+        fs::write(&keys_path, contents).unwrap();
+
+        let err = generate_aliases(&keys_path, &dir).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData, "{:?}", contents);
+    }
+
+    let err = generate_aliases(dir.join("missing.txt"), &dir).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
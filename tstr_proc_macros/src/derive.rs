@@ -0,0 +1,672 @@
+//! Parsing of the structs that the derive macros of this crate are used on.
+//!
+//! This is implemented without `syn`,
+//! since its `"derive"` feature isn't enabled by the `"use_syn"` feature of tstr.
+
+use crate::{
+    used_proc_macro::{
+        token_stream::IntoIter, Delimiter, Group, Ident, Spacing, Span, TokenStream, TokenTree,
+    },
+    utils::{ident_is, Error},
+};
+
+use std::{collections::BTreeMap, fmt::Write, iter::Peekable};
+
+mod columns;
+mod fields;
+mod into_record;
+mod serde_rename;
+mod tstr_keys;
+mod variant_access;
+mod visit_fields;
+
+use self::serde_rename::{RenameRule, SerdeRenames};
+
+pub(crate) use self::{
+    columns::derive_columns, fields::derive_fields, into_record::derive_into_record,
+    tstr_keys::derive_tstr_keys, variant_access::derive_variant_access,
+    visit_fields::derive_visit_fields,
+};
+
+type TSIterator = Peekable<IntoIter>;
+
+pub(crate) struct DeriveInput {
+    /// The path to the tstr crate, `::tstr` unless overriden with `#[tstr(crate = path)]`
+    pub(crate) crate_path: String,
+    pub(crate) name: Ident,
+    pub(crate) generics: Generics,
+    pub(crate) fields: Vec<NamedField>,
+    /// The attributes of the struct, the groups after each `#`.
+    pub(crate) attrs: Vec<Group>,
+}
+
+pub(crate) struct Generics {
+    params: Vec<GenericParam>,
+    where_predicates: String,
+}
+
+struct GenericParam {
+    /// The declaration of the parameter, without its default value.
+    decl: String,
+    name: String,
+}
+
+pub(crate) struct NamedField {
+    pub(crate) ident: Ident,
+    /// The name of the field, without the `r#` prefix of raw identifiers,
+    /// renamed by serde attributes with the `"serde_rename"` feature.
+    pub(crate) name: String,
+    pub(crate) ty: String,
+}
+
+impl Generics {
+    /// The generic parameters to write after `impl`, including the `<>`.
+    pub(crate) fn impl_params(&self) -> String {
+        self.joined_params(|param| &param.decl)
+    }
+
+    /// The generic parameters to write after `impl`, with an additional `extra` parameter.
+    pub(crate) fn impl_params_with(&self, extra: &str) -> String {
+        let mut out = self.impl_params();
+        out.pop();
+        out.push_str(extra);
+        out.push('>');
+        out
+    }
+
+    /// The generic arguments to write after the type, including the `<>`.
+    pub(crate) fn type_args(&self) -> String {
+        self.joined_params(|param| &param.name)
+    }
+
+    /// The where clause, including the `where` keyword.
+    pub(crate) fn where_clause(&self) -> String {
+        format!("where {}", self.where_predicates)
+    }
+
+    fn joined_params<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(&GenericParam) -> &String,
+    {
+        let mut out = String::from("<");
+        for param in &self.params {
+            out.push_str(f(param));
+            out.push(',');
+        }
+        out.push('>');
+        out
+    }
+}
+
+/// Type aliases of the `TStr`s of names, for the code that derives generate.
+///
+/// Each name is usually used many times in the generated code,
+/// so its type is declared once as an alias, instead of repeating a long type.
+///
+/// This uses a `BTreeMap` instead of a `HashMap` for the same reason as `OutputCache`.
+pub(crate) struct KeyAliases {
+    crate_path: String,
+    decls: String,
+    aliases: BTreeMap<String, String>,
+}
+
+impl KeyAliases {
+    pub(crate) fn new(crate_path: &str) -> Self {
+        Self {
+            crate_path: crate_path.to_string(),
+            decls: String::new(),
+            aliases: BTreeMap::new(),
+        }
+    }
+
+    /// The type of the `TStr` of `name`, without declaring an alias for it.
+    pub(crate) fn tstr_type(&self, name: &str) -> String {
+        crate::tstr_type_string(&self.crate_path, name)
+    }
+
+    /// Declares an alias of the `TStr` of `name`, returning the name of the alias.
+    ///
+    /// The alias is only declared the first time that `name` is passed,
+    /// later calls with the same name return the same alias.
+    pub(crate) fn declare(&mut self, name: &str) -> String {
+        if let Some(alias) = self.aliases.get(name) {
+            return alias.clone();
+        }
+
+        let alias = format!("__TStrKey{}", self.aliases.len());
+        let _ = write!(
+            self.decls,
+            "type {} = {};",
+            alias,
+            crate::tstr_type_string(&self.crate_path, name),
+        );
+        self.aliases.insert(name.to_string(), alias.clone());
+        alias
+    }
+
+    /// Wraps `code` in an anonymous constant along with the aliases,
+    /// so that the aliases aren't visible outside of `code`.
+    pub(crate) fn wrap(&self, code: &str) -> String {
+        format!("const _: () = {{ {} {} }};", self.decls, code)
+    }
+}
+
+pub(crate) fn parse_derive_input(ts: TokenStream) -> Result<DeriveInput, Error> {
+    const KIND_ERROR: &str = "Only structs with named fields are supported";
+
+    let (header, body) = parse_header(ts, "struct", KIND_ERROR)?;
+
+    let fields = match body {
+        Some(group) if group.delimiter() == Delimiter::Brace => {
+            parse_fields(&group, header.rename_all)?
+        }
+        Some(group) => return Err(Error::new(group.span(), KIND_ERROR)),
+        None => return Err(Error::new(header.name.span(), KIND_ERROR)),
+    };
+
+    Ok(DeriveInput {
+        crate_path: header.crate_path,
+        name: header.name,
+        generics: header.generics,
+        fields,
+        attrs: header.attrs,
+    })
+}
+
+/// The enum that a derive macro is used on.
+pub(crate) struct DeriveEnum {
+    /// The path to the tstr crate, `::tstr` unless overriden with `#[tstr(crate = path)]`
+    pub(crate) crate_path: String,
+    /// The visibility of the enum, an empty string if it's private.
+    pub(crate) vis: String,
+    pub(crate) name: Ident,
+    pub(crate) generics: Generics,
+    pub(crate) variants: Vec<Variant>,
+}
+
+pub(crate) struct Variant {
+    pub(crate) ident: Ident,
+    /// The name of the variant, without the `r#` prefix of raw identifiers,
+    /// renamed by serde attributes with the `"serde_rename"` feature.
+    pub(crate) name: String,
+    /// Whether the variant has no fields.
+    pub(crate) is_unit: bool,
+    /// Whether the fields of the variant are named, declared inside braces.
+    pub(crate) is_braced: bool,
+    pub(crate) fields: Vec<VariantField>,
+}
+
+pub(crate) struct VariantField {
+    /// The name of the field, `None` for the fields of tuple variants.
+    pub(crate) ident: Option<Ident>,
+    pub(crate) ty: String,
+}
+
+pub(crate) fn parse_derive_enum(ts: TokenStream) -> Result<DeriveEnum, Error> {
+    const KIND_ERROR: &str = "Only enums are supported";
+
+    let (header, body) = parse_header(ts, "enum", KIND_ERROR)?;
+
+    let variants = match body {
+        Some(group) if group.delimiter() == Delimiter::Brace => {
+            parse_variants(&group, header.rename_all)?
+        }
+        Some(group) => return Err(Error::new(group.span(), KIND_ERROR)),
+        None => return Err(Error::new(header.name.span(), KIND_ERROR)),
+    };
+
+    Ok(DeriveEnum {
+        crate_path: header.crate_path,
+        vis: header.vis,
+        name: header.name,
+        generics: header.generics,
+        variants,
+    })
+}
+
+/// Everything in a struct or enum before its body.
+struct Header {
+    crate_path: String,
+    vis: String,
+    name: Ident,
+    generics: Generics,
+    /// The `#[serde(rename_all = "...")]` rule, with the `"serde_rename"` feature.
+    rename_all: Option<RenameRule>,
+    attrs: Vec<Group>,
+}
+
+/// Parses everything before the body of the type, returning the body if it's a group.
+fn parse_header(
+    ts: TokenStream,
+    keyword: &str,
+    kind_error: &str,
+) -> Result<(Header, Option<Group>), Error> {
+    let iter = &mut ts.into_iter().peekable();
+
+    let mut crate_path = String::from("::tstr");
+    let mut serde_renames = SerdeRenames::default();
+    let mut attrs = Vec::new();
+
+    while is_punct(iter.peek(), '#') {
+        let pound = iter.next().unwrap();
+        match iter.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+                if let Some(path) = parse_tstr_attr(&group)? {
+                    crate_path = path;
+                }
+                if cfg!(feature = "serde_rename") {
+                    serde_renames.parse_attr(&group)?;
+                }
+                attrs.push(group);
+            }
+            _ => return Err(Error::new(pound.span(), "Expected `[...]` after `#`")),
+        }
+    }
+
+    let vis = parse_visibility(iter);
+
+    match iter.next() {
+        Some(TokenTree::Ident(ident)) if ident_is(&ident, keyword) => {}
+        Some(x) => return Err(Error::new(x.span(), kind_error)),
+        None => {
+            return Err(Error::new(
+                Span::call_site(),
+                &format!("Expected {} {}", article(keyword), keyword),
+            ))
+        }
+    }
+
+    let expected_name = format!("Expected the name of the {}", keyword);
+    let name = match iter.next() {
+        Some(TokenTree::Ident(ident)) => ident,
+        Some(x) => return Err(Error::new(x.span(), &expected_name)),
+        None => return Err(Error::new(Span::call_site(), &expected_name)),
+    };
+
+    let params = if is_punct(iter.peek(), '<') {
+        iter.next();
+        parse_generic_params(iter, name.span())?
+    } else {
+        Vec::new()
+    };
+
+    let mut where_predicates = TokenStream::new();
+    if let Some(TokenTree::Ident(ident)) = iter.peek() {
+        if ident_is(ident, "where") {
+            iter.next();
+            while let Some(tt) = iter.peek() {
+                if let TokenTree::Group(group) = tt {
+                    if group.delimiter() == Delimiter::Brace {
+                        break;
+                    }
+                }
+                where_predicates.extend(iter.next());
+            }
+        }
+    }
+    let mut where_predicates = where_predicates.to_string();
+    if !where_predicates.trim_end().is_empty() && !where_predicates.trim_end().ends_with(',') {
+        where_predicates.push(',');
+    }
+
+    let body = match iter.next() {
+        Some(TokenTree::Group(group)) => Some(group),
+        Some(x) => return Err(Error::new(x.span(), kind_error)),
+        None => None,
+    };
+
+    let header = Header {
+        crate_path,
+        vis,
+        name,
+        generics: Generics {
+            params,
+            where_predicates,
+        },
+        rename_all: serde_renames.rename_all,
+        attrs,
+    };
+
+    Ok((header, body))
+}
+
+fn article(word: &str) -> &'static str {
+    if word.starts_with(|c| "aeiou".contains(c)) {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+/// Parses the `tstr(crate = path)` attribute, ignoring other attributes.
+fn parse_tstr_attr(group: &Group) -> Result<Option<String>, Error> {
+    let mut iter = group.stream().into_iter();
+
+    match iter.next() {
+        Some(TokenTree::Ident(ident)) if ident_is(&ident, "tstr") => {}
+        _ => return Ok(None),
+    }
+
+    let args = match iter.next() {
+        Some(TokenTree::Group(args)) if args.delimiter() == Delimiter::Parenthesis => args,
+        _ => return Err(Error::new(group.span(), "Expected `tstr(crate = path)`")),
+    };
+
+    let mut args = args.stream().into_iter();
+    match (args.next(), args.next()) {
+        (Some(TokenTree::Ident(ident)), Some(TokenTree::Punct(eq)))
+            if ident_is(&ident, "crate") && eq.as_char() == '=' => {}
+        _ => return Err(Error::new(group.span(), "Expected `tstr(crate = path)`")),
+    }
+
+    let path = args.collect::<TokenStream>().to_string();
+    if path.is_empty() {
+        return Err(Error::new(group.span(), "Expected a path after `crate =`"));
+    }
+    Ok(Some(path))
+}
+
+/// Parses the visibility, returning an empty string if there is none.
+fn parse_visibility(iter: &mut TSIterator) -> String {
+    let mut vis = TokenStream::new();
+    if let Some(TokenTree::Ident(ident)) = iter.peek() {
+        if ident_is(ident, "pub") {
+            vis.extend(iter.next());
+            if let Some(TokenTree::Group(group)) = iter.peek() {
+                if group.delimiter() == Delimiter::Parenthesis {
+                    vis.extend(iter.next());
+                }
+            }
+        }
+    }
+    vis.to_string()
+}
+
+/// Parses the generic parameters after the `<`, consuming the closing `>`.
+fn parse_generic_params(
+    iter: &mut TSIterator,
+    name_span: Span,
+) -> Result<Vec<GenericParam>, Error> {
+    let mut params = Vec::new();
+    let mut param = Vec::<TokenTree>::new();
+    let mut depth = 0usize;
+    let mut prev_is_dash = false;
+
+    loop {
+        let tt = match iter.next() {
+            Some(tt) => tt,
+            None => return Err(Error::new(name_span, "Expected `>` to close the generics")),
+        };
+
+        let mut is_dash = false;
+        if let TokenTree::Punct(punct) = &tt {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' if prev_is_dash => {}
+                '>' if depth == 0 => {
+                    if !param.is_empty() {
+                        params.push(parse_generic_param(param)?);
+                    }
+                    return Ok(params);
+                }
+                '>' => depth -= 1,
+                ',' if depth == 0 => {
+                    params.push(parse_generic_param(std::mem::take(&mut param))?);
+                    prev_is_dash = false;
+                    continue;
+                }
+                '-' => is_dash = punct.spacing() == Spacing::Joint,
+                _ => {}
+            }
+        }
+        prev_is_dash = is_dash;
+        param.push(tt);
+    }
+}
+
+fn parse_generic_param(tokens: Vec<TokenTree>) -> Result<GenericParam, Error> {
+    let name = match (tokens.first(), tokens.get(1)) {
+        (Some(TokenTree::Punct(p)), Some(TokenTree::Ident(ident))) if p.as_char() == '\'' => {
+            format!("'{}", ident)
+        }
+        (Some(TokenTree::Ident(kw)), Some(TokenTree::Ident(ident))) if ident_is(kw, "const") => {
+            ident.to_string()
+        }
+        (Some(TokenTree::Ident(ident)), _) => ident.to_string(),
+        (Some(x), _) => return Err(Error::new(x.span(), "Expected a generic parameter")),
+        (None, _) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Expected a generic parameter",
+            ))
+        }
+    };
+
+    // Removes the default value of the parameter
+    let mut depth = 0usize;
+    let mut decl = TokenStream::new();
+    for tt in tokens {
+        if let TokenTree::Punct(punct) = &tt {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' => depth = depth.saturating_sub(1),
+                '=' if depth == 0 => break,
+                _ => {}
+            }
+        }
+        decl.extend(Some(tt));
+    }
+
+    Ok(GenericParam {
+        decl: decl.to_string(),
+        name,
+    })
+}
+
+fn parse_fields(group: &Group, rename_all: Option<RenameRule>) -> Result<Vec<NamedField>, Error> {
+    let mut fields = Vec::new();
+    let mut iter = group.stream().into_iter().peekable();
+
+    while iter.peek().is_some() {
+        let serde_renames = parse_member_attrs(&mut iter)?;
+
+        parse_visibility(&mut iter);
+
+        let ident = match iter.next() {
+            Some(TokenTree::Ident(ident)) => ident,
+            Some(x) => return Err(Error::new(x.span(), "Expected the name of a field")),
+            None => return Err(Error::new(group.span(), "Expected the name of a field")),
+        };
+
+        match iter.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == ':' => {}
+            _ => {
+                return Err(Error::new(
+                    ident.span(),
+                    "Expected `:` after the field name",
+                ))
+            }
+        }
+
+        let ty = parse_field_type(&mut iter);
+
+        let name = ident.to_string();
+        let name = name.trim_start_matches("r#");
+        let name = serde_renames
+            .rename
+            .or_else(|| rename_all.map(|rule| rule.apply_to_field(name)))
+            .unwrap_or_else(|| name.to_string());
+
+        fields.push(NamedField { ident, name, ty });
+    }
+
+    Ok(fields)
+}
+
+/// Parses the type of a field, consuming the `,` after it.
+fn parse_field_type(iter: &mut TSIterator) -> String {
+    let mut ty = TokenStream::new();
+    let mut depth = 0usize;
+    let mut prev_is_dash = false;
+    for tt in iter.by_ref() {
+        let mut is_dash = false;
+        if let TokenTree::Punct(punct) = &tt {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' if prev_is_dash => {}
+                '>' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => break,
+                '-' => is_dash = punct.spacing() == Spacing::Joint,
+                _ => {}
+            }
+        }
+        prev_is_dash = is_dash;
+        ty.extend(Some(tt));
+    }
+    ty.to_string()
+}
+
+/// Parses the fields of a tuple or braced variant.
+fn parse_variant_fields(group: &Group) -> Result<Vec<VariantField>, Error> {
+    let is_braced = group.delimiter() == Delimiter::Brace;
+    let mut fields = Vec::new();
+    let mut iter = group.stream().into_iter().peekable();
+
+    while iter.peek().is_some() {
+        while is_punct(iter.peek(), '#') {
+            iter.next();
+            iter.next();
+        }
+
+        let ident = if is_braced {
+            let ident = match iter.next() {
+                Some(TokenTree::Ident(ident)) => ident,
+                Some(x) => return Err(Error::new(x.span(), "Expected the name of a field")),
+                None => return Err(Error::new(group.span(), "Expected the name of a field")),
+            };
+
+            match iter.next() {
+                Some(TokenTree::Punct(p)) if p.as_char() == ':' => {}
+                _ => {
+                    return Err(Error::new(
+                        ident.span(),
+                        "Expected `:` after the field name",
+                    ))
+                }
+            }
+            Some(ident)
+        } else {
+            None
+        };
+
+        let ty = parse_field_type(&mut iter);
+        fields.push(VariantField { ident, ty });
+    }
+
+    Ok(fields)
+}
+
+fn parse_variants(group: &Group, rename_all: Option<RenameRule>) -> Result<Vec<Variant>, Error> {
+    let mut variants = Vec::new();
+    let mut iter = group.stream().into_iter().peekable();
+
+    while iter.peek().is_some() {
+        let serde_renames = parse_member_attrs(&mut iter)?;
+
+        let ident = match iter.next() {
+            Some(TokenTree::Ident(ident)) => ident,
+            Some(x) => return Err(Error::new(x.span(), "Expected the name of a variant")),
+            None => return Err(Error::new(group.span(), "Expected the name of a variant")),
+        };
+
+        let (is_unit, is_braced, fields) = match iter.peek() {
+            Some(TokenTree::Group(fields)) if fields.delimiter() != Delimiter::None => {
+                let is_braced = fields.delimiter() == Delimiter::Brace;
+                let fields = parse_variant_fields(fields)?;
+                iter.next();
+                (false, is_braced, fields)
+            }
+            _ => (true, false, Vec::new()),
+        };
+
+        // Skips the discriminant, if there is one
+        for tt in iter.by_ref() {
+            if let TokenTree::Punct(punct) = &tt {
+                if punct.as_char() == ',' {
+                    break;
+                }
+            }
+        }
+
+        let name = ident.to_string();
+        let name = name.trim_start_matches("r#");
+        let name = serde_renames
+            .rename
+            .or_else(|| rename_all.map(|rule| rule.apply_to_variant(name)))
+            .unwrap_or_else(|| name.to_string());
+
+        variants.push(Variant {
+            ident,
+            name,
+            is_unit,
+            is_braced,
+            fields,
+        });
+    }
+
+    Ok(variants)
+}
+
+/// Skips the attributes of a field or variant,
+/// parsing the serde renaming attributes with the `"serde_rename"` feature.
+fn parse_member_attrs(iter: &mut TSIterator) -> Result<SerdeRenames, Error> {
+    let mut serde_renames = SerdeRenames::default();
+    while is_punct(iter.peek(), '#') {
+        iter.next();
+        if let Some(TokenTree::Group(group)) = iter.next() {
+            if cfg!(feature = "serde_rename") {
+                serde_renames.parse_attr(&group)?;
+            }
+        }
+    }
+    Ok(serde_renames)
+}
+
+/// Converts a name in `CamelCase` or `snake_case` to `SCREAMING_SNAKE_CASE`.
+pub(crate) fn screaming_snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<char>>();
+    let mut out = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        let prev = if i == 0 { None } else { chars.get(i - 1) };
+        let next = chars.get(i + 1);
+
+        let (after_lower, starts_word) = match (prev, next) {
+            (Some(p), next) => (
+                p.is_lowercase() || p.is_numeric(),
+                p.is_uppercase() && next.filter(|n| n.is_lowercase()).is_some(),
+            ),
+            (None, _) => (false, false),
+        };
+
+        if c.is_uppercase() && (after_lower || starts_word) && !out.ends_with('_') {
+            out.push('_');
+        }
+        out.extend(c.to_uppercase());
+    }
+
+    out
+}
+
+fn is_punct(tt: Option<&TokenTree>, c: char) -> bool {
+    match tt {
+        Some(TokenTree::Punct(p)) => p.as_char() == c,
+        _ => false,
+    }
+}
+
+/// Parses the generated code, which is written as a string.
+pub(crate) fn parse_output(code: &str) -> TokenStream {
+    code.parse::<TokenStream>()
+        .expect("tstr_proc_macros generated code that doesn't tokenize")
+}
@@ -0,0 +1,120 @@
+use crate::{
+    used_proc_macro::{Delimiter, Group, Span, TokenStream, TokenTree},
+    utils::{ident_is, Error},
+};
+
+use super::{
+    parse_derive_input, parse_output, screaming_snake_case, serde_rename::parse_string,
+    DeriveInput, KeyAliases,
+};
+
+use std::fmt::Write;
+
+pub(crate) fn derive_columns(ts: TokenStream) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        crate_path: cp,
+        name,
+        generics,
+        fields,
+        attrs,
+    } = parse_derive_input(ts)?;
+
+    let mut case = None;
+    for attr in &attrs {
+        if let Some(x) = parse_columns_attr(attr)? {
+            case = Some(x);
+        }
+    }
+
+    let impl_params = generics.impl_params();
+    let type_args = generics.type_args();
+    let where_clause = generics.where_clause();
+
+    let mut aliases = KeyAliases::new(&cp);
+    let mut list = String::new();
+    let mut column_names = String::new();
+    for field in &fields {
+        let column = match case {
+            Some(case) => case.apply(&field.name),
+            None => field.name.clone(),
+        };
+        let _ = write!(list, "{}::tlist::TList<{}, ", cp, aliases.declare(&column));
+        let _ = write!(column_names, "{:?},", column);
+    }
+    let _ = write!(list, "{}::tlist::TNil", cp);
+    list.extend(fields.iter().map(|_| '>'));
+
+    let code = format!(
+        "
+        impl{impl_params} {cp}::Columns for {name}{type_args}
+        {where_clause}
+        {{
+            type List = {list};
+
+            const COLUMNS: Self::List = <{list}>::NEW;
+
+            const COLUMN_NAMES: &'static [&'static str] = &[{column_names}];
+        }}
+        ",
+        impl_params = impl_params,
+        type_args = type_args,
+        where_clause = where_clause,
+        cp = cp,
+        name = name,
+        list = list,
+        column_names = column_names,
+    );
+
+    Ok(parse_output(&aliases.wrap(&code)))
+}
+
+/// The case style of the `#[columns(case = "...")]` attribute.
+#[derive(Copy, Clone)]
+enum Case {
+    Snake,
+    ScreamingSnake,
+}
+
+impl Case {
+    fn apply(self, name: &str) -> String {
+        let screaming = screaming_snake_case(name);
+        match self {
+            Case::Snake => screaming.to_lowercase(),
+            Case::ScreamingSnake => screaming,
+        }
+    }
+}
+
+/// Parses the `columns(case = "...")` attribute, ignoring other attributes.
+fn parse_columns_attr(group: &Group) -> Result<Option<Case>, Error> {
+    const EXPECTED: &str =
+        r#"Expected `columns(case = "snake_case")` or `columns(case = "SCREAMING_SNAKE_CASE")`"#;
+
+    let mut iter = group.stream().into_iter();
+
+    match iter.next() {
+        Some(TokenTree::Ident(ident)) if ident_is(&ident, "columns") => {}
+        _ => return Ok(None),
+    }
+
+    let args = match iter.next() {
+        Some(TokenTree::Group(args)) if args.delimiter() == Delimiter::Parenthesis => args,
+        _ => return Err(Error::new(group.span(), EXPECTED)),
+    };
+
+    let mut args = args.stream().into_iter();
+    let (case, span): (String, Span) = match (args.next(), args.next(), args.next()) {
+        (
+            Some(TokenTree::Ident(ident)),
+            Some(TokenTree::Punct(eq)),
+            Some(TokenTree::Literal(lit)),
+        ) if ident_is(&ident, "case") && eq.as_char() == '=' => parse_string(&lit)?,
+        _ => return Err(Error::new(group.span(), EXPECTED)),
+    };
+
+    match case.as_str() {
+        "snake_case" => Ok(Some(Case::Snake)),
+        "SCREAMING_SNAKE_CASE" => Ok(Some(Case::ScreamingSnake)),
+        _ => Err(Error::new(span, EXPECTED)),
+    }
+}
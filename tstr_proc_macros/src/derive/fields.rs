@@ -0,0 +1,56 @@
+use crate::{used_proc_macro::TokenStream, utils::Error};
+
+use super::{parse_derive_input, parse_output, DeriveInput, KeyAliases};
+
+use std::fmt::Write;
+
+pub(crate) fn derive_fields(ts: TokenStream) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        crate_path: cp,
+        name,
+        generics,
+        fields,
+        ..
+    } = parse_derive_input(ts)?;
+
+    let impl_params = generics.impl_params();
+    let type_args = generics.type_args();
+    let where_clause = generics.where_clause();
+
+    let mut aliases = KeyAliases::new(&cp);
+    let mut list = String::new();
+    let mut field_names = String::new();
+    for field in &fields {
+        let _ = write!(
+            list,
+            "{cp}::tlist::TList<({name_ty}, {ty}), ",
+            cp = cp,
+            name_ty = aliases.declare(&field.name),
+            ty = field.ty,
+        );
+        let _ = write!(field_names, "{:?},", field.name);
+    }
+    let _ = write!(list, "{}::tlist::TNil", cp);
+    list.extend(fields.iter().map(|_| '>'));
+
+    let code = format!(
+        "
+        impl{impl_params} {cp}::Fields for {name}{type_args}
+        {where_clause}
+        {{
+            type List = {list};
+
+            const FIELD_NAMES: &'static [&'static str] = &[{field_names}];
+        }}
+        ",
+        impl_params = impl_params,
+        type_args = type_args,
+        where_clause = where_clause,
+        cp = cp,
+        name = name,
+        list = list,
+        field_names = field_names,
+    );
+
+    Ok(parse_output(&aliases.wrap(&code)))
+}
@@ -0,0 +1,79 @@
+use crate::{used_proc_macro::TokenStream, utils::Error};
+
+use super::{parse_derive_input, parse_output, DeriveInput};
+
+use std::fmt::Write;
+
+pub(crate) fn derive_into_record(ts: TokenStream) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        crate_path: cp,
+        name,
+        generics,
+        fields,
+        ..
+    } = parse_derive_input(ts)?;
+
+    let impl_params = generics.impl_params();
+    let type_args = generics.type_args();
+    let where_clause = generics.where_clause();
+
+    let mut record_type = format!("{}::Record!{{", cp);
+    let mut record_expr = format!("{}::record!{{", cp);
+    let mut pattern = String::new();
+    let mut from_record = String::new();
+    for (i, field) in fields.iter().enumerate() {
+        let _ = write!(record_type, "{:?}: {},", field.name, field.ty);
+        let _ = write!(record_expr, "{:?}: self.{},", field.name, field.ident);
+        let _ = write!(pattern, "(__field{}, ", i);
+        let _ = write!(from_record, "{}: __field{}.into_inner(),", field.ident, i);
+    }
+    record_type.push('}');
+    record_expr.push('}');
+    pattern.push_str("()");
+    pattern.extend(fields.iter().map(|_| ')'));
+
+    let code = format!(
+        "
+        impl{impl_params} {cp}::records::IntoRecord for {name}{type_args}
+        {where_clause}
+        {{
+            type Record = {record_type};
+
+            #[inline]
+            fn into_record(self) -> Self::Record {{
+                {record_expr}
+            }}
+        }}
+
+        impl{impl_params} ::core::convert::From<{name}{type_args}> for {record_type}
+        {where_clause}
+        {{
+            #[inline]
+            fn from(this: {name}{type_args}) -> Self {{
+                {cp}::records::IntoRecord::into_record(this)
+            }}
+        }}
+
+        impl{impl_params} ::core::convert::From<{record_type}> for {name}{type_args}
+        {where_clause}
+        {{
+            #[inline]
+            fn from(this: {record_type}) -> Self {{
+                let {pattern} = this.into_fields();
+                Self {{ {from_record} }}
+            }}
+        }}
+        ",
+        impl_params = impl_params,
+        type_args = type_args,
+        where_clause = where_clause,
+        cp = cp,
+        name = name,
+        record_type = record_type,
+        record_expr = record_expr,
+        pattern = pattern,
+        from_record = from_record,
+    );
+
+    Ok(parse_output(&code))
+}
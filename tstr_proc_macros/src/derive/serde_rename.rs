@@ -0,0 +1,311 @@
+//! Parsing of the `#[serde(rename = "...")]` and `#[serde(rename_all = "...")]` attributes,
+//! so that the derives use the same names as the serde derives
+//! (with the `"serde_rename"` feature).
+
+use crate::{
+    used_proc_macro::{Delimiter, Group, Literal, Span, TokenTree},
+    utils::{ident_is, Error},
+};
+
+/// The renaming rules of `#[serde(rename_all = "...")]`,
+/// which are applied the same way that serde does.
+#[derive(Copy, Clone)]
+pub(crate) enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_str(rule: &str, span: Span) -> Result<Self, Error> {
+        Ok(match rule {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "PascalCase" => RenameRule::Pascal,
+            "camelCase" => RenameRule::Camel,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebab,
+            _ => {
+                let msg = format!("unknown `rename_all` rule: {:?}", rule);
+                return Err(Error::new(span, &msg));
+            }
+        })
+    }
+
+    /// Renames a field, which is assumed to be in `snake_case`.
+    pub(crate) fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(c);
+                    }
+                }
+                pascal
+            }
+            RenameRule::Camel => lowercase_first(&RenameRule::Pascal.apply_to_field(field)),
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+
+    /// Renames a variant, which is assumed to be in `PascalCase`.
+    pub(crate) fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            RenameRule::Pascal => variant.to_string(),
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Camel => lowercase_first(variant),
+            RenameRule::Snake => {
+                let mut snake = String::new();
+                for (i, c) in variant.char_indices() {
+                    if i > 0 && c.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(c.to_ascii_lowercase());
+                }
+                snake
+            }
+            RenameRule::ScreamingSnake => RenameRule::Snake
+                .apply_to_variant(variant)
+                .to_ascii_uppercase(),
+            RenameRule::Kebab => RenameRule::Snake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+            RenameRule::ScreamingKebab => RenameRule::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+}
+
+fn lowercase_first(string: &str) -> String {
+    let mut chars = string.chars();
+    chars
+        .next()
+        .map(|c| c.to_ascii_lowercase())
+        .into_iter()
+        .chain(chars)
+        .collect()
+}
+
+/// The serde renaming attributes of a type, field, or variant.
+#[derive(Default)]
+pub(crate) struct SerdeRenames {
+    pub(crate) rename: Option<String>,
+    pub(crate) rename_all: Option<RenameRule>,
+}
+
+impl SerdeRenames {
+    /// Parses an attribute (the group after the `#`) into `self`,
+    /// ignoring attributes other than `serde`, and other arguments of `serde`.
+    pub(crate) fn parse_attr(&mut self, attr: &Group) -> Result<(), Error> {
+        let mut iter = attr.stream().into_iter();
+
+        match iter.next() {
+            Some(TokenTree::Ident(ident)) if ident_is(&ident, "serde") => {}
+            _ => return Ok(()),
+        }
+
+        let args = match iter.next() {
+            Some(TokenTree::Group(args)) if args.delimiter() == Delimiter::Parenthesis => args,
+            _ => return Ok(()),
+        };
+
+        let mut iter = args.stream().into_iter().peekable();
+        while let Some(tt) = iter.next() {
+            let arg = match tt {
+                TokenTree::Ident(ident) => ident.to_string(),
+                _ => continue,
+            };
+
+            // the `= value` or `(...)` after the argument, if any
+            let value = match iter.peek() {
+                Some(TokenTree::Punct(p)) if p.as_char() == '=' => {
+                    iter.next();
+                    iter.next().map(ArgValue::Value)
+                }
+                Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => {
+                    let group = g.clone();
+                    iter.next();
+                    Some(ArgValue::Nested(group))
+                }
+                _ => None,
+            };
+
+            match (arg.as_str(), value) {
+                ("rename", Some(value)) => {
+                    self.rename = Some(parse_value(value, attr.span())?.0);
+                }
+                ("rename_all", Some(value)) => {
+                    let (rule, span) = parse_value(value, attr.span())?;
+                    self.rename_all = Some(RenameRule::from_str(&rule, span)?);
+                }
+                _ => {}
+            }
+
+            // skips the rest of the argument
+            for tt in iter.by_ref() {
+                if let TokenTree::Punct(p) = &tt {
+                    if p.as_char() == ',' {
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+enum ArgValue {
+    /// The value of `arg = value`
+    Value(TokenTree),
+    /// The arguments of `arg(serialize = value, deserialize = value)`
+    Nested(Group),
+}
+
+/// Parses the string of a `rename` or `rename_all` argument,
+/// preferring the `serialize` name if the names are different for serialization
+/// and deserialization.
+fn parse_value(value: ArgValue, attr_span: Span) -> Result<(String, Span), Error> {
+    const EXPECTED: &str = "expected `= \"...\"` or `(serialize = \"...\", deserialize = \"...\")`";
+
+    match value {
+        ArgValue::Value(TokenTree::Literal(lit)) => parse_string(&lit),
+        ArgValue::Value(tt) => Err(Error::new(tt.span(), EXPECTED)),
+        ArgValue::Nested(group) => {
+            let mut serialize = None;
+            let mut deserialize = None;
+            let mut iter = group.stream().into_iter();
+            while let Some(tt) = iter.next() {
+                let slot = match &tt {
+                    TokenTree::Ident(ident) if ident_is(ident, "serialize") => &mut serialize,
+                    TokenTree::Ident(ident) if ident_is(ident, "deserialize") => &mut deserialize,
+                    TokenTree::Punct(p) if p.as_char() == ',' => continue,
+                    _ => return Err(Error::new(tt.span(), EXPECTED)),
+                };
+                match (iter.next(), iter.next()) {
+                    (Some(TokenTree::Punct(eq)), Some(TokenTree::Literal(lit)))
+                        if eq.as_char() == '=' =>
+                    {
+                        *slot = Some(parse_string(&lit)?);
+                    }
+                    _ => return Err(Error::new(tt.span(), EXPECTED)),
+                }
+            }
+            serialize
+                .or(deserialize)
+                .ok_or_else(|| Error::new(attr_span, EXPECTED))
+        }
+    }
+}
+
+/// Parses a string literal, which can only have the simple escapes.
+pub(crate) fn parse_string(lit: &Literal) -> Result<(String, Span), Error> {
+    let repr = lit.to_string();
+    let span = lit.span();
+    let error = || Error::new(span, "expected a string literal");
+
+    // `str::strip_prefix` requires Rust 1.45.0
+    #[allow(clippy::manual_strip)]
+    if repr.starts_with('r') {
+        let hashes = repr[1..].len() - repr[1..].trim_start_matches('#').len();
+        let inner = repr
+            .get(1 + hashes..repr.len() - hashes)
+            .and_then(unquote)
+            .ok_or_else(error)?;
+        return Ok((inner.to_string(), span));
+    }
+
+    let inner = unquote(&repr).ok_or_else(error)?;
+
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        out.push(match chars.next() {
+            Some('"') => '"',
+            Some('\'') => '\'',
+            Some('\\') => '\\',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('0') => '\0',
+            _ => {
+                let msg = "unsupported escape in the string of a serde attribute";
+                return Err(Error::new(span, msg));
+            }
+        });
+    }
+    Ok((out, span))
+}
+
+// `str::strip_prefix` requires Rust 1.45.0
+#[allow(clippy::manual_strip)]
+fn unquote(string: &str) -> Option<&str> {
+    if string.len() >= 2 && string.starts_with('"') && string.ends_with('"') {
+        Some(&string[1..string.len() - 1])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenameRule;
+
+    #[test]
+    fn rename_fields() {
+        let cases = [
+            (RenameRule::Lower, "user_id"),
+            (RenameRule::Upper, "USER_ID"),
+            (RenameRule::Pascal, "UserId"),
+            (RenameRule::Camel, "userId"),
+            (RenameRule::Snake, "user_id"),
+            (RenameRule::ScreamingSnake, "USER_ID"),
+            (RenameRule::Kebab, "user-id"),
+            (RenameRule::ScreamingKebab, "USER-ID"),
+        ];
+        for &(rule, expected) in &cases {
+            assert_eq!(rule.apply_to_field("user_id"), expected);
+        }
+        assert_eq!(RenameRule::Camel.apply_to_field("name"), "name");
+    }
+
+    #[test]
+    fn rename_variants() {
+        let cases = [
+            (RenameRule::Lower, "waitingforinput"),
+            (RenameRule::Upper, "WAITINGFORINPUT"),
+            (RenameRule::Pascal, "WaitingForInput"),
+            (RenameRule::Camel, "waitingForInput"),
+            (RenameRule::Snake, "waiting_for_input"),
+            (RenameRule::ScreamingSnake, "WAITING_FOR_INPUT"),
+            (RenameRule::Kebab, "waiting-for-input"),
+            (RenameRule::ScreamingKebab, "WAITING-FOR-INPUT"),
+        ];
+        for &(rule, expected) in &cases {
+            assert_eq!(rule.apply_to_variant("WaitingForInput"), expected);
+        }
+    }
+}
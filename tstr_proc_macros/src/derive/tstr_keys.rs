@@ -0,0 +1,183 @@
+use crate::{used_proc_macro::TokenStream, utils::Error};
+
+use super::{parse_derive_enum, parse_output, screaming_snake_case, DeriveEnum, KeyAliases};
+
+use std::fmt::Write;
+
+pub(crate) fn derive_tstr_keys(ts: TokenStream) -> Result<TokenStream, Error> {
+    let DeriveEnum {
+        crate_path: cp,
+        vis,
+        name,
+        generics,
+        variants,
+    } = parse_derive_enum(ts)?;
+
+    let impl_params = generics.impl_params();
+    let visitor_impl_params = generics.impl_params_with("__Visitor");
+    let type_args = generics.type_args();
+    let where_clause = generics.where_clause();
+    let mut visitor_where_clause = generics.where_clause();
+
+    let mut aliases = KeyAliases::new(&cp);
+    let mut keys = String::new();
+    let mut variant_names = String::new();
+    let mut name_arms = String::new();
+    let mut visit_arms = String::new();
+    let mut from_tstr_impls = String::new();
+    let mut consts = String::new();
+
+    for variant in &variants {
+        let name_ty = aliases.declare(&variant.name);
+
+        let _ = write!(keys, "{}::tlist::TList<{}, ", cp, name_ty);
+        let _ = write!(variant_names, "{:?},", variant.name);
+        let _ = write!(
+            name_arms,
+            "{name}::{ident}{{..}} => {str:?},",
+            name = name,
+            ident = variant.ident,
+            str = variant.name,
+        );
+        let _ = write!(
+            visitor_where_clause,
+            "__Visitor: {cp}::VariantVisitor<{name_ty}>,",
+            cp = cp,
+            name_ty = name_ty,
+        );
+        let _ = write!(
+            visit_arms,
+            "{name}::{ident}{{..}} => {cp}::VariantVisitor::<{name_ty}>::visit(
+                visitor,
+                <{name_ty} as {cp}::MakeTStr>::MAKE,
+                {str:?},
+            ),",
+            cp = cp,
+            name = name,
+            ident = variant.ident,
+            name_ty = name_ty,
+            str = variant.name,
+        );
+        let _ = write!(
+            consts,
+            "/// The `TStr` of the name of the `{str}` variant.
+            {vis} const {const_name}: {tstr_type} = {cp}::TStr::NEW;",
+            cp = cp,
+            vis = vis,
+            str = variant.name,
+            // the Rust name, since the serde name isn't necessarily an identifier
+            const_name = const_name(variant.ident.to_string().trim_start_matches("r#")),
+            // the full type, so that the docs don't show the alias
+            tstr_type = aliases.tstr_type(&variant.name),
+        );
+
+        if variant.is_unit {
+            let _ = write!(
+                from_tstr_impls,
+                "
+                impl{impl_params} {cp}::FromTStr<{name_ty}> for {name}{type_args}
+                {where_clause}
+                {{
+                    fn from_tstr(_: {name_ty}) -> Self {{
+                        {name}::{ident}
+                    }}
+                }}
+                ",
+                impl_params = impl_params,
+                cp = cp,
+                name_ty = name_ty,
+                name = name,
+                type_args = type_args,
+                where_clause = where_clause,
+                ident = variant.ident,
+            );
+        }
+    }
+    let _ = write!(keys, "{}::tlist::TNil", cp);
+    keys.extend(variants.iter().map(|_| '>'));
+
+    let code = format!(
+        "
+        impl{impl_params} {cp}::TStrKeys for {name}{type_args}
+        {where_clause}
+        {{
+            type Keys = {keys};
+
+            const VARIANT_NAMES: &'static [&'static str] = &[{variant_names}];
+
+            fn variant_name(&self) -> &'static str {{
+                match *self {{
+                    {name_arms}
+                }}
+            }}
+        }}
+
+        impl{visitor_impl_params} {cp}::ToTStr<__Visitor> for {name}{type_args}
+        {visitor_where_clause}
+        {{
+            #[allow(unused_variables)]
+            fn to_tstr(&self, visitor: &mut __Visitor) {{
+                match *self {{
+                    {visit_arms}
+                }}
+            }}
+        }}
+
+        {from_tstr_impls}
+        ",
+        impl_params = impl_params,
+        visitor_impl_params = visitor_impl_params,
+        type_args = type_args,
+        where_clause = where_clause,
+        visitor_where_clause = visitor_where_clause,
+        cp = cp,
+        name = name,
+        keys = keys,
+        variant_names = variant_names,
+        name_arms = name_arms,
+        visit_arms = visit_arms,
+        from_tstr_impls = from_tstr_impls,
+    );
+
+    let consts_impl = format!(
+        "
+        impl{impl_params} {name}{type_args}
+        {where_clause}
+        {{
+            {consts}
+        }}
+        ",
+        impl_params = impl_params,
+        type_args = type_args,
+        where_clause = where_clause,
+        name = name,
+        consts = consts,
+    );
+
+    Ok(parse_output(&(aliases.wrap(&code) + &consts_impl)))
+}
+
+/// The name of the constant for the `TStr` of a variant name,
+/// converting `CamelCase` to `CAMEL_CASE_KEY`.
+fn const_name(variant: &str) -> String {
+    let mut out = screaming_snake_case(variant);
+    out.push_str("_KEY");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::const_name;
+
+    #[test]
+    fn const_name_test() {
+        assert_eq!(const_name("Idle"), "IDLE_KEY");
+        assert_eq!(const_name("A"), "A_KEY");
+        assert_eq!(const_name("WaitingForInput"), "WAITING_FOR_INPUT_KEY");
+        assert_eq!(const_name("HTTPRequest"), "HTTP_REQUEST_KEY");
+        assert_eq!(const_name("Http2Request"), "HTTP2_REQUEST_KEY");
+        assert_eq!(const_name("snake_case"), "SNAKE_CASE_KEY");
+        assert_eq!(const_name("SCREAMING"), "SCREAMING_KEY");
+        assert_eq!(const_name("type"), "TYPE_KEY");
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    used_proc_macro::{Ident, TokenStream},
+    utils::Error,
+};
+
+use super::{parse_derive_enum, parse_output, DeriveEnum, KeyAliases, Variant};
+
+use std::fmt::Write;
+
+pub(crate) fn derive_variant_access(ts: TokenStream) -> Result<TokenStream, Error> {
+    let DeriveEnum {
+        crate_path: cp,
+        name,
+        generics,
+        variants,
+        ..
+    } = parse_derive_enum(ts)?;
+
+    let impl_params = generics.impl_params();
+    let type_args = generics.type_args();
+    let where_clause = generics.where_clause();
+
+    let mut aliases = KeyAliases::new(&cp);
+    let mut impls = String::new();
+
+    for variant in &variants {
+        let name_ty = aliases.declare(&variant.name);
+        let pattern = variant_pattern(&name, variant, "");
+        let payload = payload_expr(variant);
+
+        let _ = write!(
+            impls,
+            "
+            impl{impl_params} {cp}::IntoVariant<{name_ty}> for {name}{type_args}
+            {where_clause}
+            {{
+                type Payload = {payload_ty};
+
+                #[allow(unreachable_patterns)]
+                #[inline]
+                fn into_variant(self, _: {name_ty}) -> ::core::option::Option<Self::Payload> {{
+                    match self {{
+                        {pattern} => ::core::option::Option::Some({payload}),
+                        _ => ::core::option::Option::None,
+                    }}
+                }}
+            }}
+            ",
+            impl_params = impl_params,
+            cp = cp,
+            name_ty = name_ty,
+            name = name,
+            type_args = type_args,
+            where_clause = where_clause,
+            payload_ty = payload_type(variant),
+            pattern = pattern,
+            payload = payload,
+        );
+
+        if variant.fields.len() == 1 {
+            let _ = write!(
+                impls,
+                "
+                impl{impl_params} {cp}::VariantAccess<{name_ty}> for {name}{type_args}
+                {where_clause}
+                {{
+                    #[allow(unreachable_patterns)]
+                    #[inline]
+                    fn variant(&self, _: {name_ty}) -> ::core::option::Option<&Self::Payload> {{
+                        match *self {{
+                            {ref_pattern} => ::core::option::Option::Some(__field0),
+                            _ => ::core::option::Option::None,
+                        }}
+                    }}
+
+                    #[allow(unreachable_patterns)]
+                    #[inline]
+                    fn variant_mut(
+                        &mut self,
+                        _: {name_ty},
+                    ) -> ::core::option::Option<&mut Self::Payload> {{
+                        match *self {{
+                            {mut_pattern} => ::core::option::Option::Some(__field0),
+                            _ => ::core::option::Option::None,
+                        }}
+                    }}
+                }}
+                ",
+                impl_params = impl_params,
+                cp = cp,
+                name_ty = name_ty,
+                name = name,
+                type_args = type_args,
+                where_clause = where_clause,
+                ref_pattern = variant_pattern(&name, variant, "ref "),
+                mut_pattern = variant_pattern(&name, variant, "ref mut "),
+            );
+        }
+    }
+
+    Ok(parse_output(&aliases.wrap(&impls)))
+}
+
+/// The pattern that matches the variant,
+/// binding its fields to `__field0`, `__field1`, etc.
+fn variant_pattern(enum_name: &Ident, variant: &Variant, binding: &str) -> String {
+    let mut out = format!("{}::{}", enum_name, variant.ident);
+    if variant.is_unit {
+        return out;
+    }
+
+    out.push(if variant.is_braced { '{' } else { '(' });
+    for (i, field) in variant.fields.iter().enumerate() {
+        if let Some(ident) = &field.ident {
+            let _ = write!(out, "{}: ", ident);
+        }
+        let _ = write!(out, "{}__field{},", binding, i);
+    }
+    out.push(if variant.is_braced { '}' } else { ')' });
+    out
+}
+
+/// The type of the payload of the variant:
+/// the type of the field if there's exactly one, otherwise a tuple of the field types.
+fn payload_type(variant: &Variant) -> String {
+    match &variant.fields[..] {
+        [field] => field.ty.clone(),
+        fields => {
+            let mut out = String::from("(");
+            for field in fields {
+                let _ = write!(out, "{},", field.ty);
+            }
+            out.push(')');
+            out
+        }
+    }
+}
+
+/// The expression that constructs the payload from the fields bound by `variant_pattern`.
+fn payload_expr(variant: &Variant) -> String {
+    if variant.fields.len() == 1 {
+        return "__field0".to_string();
+    }
+
+    let mut out = String::from("(");
+    for i in 0..variant.fields.len() {
+        let _ = write!(out, "__field{},", i);
+    }
+    out.push(')');
+    out
+}
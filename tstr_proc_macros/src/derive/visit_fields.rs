@@ -0,0 +1,67 @@
+use crate::{used_proc_macro::TokenStream, utils::Error};
+
+use super::{parse_derive_input, parse_output, DeriveInput, KeyAliases};
+
+use std::fmt::Write;
+
+pub(crate) fn derive_visit_fields(ts: TokenStream) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        crate_path: cp,
+        name,
+        generics,
+        fields,
+        ..
+    } = parse_derive_input(ts)?;
+
+    let impl_params = generics.impl_params_with("__Visitor");
+    let type_args = generics.type_args();
+    let mut where_clause = generics.where_clause();
+
+    let mut aliases = KeyAliases::new(&cp);
+    let mut visits = String::new();
+    for field in &fields {
+        let name_ty = aliases.declare(&field.name);
+        let _ = write!(
+            where_clause,
+            "__Visitor: {cp}::FieldVisitor<{name_ty}, {ty}>,",
+            cp = cp,
+            name_ty = name_ty,
+            ty = field.ty,
+        );
+        let _ = write!(
+            visits,
+            "{cp}::FieldVisitor::<{name_ty}, {ty}>::visit(
+                visitor,
+                <{name_ty} as {cp}::MakeTStr>::MAKE,
+                {name:?},
+                &self.{ident},
+            );",
+            cp = cp,
+            name_ty = name_ty,
+            ty = field.ty,
+            name = field.name,
+            ident = field.ident,
+        );
+    }
+
+    let code = format!(
+        "
+        impl{impl_params} {cp}::VisitFields<__Visitor> for {name}{type_args}
+        {where_clause}
+        {{
+            #[allow(unused_variables)]
+            fn visit_fields(&self, visitor: &mut __Visitor) {{
+                {visits}
+            }}
+        }}
+        ",
+        impl_params = impl_params,
+        type_args = type_args,
+        where_clause = where_clause,
+        cp = cp,
+        name = name,
+        visits = visits,
+    );
+
+    Ok(parse_output(&aliases.wrap(&code)))
+}
@@ -0,0 +1,95 @@
+//! The `format!(...)` argument of the `TS` macro,
+//! which substitutes the `{}` placeholders of a string literal with the other arguments.
+
+use crate::{used_proc_macro::Span, TStr};
+
+/// Substitutes the placeholders in `template` with the strings of `args`.
+///
+/// The supported placeholders are `{}` for the next argument,
+/// and `{N}` for the `N`th argument,
+/// with `{{` and `}}` being escaped braces.
+///
+/// `span` is the span of the whole `format!(...)`, used for errors about missing arguments.
+pub(crate) fn format_tstr(
+    template: &TStr,
+    args: &[TStr],
+    span: Span,
+) -> Result<String, (Span, String)> {
+    let mut out = String::new();
+    let mut used = vec![false; args.len()];
+    let mut next_arg = 0;
+
+    let mut chars = template.string.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '{' => {
+                let mut inside = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => inside.push(c),
+                        None => {
+                            let msg = "invalid format string: expected `}`, found end of string";
+                            return Err((template.span, msg.to_string()));
+                        }
+                    }
+                }
+
+                let index = if inside.is_empty() {
+                    next_arg += 1;
+                    next_arg - 1
+                } else if inside.bytes().all(|b| b.is_ascii_digit()) {
+                    inside
+                        .parse::<usize>()
+                        .map_err(|_| (template.span, format!("invalid index: {{{}}}", inside)))?
+                } else {
+                    let msg = format!(
+                        "unsupported placeholder: `{{{}}}`, \
+                         only `{{}}` and `{{N}}` (with an argument index) are supported",
+                        inside,
+                    );
+                    return Err((template.span, msg));
+                };
+
+                match args.get(index) {
+                    Some(arg) => {
+                        out.push_str(&arg.string);
+                        used[index] = true;
+                    }
+                    None => {
+                        let msg = format!(
+                            "invalid reference to positional argument {} ({})",
+                            index,
+                            match args.len() {
+                                0 => "no arguments were given".to_string(),
+                                1 => "there is 1 argument".to_string(),
+                                len => format!("there are {} arguments", len),
+                            },
+                        );
+                        return Err((span, msg));
+                    }
+                }
+            }
+            '}' => {
+                let msg = "invalid format string: unmatched `}` found, \
+                           `}}` is an escaped `}`";
+                return Err((template.span, msg.to_string()));
+            }
+            c => out.push(c),
+        }
+    }
+
+    if let Some(unused) = used.iter().position(|&used| !used) {
+        return Err((args[unused].span, "argument never used".to_string()));
+    }
+
+    Ok(out)
+}
@@ -0,0 +1,113 @@
+//! The implementation of the `include_tstr_lines` macro,
+//! which reads a file and outputs the `TStr`s of its lines.
+
+use std::{iter, path::PathBuf};
+
+#[allow(unused_imports)]
+use crate::used_proc_macro::{Literal, Span, TokenStream, TokenTree};
+
+use crate::{
+    utils::{colon2_token, ident_token, punct_token, Error},
+    Arg, Inputs, TStr,
+};
+
+pub(crate) fn include_lines(input_tokens: TokenStream) -> TokenStream {
+    match include_lines_inner(input_tokens) {
+        Ok(out) => out,
+        Err(e) => e,
+    }
+}
+
+fn include_lines_inner(input_tokens: TokenStream) -> Result<TokenStream, TokenStream> {
+    let Inputs {
+        crate_path,
+        mut args,
+    } = crate::parse_inputs(input_tokens)?;
+
+    let arg = match (args.pop(), args.is_empty()) {
+        (Some(Arg::Str(arg)), true) => arg,
+        _ => {
+            let msg = "expected a string literal with the path of the file";
+            return Err(Error::new(Span::call_site(), msg).to_compile_error());
+        }
+    };
+
+    // Like `include_str!`, but relative to the directory of the invoking crate,
+    // because the file that the macro is invoked in is only available with Rust 1.88.0.
+    let mut path = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default());
+    path.push(&arg.string);
+
+    let contents = std::fs::read_to_string(&path).map_err(|e| {
+        let msg = format!("couldn't read `{}`: {}", path.display(), e);
+        Error::new(arg.span, &msg).to_compile_error()
+    })?;
+
+    let lines = line_args(&contents, arg.span);
+    let (crate_path, ty) = crate::output_inputs(
+        Inputs {
+            crate_path,
+            args: lines,
+        },
+        crate::output_tstr,
+    );
+
+    Ok(output_tracked_type(&crate_path, &path, arg.span, ty))
+}
+
+/// The non-empty lines of the file, with leading and trailing whitespace removed.
+fn line_args(contents: &str, span: Span) -> Vec<Arg> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            Arg::Str(TStr {
+                string: line.to_string(),
+                span,
+            })
+        })
+        .collect()
+}
+
+/// Outputs `<[<ty>; { include_bytes!("<path>"); 0 }] as <crate_path>::__IncludedFile>::Type`,
+/// which is the same type as `ty`,
+/// using `include_bytes` so that the crate is recompiled when the file changes.
+fn output_tracked_type(
+    crate_path: &TokenStream,
+    path: &std::path::Path,
+    span: Span,
+    ty: TokenStream,
+) -> TokenStream {
+    use crate::utils::{brace, bracket, paren};
+
+    let mut out = TokenStream::new();
+    out.extend(punct_token('<', span));
+    out.extend(iter::once(bracket(span, |out| {
+        out.extend(ty);
+        out.extend(punct_token(';', span));
+        out.extend(iter::once(brace(span, |out| {
+            out.extend(colon2_token(span));
+            out.extend(ident_token("core", span));
+            out.extend(colon2_token(span));
+            out.extend(ident_token("include_bytes", span));
+            out.extend(punct_token('!', span));
+            out.extend(iter::once(paren(span, |out| {
+                let mut lit = Literal::string(&path.to_string_lossy());
+                lit.set_span(span);
+                out.extend(iter::once(TokenTree::from(lit)));
+            })));
+            out.extend(punct_token(';', span));
+            let mut zero = Literal::usize_unsuffixed(0);
+            zero.set_span(span);
+            out.extend(iter::once(TokenTree::from(zero)));
+        })));
+    })));
+    out.extend(ident_token("as", span));
+    out.extend(crate_path.clone());
+    out.extend(colon2_token(span));
+    out.extend(ident_token("__IncludedFile", span));
+    out.extend(punct_token('>', span));
+    out.extend(colon2_token(span));
+    out.extend(ident_token("Type", span));
+    out
+}
@@ -9,7 +9,7 @@ use proc_macro as used_proc_macro;
 #[cfg(feature = "proc_macro2_")]
 use proc_macro2 as used_proc_macro;
 
-use std::iter;
+use std::{collections::BTreeMap, iter};
 
 #[allow(unused_imports)]
 use used_proc_macro::{
@@ -22,58 +22,384 @@ mod use_syn;
 #[cfg(not(feature = "syn_"))]
 mod non_syn_parsing;
 
-#[cfg(not(feature = "const_generics"))]
+#[cfg(any(not(feature = "const_generics"), feature = "dual_repr"))]
 mod nested_tuple_compute;
 
+mod derive;
+
+mod format_args;
+
+mod include_lines;
+
+mod type_path;
+
+mod ts_value;
+
+#[cfg(feature = "rust_1_88")]
+mod location;
+
 mod utils;
 
+#[cfg(all(test, feature = "syn_"))]
+mod tests;
+
 #[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
 mod min_const_generics;
 
 #[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
-use min_const_generics::output_tstr_param;
+use min_const_generics::{output_imported_tstr_param, output_tstr_param};
 
-#[cfg(not(feature = "min_const_generics"))]
+#[cfg(any(not(feature = "min_const_generics"), feature = "dual_repr"))]
 mod no_const_generics;
 
 #[cfg(not(feature = "min_const_generics"))]
-use no_const_generics::output_tstr_param;
+use no_const_generics::{output_imported_tstr_param, output_tstr_param};
 
 #[doc(hidden)]
 #[proc_macro]
 pub fn __ts_impl(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    use crate::utils::{paren, punct_token};
+    match ts_impl(TokenStream::from(input_tokens), output_tstr) {
+        Ok((_, ty)) => ty,
+        Err(e) => e,
+    }
+    .into()
+}
+
+/// Outputs the items that the `ts` macro constructs its `TStr`s from,
+/// declaring the type of each distinct string once.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __ts_value_impl(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    ts_value::output_value_items(TokenStream::from(input_tokens)).into()
+}
+
+/// Outputs a type alias for the `alias` macro, declaring the type of each distinct string once.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __alias_impl(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    ts_value::output_alias(TokenStream::from(input_tokens)).into()
+}
+
+/// Outputs the default representation of type-level strings,
+/// regardless of the enabled features.
+#[cfg(feature = "dual_repr")]
+#[doc(hidden)]
+#[proc_macro]
+pub fn __ts_tupled_impl(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match ts_impl(
+        TokenStream::from(input_tokens),
+        no_const_generics::output_tupled_tstr,
+    ) {
+        Ok((_, ty)) => ty,
+        Err(e) => e,
+    }
+    .into()
+}
+
+/// Outputs the same type as `__ts_impl`, with the arguments sorted by their strings.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __ts_sort_impl(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    ts_impl_with(TokenStream::from(input_tokens), sort_args).into()
+}
+
+/// Outputs the same type as `__ts_impl`, with repeated arguments removed.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __ts_dedup_impl(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    ts_impl_with(TokenStream::from(input_tokens), dedup_args).into()
+}
 
-    let input_tokens = TokenStream::from(input_tokens);
+/// Outputs the type of the `TStr`s of the lines of a file, for `tstr::include_tstr_lines`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __include_tstr_lines_impl(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    include_lines::include_lines(TokenStream::from(input_tokens)).into()
+}
 
+/// Outputs the same type as `__ts_impl`, with the arguments transformed by `map_inputs`.
+fn ts_impl_with(
+    input_tokens: TokenStream,
+    map_inputs: fn(Inputs) -> Result<Inputs, TokenStream>,
+) -> TokenStream {
+    match parse_inputs(input_tokens).and_then(map_inputs) {
+        Ok(inputs) => output_inputs(inputs, output_tstr).1,
+        Err(e) => e,
+    }
+}
+
+/// Outputs the type of a `TStr`, given the path to the tstr crate.
+type OutputTStr = fn(&TokenStream, &TStr, &mut TokenStream);
+
+/// Returns the path to the tstr crate, and the outputted type.
+fn ts_impl(
+    input_tokens: TokenStream,
+    output_tstr: OutputTStr,
+) -> Result<(TokenStream, TokenStream), TokenStream> {
+    parse_inputs(input_tokens).map(|inputs| output_inputs(inputs, output_tstr))
+}
+
+fn parse_inputs(input_tokens: TokenStream) -> Result<Inputs, TokenStream> {
     #[cfg(feature = "syn_")]
     let parsed = syn::parse2::<Inputs>(input_tokens);
 
     #[cfg(not(feature = "syn_"))]
     let parsed = non_syn_parsing::parse_inputs(input_tokens);
 
-    match parsed {
-        Ok(Inputs {
-            crate_path,
-            strings,
-        }) => {
-            let mut out = TokenStream::new();
-            if strings.len() == 1 {
-                output_tstr(&crate_path, &strings[0], &mut out);
-            } else {
-                let tt = paren(Span::call_site(), |out| {
-                    for tstr in &strings {
-                        output_tstr(&crate_path, tstr, out);
-                        out.extend(punct_token(',', tstr.span));
-                    }
-                });
-                out.extend(iter::once(tt));
+    parsed.map_err(|e| e.to_compile_error())
+}
+
+/// Sorts the arguments by their strings, comparing their UTF-8 bytes.
+///
+/// Dotted paths are rejected, since they don't have a single string to sort by.
+fn sort_args(mut inputs: Inputs) -> Result<Inputs, TokenStream> {
+    if let Some(arg) = inputs.args.iter().find(|arg| match arg {
+        Arg::Path(_) => true,
+        Arg::Str(_) => false,
+    }) {
+        let msg = "dotted paths can't be sorted, expected a string, integer, or identifier";
+        return Err(crate::utils::Error::new(arg.span(), msg).to_compile_error());
+    }
+
+    inputs.args.sort_by(|l, r| match (l, r) {
+        (Arg::Str(l), Arg::Str(r)) => l.string.cmp(&r.string),
+        _ => std::cmp::Ordering::Equal,
+    });
+    Ok(inputs)
+}
+
+/// Removes the arguments with the same string(s) as a previous argument,
+/// keeping the first occurrence of each one.
+fn dedup_args(mut inputs: Inputs) -> Result<Inputs, TokenStream> {
+    let mut seen = std::collections::BTreeSet::new();
+    inputs.args.retain(|arg| {
+        let key = match arg {
+            Arg::Str(tstr) => vec![tstr.string.clone()],
+            Arg::Path(segments) => segments.iter().map(|s| s.string.clone()).collect(),
+        };
+        // paths have at least two segments, so they never have the same key as a string
+        seen.insert(key)
+    });
+    Ok(inputs)
+}
+
+/// Returns the path to the tstr crate, and the outputted type.
+fn output_inputs(inputs: Inputs, output_tstr: OutputTStr) -> (TokenStream, TokenStream) {
+    use crate::utils::{paren, punct_token};
+
+    let Inputs { crate_path, args } = inputs;
+    let cache = &mut OutputCache::new(output_tstr);
+    let mut out = TokenStream::new();
+    if args.len() == 1 {
+        output_arg(&crate_path, &args[0], cache, &mut out);
+    } else {
+        let tt = paren(Span::call_site(), |out| {
+            for arg in &args {
+                output_arg(&crate_path, arg, cache, out);
+                out.extend(punct_token(',', arg.span()));
             }
-            out
+        });
+        out.extend(iter::once(tt));
+    }
+    (crate_path, out)
+}
+
+/// The name of the representation of type-level strings that this crate outputs,
+/// which tstr checks against the representation that it was compiled with.
+#[cfg(not(feature = "min_const_generics"))]
+const REPR_NAME: &str = "tupled";
+
+#[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
+const REPR_NAME: &str = "min_const_generics";
+
+#[cfg(feature = "const_generics")]
+const REPR_NAME: &str = "const_generics";
+
+/// Outputs `crate::__priv_check_repr!(<repr_name>);`, which tstr invokes once,
+/// which errors with an explanation if tstr was compiled with a different representation
+/// (if a crate directly enables features of this crate),
+/// instead of an error about the types in the representation.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __check_repr_impl(_: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use crate::utils::{colon2_token, ident_token, paren, punct_token};
+
+    let span = Span::call_site();
+    let mut out = TokenStream::new();
+    out.extend(ident_token("crate", span));
+    out.extend(colon2_token(span));
+    out.extend(ident_token("__priv_check_repr", span));
+    out.extend(punct_token('!', span));
+    out.extend(iter::once(paren(span, |args| {
+        args.extend(ident_token(REPR_NAME, span));
+    })));
+    out.extend(punct_token(';', span));
+    out.into()
+}
+
+/// Outputs the type of the `string` type-level string, for the derive macros,
+/// which generate code as a string.
+///
+/// This is used instead of generating `TS` invocations,
+/// so that derives don't expand a macro for every use of every name,
+/// which is expensive for long names.
+fn tstr_type_string(crate_path: &str, string: &str) -> String {
+    let crate_path = derive::parse_output(crate_path);
+    let tstr = TStr {
+        string: string.to_string(),
+        span: Span::call_site(),
+    };
+
+    let mut ty = TokenStream::new();
+    output_tstr(&crate_path, &tstr, &mut ty);
+    ty.to_string()
+}
+
+/// Derives conversions between a struct and the equivalent anonymous record.
+///
+/// For more details, you can look at the docs for the `tstr::records::IntoRecord` trait.
+#[proc_macro_derive(IntoRecord, attributes(tstr))]
+pub fn derive_into_record(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive::derive_into_record(TokenStream::from(input_tokens))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives the `tstr::Fields` trait, with reflection information about the fields of a struct.
+///
+/// For more details, you can look at the docs for the `tstr::Fields` trait.
+#[proc_macro_derive(Fields, attributes(tstr))]
+pub fn derive_fields(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive::derive_fields(TokenStream::from(input_tokens))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives the `tstr::Columns` trait, with the `TStr`s of the column names of a struct.
+///
+/// For more details, you can look at the docs for the `tstr::Columns` trait.
+#[proc_macro_derive(Columns, attributes(tstr, columns))]
+pub fn derive_columns(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive::derive_columns(TokenStream::from(input_tokens))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives the `tstr::VisitFields` trait, for visiting the named fields of a struct.
+///
+/// For more details, you can look at the docs for the `tstr::VisitFields` trait.
+#[proc_macro_derive(VisitFields, attributes(tstr))]
+pub fn derive_visit_fields(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive::derive_visit_fields(TokenStream::from(input_tokens))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives the `tstr::TStrKeys` trait and related items for enums,
+/// mapping between the variants and the `TStr`s of their names.
+///
+/// For more details, you can look at the docs for the `tstr::TStrKeys` trait.
+#[proc_macro_derive(TStrKeys, attributes(tstr))]
+pub fn derive_tstr_keys(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive::derive_tstr_keys(TokenStream::from(input_tokens))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives the `tstr::IntoVariant` and `tstr::VariantAccess` traits for enums,
+/// for accessing the fields of each variant by the `TStr` of its name.
+///
+/// For more details, you can look at the docs for the `tstr::VariantAccess` trait.
+#[proc_macro_derive(VariantAccess, attributes(tstr))]
+pub fn derive_variant_access(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive::derive_variant_access(TokenStream::from(input_tokens))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Implementation of the `tstr::TS_file` macro.
+#[cfg(feature = "rust_1_88")]
+#[allow(non_snake_case)]
+#[proc_macro]
+pub fn TS_file(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    location::output_file("TS", input_tokens)
+}
+
+/// Implementation of the `tstr::ts_file` macro.
+#[cfg(feature = "rust_1_88")]
+#[proc_macro]
+pub fn ts_file(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    location::output_file("ts", input_tokens)
+}
+
+/// Implementation of the `tstr::TS_line` macro.
+#[cfg(feature = "rust_1_88")]
+#[allow(non_snake_case)]
+#[proc_macro]
+pub fn TS_line(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    location::output_line("TS", input_tokens)
+}
+
+/// Implementation of the `tstr::ts_line` macro.
+#[cfg(feature = "rust_1_88")]
+#[proc_macro]
+pub fn ts_line(input_tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    location::output_line("ts", input_tokens)
+}
+
+/// Caches the type of each distinct string in a single macro invocation,
+/// so that strings that are repeated in that invocation are only encoded once.
+///
+/// The cached type has the span of the first occurrence of the string,
+/// which is where errors about the type of a repeated string point at.
+///
+/// This uses a `BTreeMap` instead of a `HashMap`,
+/// so that no randomly seeded hasher can affect the output of the macros,
+/// which must be the same for the same input (for incremental compilation and build caches).
+struct OutputCache {
+    types: BTreeMap<String, TokenStream>,
+    output_tstr: OutputTStr,
+}
+
+impl OutputCache {
+    fn new(output_tstr: OutputTStr) -> Self {
+        Self {
+            types: BTreeMap::new(),
+            output_tstr,
+        }
+    }
+}
+
+fn output_arg(crate_path: &TokenStream, arg: &Arg, cache: &mut OutputCache, out: &mut TokenStream) {
+    use crate::utils::{paren, punct_token};
+
+    match arg {
+        Arg::Str(tstr) => output_cached_tstr(crate_path, tstr, cache, out),
+        Arg::Path(segments) => {
+            let tt = paren(arg.span(), |out| {
+                for tstr in segments {
+                    output_cached_tstr(crate_path, tstr, cache, out);
+                    out.extend(punct_token(',', tstr.span));
+                }
+            });
+            out.extend(iter::once(tt));
         }
-        Err(e) => e.to_compile_error(),
     }
-    .into()
+}
+
+fn output_cached_tstr(
+    crate_path: &TokenStream,
+    tstr: &TStr,
+    cache: &mut OutputCache,
+    out: &mut TokenStream,
+) {
+    let output_tstr = cache.output_tstr;
+    let ty = cache.types.entry(tstr.string.clone()).or_insert_with(|| {
+        let mut ty = TokenStream::new();
+        output_tstr(crate_path, tstr, &mut ty);
+        ty
+    });
+    out.extend(ty.clone());
 }
 
 fn output_tstr(crate_path: &TokenStream, tstr: &TStr, out: &mut TokenStream) {
@@ -108,14 +434,38 @@ fn output_tstr_param(_crate_path: &TokenStream, tstr: &TStr, out: &mut TokenStre
     let string = tstr.string.as_str();
     let span = tstr.span;
 
-    let mut lit = Literal::string(&string);
+    let mut lit = Literal::string(string);
     lit.set_span(span);
     out.extend(iter::once(TokenTree::from(lit)));
 }
 
 struct Inputs {
     crate_path: TokenStream,
-    strings: Vec<TStr>,
+    args: Vec<Arg>,
+}
+
+/// An argument to the `TS` macro
+enum Arg {
+    Str(TStr),
+    /// A dotted path (eg: `foo.bar.baz`), with at least two segments
+    Path(Vec<TStr>),
+}
+
+impl Arg {
+    fn from_segments(mut segments: Vec<TStr>) -> Self {
+        if segments.len() == 1 {
+            Arg::Str(segments.remove(0))
+        } else {
+            Arg::Path(segments)
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Arg::Str(tstr) => tstr.span,
+            Arg::Path(segments) => segments[0].span,
+        }
+    }
 }
 
 struct TStr {
@@ -0,0 +1,101 @@
+//! The macros that get the location that they're invoked at.
+//!
+//! These are implemented as proc macros that are invoked directly
+//! (instead of through a `macro_rules!` macro in tstr),
+//! because `Span::call_site()` is the location of the macro that invokes the proc macro.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+pub(crate) fn output_file(macro_name: &str, input: TokenStream) -> TokenStream {
+    let span = Span::call_site();
+    match parse_crate_path(macro_name, input) {
+        Ok(crate_path) => output_with(crate_path, macro_name, Literal::string(&span.file()), span),
+        Err(e) => e,
+    }
+}
+
+pub(crate) fn output_line(macro_name: &str, input: TokenStream) -> TokenStream {
+    let span = Span::call_site();
+    match parse_crate_path(macro_name, input) {
+        Ok(crate_path) => output_with(
+            crate_path,
+            macro_name,
+            Literal::usize_unsuffixed(span.line()),
+            span,
+        ),
+        Err(e) => e,
+    }
+}
+
+/// Parses the optional `crate = path::to::tstr` argument, defaulting to `::tstr`.
+fn parse_crate_path(macro_name: &str, input: TokenStream) -> Result<TokenStream, TokenStream> {
+    let mut iter = input.into_iter();
+
+    let first = match iter.next() {
+        None => return Ok(path_tokens("::tstr", Span::call_site())),
+        Some(first) => first,
+    };
+
+    match (&first, iter.next()) {
+        (TokenTree::Ident(ident), Some(TokenTree::Punct(eq)))
+            if ident.to_string() == "crate" && eq.as_char() == '=' =>
+        {
+            let path = iter.collect::<TokenStream>();
+            if path.is_empty() {
+                Err(compile_error("Expected a path after `crate =`", eq.span()))
+            } else {
+                Ok(path)
+            }
+        }
+        _ => {
+            let message = format!(
+                "The `{}` macro only takes an optional `crate = path::to::tstr` argument",
+                macro_name,
+            );
+            Err(compile_error(&message, first.span()))
+        }
+    }
+}
+
+// Outputs `<crate_path>::TS!(<lit>)` or `<crate_path>::ts!(<lit>)`
+fn output_with(crate_path: TokenStream, macro_name: &str, lit: Literal, span: Span) -> TokenStream {
+    let mut out = crate_path;
+    out.extend(path_tokens(&format!("::{}", macro_name), span));
+    output_macro_args(&mut out, lit, span);
+    out
+}
+
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut out = path_tokens("::core::compile_error", span);
+    output_macro_args(&mut out, Literal::string(message), span);
+    out
+}
+
+// Outputs `!(<lit>)`
+fn output_macro_args(out: &mut TokenStream, mut lit: Literal, span: Span) {
+    lit.set_span(span);
+
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    out.extend(Some(TokenTree::from(bang)));
+
+    let mut group = Group::new(Delimiter::Parenthesis, TokenTree::from(lit).into());
+    group.set_span(span);
+    out.extend(Some(TokenTree::from(group)));
+}
+
+// Tokenizes a path that starts with `::`
+fn path_tokens(path: &str, span: Span) -> TokenStream {
+    let mut out = TokenStream::new();
+    for segment in path.trim_start_matches("::").split("::") {
+        let mut colon = Punct::new(':', Spacing::Joint);
+        colon.set_span(span);
+        out.extend(Some(TokenTree::from(colon.clone())));
+        let mut colon = Punct::new(':', Spacing::Alone);
+        colon.set_span(span);
+        out.extend(Some(TokenTree::from(colon)));
+
+        out.extend(Some(TokenTree::from(Ident::new(segment, span))));
+    }
+    out
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use crate::{
     used_proc_macro::{Span, TokenStream},
     utils::{char_token, colon2_token, ident_token, punct_token},
@@ -12,16 +14,46 @@ pub(crate) fn output_tstr_param(crate_path: &TokenStream, tstr: &TStr, out: &mut
         &string,
         span,
         &mut |string, ts| {
-            write_chars(ts, string, crate_path, span);
+            write_chars(ts, string, Some(crate_path), span);
         },
     ));
 }
 
-fn write_chars(ts: &mut TokenStream, string: &[char], crate_path: &TokenStream, span: Span) {
-    const TY: &[&str; 9] = &["", "__a", "__b", "__c", "__d", "__e", "__f", "__g", "__"];
+/// Outputs the `TStr` type parameter in this representation,
+/// naming the chunks of chars without a path,
+/// for code that imports the names added to `imports`.
+pub(crate) fn output_imported_tstr_param(
+    tstr: &TStr,
+    out: &mut TokenStream,
+    imports: &mut BTreeSet<&'static str>,
+) {
+    let string = tstr.string.chars().collect::<Vec<char>>();
+    let span = tstr.span;
 
-    ts.extend(crate_path.clone());
-    ts.extend(colon2_token(span));
+    out.extend(crate::nested_tuple_compute::compute(
+        &string,
+        span,
+        &mut |string, ts| {
+            imports.insert(TY[string.len()]);
+            write_chars(ts, string, None, span);
+        },
+    ));
+}
+
+/// The names of the types that contain 1 to 8 chars.
+const TY: &[&str; 9] = &["", "__a", "__b", "__c", "__d", "__e", "__f", "__g", "__"];
+
+/// Outputs a chunk of up to 8 chars, with `crate_path` before its name, if it's passed.
+fn write_chars(
+    ts: &mut TokenStream,
+    string: &[char],
+    crate_path: Option<&TokenStream>,
+    span: Span,
+) {
+    if let Some(crate_path) = crate_path {
+        ts.extend(crate_path.clone());
+        ts.extend(colon2_token(span));
+    }
     ts.extend(ident_token(TY[string.len()], span));
     ts.extend(punct_token('<', span));
     for &c in string {
@@ -1,12 +1,55 @@
 use std::iter;
 
+#[cfg(not(feature = "min_const_generics"))]
+use std::collections::BTreeSet;
+
 use crate::{
     used_proc_macro::{Span, TokenStream},
     utils::{colon2_token, ident_token, paren, punct_token},
     TStr,
 };
 
+#[cfg(not(feature = "min_const_generics"))]
 pub(super) fn output_tstr_param(crate_path: &TokenStream, tstr: &TStr, out: &mut TokenStream) {
+    output_bytes(Some(crate_path), tstr, out, &|b| {
+        BYTE_NAME[b as usize].into()
+    });
+}
+
+/// Outputs the `TStr` type parameter in this representation,
+/// naming the bytes without a path, for code that imports the names added to `imports`.
+#[cfg(not(feature = "min_const_generics"))]
+pub(super) fn output_imported_tstr_param(
+    tstr: &TStr,
+    out: &mut TokenStream,
+    imports: &mut BTreeSet<&'static str>,
+) {
+    imports.extend(tstr.string.bytes().map(|b| BYTE_NAME[b as usize]));
+    output_bytes(None, tstr, out, &|b| BYTE_NAME[b as usize].into());
+}
+
+/// Outputs the `TStr` type in this representation,
+/// naming every byte `__0xNN`, since the aliases of bytes
+/// are only defined when this is the only representation.
+#[cfg(feature = "dual_repr")]
+pub(super) fn output_tupled_tstr(crate_path: &TokenStream, tstr: &TStr, out: &mut TokenStream) {
+    let span = tstr.span;
+    out.extend(crate_path.clone());
+    out.extend(colon2_token(span));
+    out.extend(ident_token("TStr", span));
+    out.extend(punct_token('<', span));
+    output_bytes(Some(crate_path), tstr, out, &|b| format!("__0x{:02X}", b));
+    out.extend(punct_token('>', span));
+}
+
+/// Outputs the nested tuples of the bytes of `tstr`,
+/// with `crate_path` before the name of every byte, if it's passed.
+fn output_bytes(
+    crate_path: Option<&TokenStream>,
+    tstr: &TStr,
+    out: &mut TokenStream,
+    byte_name: &dyn Fn(u8) -> String,
+) {
     let string = tstr.string.as_str();
     let span = tstr.span;
     let string = string.as_bytes();
@@ -14,16 +57,24 @@ pub(super) fn output_tstr_param(crate_path: &TokenStream, tstr: &TStr, out: &mut
     out.extend(crate::nested_tuple_compute::compute(
         string,
         span,
-        &mut |string, ts| write_bytes(ts, string, crate_path, span),
+        &mut |string, ts| write_bytes(ts, string, crate_path, span, byte_name),
     ));
 }
 
-fn write_bytes(ts: &mut TokenStream, string: &[u8], crate_path: &TokenStream, span: Span) {
+fn write_bytes(
+    ts: &mut TokenStream,
+    string: &[u8],
+    crate_path: Option<&TokenStream>,
+    span: Span,
+    byte_name: &dyn Fn(u8) -> String,
+) {
     let tt = paren(span, |ts| {
         for &b in string {
-            ts.extend(crate_path.clone());
-            ts.extend(colon2_token(span));
-            ts.extend(ident_token(BYTE_NAME[b as usize], span));
+            if let Some(crate_path) = crate_path {
+                ts.extend(crate_path.clone());
+                ts.extend(colon2_token(span));
+            }
+            ts.extend(ident_token(&byte_name(b), span));
             ts.extend(punct_token(',', span));
         }
     });
@@ -31,6 +82,7 @@ fn write_bytes(ts: &mut TokenStream, string: &[u8], crate_path: &TokenStream, sp
     ts.extend(iter::once(tt));
 }
 
+#[cfg(not(feature = "min_const_generics"))]
 const BYTE_NAME: [&str; 256] = [
     "__0x00", "__0x01", "__0x02", "__0x03", "__0x04", "__0x05", "__0x06", "__0x07", "__0x08",
     "__0x09", "__0x0A", "__0x0B", "__0x0C", "__0x0D", "__0x0E", "__0x0F", "__0x10", "__0x11",
@@ -1,14 +1,20 @@
-use std::iter::once;
+use std::iter::Peekable;
 
 #[allow(unused_imports)]
 use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
-use proc_macro::token_stream::IntoIter as TSIterator;
+type TSIterator = Peekable<proc_macro::token_stream::IntoIter>;
 
-use super::{Inputs, TStr};
+use super::{Arg, Inputs, TStr};
+
+use crate::{
+    format_args::format_tstr,
+    type_path::{is_punct, is_type_path_start, TypePathTokens},
+    utils::Error,
+};
 
 pub(crate) fn parse_inputs(ts: TokenStream) -> Result<Inputs, Error> {
-    let iter = &mut ts.into_iter();
+    let iter = &mut ts.into_iter().peekable();
 
     let crate_path = match iter.next() {
         Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
@@ -28,40 +34,182 @@ pub(crate) fn parse_inputs(ts: TokenStream) -> Result<Inputs, Error> {
         }
     };
 
-    let mut strings = Vec::<TStr>::with_capacity(1);
+    let mut args = Vec::<Arg>::with_capacity(1);
+
+    while let Some(x) = parse_arg(iter)? {
+        args.push(x);
+
+        match iter.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == ',' => {}
+            Some(x) => {
+                return Err(Error::new(
+                    x.span(),
+                    &format!("Expected `,` or the end of the arguments, found: {}", x),
+                ))
+            }
+            None => break,
+        }
 
-    while let Some(x) = parse_tstr(iter)? {
-        strings.push(x);
+        // trailing commas
+        if is_punct(iter.peek(), ',') {
+            for x in iter.by_ref() {
+                assert_punct(Some(x), ',', Span::call_site())?;
+            }
+        }
     }
 
-    Ok(Inputs {
-        crate_path,
-        strings,
-    })
+    Ok(Inputs { crate_path, args })
+}
+
+fn parse_arg(iter: &mut TSIterator) -> Result<Option<Arg>, Error> {
+    let starts_type_path = is_type_path_start(iter.clone());
+
+    match iter.peek() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::None => {
+            let inner = &mut group.stream().into_iter().peekable();
+            iter.next();
+
+            let arg = parse_arg(inner)?;
+            if let Some(x) = inner.next() {
+                return Err(Error::new(
+                    x.span(),
+                    &format!("Expected `.` or the end of the argument, found: {}", x),
+                ));
+            }
+            Ok(arg)
+        }
+        _ if starts_type_path => {
+            let mut tokens = TypePathTokens::new();
+            while let Some(tt) = iter.peek() {
+                if tokens.ends_at(tt) {
+                    break;
+                }
+                tokens.push(tt.clone());
+                iter.next();
+            }
+            Ok(Some(Arg::Str(tokens.into_tstr())))
+        }
+        _ => {
+            let mut segments = Vec::<TStr>::with_capacity(1);
+
+            match parse_tstr(iter)? {
+                Some(tstr) => segments.push(tstr),
+                None => return Ok(None),
+            }
+
+            while let Some(TokenTree::Punct(p)) = iter.peek() {
+                if p.as_char() != '.' {
+                    break;
+                }
+                let dot_span = p.span();
+                iter.next();
+
+                parse_path_segments(iter, dot_span, &mut segments)?;
+            }
+
+            Ok(Some(Arg::from_segments(segments)))
+        }
+    }
+}
+
+// Parses the segment(s) after a `.` in a path,
+// `foo.0.1` is tokenized as `foo` `.` `0.1`, requiring `0.1` to be split into two segments.
+fn parse_path_segments(
+    iter: &mut TSIterator,
+    dot_span: Span,
+    segments: &mut Vec<TStr>,
+) -> Result<(), Error> {
+    if let Some(TokenTree::Literal(lit)) = iter.peek() {
+        let string = lit.to_string();
+        if let Some(dot) = string.find('.') {
+            let span = lit.span();
+            let (left, right) = (&string[..dot], &string[dot + 1..]);
+            let is_index = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+            if !is_index(left) || !is_index(right) {
+                return Err(Error::new(
+                    span,
+                    &format!("Expected path segment after `.`, found: {}", string),
+                ));
+            }
+            iter.next();
+
+            segments.push(TStr {
+                string: left.to_string(),
+                span,
+            });
+            segments.push(TStr {
+                string: right.to_string(),
+                span,
+            });
+            return Ok(());
+        }
+    }
+
+    match parse_tstr(iter)? {
+        Some(tstr) => {
+            segments.push(tstr);
+            Ok(())
+        }
+        None => Err(Error::new(dot_span, "Expected path segment after `.`")),
+    }
 }
 
 fn parse_tstr(iter: &mut TSIterator) -> Result<Option<TStr>, Error> {
-    const IN_MSG: &str = "Expected one of: string literal, integer literal, identifier";
+    const IN_MSG: &str =
+        "Expected one of: string literal, integer literal, float literal, negative number, identifier";
     match iter.next() {
         Some(TokenTree::Ident(ident)) => {
             let mut string = ident.to_string();
             if string == "concat" {
-                let (span, ts) = parse_post_macro_name(iter)?;
+                let (span, ts) = parse_post_macro_name(iter, &ident)?;
 
                 let mut string = String::new();
-                let iter = &mut ts.into_iter();
+                let iter = &mut ts.into_iter().peekable();
 
-                while let Some(tstr) = parse_tstr(iter)? {
+                while let Some(tstr) = parse_concat_arg(iter)? {
                     string.push_str(&tstr.string);
 
                     if let sep @ Some(_) = iter.next() {
-                        assert_punct(sep, ',')?;
+                        assert_punct(sep, ',', tstr.span)?;
+                    }
+                }
+
+                Ok(Some(TStr { string, span }))
+            } else if string == "format" && is_punct(iter.peek(), '!') {
+                let (span, ts) = parse_post_macro_name(iter, &ident)?;
+                let iter = &mut ts.into_iter().peekable();
+
+                let template = match iter.next() {
+                    Some(TokenTree::Literal(lit))
+                        if lit.to_string().starts_with(['"', 'r'].as_ref()) =>
+                    {
+                        parse_literal(lit)?
+                    }
+                    Some(x) => {
+                        return Err(Error::new(
+                            x.span(),
+                            &format!("Expected a string literal, found: {}", x),
+                        ))
+                    }
+                    None => return Err(Error::new(span, "Expected a string literal")),
+                };
+
+                let mut args = Vec::new();
+                while let sep @ Some(_) = iter.next() {
+                    let prev_span = args.last().map_or(template.span, |arg: &TStr| arg.span);
+                    assert_punct(sep, ',', prev_span)?;
+                    match parse_format_arg(iter)? {
+                        Some(arg) => args.push(arg),
+                        None => break,
                     }
                 }
 
+                let string = format_tstr(&template, &args, span)
+                    .map_err(|(span, msg)| Error::new(span, &msg))?;
+
                 Ok(Some(TStr { string, span }))
             } else if string == "stringify" {
-                let (span, ts) = parse_post_macro_name(iter)?;
+                let (span, ts) = parse_post_macro_name(iter, &ident)?;
 
                 let string = ts.to_string();
 
@@ -79,16 +227,167 @@ fn parse_tstr(iter: &mut TSIterator) -> Result<Option<TStr>, Error> {
             }
         }
         Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::None => {
-            parse_tstr(&mut group.stream().into_iter())
+            parse_tstr(&mut group.stream().into_iter().peekable())
+        }
+        Some(TokenTree::Literal(lit)) => match parse_float_literal(&lit) {
+            Some(tstr) => Ok(Some(tstr)),
+            None => parse_literal(lit).map(Some),
+        },
+        Some(TokenTree::Punct(p)) if p.as_char() == '-' => {
+            let minus_span = p.span();
+            match iter.next() {
+                Some(TokenTree::Literal(lit))
+                    if lit.to_string().starts_with(|c: char| c.is_ascii_digit()) =>
+                {
+                    let mut tstr = match parse_float_literal(&lit) {
+                        Some(tstr) => tstr,
+                        None => parse_literal(lit)?,
+                    };
+                    tstr.string.insert(0, '-');
+                    tstr.span = minus_span;
+                    Ok(Some(tstr))
+                }
+                _ => Err(Error::new(
+                    minus_span,
+                    "Expected a number literal after `-`",
+                )),
+            }
         }
-        Some(TokenTree::Literal(lit)) => parse_literal(lit).map(Some),
         Some(x) => Err(Error::new(x.span(), &format!("{}\nFound: {}", IN_MSG, x))),
         None => Ok(None),
     }
 }
 
-fn parse_post_macro_name(iter: &mut TSIterator) -> Result<(Span, TokenStream), Error> {
-    let bang_span = assert_punct(iter.next(), '!')?;
+// Parses an argument of `concat!(...)`,
+// which also accepts char literals, like `std::concat`.
+fn parse_concat_arg(iter: &mut TSIterator) -> Result<Option<TStr>, Error> {
+    match iter.peek() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::None => {
+            let inner = &mut group.stream().into_iter().peekable();
+            iter.next();
+            parse_concat_arg(inner)
+        }
+        Some(TokenTree::Literal(_)) => match iter.next() {
+            Some(TokenTree::Literal(lit)) => parse_concat_literal(lit).map(Some),
+            _ => unreachable!(),
+        },
+        _ => parse_tstr(iter),
+    }
+}
+
+// Parses an argument of `format!(...)`,
+// which accepts the same arguments as `concat!(...)`, and `TS!(...)` or `ts!(...)`
+// with a single argument.
+fn parse_format_arg(iter: &mut TSIterator) -> Result<Option<TStr>, Error> {
+    let ident = match iter.peek() {
+        Some(TokenTree::Ident(ident)) => ident.clone(),
+        _ => return parse_concat_arg(iter),
+    };
+    let name = ident.to_string();
+    let mut lookahead = iter.clone();
+    lookahead.next();
+    if (name != "TS" && name != "ts") || !is_punct(lookahead.peek(), '!') {
+        return parse_concat_arg(iter);
+    }
+    iter.next();
+
+    let (span, ts) = parse_post_macro_name(iter, &ident)?;
+    let inner = &mut ts.into_iter().peekable();
+    let tstr = parse_tstr(inner)?.ok_or_else(|| Error::new(span, "Expected an argument"))?;
+    if let Some(x) = inner.next() {
+        return Err(Error::new(
+            x.span(),
+            &format!(
+                "Expected `{}!` to have a single argument, found: {}",
+                name, x
+            ),
+        ));
+    }
+    Ok(Some(tstr))
+}
+
+#[cfg(feature = "litrs_")]
+fn parse_concat_literal(lit: Literal) -> Result<TStr, Error> {
+    let span = lit.span();
+    match litrs::Literal::parse(lit.to_string()) {
+        Ok(litrs::Literal::Char(c)) => Ok(TStr {
+            string: c.value().to_string(),
+            span,
+        }),
+        _ => match parse_float_literal(&lit) {
+            Some(tstr) => Ok(tstr),
+            None => parse_literal(lit),
+        },
+    }
+}
+
+// Parses a float literal into its digits, without `_` separators or the type suffix.
+#[cfg(feature = "litrs_")]
+fn parse_float_literal(lit: &Literal) -> Option<TStr> {
+    match litrs::Literal::parse(lit.to_string()) {
+        Ok(litrs::Literal::Float(f)) => Some(TStr {
+            string: f.number_part().replace('_', ""),
+            span: lit.span(),
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(not(feature = "litrs_"))]
+fn parse_concat_literal(lit: Literal) -> Result<TStr, Error> {
+    let span = lit.span();
+    let string = lit.to_string();
+
+    if string.starts_with('\'') {
+        // char literals use the same escapes as string literals
+        let inner = &string[1..string.len() - 1];
+        let string = parse_string(&format!("\"{}\"", inner), span)?;
+        Ok(TStr { string, span })
+    } else {
+        match parse_float_literal(&lit) {
+            Some(tstr) => Ok(tstr),
+            None => parse_literal(lit),
+        }
+    }
+}
+
+// Parses a float literal into its digits, without `_` separators or the type suffix.
+#[cfg(not(feature = "litrs_"))]
+fn parse_float_literal(lit: &Literal) -> Option<TStr> {
+    let string = lit.to_string();
+    if is_float_literal(&string) {
+        let string = string
+            .trim_end_matches("f32")
+            .trim_end_matches("f64")
+            .replace('_', "");
+        Some(TStr {
+            string,
+            span: lit.span(),
+        })
+    } else {
+        None
+    }
+}
+
+#[cfg(not(feature = "litrs_"))]
+fn is_float_literal(string: &str) -> bool {
+    let is_prefixed = ["0x", "0o", "0b"].iter().any(|p| string.starts_with(p));
+    // the `e` in `usize`/`isize` isn't an exponent
+    let is_int_suffixed = string.contains(['u', 'i'].as_ref());
+
+    string.starts_with(|c: char| c.is_ascii_digit())
+        && !is_prefixed
+        && !is_int_suffixed
+        && (string.contains(['.', 'e', 'E'].as_ref())
+            || string.ends_with("f32")
+            || string.ends_with("f64"))
+}
+
+fn parse_post_macro_name(
+    iter: &mut TSIterator,
+    name: &Ident,
+) -> Result<(Span, TokenStream), Error> {
+    let bang_span = assert_punct(iter.next(), '!', name.span())?;
     match iter.next() {
         Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => {
             Ok((g.span(), g.stream()))
@@ -97,6 +396,38 @@ fn parse_post_macro_name(iter: &mut TSIterator) -> Result<(Span, TokenStream), E
     }
 }
 
+#[cfg(feature = "litrs_")]
+fn parse_literal(lit: Literal) -> Result<TStr, Error> {
+    let span = lit.span();
+    let string = lit.to_string();
+
+    let string = match litrs::Literal::parse(string.as_str()) {
+        Ok(litrs::Literal::String(lit)) => lit.value().to_string(),
+        Ok(litrs::Literal::Integer(lit)) => match lit.value::<u128>() {
+            Some(int) => int.to_string(),
+            None => {
+                return Err(Error::new(
+                    span,
+                    &format!("integer literal is larger than u128::MAX: {}", string),
+                ))
+            }
+        },
+        Ok(_) => {
+            return Err(Error::new(
+                span,
+                &format!(
+                    "Unsupported literal, expected a string, integer, or float literal, found: {}",
+                    string
+                ),
+            ))
+        }
+        Err(e) => return Err(Error::new(span, &format!("{}: {}", e, string))),
+    };
+
+    Ok(TStr { string, span })
+}
+
+#[cfg(not(feature = "litrs_"))]
 fn parse_literal(lit: Literal) -> Result<TStr, Error> {
     let span = lit.span();
     let string = lit.to_string();
@@ -105,13 +436,22 @@ fn parse_literal(lit: Literal) -> Result<TStr, Error> {
         parse_string(&string, span)?
     } else if string.starts_with('r') {
         parse_raw_string(&string, span)?
-    } else {
+    } else if string.starts_with(|c: char| c.is_ascii_digit()) {
         parse_integer(&string, span)?
+    } else {
+        return Err(Error::new(
+            span,
+            &format!(
+                "Unsupported literal, expected a string, integer, or float literal, found: {}",
+                string
+            ),
+        ));
     };
 
     Ok(TStr { string, span })
 }
 
+#[cfg(not(feature = "litrs_"))]
 fn parse_string(input: &str, span: Span) -> Result<String, Error> {
     if !input.ends_with('"') {
         return Err(Error::new(
@@ -120,19 +460,20 @@ fn parse_string(input: &str, span: Span) -> Result<String, Error> {
         ));
     }
 
-    let make_err = |rem: &str, error: &str| -> Error {
-        let pos = rem.as_ptr() as usize - input.as_ptr() as usize;
-
-        let upto = input[..pos]
-            .chars()
-            .rev()
-            .take(10)
-            .collect::<String>()
-            .chars()
-            .rev()
-            .collect::<String>();
+    // `esc` is the rest of the string literal, starting from the `\` of the escape,
+    // and `esc_len` is the amount of characters in the escape.
+    let make_err = |esc: &str, esc_len: usize, error: &str| -> Error {
+        let pos = esc.as_ptr() as usize - input.as_ptr() as usize;
+        let char_pos = input[..pos].chars().count();
+        let escape = esc.chars().take(esc_len).collect::<String>();
 
-        Error::new(span, &format!("Error: {}    After: {}", error, upto,))
+        Error::new(
+            span,
+            &format!(
+                "{}: `{}`, at character {} of the string literal",
+                error, escape, char_pos,
+            ),
+        )
     };
 
     let mut rem = &input[1..input.len() - 1];
@@ -148,75 +489,62 @@ fn parse_string(input: &str, span: Span) -> Result<String, Error> {
             break;
         }
 
-        // The byte after the '\\' character
-        let b = get_byte(rem, 1);
+        let esc = rem;
+
+        // The character after the '\\' character
+        let mut chars = rem[1..].chars();
+        let c = chars.next();
 
         // Now we're at the character right after the matched one.
-        rem = &rem[2..];
-
-        out.push(match b {
-            b'x' => {
-                if let Some(hex) = rem.get(..2) {
-                    let num = u8::from_str_radix(hex, 16)
-                        .ok()
-                        .filter(|&x| x < 128)
-                        .ok_or_else(|| {
-                            make_err(
-                                rem,
-                                &format!("expected values from \\x00 to \\x7F, found: {}", hex),
-                            )
-                        })?;
-                    out.push(num as char);
-                } else {
-                    return Err(make_err(rem, "invalid ascii escape"));
-                }
+        rem = chars.as_str();
+
+        out.push(match c {
+            Some('x') => {
+                let num = rem
+                    .get(..2)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .filter(|&x| x < 128)
+                    .ok_or_else(|| {
+                        make_err(esc, 4, "expected an ascii escape from \\x00 to \\x7F")
+                    })?;
+                out.push(num as char);
                 rem = &rem[2..];
                 continue;
             }
-            b'u' => {
-                if let Some(end_brace) = rem.bytes().position(|b| b == b'}') {
-                    let c: char = u32::from_str_radix(&rem[1..end_brace], 16)
-                        .ok()
-                        .and_then(std::char::from_u32)
-                        .ok_or_else(|| {
-                            make_err(
-                                rem,
-                                &format!("Invalid unicode escape: {}", &rem[..end_brace]),
-                            )
-                        })?;
-                    out.push(c);
-
-                    rem = &rem[end_brace + 1..];
-                } else {
-                    return Err(make_err(rem, "Expected closing brace for unicode escape"));
-                }
+            Some('u') => {
+                let end_brace = rem
+                    .find('}')
+                    .filter(|_| rem.starts_with('{'))
+                    .ok_or_else(|| make_err(esc, 3, "expected braces around unicode escape"))?;
+
+                let c: char = u32::from_str_radix(&rem[1..end_brace], 16)
+                    .ok()
+                    .and_then(std::char::from_u32)
+                    .ok_or_else(|| make_err(esc, end_brace + 3, "invalid unicode escape"))?;
+                out.push(c);
+
+                rem = &rem[end_brace + 1..];
                 continue;
             }
-            b'n' => '\n',
-            b'r' => '\r',
-            b't' => '\t',
-            b'\\' => '\\',
-            b'0' => '\0',
-            b'\'' => '\'',
-            b'"' => '"',
-            b'\r' | b'\n' => {
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('\\') => '\\',
+            Some('0') => '\0',
+            Some('\'') => '\'',
+            Some('"') => '"',
+            Some('\r') | Some('\n') => {
                 rem = rem.trim_start();
                 continue;
             }
-            _ => return Err(make_err(rem, "invalid escape")),
+            _ => return Err(make_err(esc, 2, "invalid escape")),
         });
     }
 
     Ok(out)
 }
 
-fn get_byte(s: &str, at: usize) -> u8 {
-    match s.as_bytes().get(at) {
-        Some(&x) => x,
-        None => 0,
-    }
-}
-
+#[cfg(not(feature = "litrs_"))]
 fn parse_raw_string(input: &str, span: Span) -> Result<String, Error> {
     let input = &input[1..];
 
@@ -247,6 +575,7 @@ fn parse_raw_string(input: &str, span: Span) -> Result<String, Error> {
     Ok(input[hash_count + 1..end_quote].to_string())
 }
 
+#[cfg(not(feature = "litrs_"))]
 fn parse_integer(input: &str, span: Span) -> Result<String, Error> {
     fn make_err(input: &str, span: Span) -> Error {
         Error::new(
@@ -256,10 +585,10 @@ fn parse_integer(input: &str, span: Span) -> Result<String, Error> {
     }
 
     let input = input.replace('_', "");
-    let input = input.as_str();
+    let input = strip_integer_suffix(&input, span)?;
 
     let input_bytes = input.as_bytes();
-    if input_bytes.get(0) == Some(&b'0') {
+    if input_bytes.first() == Some(&b'0') {
         let radix = match input_bytes.get(1) {
             Some(b'x') => 16,
             Some(b'o') => 8,
@@ -279,87 +608,36 @@ fn parse_integer(input: &str, span: Span) -> Result<String, Error> {
     .map(|i| i.to_string())
 }
 
-fn assert_punct(tt: Option<TokenTree>, c: char) -> Result<Span, Error> {
+// Removes the type suffix of an integer literal (eg: the `usize` in `42usize`),
+// suffixes can't be confused with hexadecimal digits because they start with `u` or `i`.
+#[cfg(not(feature = "litrs_"))]
+fn strip_integer_suffix(input: &str, span: Span) -> Result<&str, Error> {
+    const SUFFIXES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    match input.find(['u', 'i'].as_ref()) {
+        Some(pos) if SUFFIXES.contains(&&input[pos..]) => Ok(&input[..pos]),
+        Some(pos) => Err(Error::new(
+            span,
+            &format!("invalid suffix `{}` for integer literal", &input[pos..]),
+        )),
+        None => Ok(input),
+    }
+}
+
+// `prev_span` is the span of the token before `tt`,
+// used for the error when there's no token.
+fn assert_punct(tt: Option<TokenTree>, c: char, prev_span: Span) -> Result<Span, Error> {
     match tt {
         Some(TokenTree::Punct(p)) if p.as_char() == c => Ok(p.span()),
         Some(x) => Err(Error::new(
             x.span(),
             &format!("Expected `{}`, found `{}`", c, x),
         )),
-        None => Err(Error::new(Span::call_site(), "Expected some token")),
-    }
-}
-
-pub(crate) struct Error {
-    span: Span,
-    message: String,
-}
-
-impl Error {
-    fn new(span: Span, message: &str) -> Self {
-        Self {
-            span,
-            message: message.to_string(),
-        }
-    }
-
-    pub(crate) fn to_compile_error(&self) -> TokenStream {
-        let Error { ref message, span } = *self;
-
-        let mut out = TokenStream::new();
-
-        out.extend(crate::utils::ident_token("compile_error", span));
-
-        out.extend(crate::utils::punct_token('!', span));
-
-        let msg_paren = crate::utils::paren(span, |ts| {
-            let mut msg = Literal::string(message);
-            msg.set_span(self.span);
-            let msg = TokenTree::from(msg);
-            ts.extend(once(msg))
-        });
-        out.extend(once(msg_paren));
-
-        out
-    }
-}
-
-trait TokenTreeExt: Sized {
-    fn into_token_tree(self) -> TokenTree;
-
-    fn set_span_recursive(self, span: Span) -> TokenTree {
-        let mut tt = self.into_token_tree();
-
-        tt.set_span(span);
-        if let TokenTree::Group(group) = tt {
-            let delim = group.delimiter();
-            let stream = group.stream().set_span_recursive(span);
-            tt = TokenTree::Group(Group::new(delim, stream));
-        }
-        tt.set_span(span);
-        tt
-    }
-}
-
-impl TokenTreeExt for TokenTree {
-    fn into_token_tree(self) -> TokenTree {
-        self
-    }
-}
-
-pub trait TokenStreamExt: Sized {
-    fn into_token_stream(self) -> TokenStream;
-
-    fn set_span_recursive(self, span: Span) -> TokenStream {
-        self.into_token_stream()
-            .into_iter()
-            .map(|tt| tt.set_span_recursive(span))
-            .collect()
-    }
-}
-
-impl TokenStreamExt for TokenStream {
-    fn into_token_stream(self) -> TokenStream {
-        self
+        None => Err(Error::new(
+            prev_span,
+            &format!("Expected `{}` after this", c),
+        )),
     }
 }
@@ -0,0 +1,212 @@
+//! Tests that the macros output the same tokens for the same input,
+//! so that incremental compilation and build caches can reuse the code that uses them.
+
+use crate::{output_tstr, ts_impl, ts_value::output_value_items};
+
+use proc_macro2::{TokenStream, TokenTree};
+
+fn ts_output(input: &str) -> String {
+    let input = input.parse::<TokenStream>().unwrap();
+    match ts_impl(input, output_tstr) {
+        Ok((_, ty)) => ty.to_string(),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+fn ts_value_output(input: &str) -> TokenStream {
+    output_value_items(input.parse::<TokenStream>().unwrap())
+}
+
+/// The number of tokens in `tokens`, including the tokens inside of groups.
+fn count_tokens(tokens: TokenStream) -> usize {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Group(g) => 1 + count_tokens(g.stream()),
+            _ => 1,
+        })
+        .sum()
+}
+
+type DeriveFn = fn(TokenStream) -> Result<TokenStream, crate::utils::Error>;
+
+fn derive_output(derive: DeriveFn, input: &str) -> String {
+    let input = input.parse::<TokenStream>().unwrap();
+    match derive(input) {
+        Ok(x) => x.to_string(),
+        Err(e) => panic!("{}", e.to_compile_error()),
+    }
+}
+
+#[test]
+fn ts_same_input_same_output() {
+    let inputs = [
+        "(::tstr) foo",
+        "(::tstr) \"hello, world\"",
+        "(::tstr) 100",
+        "(::tstr) foo.bar.0.1",
+        "(::tstr) a, b, a, \"a\", b",
+        "(::tstr) format!(\"{}_{1}\", foo, TS!(0))",
+        "(crate) \"a string that's longer than sixty four bytes, which requires nesting the tuples\"",
+    ];
+
+    for input in inputs.iter() {
+        let first = ts_output(input);
+        let first_value = ts_value_output(input).to_string();
+        for _ in 0..4 {
+            assert_eq!(ts_output(input), first, "input: {}", input);
+            assert_eq!(
+                ts_value_output(input).to_string(),
+                first_value,
+                "input: {}",
+                input
+            );
+        }
+    }
+}
+
+#[test]
+fn ts_equivalent_inputs_same_output() {
+    let equivalent: &[&[&str]] = &[
+        &[
+            "(::tstr) foo",
+            "(::tstr) \"foo\"",
+            "(::tstr) r\"foo\"",
+            "(::tstr) concat!(\"fo\", \"o\")",
+            "(::tstr) stringify!(foo)",
+        ],
+        &[
+            "(::tstr) 10",
+            "(::tstr) \"10\"",
+            "(::tstr) 10usize",
+            "(::tstr) 0xAu8",
+            "(::tstr) 1_0_i64",
+        ],
+        &[
+            "(::tstr) 1.5",
+            "(::tstr) \"1.5\"",
+            "(::tstr) 1_.5_f64",
+            "(::tstr) concat!(1, \".\", 5)",
+        ],
+        &[
+            "(::tstr) -5",
+            "(::tstr) \"-5\"",
+            "(::tstr) -0x5",
+            "(::tstr) concat!(-5)",
+            "(::tstr) concat!(\"-\", 5)",
+        ],
+        &[
+            "(::tstr) true",
+            "(::tstr) \"true\"",
+            "(::tstr) concat!(true)",
+            "(::tstr) stringify!(true)",
+        ],
+    ];
+
+    for inputs in equivalent {
+        let first = ts_output(inputs[0]);
+        for input in &inputs[1..] {
+            assert_eq!(ts_output(input), first, "input: {}", input);
+        }
+    }
+}
+
+// The types of repeated strings are cached within an invocation,
+// which must not affect the output.
+#[test]
+fn ts_cached_same_output() {
+    let strip = |s: String| s.replace(' ', "");
+
+    let foo = strip(ts_output("(::tstr) foo"));
+    let bar = strip(ts_output("(::tstr) bar"));
+
+    assert_eq!(
+        strip(ts_output("(::tstr) foo, bar, foo, foo")),
+        format!("({f},{b},{f},{f},)", f = foo, b = bar),
+    );
+    assert_eq!(
+        strip(ts_output("(::tstr) foo.bar.foo")),
+        format!("({f},{b},{f},)", f = foo, b = bar),
+    );
+}
+
+// The items that the `ts` macro is expanded to
+// declare the type of every distinct string once,
+// without the path to the tstr crate before every byte.
+#[test]
+fn ts_value_expansion_size() {
+    let long = format!(
+        "\"{}\"",
+        "the quick brown fox jumps over the lazy dog ".repeat(3)
+    );
+    let len = long.len() - 2;
+    assert!(len >= 100);
+
+    let single = format!("(::tstr) {}", long);
+    let single_size = count_tokens(ts_value_output(&single));
+    assert!(
+        single_size < len * 3,
+        "{} tokens for {} bytes",
+        single_size,
+        len
+    );
+    // the bytes are imported instead of being prefixed with the path to the tstr crate
+    #[cfg(not(feature = "min_const_generics"))]
+    assert!(single_size * 3 < count_tokens(ts_output(&single).parse().unwrap()) * 2);
+
+    // repeating the string only adds a reference to its alias, and a comma
+    let repeated = format!("(::tstr) {l}, {l}, {l}", l = long);
+    let repeated_size = count_tokens(ts_value_output(&repeated));
+    assert!(
+        repeated_size <= single_size + 8,
+        "{} tokens for three strings, {} for one",
+        repeated_size,
+        single_size,
+    );
+}
+
+#[test]
+fn key_aliases_declared_once() {
+    let mut aliases = crate::derive::KeyAliases::new("::tstr");
+    let foo = aliases.declare("foo");
+    let bar = aliases.declare("bar");
+    assert_ne!(foo, bar);
+    assert_eq!(aliases.declare("foo"), foo);
+    assert_eq!(aliases.declare("bar"), bar);
+
+    let code = aliases.wrap("");
+    assert_eq!(code.matches("type ").count(), 2, "{}", code);
+}
+
+#[test]
+fn derives_same_input_same_output() {
+    let struct_ = "
+        #[tstr(crate = ::foo::tstr)]
+        pub struct Foo<'a, T: Clone, const N: usize> where T: Copy {
+            pub a_rather_long_field_name: &'a T,
+            bar: [u8; N],
+            r#type: (),
+        }
+    ";
+    let enum_ = "
+        enum State<T> {
+            Idle,
+            WaitingForInput { timeout: T },
+            Failed(String),
+        }
+    ";
+
+    let derives: &[(&str, DeriveFn, &str)] = &[
+        ("Fields", crate::derive::derive_fields, struct_),
+        ("VisitFields", crate::derive::derive_visit_fields, struct_),
+        ("IntoRecord", crate::derive::derive_into_record, struct_),
+        ("TStrKeys", crate::derive::derive_tstr_keys, enum_),
+    ];
+
+    for &(name, derive, input) in derives {
+        let first = derive_output(derive, input);
+        for _ in 0..4 {
+            assert_eq!(derive_output(derive, input), first, "derive: {}", name);
+        }
+    }
+}
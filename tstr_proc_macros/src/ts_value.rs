@@ -0,0 +1,222 @@
+//! The implementation of the `ts` and `alias` macros,
+//! which output the items that the `TStr` types are declared with,
+//! inside a module that's only used by the macro invocation.
+//!
+//! Unlike the type that `__ts_impl` outputs, these are items,
+//! so the type of each distinct string is declared once as an alias,
+//! the same way that the derives do with `derive::KeyAliases`.
+//! The types that the strings are made of (eg: the bytes in the default representation)
+//! are also imported, instead of repeating the path to the tstr crate before each one.
+
+use std::{collections::BTreeMap, iter};
+
+#[cfg(not(feature = "const_generics"))]
+use std::collections::BTreeSet;
+
+#[allow(unused_imports)]
+use crate::used_proc_macro::{Delimiter, Span, TokenStream, TokenTree};
+
+use crate::{
+    utils::{brace, colon2_token, ident_token, paren, punct_token, Error},
+    Arg, Inputs, TStr,
+};
+
+/// The name of the type alias, declared in the module, of the `TStr`(s) of the arguments.
+const VALUE_ALIAS: &str = "__TStrValue";
+
+/// Outputs the contents of the module that the `ts` macro constructs its value from.
+pub(crate) fn output_value_items(input_tokens: TokenStream) -> TokenStream {
+    match crate::parse_inputs(input_tokens) {
+        Ok(inputs) => value_items(inputs),
+        Err(e) => e,
+    }
+}
+
+/// Outputs a type alias of the `TStr`(s) of the arguments, for the `alias` macro,
+/// along with the module that the type is declared in.
+///
+/// The input is `[<attributes>] (<visibility>) <name> (<crate_path>) <arguments>`.
+pub(crate) fn output_alias(input_tokens: TokenStream) -> TokenStream {
+    let mut iter = input_tokens.into_iter();
+    let (attrs, vis, name) = match (iter.next(), iter.next(), iter.next()) {
+        (
+            Some(TokenTree::Group(attrs)),
+            Some(TokenTree::Group(vis)),
+            Some(TokenTree::Ident(name)),
+        ) if attrs.delimiter() == Delimiter::Bracket
+            && vis.delimiter() == Delimiter::Parenthesis =>
+        {
+            (attrs.stream(), vis.stream(), name)
+        }
+        _ => {
+            let msg = "expected `[<attributes>] (<visibility>) <name>`";
+            return Error::new(Span::call_site(), msg).to_compile_error();
+        }
+    };
+    let items = match crate::parse_inputs(iter.collect()) {
+        Ok(inputs) => value_items(inputs),
+        Err(e) => return e,
+    };
+
+    let span = Span::call_site();
+    let module = format!("__tstr_alias_{}", name);
+    let mut out = TokenStream::new();
+
+    out.extend(attrs.clone());
+    out.extend(punct_token('#', span));
+    out.extend(iter::once(bracket_ident(span, "doc", "hidden")));
+    out.extend(punct_token('#', span));
+    out.extend(iter::once(bracket_ident(span, "allow", "non_snake_case")));
+    out.extend(ident_token("mod", span));
+    out.extend(ident_token(&module, span));
+    out.extend(iter::once(brace(span, |out| out.extend(items))));
+
+    out.extend(attrs);
+    out.extend(vis);
+    out.extend(ident_token("type", span));
+    out.extend(iter::once(TokenTree::from(name)));
+    out.extend(punct_token('=', span));
+    out.extend(ident_token(&module, span));
+    out.extend(colon2_token(span));
+    out.extend(ident_token(VALUE_ALIAS, span));
+    out.extend(punct_token(';', span));
+    out
+}
+
+/// Outputs `[<attribute>(<arg>)]`
+fn bracket_ident(span: Span, attribute: &str, arg: &str) -> TokenTree {
+    crate::utils::bracket(span, |out| {
+        out.extend(ident_token(attribute, span));
+        out.extend(iter::once(paren(span, |out| {
+            out.extend(ident_token(arg, span))
+        })));
+    })
+}
+
+/// Outputs the type aliases of the strings, followed by
+/// `pub type __TStrValue = <the type of the arguments>;`
+fn value_items(inputs: Inputs) -> TokenStream {
+    let Inputs { crate_path, args } = inputs;
+
+    let mut aliases = ValueAliases::new(&crate_path);
+    let mut value = TokenStream::new();
+    if args.len() == 1 {
+        output_arg(&args[0], &mut aliases, &mut value);
+    } else {
+        let tt = paren(Span::call_site(), |out| {
+            for arg in &args {
+                output_arg(arg, &mut aliases, out);
+                out.extend(punct_token(',', arg.span()));
+            }
+        });
+        value.extend(iter::once(tt));
+    }
+
+    let span = Span::call_site();
+    let mut out = aliases.into_items();
+    out.extend(ident_token("pub", span));
+    out.extend(ident_token("type", span));
+    out.extend(ident_token(VALUE_ALIAS, span));
+    out.extend(punct_token('=', span));
+    out.extend(value);
+    out.extend(punct_token(';', span));
+    out
+}
+
+fn output_arg(arg: &Arg, aliases: &mut ValueAliases<'_>, out: &mut TokenStream) {
+    match arg {
+        Arg::Str(tstr) => out.extend(aliases.declare(tstr)),
+        Arg::Path(segments) => {
+            let tt = paren(arg.span(), |out| {
+                for tstr in segments {
+                    out.extend(aliases.declare(tstr));
+                    out.extend(punct_token(',', tstr.span));
+                }
+            });
+            out.extend(iter::once(tt));
+        }
+    }
+}
+
+/// Type aliases of the `TStr`s of the distinct strings in a `ts` invocation.
+///
+/// This uses a `BTreeMap` instead of a `HashMap` for the same reason as `OutputCache`.
+struct ValueAliases<'a> {
+    crate_path: &'a TokenStream,
+    decls: TokenStream,
+    aliases: BTreeMap<String, String>,
+    /// The names that are imported from the tstr crate.
+    #[cfg(not(feature = "const_generics"))]
+    imports: BTreeSet<&'static str>,
+}
+
+impl<'a> ValueAliases<'a> {
+    fn new(crate_path: &'a TokenStream) -> Self {
+        Self {
+            crate_path,
+            decls: TokenStream::new(),
+            aliases: BTreeMap::new(),
+            #[cfg(not(feature = "const_generics"))]
+            imports: BTreeSet::new(),
+        }
+    }
+
+    /// Declares an alias of the type of `tstr` the first time that its string is passed,
+    /// returning the name of the alias.
+    fn declare(&mut self, tstr: &TStr) -> TokenStream {
+        let span = tstr.span;
+        if let Some(alias) = self.aliases.get(&tstr.string) {
+            return ident_token(alias, span).collect();
+        }
+
+        let alias = format!("__TStrKey{}", self.aliases.len());
+        self.decls.extend(ident_token("type", span));
+        self.decls.extend(ident_token(&alias, span));
+        self.decls.extend(punct_token('=', span));
+        self.output_tstr(tstr);
+        self.decls.extend(punct_token(';', span));
+
+        let out = ident_token(&alias, span).collect();
+        self.aliases.insert(tstr.string.clone(), alias);
+        out
+    }
+
+    #[cfg(not(feature = "const_generics"))]
+    fn output_tstr(&mut self, tstr: &TStr) {
+        let span = tstr.span;
+        self.decls.extend(self.crate_path.clone());
+        self.decls.extend(colon2_token(span));
+        self.decls.extend(ident_token("TStr", span));
+        self.decls.extend(punct_token('<', span));
+        crate::output_imported_tstr_param(tstr, &mut self.decls, &mut self.imports);
+        self.decls.extend(punct_token('>', span));
+    }
+
+    #[cfg(feature = "const_generics")]
+    fn output_tstr(&mut self, tstr: &TStr) {
+        crate::output_tstr(self.crate_path, tstr, &mut self.decls);
+    }
+
+    /// The import of the types that the strings are made of, followed by the aliases.
+    fn into_items(self) -> TokenStream {
+        let mut out = TokenStream::new();
+
+        #[cfg(not(feature = "const_generics"))]
+        if !self.imports.is_empty() {
+            let span = Span::call_site();
+            out.extend(ident_token("use", span));
+            out.extend(self.crate_path.clone());
+            out.extend(colon2_token(span));
+            out.extend(iter::once(brace(span, |out| {
+                for name in &self.imports {
+                    out.extend(ident_token(name, span));
+                    out.extend(punct_token(',', span));
+                }
+            })));
+            out.extend(punct_token(';', span));
+        }
+
+        out.extend(self.decls);
+        out
+    }
+}
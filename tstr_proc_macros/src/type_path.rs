@@ -0,0 +1,186 @@
+//! Type paths as arguments of the `TS` macro (eg: `TS!(Vec<u8>)`),
+//! which are stringified with the same spacing regardless of how they're spaced in the source.
+
+use crate::{
+    used_proc_macro::{Delimiter, Spacing, TokenTree},
+    TStr,
+};
+
+/// Keywords that are separated from a following `(...)` or `[...]` by a space,
+/// eg: `&mut [u8]`.
+const SPACED_KEYWORDS: &[&str] = &["const", "dyn", "impl", "mut"];
+
+/// Whether the tokens start a type path:
+/// an identifier followed by `::` or `<`, or a leading `::`.
+pub(crate) fn is_type_path_start<I>(mut iter: I) -> bool
+where
+    I: Iterator<Item = TokenTree>,
+{
+    match (iter.next(), iter.next()) {
+        (Some(TokenTree::Group(group)), _) if group.delimiter() == Delimiter::None => {
+            is_type_path_start(group.stream().into_iter())
+        }
+        (Some(TokenTree::Ident(ident)), Some(TokenTree::Punct(p))) => {
+            let ident = ident.to_string();
+            ident != "concat"
+                && ident != "stringify"
+                && (p.as_char() == '<' || p.as_char() == ':' && p.spacing() == Spacing::Joint)
+        }
+        (Some(TokenTree::Punct(p0)), Some(TokenTree::Punct(p1))) => {
+            p0.as_char() == ':' && p0.spacing() == Spacing::Joint && p1.as_char() == ':'
+        }
+        _ => false,
+    }
+}
+
+/// The tokens of a type path argument,
+/// which ends at the first `,` that isn't inside of `<...>`.
+pub(crate) struct TypePathTokens {
+    tokens: Vec<TokenTree>,
+    angle_depth: usize,
+}
+
+impl TypePathTokens {
+    pub(crate) fn new() -> Self {
+        Self {
+            tokens: Vec::new(),
+            angle_depth: 0,
+        }
+    }
+
+    /// Whether `next` is the `,` after the type path.
+    pub(crate) fn ends_at(&self, next: &TokenTree) -> bool {
+        match next {
+            TokenTree::Punct(p) => self.angle_depth == 0 && p.as_char() == ',',
+            _ => false,
+        }
+    }
+
+    pub(crate) fn push(&mut self, tt: TokenTree) {
+        if let TokenTree::Punct(p) = &tt {
+            match p.as_char() {
+                '<' => self.angle_depth += 1,
+                // the `>` in `->` doesn't close a `<`
+                '>' if !self.after_joint_minus() => {
+                    self.angle_depth = self.angle_depth.saturating_sub(1)
+                }
+                _ => {}
+            }
+        }
+        self.tokens.push(tt);
+    }
+
+    fn after_joint_minus(&self) -> bool {
+        match self.tokens.last() {
+            Some(TokenTree::Punct(p)) => p.as_char() == '-' && p.spacing() == Spacing::Joint,
+            _ => false,
+        }
+    }
+
+    pub(crate) fn into_tstr(self) -> TStr {
+        let span = self.tokens[0].span();
+        let mut string = String::new();
+        write_tokens(&mut string, &mut Prev::Start, self.tokens.into_iter());
+        TStr { string, span }
+    }
+}
+
+/// What the previous token was, to determine whether a space goes before the next one.
+#[derive(Copy, Clone, PartialEq)]
+enum Prev {
+    Start,
+    Word,
+    SpacedKeyword,
+    Punct,
+    // The `'` of a lifetime
+    Quote,
+    // Tokens that are followed by a space, eg: `,`
+    SpaceAfter,
+}
+
+fn write_tokens<I>(out: &mut String, prev: &mut Prev, iter: I)
+where
+    I: Iterator<Item = TokenTree>,
+{
+    let mut iter = iter.peekable();
+
+    while let Some(tt) = iter.next() {
+        match tt {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::None => {
+                        write_tokens(out, prev, group.stream().into_iter());
+                        continue;
+                    }
+                    Delimiter::Parenthesis => ('(', ')'),
+                    Delimiter::Bracket => ('[', ']'),
+                    Delimiter::Brace => ('{', '}'),
+                };
+
+                if *prev == Prev::SpacedKeyword || *prev == Prev::SpaceAfter {
+                    out.push(' ');
+                }
+                out.push(open);
+                write_tokens(out, &mut Prev::Start, group.stream().into_iter());
+                out.push(close);
+                *prev = Prev::Word;
+            }
+            TokenTree::Ident(ident) => {
+                let ident = ident.to_string();
+                push_word(out, prev, &ident);
+                if SPACED_KEYWORDS.contains(&&*ident) {
+                    *prev = Prev::SpacedKeyword;
+                }
+            }
+            TokenTree::Literal(lit) => push_word(out, prev, &lit.to_string()),
+            TokenTree::Punct(p) => match p.as_char() {
+                ',' | ';' => {
+                    out.push(p.as_char());
+                    *prev = Prev::SpaceAfter;
+                }
+                '-' if p.spacing() == Spacing::Joint && is_punct(iter.peek(), '>') => {
+                    iter.next();
+                    out.push_str(" ->");
+                    *prev = Prev::SpaceAfter;
+                }
+                '+' | '=' => {
+                    out.push(' ');
+                    out.push(p.as_char());
+                    *prev = Prev::SpaceAfter;
+                }
+                '\'' => {
+                    push_space_before_word(out, *prev);
+                    out.push('\'');
+                    *prev = Prev::Quote;
+                }
+                c => {
+                    if *prev == Prev::SpaceAfter {
+                        out.push(' ');
+                    }
+                    out.push(c);
+                    *prev = Prev::Punct;
+                }
+            },
+        }
+    }
+}
+
+fn push_word(out: &mut String, prev: &mut Prev, word: &str) {
+    push_space_before_word(out, *prev);
+    out.push_str(word);
+    *prev = Prev::Word;
+}
+
+fn push_space_before_word(out: &mut String, prev: Prev) {
+    match prev {
+        Prev::Word | Prev::SpacedKeyword | Prev::SpaceAfter => out.push(' '),
+        Prev::Start | Prev::Punct | Prev::Quote => {}
+    }
+}
+
+pub(crate) fn is_punct(tt: Option<&TokenTree>, c: char) -> bool {
+    match tt {
+        Some(TokenTree::Punct(p)) => p.as_char() == c,
+        _ => false,
+    }
+}
@@ -1,13 +1,17 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Span, TokenStream, TokenTree};
 
 use syn::{
     ext::IdentExt,
     parenthesized,
     parse::{Parse, ParseBuffer, ParseStream},
-    LitInt, LitStr,
+    LitChar, LitFloat, LitInt, LitStr,
 };
 
-use super::{Inputs, TStr};
+use super::{
+    format_args::format_tstr,
+    type_path::{is_type_path_start, TypePathTokens},
+    Arg, Inputs, TStr,
+};
 
 impl Parse for Inputs {
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
@@ -16,15 +20,78 @@ impl Parse for Inputs {
 
         let crate_path = content.parse::<proc_macro2::TokenStream>()?;
 
-        let mut strings = Vec::<TStr>::new();
+        let mut args = Vec::<Arg>::new();
         while !input.is_empty() {
-            strings.push(input.parse()?);
+            args.push(input.parse()?);
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<syn::Token!(,)>()?;
+
+            // trailing commas
+            if input.peek(syn::Token!(,)) {
+                while !input.is_empty() {
+                    input.parse::<syn::Token!(,)>()?;
+                }
+            }
         }
 
-        Ok(Self {
-            crate_path,
-            strings,
-        })
+        Ok(Self { crate_path, args })
+    }
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if is_type_path_start(peek_token_trees(input)) {
+            let mut tokens = TypePathTokens::new();
+            while let Some((tt, _)) = input.cursor().token_tree() {
+                if tokens.ends_at(&tt) {
+                    break;
+                }
+                tokens.push(input.parse::<TokenTree>()?);
+            }
+            return Ok(Arg::Str(tokens.into_tstr()));
+        }
+
+        let mut segments = vec![input.parse::<TStr>()?];
+
+        while input.peek(syn::Token!(.)) {
+            input.parse::<syn::Token!(.)>()?;
+
+            // `foo.0.1` is tokenized as `foo` `.` `0.1`,
+            // requiring `0.1` to be split into two segments.
+            if input.peek(LitFloat) {
+                let lit = input.parse::<LitFloat>()?;
+                let span = lit.span();
+                let digits = lit.to_string();
+
+                let is_index = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+                match digits
+                    .find('.')
+                    .map(|dot| (&digits[..dot], &digits[dot + 1..]))
+                {
+                    Some((left, right)) if is_index(left) && is_index(right) => {
+                        for string in [left, right].iter() {
+                            segments.push(TStr {
+                                string: string.to_string(),
+                                span,
+                            });
+                        }
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            span,
+                            format!("Expected path segment after `.`, found: {}", digits),
+                        ))
+                    }
+                }
+            } else {
+                segments.push(input.parse::<TStr>()?);
+            }
+        }
+
+        Ok(Arg::from_segments(segments))
     }
 }
 
@@ -37,7 +104,7 @@ impl Parse for TStr {
             let mut value = String::new();
 
             while !content.is_empty() {
-                let tstr = content.parse::<TStr>()?;
+                let tstr = parse_concat_arg(&content)?;
 
                 value.push_str(&tstr.string);
 
@@ -47,12 +114,34 @@ impl Parse for TStr {
             }
 
             (value, span)
+        } else if input.peek(kw::format) && input.peek2(syn::Token!(!)) {
+            input.parse::<kw::format>()?;
+            let (span, content) = parse_post_macro_name(input)?;
+
+            let lit = content.parse::<LitStr>()?;
+            let template = TStr {
+                string: lit.value(),
+                span: lit.span(),
+            };
+
+            let mut args = Vec::new();
+            while !content.is_empty() {
+                content.parse::<syn::Token!(,)>()?;
+                if content.is_empty() {
+                    break;
+                }
+                args.push(parse_format_arg(&content)?);
+            }
+
+            let string = format_tstr(&template, &args, span)
+                .map_err(|(span, msg)| syn::Error::new(span, msg))?;
+            (string, span)
         } else if lookahead.peek(kw::stringify) {
             input.parse::<kw::stringify>()?;
             let (span, content) = parse_post_macro_name(input)?;
             (content.parse::<TokenStream>()?.to_string(), span)
         } else if lookahead.peek(syn::Ident::peek_any) {
-            let ident = input.parse::<syn::Ident>()?;
+            let ident = syn::Ident::parse_any(input)?;
             let mut value = ident.to_string();
             if value.starts_with("r#") {
                 value.drain(..2);
@@ -64,6 +153,20 @@ impl Parse for TStr {
         } else if lookahead.peek(LitInt) {
             let lit = input.parse::<LitInt>()?;
             (lit.base10_digits().to_string(), lit.span())
+        } else if lookahead.peek(LitFloat) {
+            let lit = input.parse::<LitFloat>()?;
+            (lit.base10_digits().to_string(), lit.span())
+        } else if lookahead.peek(syn::Token!(-)) {
+            let minus = input.parse::<syn::Token!(-)>()?;
+            let lookahead = input.lookahead1();
+            let digits = if lookahead.peek(LitInt) {
+                input.parse::<LitInt>()?.base10_digits().to_string()
+            } else if lookahead.peek(LitFloat) {
+                input.parse::<LitFloat>()?.base10_digits().to_string()
+            } else {
+                return Err(lookahead.error());
+            };
+            (format!("-{}", digits), minus.span)
         } else {
             return Err(lookahead.error());
         };
@@ -71,6 +174,54 @@ impl Parse for TStr {
     }
 }
 
+// Parses an argument of `concat!(...)`,
+// which also accepts bool and char literals, like `std::concat`.
+fn parse_concat_arg(input: ParseStream) -> syn::Result<TStr> {
+    let lookahead = input.lookahead1();
+    if lookahead.peek(LitChar) {
+        let lit = input.parse::<LitChar>()?;
+        Ok(TStr {
+            string: lit.value().to_string(),
+            span: lit.span(),
+        })
+    } else if lookahead.peek(syn::LitBool) {
+        let lit = input.parse::<syn::LitBool>()?;
+        Ok(TStr {
+            string: lit.value.to_string(),
+            span: lit.span,
+        })
+    } else {
+        input.parse::<TStr>()
+    }
+}
+
+// Parses an argument of `format!(...)`,
+// which accepts the same arguments as `concat!(...)`, and `TS!(...)` or `ts!(...)`
+// with a single argument.
+fn parse_format_arg(input: ParseStream) -> syn::Result<TStr> {
+    if (input.peek(kw::TS) || input.peek(kw::ts)) && input.peek2(syn::Token!(!)) {
+        input.parse::<proc_macro2::Ident>()?;
+        let (_, content) = parse_post_macro_name(input)?;
+        let tstr = content.parse::<TStr>()?;
+        if !content.is_empty() {
+            return Err(content.error("expected a single argument"));
+        }
+        Ok(tstr)
+    } else {
+        parse_concat_arg(input)
+    }
+}
+
+// The token trees after the cursor of `input`, without advancing it.
+fn peek_token_trees(input: ParseStream<'_>) -> impl Iterator<Item = TokenTree> + '_ {
+    let mut cursor = input.cursor();
+    std::iter::from_fn(move || {
+        let (tt, next) = cursor.token_tree()?;
+        cursor = next;
+        Some(tt)
+    })
+}
+
 fn parse_post_macro_name(input: ParseStream) -> syn::Result<(Span, ParseBuffer)> {
     input.parse::<syn::Token!(!)>()?;
     let content;
@@ -80,5 +231,8 @@ fn parse_post_macro_name(input: ParseStream) -> syn::Result<(Span, ParseBuffer)>
 
 mod kw {
     syn::custom_keyword!(concat);
+    syn::custom_keyword!(format);
     syn::custom_keyword!(stringify);
+    syn::custom_keyword!(ts);
+    syn::custom_keyword!(TS);
 }
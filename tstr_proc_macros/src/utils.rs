@@ -11,6 +11,19 @@ pub(crate) fn ident_token(ident: &str, span: Span) -> Once<TokenTree> {
     iter::once(tt)
 }
 
+/// Whether `ident` is the `name` identifier,
+/// `proc_macro2::Ident` can be compared without allocating a string.
+#[cfg(feature = "proc_macro2_")]
+pub(crate) fn ident_is(ident: &Ident, name: &str) -> bool {
+    ident == name
+}
+
+/// Whether `ident` is the `name` identifier.
+#[cfg(not(feature = "proc_macro2_"))]
+pub(crate) fn ident_is(ident: &Ident, name: &str) -> bool {
+    ident.to_string() == name
+}
+
 #[cfg(all(feature = "min_const_generics", not(feature = "const_generics")))]
 pub(crate) fn char_token(c: char, span: Span) -> Once<TokenTree> {
     let mut lit = Literal::character(c);
@@ -32,12 +45,67 @@ pub(crate) fn colon2_token(span: Span) -> TokenStream {
 }
 
 pub(crate) fn paren<F>(span: Span, f: F) -> TokenTree
+where
+    F: FnOnce(&mut TokenStream),
+{
+    group(Delimiter::Parenthesis, span, f)
+}
+
+pub(crate) fn bracket<F>(span: Span, f: F) -> TokenTree
+where
+    F: FnOnce(&mut TokenStream),
+{
+    group(Delimiter::Bracket, span, f)
+}
+
+pub(crate) fn brace<F>(span: Span, f: F) -> TokenTree
+where
+    F: FnOnce(&mut TokenStream),
+{
+    group(Delimiter::Brace, span, f)
+}
+
+fn group<F>(delimiter: Delimiter, span: Span, f: F) -> TokenTree
 where
     F: FnOnce(&mut TokenStream),
 {
     let mut ts = TokenStream::new();
     f(&mut ts);
-    let mut tt = Group::new(Delimiter::Parenthesis, ts);
+    let mut tt = Group::new(delimiter, ts);
     tt.set_span(span);
     TokenTree::from(tt)
 }
+
+pub(crate) struct Error {
+    span: Span,
+    message: String,
+}
+
+impl Error {
+    pub(crate) fn new(span: Span, message: &str) -> Self {
+        Self {
+            span,
+            message: message.to_string(),
+        }
+    }
+
+    pub(crate) fn to_compile_error(&self) -> TokenStream {
+        let Error { ref message, span } = *self;
+
+        let mut out = TokenStream::new();
+
+        out.extend(ident_token("compile_error", span));
+
+        out.extend(punct_token('!', span));
+
+        let msg_paren = paren(span, |ts| {
+            let mut msg = Literal::string(message);
+            msg.set_span(self.span);
+            let msg = TokenTree::from(msg);
+            ts.extend(iter::once(msg))
+        });
+        out.extend(iter::once(msg_paren));
+
+        out
+    }
+}